@@ -1,16 +1,65 @@
 use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use argon2::password_hash::{rand_core::OsRng, SaltString};
 use std::collections::HashMap;
 use std::fs;
+use std::io::Write;
 use std::path::Path;
 use crate::forge::ForgeCharacter;
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
+
+pub mod profile;
+pub use profile::*;
+
+// Replace a save file without ever leaving a half-written one behind. The content
+// goes to a temporary file that is flushed to disk and then renamed over the
+// target, so a crash or power loss keeps either the old file or the new one.
+// With a backup path the old file is copied there first.
+pub fn write_atomically(path: &Path, content: &str, backup_path: Option<&Path>) -> Result<()> {
+    let mut temp_name = path.file_name()
+        .ok_or_else(|| anyhow!("{} is not a file path", path.display()))?
+        .to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    let mut file = fs::File::create(&temp_path)
+        .with_context(|| format!("Failed to create {}", temp_path.display()))?;
+    file.write_all(content.as_bytes())
+        .and_then(|_| file.sync_all())
+        .with_context(|| format!("Failed to write {}", temp_path.display()))?;
+
+    if let Some(backup_path) = backup_path.filter(|_| path.exists()) {
+        fs::copy(path, backup_path)
+            .with_context(|| format!("Failed to back up {}", path.display()))?;
+    }
+    fs::rename(&temp_path, path)
+        .with_context(|| format!("Failed to rename {} to {}", temp_path.display(), path.display()))?;
+    Ok(())
+}
+
+// Salted Argon2id, deliberately slow to guess against
+fn hash_password(password: &str) -> Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default().hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|error| anyhow!("Failed to hash password: {}", error))
+}
+
+// How passwords were stored before Argon2: a single salted SHA-256
+fn legacy_hash(password: &str, salt: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(password.as_bytes());
+    hasher.update(salt.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterRecord {
     pub character: ForgeCharacter,
-    pub password_hash: String,
-    pub salt: String,
+    pub password_hash: String, // Argon2 hash string, which carries its own salt
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub salt: String, // Only set on records still using the old SHA-256 hash
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,40 +75,74 @@ impl CharacterDatabase {
     }
 
     pub fn load_or_create(path: &Path) -> Result<Self> {
-        if path.exists() {
-            let data = fs::read_to_string(path)?;
-            
-            // Try to load with current format first
-            match serde_json::from_str::<CharacterDatabase>(&data) {
-                Ok(db) => Ok(db),
-                Err(_) => {
-                    // If that fails, try to migrate from old format
-                    println!("🔄 Migrating character data to new format with magic system...");
-                    
-                    // Backup old file
-                    let backup_path = path.with_extension("json.backup");
-                    fs::copy(path, &backup_path)?;
-                    println!("📁 Backed up old data to: {}", backup_path.display());
-                    
-                    // Try to load as old format and migrate
-                    let migrated_db = Self::migrate_from_old_format(&data)?;
-                    
-                    // Save migrated data
-                    migrated_db.save(path)?;
-                    println!("✅ Migration complete!");
-                    
-                    Ok(migrated_db)
-                }
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+
+        match Self::load(path) {
+            Ok(db) => Ok(db),
+            Err(error) => Self::restore_backup(path, error),
+        }
+    }
+
+    fn load(path: &Path) -> Result<Self> {
+        let data = fs::read_to_string(path)?;
+        
+        // Try to load with current format first
+        match serde_json::from_str::<CharacterDatabase>(&data) {
+            Ok(db) => Ok(db),
+            Err(_) => {
+                // If that fails, try to migrate from old format
+                println!("🔄 Migrating character data to new format with magic system...");
+                
+                // Try to load as old format and migrate
+                let migrated_db = Self::migrate_from_old_format(&data)?;
+                
+                // Backup old file
+                let backup_path = Self::backup_path(path);
+                fs::copy(path, &backup_path)?;
+                println!("📁 Backed up old data to: {}", backup_path.display());
+                
+                // Save migrated data
+                migrated_db.save(path)?;
+                println!("✅ Migration complete!");
+                
+                Ok(migrated_db)
             }
-        } else {
-            Ok(Self::new())
         }
     }
 
+    // A damaged database (say, cut short by a crash) falls back to the copy kept by the last save
+    fn restore_backup(path: &Path, error: anyhow::Error) -> Result<Self> {
+        let backup_path = Self::backup_path(path);
+        let backup = fs::read_to_string(&backup_path).ok()
+            .and_then(|data| serde_json::from_str::<CharacterDatabase>(&data).ok());
+        let Some(db) = backup else {
+            return Err(error.context(format!(
+                "{} is damaged and there is no usable backup at {}",
+                path.display(), backup_path.display()
+            )));
+        };
+
+        // Keep the damaged file for inspection instead of overwriting it
+        let damaged_path = path.with_extension("json.damaged");
+        fs::rename(path, &damaged_path)?;
+        println!("⚠️ {} could not be read, restored the last good copy from {}", path.display(), backup_path.display());
+        println!("📁 The damaged file was moved to: {}", damaged_path.display());
+
+        db.save(path)?;
+        Ok(db)
+    }
+
+    // Writes to a temporary file first so a crash never leaves a half-written
+    // database, and keeps the previous save around as a backup
     pub fn save(&self, path: &Path) -> Result<()> {
         let data = serde_json::to_string_pretty(self)?;
-        fs::write(path, data)?;
-        Ok(())
+        write_atomically(path, &data, Some(&Self::backup_path(path)))
+    }
+
+    fn backup_path(path: &Path) -> std::path::PathBuf {
+        path.with_extension("json.bak")
     }
 
     pub fn create_character(&mut self, name: String, password: String, character: ForgeCharacter) -> Result<()> {
@@ -67,26 +150,33 @@ impl CharacterDatabase {
             return Err(anyhow!("Character with name '{}' already exists", name));
         }
 
-        let salt = format!("{:x}", rand::random::<u64>());
-        let password_hash = self.hash_password(&password, &salt);
-
         let record = CharacterRecord {
             character,
-            password_hash,
-            salt,
+            password_hash: hash_password(&password)?,
+            salt: String::new(),
         };
 
         self.characters.insert(name, record);
         Ok(())
     }
 
-    pub fn authenticate(&self, name: &str, password: &str) -> Result<ForgeCharacter> {
-        let record = self.characters.get(name)
+    // Records saved with the old SHA-256 hash are upgraded to Argon2 on a
+    // successful login. Save the database afterwards to keep the upgrade.
+    pub fn authenticate(&mut self, name: &str, password: &str) -> Result<ForgeCharacter> {
+        let record = self.characters.get_mut(name)
             .ok_or_else(|| anyhow!("Character '{}' not found", name))?;
 
-        let expected_hash = self.hash_password(password, &record.salt);
-        if expected_hash != record.password_hash {
-            return Err(anyhow!("Invalid password"));
+        if record.salt.is_empty() {
+            let hash = PasswordHash::new(&record.password_hash)
+                .map_err(|error| anyhow!("Stored password for '{}' is unreadable: {}", name, error))?;
+            Argon2::default().verify_password(password.as_bytes(), &hash)
+                .map_err(|_| anyhow!("Invalid password"))?;
+        } else {
+            if legacy_hash(password, &record.salt) != record.password_hash {
+                return Err(anyhow!("Invalid password"));
+            }
+            record.password_hash = hash_password(password)?;
+            record.salt.clear();
         }
 
         Ok(record.character.clone())
@@ -100,9 +190,9 @@ impl CharacterDatabase {
         Ok(())
     }
 
-    pub fn list_characters(&self) -> Vec<(String, chrono::DateTime<chrono::Utc>)> {
+    pub fn list_characters(&self) -> Vec<(String, chrono::DateTime<chrono::Utc>, crate::forge::PlayStats)> {
         self.characters.iter()
-            .map(|(name, record)| (name.clone(), record.character.last_played))
+            .map(|(name, record)| (name.clone(), record.character.last_played, record.character.stats.clone()))
             .collect()
     }
 
@@ -112,13 +202,6 @@ impl CharacterDatabase {
         Ok(())
     }
 
-    fn hash_password(&self, password: &str, salt: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(password.as_bytes());
-        hasher.update(salt.as_bytes());
-        format!("{:x}", hasher.finalize())
-    }
-    
     fn migrate_from_old_format(data: &str) -> Result<Self> {
         use serde_json::Value;
         
@@ -207,4 +290,79 @@ impl CharacterDatabase {
         let migrated_db: CharacterDatabase = serde_json::from_value(json)?;
         Ok(migrated_db)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge::sample_character;
+    use std::path::PathBuf;
+
+    fn scratch_file() -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("warlords-db-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&directory).unwrap();
+        directory.join("characters.json")
+    }
+
+    fn database_with(name: &str) -> CharacterDatabase {
+        let mut database = CharacterDatabase::new();
+        database.create_character(name.to_string(), "secret".to_string(), sample_character(name)).unwrap();
+        database
+    }
+
+    #[test]
+    fn a_truncated_database_is_restored_from_its_backup() {
+        let path = scratch_file();
+        database_with("Aria").save(&path).unwrap();
+        database_with("Aria").save(&path).unwrap();
+        let data = fs::read_to_string(&path).unwrap();
+        fs::write(&path, &data[..data.len() / 2]).unwrap();
+
+        let database = CharacterDatabase::load_or_create(&path).unwrap();
+        assert!(database.characters.contains_key("Aria"));
+        // The damaged file is kept aside and a good copy written in its place
+        assert!(path.with_extension("json.damaged").exists());
+        assert!(CharacterDatabase::load(&path).is_ok());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn a_truncated_database_without_a_backup_is_an_error() {
+        let path = scratch_file();
+        database_with("Aria").save(&path).unwrap();
+        let data = fs::read_to_string(&path).unwrap();
+        fs::write(&path, &data[..data.len() / 2]).unwrap();
+
+        assert!(CharacterDatabase::load_or_create(&path).is_err());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn a_write_that_fails_part_way_leaves_the_previous_file_intact() {
+        let path = scratch_file();
+        write_atomically(&path, "first", None).unwrap();
+        // A directory where the temporary file should go makes the write fail before the rename
+        let temp_path = path.with_file_name("characters.json.tmp");
+        fs::create_dir(&temp_path).unwrap();
+
+        assert!(write_atomically(&path, "second", Some(&path.with_extension("json.bak"))).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first");
+        assert!(!path.with_extension("json.bak").exists());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn a_leftover_temporary_file_from_a_crash_is_ignored_and_replaced() {
+        let path = scratch_file();
+        database_with("Aria").save(&path).unwrap();
+        // What a crash mid-write leaves behind
+        let temp_path = path.with_file_name("characters.json.tmp");
+        fs::write(&temp_path, "{\"characters\": {").unwrap();
+
+        assert!(CharacterDatabase::load_or_create(&path).unwrap().characters.contains_key("Aria"));
+        database_with("Brom").save(&path).unwrap();
+        assert!(!temp_path.exists());
+        assert!(CharacterDatabase::load(&path).unwrap().characters.contains_key("Brom"));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,66 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{Result, anyhow};
+
+pub const PROFILES_DIR: &str = "profiles"; // Each named profile gets its own folder in here
+
+// Where a campaign keeps its characters and world. Without a name the saves
+// live in the working directory, as they always have.
+#[derive(Debug, Clone, Default)]
+pub struct SaveProfile {
+    name: Option<String>,
+}
+
+impl SaveProfile {
+    pub fn new(name: Option<&str>) -> Result<Self> {
+        let Some(name) = name else {
+            return Ok(Self::default());
+        };
+        // The name becomes a folder, so keep it to something that can't escape the profiles directory
+        let valid = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(anyhow!("Profile names may only use letters, digits, '-' and '_': '{}'", name));
+        }
+        Ok(Self { name: Some(name.to_string()) })
+    }
+
+    fn root(&self) -> PathBuf {
+        match &self.name {
+            Some(name) => Path::new(PROFILES_DIR).join(name),
+            None => PathBuf::from("."),
+        }
+    }
+
+    pub fn database_path(&self) -> PathBuf {
+        self.root().join("characters.json")
+    }
+
+    pub fn world_dir(&self) -> PathBuf {
+        self.root().join("world_data")
+    }
+
+    // Make sure a named profile's folder exists before anything is saved into it
+    pub fn prepare(&self) -> Result<()> {
+        if self.name.is_some() {
+            fs::create_dir_all(self.root())?;
+        }
+        Ok(())
+    }
+
+    // Names of the profiles that have been played, sorted
+    pub fn list() -> Result<Vec<String>> {
+        let dir = Path::new(PROFILES_DIR);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut names = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                names.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+}
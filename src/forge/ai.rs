@@ -0,0 +1,267 @@
+use rand::Rng;
+use crate::world::CreatureType;
+use super::{CombatAction, CombatEncounter, CombatResult};
+
+// Cowards try to run once their hit points drop below this share of the max
+pub const COWARD_FLEE_HP_PERCENT: u32 = 35;
+// Any creature with a sense of self-preservation runs below this share
+pub const FLEE_HP_PERCENT: u32 = 20;
+
+// How an enemy fights, picked from the kind of creature it is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AiProfile {
+    Standard,   // Goes straight for the first foe
+    Skirmisher, // Darts in to strike, then pulls back out of reach
+    Brute,      // Charges whoever has the weakest armor
+    Caster,     // Hurls magic instead of closing in
+    Pack,       // Gangs up on the most wounded foe
+    Coward,     // Runs once badly hurt
+}
+
+impl AiProfile {
+    pub fn for_creature(creature_type: Option<&CreatureType>) -> Self {
+        match creature_type {
+            Some(CreatureType::Bat | CreatureType::Spider) => AiProfile::Skirmisher,
+            Some(CreatureType::Zombie | CreatureType::Orc | CreatureType::Construct) => AiProfile::Brute,
+            Some(CreatureType::Ghost | CreatureType::GuardianSpirit) => AiProfile::Caster,
+            Some(CreatureType::WildAnimal | CreatureType::Rat) => AiProfile::Pack,
+            Some(CreatureType::Goblin | CreatureType::Bandit) => AiProfile::Coward,
+            Some(CreatureType::Skeleton) | None => AiProfile::Standard,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            AiProfile::Standard => "Standard",
+            AiProfile::Skirmisher => "Skirmisher",
+            AiProfile::Brute => "Brute",
+            AiProfile::Caster => "Caster",
+            AiProfile::Pack => "Pack Hunter",
+            AiProfile::Coward => "Coward",
+        }
+    }
+}
+
+// How cleverly a creature picks who to hit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreatureIntelligence {
+    Animal,   // Lashes out at whoever it happens to face
+    Mindless, // Plods toward the nearest foe
+    Cunning,  // Finishes off the wounded
+    Ancient,  // Goes after the most dangerous foe
+}
+
+impl CreatureIntelligence {
+    pub fn for_creature(creature_type: Option<&CreatureType>) -> Self {
+        match creature_type {
+            Some(CreatureType::Rat | CreatureType::Bat | CreatureType::Spider | CreatureType::WildAnimal) => CreatureIntelligence::Animal,
+            Some(CreatureType::Skeleton | CreatureType::Zombie | CreatureType::Construct) => CreatureIntelligence::Mindless,
+            Some(CreatureType::Ghost | CreatureType::GuardianSpirit) => CreatureIntelligence::Ancient,
+            Some(CreatureType::Goblin | CreatureType::Orc | CreatureType::Bandit) | None => CreatureIntelligence::Cunning,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            CreatureIntelligence::Animal => "Animal",
+            CreatureIntelligence::Mindless => "Mindless",
+            CreatureIntelligence::Cunning => "Cunning",
+            CreatureIntelligence::Ancient => "Ancient",
+        }
+    }
+
+    // Beasts and the mindless dead are stopped by a closed door
+    pub fn opens_doors(&self) -> bool {
+        matches!(self, CreatureIntelligence::Cunning | CreatureIntelligence::Ancient)
+    }
+}
+
+// What a computer controlled participant wants to do on its turn. Casters
+// hurl magic, skirmishers defend while pulling back and the rest attack.
+pub fn decide_action(encounter: &CombatEncounter, participant_index: usize) -> CombatAction {
+    let actor = &encounter.participants[participant_index];
+    let profile = AiProfile::for_creature(actor.creature_type.as_ref());
+    let intelligence = CreatureIntelligence::for_creature(actor.creature_type.as_ref());
+    let hp = &actor.combat_stats.hit_points;
+    let hp_percent = hp.current * 100 / hp.max.max(1);
+
+    // The mindless dead fight until they drop, everything else knows when it's beaten
+    let flee_below = match (profile, intelligence) {
+        (_, CreatureIntelligence::Mindless) => 0,
+        (AiProfile::Coward, _) => COWARD_FLEE_HP_PERCENT,
+        _ if actor.creature_type.is_some() => FLEE_HP_PERCENT,
+        _ => 0,
+    };
+    if hp_percent < flee_below {
+        return CombatAction::Flee;
+    }
+
+    let Some(target_index) = encounter.pick_ai_target(participant_index, profile, intelligence) else {
+        return CombatAction::Defend;
+    };
+
+    match profile {
+        // Strike on odd rounds, keep out of reach on even ones
+        AiProfile::Skirmisher if encounter.round.is_multiple_of(2) => CombatAction::Defend,
+        AiProfile::Caster => CombatAction::CastSpell { spell_name: "Dark Bolt".to_string(), target_index: Some(target_index) },
+        _ => CombatAction::Attack { target_index },
+    }
+}
+
+impl CombatEncounter {
+    // Let the current (computer controlled) participant act according to its profile
+    pub fn take_ai_turn(&mut self) -> CombatResult {
+        let actor_index = self.current_turn;
+        let profile = AiProfile::for_creature(self.participants[actor_index].creature_type.as_ref());
+
+        match decide_action(self, actor_index) {
+            CombatAction::Flee => {
+                let result = self.perform_action(CombatAction::Flee);
+                if result.success {
+                    self.participants[actor_index].fled = true;
+                }
+                result
+            }
+            CombatAction::Defend if profile == AiProfile::Skirmisher => {
+                let name = self.participants[actor_index].name.clone();
+                self.add_log(format!("🦇 {} darts back out of reach!", name));
+                CombatResult {
+                    success: true,
+                    damage: None,
+                    message: "Pulled back".to_string(),
+                    critical: false,
+                }
+            }
+            CombatAction::CastSpell { target_index: Some(target_index), .. } => self.perform_enemy_spell(actor_index, target_index),
+            action => self.perform_action(action),
+        }
+    }
+
+    // Brutes and packs have their own favourite victims, archers pick off whoever
+    // is closest to dropping and everyone else picks by wits. Foes are listed
+    // front line first, so the first one counts as the nearest.
+    fn pick_ai_target(&self, actor_index: usize, profile: AiProfile, intelligence: CreatureIntelligence) -> Option<usize> {
+        let own_side = self.participants[actor_index].side;
+        let foes: Vec<usize> = self.participants.iter()
+            .enumerate()
+            .filter(|(_, p)| p.is_alive() && p.side != own_side)
+            .map(|(i, _)| i)
+            .collect();
+        let hp_percent = |i: &usize| {
+            let hp = &self.participants[*i].combat_stats.hit_points;
+            hp.current * 100 / hp.max.max(1)
+        };
+        if self.participants[actor_index].ranged_weapon.is_some() && intelligence != CreatureIntelligence::Mindless {
+            return foes.into_iter().min_by_key(|&i| self.participants[i].combat_stats.hit_points.current);
+        }
+        match (profile, intelligence) {
+            (AiProfile::Brute, _) => foes.into_iter()
+                .min_by_key(|&i| self.participants[i].get_total_defense_value()),
+            (AiProfile::Pack, _) => foes.into_iter()
+                .min_by_key(|&i| self.participants[i].combat_stats.hit_points.current),
+            (_, CreatureIntelligence::Animal) if !foes.is_empty() => {
+                Some(foes[crate::rng::session_rng().gen_range(0..foes.len())])
+            }
+            (_, CreatureIntelligence::Cunning) => foes.into_iter().min_by_key(hp_percent),
+            (_, CreatureIntelligence::Ancient) => foes.into_iter()
+                .max_by_key(|&i| self.participants[i].get_total_attack_value()),
+            _ => foes.first().copied(),
+        }
+    }
+
+    // Spirits and other casters throw a bolt of raw magic that only a little armor stops
+    fn perform_enemy_spell(&mut self, caster_index: usize, target_index: usize) -> CombatResult {
+        let mut rng = crate::rng::session_rng();
+        let damage = rng.gen_range(1..=6) + self.participants[caster_index].combat_stats.attack_value as u32 / 4;
+        let caster_name = self.participants[caster_index].name.clone();
+        let target_name = self.participants[target_index].name.clone();
+        let report = self.participants[target_index].receive_hit(damage, 1);
+
+        self.add_log(format!("🔮 {} hurls a bolt of dark magic at {}: {}!", caster_name, target_name, report.describe(&target_name)));
+        if !self.participants[target_index].is_alive() {
+            self.add_log(format!("{} has been defeated by magic!", target_name));
+        }
+
+        CombatResult {
+            success: true,
+            damage: Some(report.hp_lost),
+            message: format!("Spell hit {}", target_name),
+            critical: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge::{create_goblin, create_orc, create_skeleton, create_zombie, CombatParticipant, CombatSide, Weapon};
+
+    fn hero(name: &str, max_hp: u32, current_hp: u32) -> CombatParticipant {
+        let mut hero = CombatParticipant::create_enemy(name, max_hp, 8, 8, Some(Weapon::rusty_sword())).with_side(CombatSide::Players);
+        hero.combat_stats.hit_points.current = current_hp;
+        hero
+    }
+
+    // Initiative shuffles the participants, so look them up by name
+    fn index_of(encounter: &CombatEncounter, name: &str) -> usize {
+        encounter.participants.iter().position(|p| p.name == name).expect("no such participant")
+    }
+
+    fn wounded(mut creature: CombatParticipant, current_hp: u32) -> CombatParticipant {
+        creature.combat_stats.hit_points.current = current_hp;
+        creature
+    }
+
+    #[test]
+    fn a_badly_hurt_creature_flees() {
+        // 4 of 25 is 16%, 6 of 25 is 24%
+        let encounter = CombatEncounter::new(vec![hero("Hero", 20, 20), wounded(create_orc(), 4)]);
+        assert!(matches!(decide_action(&encounter, index_of(&encounter, "Orc")), CombatAction::Flee));
+
+        let encounter = CombatEncounter::new(vec![hero("Hero", 20, 20), wounded(create_orc(), 6)]);
+        assert!(matches!(decide_action(&encounter, index_of(&encounter, "Orc")), CombatAction::Attack { .. }));
+    }
+
+    #[test]
+    fn skeletons_and_zombies_never_flee() {
+        for undead in [create_skeleton(), create_zombie()] {
+            let name = undead.name.clone();
+            let encounter = CombatEncounter::new(vec![hero("Hero", 20, 20), wounded(undead, 1)]);
+            assert!(matches!(decide_action(&encounter, index_of(&encounter, &name)), CombatAction::Attack { .. }), "{} fled", name);
+        }
+    }
+
+    #[test]
+    fn the_most_wounded_hero_is_targeted() {
+        let encounter = CombatEncounter::new(vec![hero("Fresh", 20, 18), hero("Bleeding", 20, 5), hero("Scratched", 20, 12), create_goblin()]);
+        let bleeding = index_of(&encounter, "Bleeding");
+        assert!(matches!(decide_action(&encounter, index_of(&encounter, "Goblin")), CombatAction::Attack { target_index } if target_index == bleeding));
+    }
+
+    #[test]
+    fn archers_and_casters_fight_differently_from_melee() {
+        // The giant has the fewest hit points left, the runt the smallest share of them
+        let heroes = || vec![hero("Giant", 40, 10), hero("Runt", 10, 6)];
+
+        let mut party = heroes();
+        party.push(create_goblin());
+        let encounter = CombatEncounter::new(party);
+        let giant = index_of(&encounter, "Giant");
+        assert!(matches!(decide_action(&encounter, index_of(&encounter, "Goblin")), CombatAction::Attack { target_index } if target_index == giant));
+
+        let mut archer = create_goblin();
+        archer.ranged_weapon = Some(Weapon::short_bow());
+        archer.ammo = 10;
+        let mut party = heroes();
+        party.push(archer);
+        let encounter = CombatEncounter::new(party);
+        let runt = index_of(&encounter, "Runt");
+        assert!(matches!(decide_action(&encounter, index_of(&encounter, "Goblin")), CombatAction::Attack { target_index } if target_index == runt));
+
+        let ghost = CombatParticipant::create_enemy("Ghost", 15, 6, 6, None).with_creature_type(CreatureType::Ghost);
+        let mut party = heroes();
+        party.push(ghost);
+        let encounter = CombatEncounter::new(party);
+        assert!(matches!(decide_action(&encounter, index_of(&encounter, "Ghost")), CombatAction::CastSpell { target_index: Some(_), .. }));
+    }
+}
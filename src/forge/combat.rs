@@ -1,6 +1,15 @@
 use serde::{Deserialize, Serialize};
 use rand::Rng;
-use super::{ForgeCharacter, CombatStats};
+use super::{ForgeCharacter, CombatStats, StatusEffect};
+use crate::world::CreatureType;
+
+pub const STAMINA_COST_SKILL_ATTACK: u32 = 2; // Trained strikes and shots put your back into it
+pub const STAMINA_COST_FLEE: u32 = 3;         // Sprinting away
+pub const STAMINA_DEFEND_RECOVERY: u32 = 2;   // Catching your breath behind your guard
+pub const DEFEND_DV_BONUS: u8 = 4;            // Defense added while guarding, until the defender's next turn
+pub const EXHAUSTED_PENALTY: i16 = 2;         // Attack and defense lost while out of stamina
+pub const STAMINA_COST_POWER_ATTACK: u32 = 3;
+pub const POWER_ATTACK: &str = "Power Attack";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum DamageType {
@@ -23,6 +32,48 @@ pub enum WeaponType {
     Unarmed,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AmmoType {
+    Arrow,
+    Bolt,
+}
+
+impl AmmoType {
+    // Inventory name; a stack is stored as e.g. "Arrows (20)"
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            AmmoType::Arrow => "Arrows",
+            AmmoType::Bolt => "Bolts",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        [AmmoType::Arrow, AmmoType::Bolt].into_iter().find(|ammo_type| ammo_type.get_name() == name)
+    }
+
+    pub fn count_in(&self, inventory: &[String]) -> u32 {
+        inventory.iter()
+            .filter_map(|item| self.stack_size(item))
+            .sum()
+    }
+
+    // Replace every stack of this ammo with a single stack of the given size
+    pub fn set_count_in(&self, inventory: &mut Vec<String>, count: u32) {
+        inventory.retain(|item| self.stack_size(item).is_none());
+        if count > 0 {
+            inventory.push(format!("{} ({})", self.get_name(), count));
+        }
+    }
+
+    fn stack_size(&self, item: &str) -> Option<u32> {
+        let rest = item.strip_prefix(self.get_name())?;
+        if rest.is_empty() {
+            return Some(1);
+        }
+        rest.trim().strip_prefix('(')?.strip_suffix(')')?.parse().ok()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Weapon {
     pub name: String,
@@ -83,15 +134,169 @@ impl Armor {
     }
 }
 
+// What happened to the defender when a hit landed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DamageReport {
+    pub raw: u32,          // Damage rolled, after bonuses and critical doubling
+    pub absorbed: u32,     // Taken by armor and shield
+    pub hp_lost: u32,
+    pub hp_remaining: u32,
+    pub hp_max: u32,
+}
+
+impl DamageReport {
+    // e.g. "9 rolled, 6 absorbed, 3 HP lost (Goblin 4/7 HP)"
+    pub fn describe(&self, target_name: &str) -> String {
+        format!("{} rolled, {} absorbed, {} HP lost ({} {}/{} HP)",
+            self.raw, self.absorbed, self.hp_lost, target_name, self.hp_remaining, self.hp_max)
+    }
+}
+
+// Percent chance that d20 + attack beats the defense. A natural 20 always hits.
+pub fn hit_chance(attack_total: u8, defense_value: u8) -> u32 {
+    let lowest_hitting_roll = (defense_value as i32 - attack_total as i32 + 1).clamp(1, 20);
+    (21 - lowest_hitting_roll) as u32 * 5
+}
+
+// A critical hit doubles the rolled damage. The number of damage dice (the
+// armor-piercing part in Forge) is unchanged so armor is only counted once.
+pub fn apply_critical(damage: u32, dice_count: u32, critical: bool) -> (u32, u32) {
+    if critical {
+        (damage * 2, dice_count)
+    } else {
+        (damage, dice_count)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreatLevel {
+    Easy,
+    Even,
+    Dangerous,
+    Deadly,
+}
+
+impl ThreatLevel {
+    // Compare the enemies' combined fighting strength against the player's
+    pub fn assess(player: &CombatParticipant, enemies: &[CombatParticipant]) -> Self {
+        let player_strength = player.fighting_strength().max(1) as f32;
+        let enemy_strength: u32 = enemies.iter().map(|e| e.fighting_strength()).sum();
+        let ratio = enemy_strength as f32 / player_strength;
+
+        if ratio < 0.5 {
+            ThreatLevel::Easy
+        } else if ratio < 1.0 {
+            ThreatLevel::Even
+        } else if ratio < 1.75 {
+            ThreatLevel::Dangerous
+        } else {
+            ThreatLevel::Deadly
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ThreatLevel::Easy => "an easy fight",
+            ThreatLevel::Even => "an even match",
+            ThreatLevel::Dangerous => "a dangerous fight",
+            ThreatLevel::Deadly => "likely a deadly fight",
+        }
+    }
+}
+
+// e.g. "a lone wounded Goblin" or "a group of 3: Wolf, Wolf and Bandit"
+pub fn describe_enemies(enemies: &[CombatParticipant]) -> String {
+    let describe = |enemy: &CombatParticipant| {
+        let hp = &enemy.combat_stats.hit_points;
+        if hp.current < hp.max {
+            format!("wounded {}", enemy.name)
+        } else {
+            enemy.name.clone()
+        }
+    };
+
+    match enemies {
+        [] => "nothing at all".to_string(),
+        [enemy] => format!("a lone {}", describe(enemy)),
+        [first, second] => format!("a pair: {} and {}", describe(first), describe(second)),
+        [rest @ .., last] => format!("a group of {}: {} and {}",
+            enemies.len(),
+            rest.iter().map(describe).collect::<Vec<_>>().join(", "),
+            describe(last)),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CombatParticipant {
     pub name: String,
     pub combat_stats: CombatStats,
     pub weapon: Option<Weapon>,
+    #[serde(default)]
+    pub ranged_weapon: Option<Weapon>, // Used by the Ranged Combat skill
+    #[serde(default)]
+    pub ammo: u32, // Arrows or bolts left for the ranged weapon
+    #[serde(default)]
+    pub ammo_spent: u32, // Shots fired this fight, some can be recovered afterwards
+    #[serde(default)]
+    pub creature_type: Option<CreatureType>, // Loot table rolled when defeated
     pub armor: Option<Armor>,
     pub shield: Option<Armor>,
     pub initiative: u8,
-    pub is_player: bool,
+    pub is_player: bool, // Controlled by the player rather than the AI
+    #[serde(default)]
+    pub side: CombatSide,
+    #[serde(default)]
+    pub fled: bool, // Ran from the fight, neither in it nor defeated
+    #[serde(default)]
+    pub status_effects: Vec<StatusEffect>, // Carried in from the character and written back afterwards
+    #[serde(default)]
+    pub active_effects: Vec<ActiveEffect>, // Spell buffs and debuffs, these end with the fight
+    #[serde(default)]
+    pub stamina: u32,
+    #[serde(default)]
+    pub max_stamina: u32, // 0 for creatures, which don't tire
+    #[serde(default)]
+    pub overloaded: bool, // Carrying more than they can, which slows their reactions
+    #[serde(default)]
+    pub guarding: bool, // Chose to defend, which lasts until their next turn
+}
+
+// A spell's hold on a participant's attack, defense or damage, counted down in rounds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveEffect {
+    pub source: String, // The spell that caused it
+    pub stat: String,   // "attack", "defense" or "damage"
+    pub modifier: i8,
+    pub rounds_remaining: u8,
+}
+
+impl ActiveEffect {
+    pub fn new(source: &str, stat: &str, modifier: i8, rounds: u8) -> Self {
+        Self {
+            source: source.to_string(),
+            stat: stat.to_string(),
+            modifier,
+            rounds_remaining: rounds,
+        }
+    }
+}
+
+// Which team a participant fights for. Minions and party members stand with the
+// player without being player controlled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CombatSide {
+    Players,
+    #[default]
+    Enemies,
+}
+
+impl CombatSide {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            CombatSide::Players => "Player",
+            CombatSide::Enemies => "Enemies",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -148,8 +353,91 @@ impl Weapon {
         }
     }
 
+    pub fn short_bow() -> Self {
+        Weapon {
+            name: "Short Bow".to_string(),
+            weapon_type: WeaponType::Bow,
+            damage_dice: "1d6".to_string(),
+            damage_type: DamageType::Piercing,
+            damage_bonus: 0,
+            attack_bonus: 1,
+            two_handed: true,
+            ranged: true,
+            range: Some(80),
+        }
+    }
+
+    pub fn crossbow() -> Self {
+        Weapon {
+            name: "Crossbow".to_string(),
+            weapon_type: WeaponType::Crossbow,
+            damage_dice: "1d8".to_string(),
+            damage_type: DamageType::Piercing,
+            damage_bonus: 1,
+            attack_bonus: 0,
+            two_handed: true,
+            ranged: true,
+            range: Some(100),
+        }
+    }
+
+    pub fn longbow() -> Self {
+        Weapon {
+            name: "Longbow".to_string(),
+            weapon_type: WeaponType::Bow,
+            damage_dice: "1d8".to_string(),
+            damage_type: DamageType::Piercing,
+            damage_bonus: 0,
+            attack_bonus: 1,
+            two_handed: true,
+            ranged: true,
+            range: Some(150),
+        }
+    }
+
+    // The weapon an inventory item stands for, if it's one
+    pub fn from_item_name(name: &str) -> Option<Self> {
+        match name {
+            "Rusty Sword" => Some(Self::rusty_sword()),
+            "Iron Sword" => Some(Weapon {
+                name: "Iron Sword".to_string(),
+                weapon_type: WeaponType::Sword,
+                damage_dice: "1d8".to_string(),
+                damage_type: DamageType::Slashing,
+                damage_bonus: 0,
+                attack_bonus: 1,
+                two_handed: false,
+                ranged: false,
+                range: None,
+            }),
+            "Steel Hammer" => Some(Weapon {
+                name: "Steel Hammer".to_string(),
+                weapon_type: WeaponType::Mace,
+                damage_dice: "1d10".to_string(),
+                damage_type: DamageType::Bludgeoning,
+                damage_bonus: 1,
+                attack_bonus: 0,
+                two_handed: true,
+                ranged: false,
+                range: None,
+            }),
+            "Short Bow" => Some(Self::short_bow()),
+            "Longbow" => Some(Self::longbow()),
+            "Crossbow" => Some(Self::crossbow()),
+            _ => None,
+        }
+    }
+
+    pub fn ammo_type(&self) -> Option<AmmoType> {
+        match self.weapon_type {
+            WeaponType::Bow => Some(AmmoType::Arrow),
+            WeaponType::Crossbow => Some(AmmoType::Bolt),
+            _ => None,
+        }
+    }
+
     pub fn roll_damage(&self) -> (u32, u32) {
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::rng::session_rng();
         let mut total = 0u32;
         let mut dice_count = 0u32;
         
@@ -172,18 +460,159 @@ impl Weapon {
         
         (final_damage, dice_count)
     }
+
+    // Lowest and highest result of roll_damage
+    pub fn damage_range(&self) -> (u32, u32) {
+        let (dice_count, die_size) = match self.damage_dice.split_once('d') {
+            Some((num_dice, die_size)) => (num_dice.parse().unwrap_or(1), die_size.parse().unwrap_or(4)),
+            None => (0, 0),
+        };
+        let bonus = self.damage_bonus as i32;
+        (apply_damage_modifier(dice_count, bonus), apply_damage_modifier(dice_count * die_size, bonus))
+    }
+}
+
+// The numbers behind a skill attack, shared by the resolver and the combat previews
+#[derive(Debug, Clone, Copy)]
+pub struct AttackOdds {
+    pub attack_total: u8,     // Added to the d20 roll
+    pub defense: u8,
+    pub hit_chance: u32,      // Percent
+    pub damage_modifier: i32, // Added to the weapon's damage roll
+    pub min_damage: u32,      // Before armor
+    pub max_damage: u32,
+}
+
+impl AttackOdds {
+    // Every 2 skill levels add +1 to attack; strength only drives melee blows,
+    // and skill 5+ adds a point of damage. Spells, curses and exhaustion count
+    // through the attacker's totals.
+    pub fn new(attacker: &CombatParticipant, weapon: &Weapon, skill_level: u8, target: &CombatParticipant) -> Self {
+        // The totals include the wielded melee weapon, so swap in the one actually used
+        let (melee_attack, melee_damage) = attacker.weapon.as_ref()
+            .map(|melee| (melee.attack_bonus as i16, melee.damage_bonus as i16))
+            .unwrap_or((0, 0));
+        let attack_value = attacker.get_total_attack_value() as i16 - melee_attack + weapon.attack_bonus as i16;
+        let attack_total = (attack_value.clamp(0, u8::MAX as i16) as u8).saturating_add(skill_level / 2);
+        let defense = target.get_total_defense_value();
+
+        let unused_strength = if weapon.ranged { attacker.combat_stats.damage_bonus as i16 } else { 0 };
+        let damage_bonus = (attacker.get_total_damage_bonus() as i16 - melee_damage - unused_strength + weapon.damage_bonus as i16) as i32;
+        let skill_damage_bonus = if damage_bonus >= 0 && skill_level >= 5 { 1 } else { 0 };
+        let damage_modifier = damage_bonus + skill_damage_bonus;
+        let (min_roll, max_roll) = weapon.damage_range();
+
+        Self {
+            attack_total,
+            defense,
+            hit_chance: hit_chance(attack_total, defense),
+            damage_modifier,
+            min_damage: apply_damage_modifier(min_roll, damage_modifier),
+            max_damage: apply_damage_modifier(max_roll, damage_modifier),
+        }
+    }
+
+    // Odds for one of the player's attack options, using the skill that trains it
+    // and, for a power attack, the character's strength
+    pub fn for_skill(attacker: &CombatParticipant, weapon: &Weapon, skill_name: &str, character: Option<&ForgeCharacter>, target: &CombatParticipant) -> Self {
+        let skill_level = character.and_then(|character| character.skills.get(trained_skill(skill_name)).copied()).unwrap_or(0);
+        let odds = Self::new(attacker, weapon, skill_level, target);
+        match character {
+            Some(character) if skill_name == POWER_ATTACK => odds.with_power_attack(&PowerAttack::for_strength(character.characteristics.strength), weapon),
+            _ => odds,
+        }
+    }
+
+    pub fn with_power_attack(mut self, power_attack: &PowerAttack, weapon: &Weapon) -> Self {
+        let (min_roll, max_roll) = weapon.damage_range();
+        self.attack_total = self.attack_total.saturating_sub(power_attack.to_hit_penalty);
+        self.hit_chance = hit_chance(self.attack_total, self.defense);
+        self.damage_modifier += power_attack.damage_bonus;
+        self.min_damage = apply_damage_modifier(min_roll, self.damage_modifier);
+        self.max_damage = apply_damage_modifier(max_roll, self.damage_modifier);
+        self
+    }
+
+    pub fn is_hit(&self, attack_roll: u8) -> bool {
+        attack_roll == 20 || attack_roll + self.attack_total > self.defense
+    }
+
+    pub fn describe(&self) -> String {
+        format!("{}% to hit, {}-{} dmg", self.hit_chance, self.min_damage, self.max_damage)
+    }
+}
+
+// Trading accuracy for a heavier blow. Only the strong can put everything behind it.
+#[derive(Debug, Clone, Copy)]
+pub struct PowerAttack {
+    pub to_hit_penalty: u8,
+    pub damage_bonus: i32,
+}
+
+impl PowerAttack {
+    pub fn for_strength(strength: f32) -> Self {
+        let (to_hit_penalty, damage_bonus) = if strength >= 16.0 {
+            (4, 6)
+        } else if strength >= 12.0 {
+            (3, 4)
+        } else {
+            (2, 2)
+        };
+        Self { to_hit_penalty, damage_bonus }
+    }
+
+    pub fn describe(&self) -> String {
+        format!("-{} to hit, +{} damage", self.to_hit_penalty, self.damage_bonus)
+    }
+}
+
+// The skill an attack option is trained with and rolls against
+pub fn trained_skill(skill_name: &str) -> &str {
+    if skill_name == POWER_ATTACK { "Melee Combat" } else { skill_name }
+}
+
+pub fn apply_damage_modifier(damage: u32, modifier: i32) -> u32 {
+    if modifier >= 0 {
+        damage + modifier as u32
+    } else {
+        damage.saturating_sub(modifier.unsigned_abs())
+    }
 }
 
 impl CombatParticipant {
-    pub fn from_character(character: &ForgeCharacter, weapon: Option<Weapon>) -> Self {
+    // Fights with what the character has equipped. An equipped bow or crossbow is
+    // the ranged weapon, leaving the fists for close quarters. One left in the
+    // pack can't be shot.
+    pub fn from_character(character: &ForgeCharacter) -> Self {
+        let (weapon, ranged_weapon) = match &character.equipped_weapon {
+            Some(weapon) if weapon.ranged => (Weapon::unarmed(), Some(weapon.clone())),
+            Some(weapon) => (weapon.clone(), None),
+            None => (Weapon::unarmed(), None),
+        };
+        let ammo = ranged_weapon.as_ref()
+            .and_then(|w| w.ammo_type())
+            .map(|ammo_type| ammo_type.count_in(&character.inventory))
+            .unwrap_or(0);
         CombatParticipant {
             name: character.name.clone(),
             combat_stats: character.combat_stats.clone(),
-            weapon,
-            armor: None,
-            shield: None,
+            weapon: Some(weapon),
+            ranged_weapon,
+            ammo,
+            ammo_spent: 0,
+            creature_type: None,
+            armor: character.equipped_armor.clone(),
+            shield: character.equipped_shield.clone(),
             initiative: 0,
             is_player: true,
+            side: CombatSide::Players,
+            fled: false,
+            status_effects: character.status_effects.clone(),
+            active_effects: Vec::new(),
+            stamina: character.current_stamina(),
+            max_stamina: character.max_stamina(),
+            overloaded: character.is_overloaded(),
+            guarding: false,
         }
     }
 
@@ -197,36 +626,151 @@ impl CombatParticipant {
                 damage_bonus: 0,
             },
             weapon,
+            ranged_weapon: None,
+            ammo: 0,
+            ammo_spent: 0,
+            creature_type: None,
             armor: None,
             shield: None,
             initiative: 0,
             is_player: false,
+            side: CombatSide::Enemies,
+            fled: false,
+            status_effects: Vec::new(),
+            active_effects: Vec::new(),
+            stamina: 0,
+            max_stamina: 0,
+            overloaded: false,
+            guarding: false,
         }
     }
 
+    // Fight alongside the player, e.g. a summoned creature or a hired sword
+    pub fn with_side(mut self, side: CombatSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub fn with_creature_type(mut self, creature_type: CreatureType) -> Self {
+        self.creature_type = Some(creature_type);
+        self
+    }
+
     pub fn roll_initiative(&mut self) {
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::rng::session_rng();
         self.initiative = rng.gen_range(1..=20) + (self.combat_stats.defensive_value / 2);
+        if self.overloaded {
+            self.initiative = self.initiative.saturating_sub(super::OVERLOAD_INITIATIVE_PENALTY);
+        }
     }
 
     pub fn get_total_attack_value(&self) -> u8 {
         let weapon_bonus = self.weapon.as_ref().map(|w| w.attack_bonus).unwrap_or(0);
-        (self.combat_stats.attack_value as i8 + weapon_bonus).max(0) as u8
+        let status_bonus = super::status_attack_modifier(&self.status_effects) as i16 + self.effect_modifier("attack") - self.exhaustion_penalty();
+        (self.combat_stats.attack_value as i16 + weapon_bonus as i16 + status_bonus).clamp(0, u8::MAX as i16) as u8
     }
 
     pub fn get_total_defense_value(&self) -> u8 {
         let armor_rating = self.armor.as_ref().map(|a| a.get_current_armor_rating()).unwrap_or(0);
         let shield_rating = self.shield.as_ref().map(|s| s.get_current_armor_rating()).unwrap_or(0);
-        self.combat_stats.defensive_value + armor_rating + shield_rating
+        let guard_bonus = if self.guarding { DEFEND_DV_BONUS as i16 } else { 0 };
+        let status_bonus = super::status_defense_modifier(&self.status_effects) as i16 + self.effect_modifier("defense") + guard_bonus - self.exhaustion_penalty();
+        (self.combat_stats.defensive_value as i16 + armor_rating as i16 + shield_rating as i16 + status_bonus).clamp(0, u8::MAX as i16) as u8
+    }
+
+    // Out of breath: attacks and defense suffer until some stamina comes back
+    pub fn is_exhausted(&self) -> bool {
+        self.max_stamina > 0 && self.stamina == 0
+    }
+
+    fn exhaustion_penalty(&self) -> i16 {
+        if self.is_exhausted() { EXHAUSTED_PENALTY } else { 0 }
+    }
+
+    // Heavy actions can always be attempted, but running dry leaves you exhausted.
+    // Returns true when this spending did it.
+    pub fn spend_stamina(&mut self, cost: u32) -> bool {
+        let was_exhausted = self.is_exhausted();
+        self.stamina = self.stamina.saturating_sub(cost);
+        !was_exhausted && self.is_exhausted()
+    }
+
+    pub fn recover_stamina(&mut self, amount: u32) {
+        self.stamina = (self.stamina + amount).min(self.max_stamina);
+    }
+
+    // The weapon a combat skill attacks with, or None when the skill can't be used
+    pub fn weapon_for_skill(&self, skill_name: &str) -> Option<Weapon> {
+        match skill_name {
+            "Ranged Combat" => self.ranged_weapon.clone(),
+            "Unarmed Combat" => Some(Weapon::unarmed()),
+            _ => Some(self.weapon.clone().unwrap_or_else(Weapon::unarmed)),
+        }
+    }
+
+    // Rough measure of how much of a fight this participant can put up
+    pub fn fighting_strength(&self) -> u32 {
+        self.combat_stats.hit_points.current
+            * (self.get_total_attack_value() as u32 + self.get_total_defense_value() as u32)
+    }
+
+    // Loose one arrow or bolt, false when the quiver is empty
+    pub fn spend_ammo(&mut self) -> bool {
+        if self.ammo == 0 {
+            return false;
+        }
+        self.ammo -= 1;
+        self.ammo_spent += 1;
+        true
     }
 
     pub fn get_total_damage_bonus(&self) -> i8 {
         let weapon_bonus = self.weapon.as_ref().map(|w| w.damage_bonus).unwrap_or(0);
-        self.combat_stats.damage_bonus + weapon_bonus
+        (self.combat_stats.damage_bonus as i16 + weapon_bonus as i16 + self.effect_modifier("damage"))
+            .clamp(i8::MIN as i16, i8::MAX as i16) as i8
     }
 
+    // Casting the same spell again starts its duration over instead of stacking.
+    // Returns true when an existing effect was refreshed.
+    pub fn add_effect(&mut self, effect: ActiveEffect) -> bool {
+        match self.active_effects.iter_mut().find(|e| e.source == effect.source && e.stat == effect.stat) {
+            Some(existing) => {
+                *existing = effect;
+                true
+            }
+            None => {
+                self.active_effects.push(effect);
+                false
+            }
+        }
+    }
+
+    fn effect_modifier(&self, stat: &str) -> i16 {
+        self.active_effects.iter()
+            .filter(|effect| effect.stat == stat)
+            .map(|effect| effect.modifier as i16)
+            .sum()
+    }
+
+    // Count down a round, returning the effects that just wore off
+    pub fn tick_effects(&mut self) -> Vec<ActiveEffect> {
+        for effect in &mut self.active_effects {
+            effect.rounds_remaining = effect.rounds_remaining.saturating_sub(1);
+        }
+        let (expired, active) = std::mem::take(&mut self.active_effects)
+            .into_iter()
+            .partition(|effect| effect.rounds_remaining == 0);
+        self.active_effects = active;
+        expired
+    }
+
+    // Still standing and still in the fight
     pub fn is_alive(&self) -> bool {
-        self.combat_stats.hit_points.current > 0
+        self.combat_stats.hit_points.current > 0 && !self.fled
+    }
+
+    pub fn is_defeated(&self) -> bool {
+        self.combat_stats.hit_points.current == 0
     }
 
     pub fn take_damage(&mut self, damage: u32, damage_dice_count: u32) -> (u32, u32) {
@@ -265,10 +809,36 @@ impl CombatParticipant {
         (total_actual_damage, damage.saturating_sub(total_actual_damage))
     }
 
-    pub fn heal(&mut self, amount: u32) {
-        self.combat_stats.hit_points.current = 
-            (self.combat_stats.hit_points.current + amount)
-                .min(self.combat_stats.hit_points.max);
+    pub fn receive_hit(&mut self, damage: u32, damage_dice_count: u32) -> DamageReport {
+        let (hp_lost, absorbed) = self.take_damage(damage, damage_dice_count);
+        DamageReport {
+            raw: damage,
+            absorbed,
+            hp_lost,
+            hp_remaining: self.combat_stats.hit_points.current,
+            hp_max: self.combat_stats.hit_points.max,
+        }
+    }
+
+    // Damage that ignores armor and shield entirely, e.g. magical feedback
+    pub fn take_direct_damage(&mut self, damage: u32) -> DamageReport {
+        self.combat_stats.hit_points.current = self.combat_stats.hit_points.current.saturating_sub(damage);
+        DamageReport {
+            raw: damage,
+            absorbed: 0,
+            hp_lost: damage,
+            hp_remaining: self.combat_stats.hit_points.current,
+            hp_max: self.combat_stats.hit_points.max,
+        }
+    }
+
+    // Heal up to max hit points, returning how much was actually restored
+    pub fn heal(&mut self, amount: u32) -> u32 {
+        let before = self.combat_stats.hit_points.current;
+        self.combat_stats.hit_points.current = before
+            .saturating_add(amount)
+            .min(self.combat_stats.hit_points.max);
+        self.combat_stats.hit_points.current - before
     }
 }
 
@@ -305,13 +875,25 @@ impl CombatEncounter {
     pub fn perform_action(&mut self, action: CombatAction) -> CombatResult {
         let attacker_index = self.current_turn;
         
+        // The fallen don't get to act
+        if !self.participants[attacker_index].is_alive() {
+            return CombatResult {
+                success: false,
+                damage: None,
+                message: format!("{} has fallen and cannot act", self.participants[attacker_index].name),
+                critical: false,
+            };
+        }
+        
         match action {
             CombatAction::Attack { target_index } => {
                 self.perform_attack(attacker_index, target_index)
             }
             CombatAction::Defend => {
-                self.add_log(format!("{} takes a defensive stance!", 
-                    self.participants[attacker_index].name));
+                self.participants[attacker_index].recover_stamina(STAMINA_DEFEND_RECOVERY);
+                self.participants[attacker_index].guarding = true;
+                self.add_log(format!("🛡️ {} raises their guard (+{} DV).",
+                    self.participants[attacker_index].name, DEFEND_DV_BONUS));
                 CombatResult {
                     success: true,
                     damage: None,
@@ -320,7 +902,8 @@ impl CombatEncounter {
                 }
             }
             CombatAction::Flee => {
-                let mut rng = rand::thread_rng();
+                self.spend_stamina(attacker_index, STAMINA_COST_FLEE);
+                let mut rng = crate::rng::session_rng();
                 let flee_chance = rng.gen_range(1..=20);
                 if flee_chance >= 10 {
                     self.add_log(format!("{} flees from combat!", 
@@ -348,7 +931,7 @@ impl CombatEncounter {
                 // Handle different item types
                 match item.as_str() {
                     "Health Potion" => {
-                        let heal_amount = 10; // Basic health potion heals 10 HP
+                        let heal_amount = super::HEALTH_POTION_HP;
                         self.participants[attacker_index].heal(heal_amount);
                         self.add_log(format!("{} drinks a health potion and recovers {} HP!", 
                             participant_name, heal_amount));
@@ -386,7 +969,7 @@ impl CombatEncounter {
     }
 
     fn perform_attack(&mut self, attacker_index: usize, target_index: usize) -> CombatResult {
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::rng::session_rng();
         
         // Get attack and defense values
         let attack_value = self.participants[attacker_index].get_total_attack_value();
@@ -417,21 +1000,18 @@ impl CombatEncounter {
                 damage = damage.saturating_sub(damage_bonus.abs() as u32);
             }
             
-            // Double damage on critical (including dice count for actual damage)
-            let final_dice_count = if critical { dice_count * 2 } else { dice_count };
-            if critical {
-                damage *= 2;
-            }
+            // Double damage on critical
+            let (damage, final_dice_count) = apply_critical(damage, dice_count, critical);
             
             // Apply damage using Forge rules
-            let (actual_damage, armor_damage) = self.participants[target_index].take_damage(damage, final_dice_count);
+            let report = self.participants[target_index].receive_hit(damage, final_dice_count);
             
             let message = if critical {
-                format!("{} critically hits {} with {} for {} damage ({} actual, {} absorbed)!", 
-                    attacker_name, target_name, weapon.name, damage, actual_damage, armor_damage)
+                format!("{} critically hits {} with {}: {}!", 
+                    attacker_name, target_name, weapon.name, report.describe(&target_name))
             } else {
-                format!("{} hits {} with {} for {} damage ({} actual, {} absorbed)!", 
-                    attacker_name, target_name, weapon.name, damage, actual_damage, armor_damage)
+                format!("{} hits {} with {}: {}!", 
+                    attacker_name, target_name, weapon.name, report.describe(&target_name))
             };
             
             self.add_log(message.clone());
@@ -469,10 +1049,14 @@ impl CombatEncounter {
             // If we've gone through all participants, increment round
             if self.current_turn == 0 {
                 self.round += 1;
+                self.tick_effects();
             }
             
             // If current participant is alive, break
             if self.participants[self.current_turn].is_alive() {
+                // A little breath comes back every turn, and last turn's guard drops
+                self.participants[self.current_turn].recover_stamina(1);
+                self.participants[self.current_turn].guarding = false;
                 break;
             }
             
@@ -483,35 +1067,85 @@ impl CombatEncounter {
         }
     }
 
+    // Spell effects run down once per round
+    fn tick_effects(&mut self) {
+        let mut expired = Vec::new();
+        for participant in &mut self.participants {
+            for effect in participant.tick_effects() {
+                expired.push(format!("⏳ {}'s {} wears off.", participant.name, effect.source));
+            }
+        }
+        for message in expired {
+            self.add_log(message);
+        }
+    }
+
+    pub fn spend_stamina(&mut self, participant_index: usize, cost: u32) {
+        if self.participants[participant_index].spend_stamina(cost) {
+            let name = self.participants[participant_index].name.clone();
+            self.add_log(format!("😮‍💨 {} is exhausted! Attack and defense suffer.", name));
+        }
+    }
+
+    // Living participants on the current participant's side, or on the other side
+    pub fn target_candidates(&self, allies: bool) -> Vec<usize> {
+        let own_side = self.get_current_participant().map(|p| p.side).unwrap_or(CombatSide::Players);
+        self.participants.iter()
+            .enumerate()
+            .filter(|(_, p)| p.is_alive() && (p.side == own_side) == allies)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn side_standing(&self, side: CombatSide) -> bool {
+        self.participants.iter().any(|p| p.side == side && p.is_alive())
+    }
+
+    // Allies can't carry on the fight for a player who has gone down
+    fn player_down(&self) -> bool {
+        self.participants.iter().any(|p| p.is_player && p.is_defeated())
+    }
+
+    // The fight is over once either side has nobody left standing, or the player falls
     pub fn is_combat_over(&self) -> bool {
-        let alive_players = self.participants.iter()
-            .filter(|p| p.is_player && p.is_alive())
-            .count();
-        let alive_enemies = self.participants.iter()
-            .filter(|p| !p.is_player && p.is_alive())
-            .count();
-        
-        alive_players == 0 || alive_enemies == 0
+        self.player_down() || !self.side_standing(CombatSide::Players) || !self.side_standing(CombatSide::Enemies)
     }
 
-    pub fn get_winner(&self) -> Option<String> {
+    // The side still standing. A fallen player loses even if their allies are up.
+    pub fn get_winner(&self) -> Option<CombatSide> {
         if !self.is_combat_over() {
             return None;
         }
         
-        let alive_players = self.participants.iter()
-            .filter(|p| p.is_player && p.is_alive())
-            .count();
-        
-        if alive_players > 0 {
-            Some("Player".to_string())
+        if self.side_standing(CombatSide::Players) && !self.player_down() {
+            Some(CombatSide::Players)
         } else {
-            Some("Enemies".to_string())
+            Some(CombatSide::Enemies)
         }
     }
 }
 
 impl Armor {
+    // The armor or shield an inventory item stands for, if it's one
+    pub fn from_item_name(name: &str) -> Option<Self> {
+        match name {
+            "Leather Armor" => Some(Self::leather()),
+            "Studded Leather" => Some(Self::studded_leather()),
+            "Ring Mail" => Some(Self::ring_mail()),
+            "Chain Mail" => Some(Self::chain_mail()),
+            "Banded Mail" => Some(Self::banded_mail()),
+            "Plate Mail" => Some(Self::plate_mail()),
+            "Small Shield" => Some(Self::small_shield()),
+            "Medium Shield" => Some(Self::medium_shield()),
+            "Large Shield" => Some(Self::large_shield()),
+            _ => None,
+        }
+    }
+
+    pub fn is_shield(&self) -> bool {
+        matches!(self.armor_type, ArmorType::Shield)
+    }
+
     pub fn leather() -> Self {
         Armor {
             name: "Leather Armor".to_string(),
@@ -629,7 +1263,7 @@ pub fn create_wild_boar() -> CombatParticipant {
             ranged: false,
             range: None,
         })
-    )
+    ).with_creature_type(CreatureType::WildAnimal)
 }
 
 pub fn create_wolf() -> CombatParticipant {
@@ -649,7 +1283,7 @@ pub fn create_wolf() -> CombatParticipant {
             ranged: false,
             range: None,
         })
-    )
+    ).with_creature_type(CreatureType::WildAnimal)
 }
 
 pub fn create_goblin() -> CombatParticipant {
@@ -669,7 +1303,7 @@ pub fn create_goblin() -> CombatParticipant {
             ranged: false,
             range: None,
         })
-    );
+    ).with_creature_type(CreatureType::Goblin);
     goblin.armor = Some(Armor::leather());
     goblin
 }
@@ -691,7 +1325,7 @@ pub fn create_bandit() -> CombatParticipant {
             ranged: false,
             range: None,
         })
-    );
+    ).with_creature_type(CreatureType::Bandit);
     bandit.armor = Some(Armor::studded_leather());
     bandit.shield = Some(Armor::small_shield());
     bandit
@@ -714,7 +1348,7 @@ pub fn create_orc() -> CombatParticipant {
             ranged: false,
             range: None,
         })
-    );
+    ).with_creature_type(CreatureType::Orc);
     orc.armor = Some(Armor::chain_mail());
     orc
 }
@@ -736,7 +1370,7 @@ pub fn create_giant_spider() -> CombatParticipant {
             ranged: false,
             range: None,
         })
-    )
+    ).with_creature_type(CreatureType::Spider)
 }
 
 pub fn create_mountain_lion() -> CombatParticipant {
@@ -756,7 +1390,7 @@ pub fn create_mountain_lion() -> CombatParticipant {
             ranged: false,
             range: None,
         })
-    )
+    ).with_creature_type(CreatureType::WildAnimal)
 }
 
 pub fn create_skeleton() -> CombatParticipant {
@@ -776,7 +1410,7 @@ pub fn create_skeleton() -> CombatParticipant {
             ranged: false,
             range: None,
         })
-    );
+    ).with_creature_type(CreatureType::Skeleton);
     skeleton.armor = Some(Armor {
         name: "Bone Armor".to_string(),
         armor_type: ArmorType::Light,
@@ -805,5 +1439,233 @@ pub fn create_zombie() -> CombatParticipant {
             ranged: false,
             range: None,
         })
-    )
-}
\ No newline at end of file
+    ).with_creature_type(CreatureType::Zombie)
+}
+
+// The known creature one word of an encounter name stands for, singular or plural
+fn named_creature(word: &str) -> Option<fn() -> CombatParticipant> {
+    let singular = match word {
+        "wolves" => "wolf",
+        _ => word.strip_suffix('s').unwrap_or(word),
+    };
+    match singular {
+        "goblin" => Some(create_goblin),
+        "bandit" => Some(create_bandit),
+        "orc" => Some(create_orc),
+        "spider" => Some(create_giant_spider),
+        "wolf" | "animal" => Some(create_wolf),
+        "boar" => Some(create_wild_boar),
+        "lion" => Some(create_mountain_lion),
+        "skeleton" => Some(create_skeleton),
+        "zombie" | "undead" => Some(create_zombie),
+        _ => None,
+    }
+}
+
+// The enemies a scripted encounter names, like "Goblins" or "Skeleton Warrior".
+// Known creatures keep their usual stats under the encounter's name, and a
+// plural brings a pair of them. Anything else is a generic beast as strong as
+// the place it guards.
+pub fn create_named_enemies(name: &str, difficulty: u8) -> Vec<CombatParticipant> {
+    // Whole words only, so a "Sorcerer" is no orc
+    let lower = name.to_lowercase();
+    let known = lower.split(|c: char| !c.is_alphabetic()).find_map(named_creature);
+    // The last word says how many there are: "Goblins" but not "Bandits' Boss" or "Moss Golem"
+    let plural = lower.split_whitespace().last()
+        .is_some_and(|last| last.ends_with('s') && !last.ends_with("ss"));
+
+    match known {
+        Some(create) if plural => vec![create(), create()],
+        Some(create) => {
+            let mut enemy = create();
+            enemy.name = name.to_string();
+            vec![enemy]
+        }
+        None => {
+            let difficulty = difficulty.clamp(1, 10);
+            let enemy = CombatParticipant::create_enemy(
+                name,
+                8 + difficulty as u32 * 3, // HP
+                5 + difficulty,            // Attack
+                4 + difficulty,            // Defense
+                Some(Weapon {
+                    name: "Claws".to_string(),
+                    weapon_type: WeaponType::Unarmed,
+                    damage_dice: "1d6".to_string(),
+                    damage_type: DamageType::Slashing,
+                    damage_bonus: (difficulty / 3) as i8,
+                    attack_bonus: 0,
+                    two_handed: false,
+                    ranged: false,
+                    range: None,
+                })
+            );
+            if plural {
+                vec![enemy.clone(), enemy]
+            } else {
+                vec![enemy]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swordsman() -> CombatParticipant {
+        let mut sword = Weapon::rusty_sword();
+        sword.attack_bonus = 2;
+        sword.damage_bonus = 1;
+        let mut fighter = CombatParticipant::create_enemy("Fighter", 20, 10, 5, Some(sword));
+        fighter.combat_stats.damage_bonus = 3;
+        fighter
+    }
+
+    #[test]
+    fn attack_odds_count_spell_effects() {
+        let target = CombatParticipant::create_enemy("Target", 10, 5, 20, None);
+        let mut fighter = swordsman();
+        let sword = fighter.weapon.clone().unwrap();
+        let plain = AttackOdds::new(&fighter, &sword, 0, &target);
+        assert_eq!(plain.attack_total, 12);
+        assert_eq!(plain.damage_modifier, 4);
+
+        fighter.add_effect(ActiveEffect::new("Bless Weapon", "attack", 2, 3));
+        fighter.add_effect(ActiveEffect::new("Bear Strength", "damage", 3, 3));
+        let blessed = AttackOdds::new(&fighter, &sword, 0, &target);
+        assert_eq!(blessed.attack_total, 14);
+        assert_eq!(blessed.damage_modifier, 7);
+        assert!(blessed.hit_chance > plain.hit_chance);
+    }
+
+    #[test]
+    fn attack_odds_use_the_chosen_weapon_and_skip_strength_for_bows() {
+        let target = CombatParticipant::create_enemy("Target", 10, 5, 20, None);
+        let fighter = swordsman();
+        let bow = Weapon::short_bow();
+        let odds = AttackOdds::new(&fighter, &bow, 4, &target);
+        assert_eq!(odds.attack_total, 10 + bow.attack_bonus.max(0) as u8 + 2);
+        assert_eq!(odds.damage_modifier, bow.damage_bonus as i32);
+    }
+
+    #[test]
+    fn exhaustion_lowers_attack_odds() {
+        let target = CombatParticipant::create_enemy("Target", 10, 5, 20, None);
+        let mut fighter = swordsman();
+        let sword = fighter.weapon.clone().unwrap();
+        let rested = AttackOdds::new(&fighter, &sword, 0, &target);
+        fighter.max_stamina = 5;
+        fighter.stamina = 0;
+        let exhausted = AttackOdds::new(&fighter, &sword, 0, &target);
+        assert_eq!(exhausted.attack_total as i16, rested.attack_total as i16 - EXHAUSTED_PENALTY);
+    }
+
+    #[test]
+    fn skill_odds_follow_curses_and_power_attacks() {
+        let target = CombatParticipant::create_enemy("Target", 10, 5, 20, None);
+        let mut character = crate::forge::sample_character("Hero");
+        character.skills.insert("Melee Combat".to_string(), 4);
+        let mut hero = CombatParticipant::from_character(&character);
+        let weapon = hero.weapon_for_skill("Melee Combat").unwrap();
+        let healthy = AttackOdds::for_skill(&hero, &weapon, "Melee Combat", Some(&character), &target);
+
+        hero.status_effects.push(StatusEffect::new(crate::forge::StatusKind::Curse, "a dark altar", 2, 10));
+        let cursed = AttackOdds::for_skill(&hero, &weapon, "Melee Combat", Some(&character), &target);
+        assert_eq!(cursed.attack_total + 2, healthy.attack_total);
+
+        let power = AttackOdds::for_skill(&hero, &weapon, POWER_ATTACK, Some(&character), &target);
+        let power_attack = PowerAttack::for_strength(character.characteristics.strength);
+        assert_eq!(power.attack_total, cursed.attack_total.saturating_sub(power_attack.to_hit_penalty));
+        assert_eq!(power.damage_modifier, cursed.damage_modifier + power_attack.damage_bonus);
+    }
+
+    #[test]
+    fn a_fallen_player_loses_even_when_allies_win() {
+        let mut hero = CombatParticipant::create_enemy("Hero", 10, 5, 5, None).with_side(CombatSide::Players);
+        hero.is_player = true;
+        let skeleton = CombatParticipant::create_enemy("Skeleton", 10, 5, 5, None).with_side(CombatSide::Players);
+        let goblin = CombatParticipant::create_enemy("Goblin", 10, 5, 5, None);
+        let mut encounter = CombatEncounter::new(vec![hero, skeleton, goblin]);
+        assert_eq!(encounter.get_winner(), None);
+
+        for participant in &mut encounter.participants {
+            if participant.name == "Goblin" {
+                participant.combat_stats.hit_points.current = 0;
+            }
+        }
+        assert_eq!(encounter.get_winner(), Some(CombatSide::Players));
+
+        for participant in &mut encounter.participants {
+            if participant.is_player {
+                participant.combat_stats.hit_points.current = 0;
+            }
+        }
+        assert!(encounter.is_combat_over());
+        assert_eq!(encounter.get_winner(), Some(CombatSide::Enemies));
+    }
+
+    #[test]
+    fn critical_hits_double_damage_but_not_dice() {
+        assert_eq!(apply_critical(7, 2, false), (7, 2));
+        assert_eq!(apply_critical(7, 2, true), (14, 2));
+    }
+
+    #[test]
+    fn armor_soaks_all_but_one_point_per_die() {
+        let mut knight = CombatParticipant::create_enemy("Knight", 20, 5, 5, None);
+        knight.armor = Some(Armor::leather());
+        let (damage, dice) = apply_critical(9, 2, true);
+        let report = knight.receive_hit(damage, dice);
+        assert_eq!(report, DamageReport { raw: 18, absorbed: 16, hp_lost: 2, hp_remaining: 18, hp_max: 20 });
+        assert_eq!(knight.armor.as_ref().unwrap().armor_points, 4);
+        assert_eq!(report.describe("Knight"), "18 rolled, 16 absorbed, 2 HP lost (Knight 18/20 HP)");
+
+        // Once the armor gives out, the rest goes straight through
+        let report = knight.receive_hit(10, 1);
+        assert_eq!((report.absorbed, report.hp_lost, report.hp_remaining), (4, 6, 12));
+    }
+
+    #[test]
+    fn named_enemies_match_whole_words_and_real_plurals() {
+        let names = |name: &str| create_named_enemies(name, 3).iter().map(|enemy| enemy.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names("Goblins"), vec!["Goblin", "Goblin"]);
+        assert_eq!(names("Wolves"), vec!["Wolf", "Wolf"]);
+        assert_eq!(names("Skeleton Warrior"), vec!["Skeleton Warrior"]);
+        assert!(matches!(create_named_enemies("Skeleton Warrior", 3)[0].creature_type, Some(CreatureType::Skeleton)));
+        // Neither of these is an orc or a pair
+        for name in ["Sorcerer", "Torch Bearer", "Moss Golem"] {
+            let enemies = create_named_enemies(name, 3);
+            assert_eq!(enemies.len(), 1, "{}", name);
+            assert!(!matches!(enemies[0].creature_type, Some(CreatureType::Orc)), "{}", name);
+        }
+        assert_eq!(names("Bandits' Boss"), vec!["Bandits' Boss"]);
+    }
+
+    #[test]
+    fn a_downed_player_cannot_act_and_loses_the_fight() {
+        let mut player = CombatParticipant::create_enemy("Hero", 20, 10, 5, Some(Weapon::rusty_sword())).with_side(CombatSide::Players);
+        player.is_player = true;
+        let ally = CombatParticipant::create_enemy("Squire", 20, 10, 5, Some(Weapon::rusty_sword())).with_side(CombatSide::Players);
+        let mut encounter = CombatEncounter::new(vec![player, ally, create_goblin()]);
+        let hero = encounter.participants.iter().position(|p| p.is_player).unwrap();
+        let goblin = encounter.participants.iter().position(|p| p.name == "Goblin").unwrap();
+
+        // Cut down mid-fight while the squire is still standing
+        encounter.participants[hero].combat_stats.hit_points.current = 0;
+        assert!(encounter.is_combat_over());
+        assert!(matches!(encounter.get_winner(), Some(CombatSide::Enemies)));
+
+        // A swing on their turn is refused and nobody gets hurt
+        encounter.current_turn = hero;
+        let result = encounter.perform_action(CombatAction::Attack { target_index: goblin });
+        assert!(!result.success);
+        assert_eq!(encounter.participants[goblin].combat_stats.hit_points.current, 8);
+
+        // And the turn order passes them by
+        for _ in 0..encounter.participants.len() * 2 {
+            encounter.next_turn();
+            assert_ne!(encounter.current_turn, hero);
+        }
+    }
+}
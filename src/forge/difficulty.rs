@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use super::CombatStats;
+
+pub const MAX_ENEMY_GROUP: usize = 5;
+pub const WEAK_CREATURE_LEVELS: u8 = 5; // Every this many levels the weakest creature of a terrain stops showing up
+
+// How hard the game is, chosen when a character is created
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn all() -> [Difficulty; 3] {
+        [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard]
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|difficulty| difficulty.get_name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Weaker enemies, better rest. Death sends you back to the nearest settlement.",
+            Difficulty::Normal => "The intended challenge. Death costs a good part of your experience.",
+            Difficulty::Hard => "Tougher enemies, poor rest. Death is permanent.",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+
+    // Percentage applied to enemy hit points, attack and defense
+    pub fn enemy_stat_percent(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 75,
+            Difficulty::Normal => 100,
+            Difficulty::Hard => 125,
+        }
+    }
+
+    pub fn scale_enemy_stats(&self, stats: &mut CombatStats) {
+        let percent = self.enemy_stat_percent();
+        let scale = |value: u32| (value * percent).div_ceil(100).max(1);
+
+        stats.hit_points.max = scale(stats.hit_points.max);
+        stats.hit_points.current = scale(stats.hit_points.current).min(stats.hit_points.max);
+        stats.attack_value = scale(stats.attack_value as u32).min(u8::MAX as u32) as u8;
+        stats.defensive_value = scale(stats.defensive_value as u32).min(u8::MAX as u32) as u8;
+    }
+
+    // Smallest and largest group a random encounter sends against a character
+    // of this level. Groups grow as the character does.
+    pub fn enemy_group_range(&self, level: u8) -> (usize, usize) {
+        let min = 1 + level as usize / 5;
+        let max = 1 + level as usize / 3;
+        let (min, max) = match self {
+            Difficulty::Easy => (min, max.saturating_sub(1).max(min)),
+            Difficulty::Normal => (min, max),
+            Difficulty::Hard => (min, max + 1),
+        };
+        (min.min(MAX_ENEMY_GROUP), max.min(MAX_ENEMY_GROUP))
+    }
+
+    // Percentage applied to the hit points recovered by camping
+    pub fn camp_healing_percent(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 150,
+            Difficulty::Normal => 100,
+            Difficulty::Hard => 75,
+        }
+    }
+
+    // Share of current experience lost when the character dies
+    pub fn death_xp_penalty_percent(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 10,
+            Difficulty::Normal => 25,
+            Difficulty::Hard => 100,
+        }
+    }
+
+    pub fn is_permadeath(&self) -> bool {
+        matches!(self, Difficulty::Hard)
+    }
+}
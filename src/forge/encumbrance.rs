@@ -0,0 +1,59 @@
+use super::ForgeCharacter;
+
+pub const OVERLOAD_INITIATIVE_PENALTY: u8 = 4; // Heavy packs make for slow reactions
+
+// What one of an item weighs in pounds
+pub fn item_weight(name: &str) -> f32 {
+    match name {
+        "Plate Mail" => 45.0,
+        "Banded Mail" => 35.0,
+        "Chain Mail" => 30.0,
+        "Ring Mail" => 25.0,
+        "Studded Leather" => 15.0,
+        "Leather Armor" => 12.0,
+        "Large Shield" => 12.0,
+        "Medium Shield" => 8.0,
+        "Small Shield" => 5.0,
+        "Steel Hammer" => 10.0,
+        "Iron Sword" | "Rusty Sword" => 4.0,
+        "Short Bow" | "Hunting Bow" => 2.0,
+        "Longbow" => 3.0,
+        "Crossbow" => 7.0,
+        "Arrows" | "Bolts" => 0.1,
+        "Iron Ingot" | "Iron Ore" | "Stone" | "Rare Minerals" => 5.0,
+        "Wood" | "Sand" => 4.0,
+        "Bedroll" | "Simple tools" => 5.0,
+        "Farm clothes" => 2.0,
+        "Coin Purse" | "Lockpicks" | "Iron Key" | "Map" | "Tracking Guide" => 0.2,
+        "Rations" | "Torch" | "Holy Water" | "Murky Water" => 1.0,
+        name if name.contains("Mail") || name.contains("Armor") => 20.0,
+        name if name.contains("Potion") => 0.5,
+        name if name.contains("Scroll") || name.contains("Amulet") || name.contains("Ring") => 0.1,
+        _ => 1.0,
+    }
+}
+
+impl ForgeCharacter {
+    // Everything in the pack plus what's worn and wielded
+    pub fn current_weight(&self) -> f32 {
+        let pack: f32 = self.inventory.iter()
+            .map(|item| {
+                let (name, count) = crate::world::split_stack(item);
+                item_weight(name) * count as f32
+            })
+            .sum();
+        let worn: f32 = self.equipped_weapon.iter().map(|weapon| item_weight(&weapon.name))
+            .chain(self.equipped_armor.iter().chain(&self.equipped_shield).map(|armor| item_weight(&armor.name)))
+            .sum();
+        pack + worn
+    }
+
+    // Worked out from Strength along with the other secondary stats
+    pub fn carry_capacity(&self) -> f32 {
+        self.secondary_stats().carry_capacity as f32
+    }
+
+    pub fn is_overloaded(&self) -> bool {
+        self.current_weight() > self.carry_capacity()
+    }
+}
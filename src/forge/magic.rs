@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::OnceLock;
+use rand::Rng;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum MagicSchool {
@@ -16,12 +18,31 @@ pub struct SpellPoints {
     pub max: u32,
 }
 
+// Where spell points come from. With school reserves, each school the character
+// is trained in adds a small pool of its own (one point per skill level) that its
+// spells draw on before the general pool, so a hybrid caster isn't stretched
+// across one budget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpellPointModel {
+    Shared,
+    #[default]
+    SchoolReserves,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MagicSystem {
     pub spell_points: SpellPoints,
+    #[serde(default)]
+    pub point_model: SpellPointModel,
+    #[serde(default)]
+    pub reserve_spent: HashMap<MagicSchool, u32>,       // School -> reserve points used since the last rest
     pub known_spells: HashMap<MagicSchool, Vec<String>>, // School -> list of known spell names
     pub school_skills: HashMap<MagicSchool, u8>,         // School -> skill level (0-20)
     pub school_pips: HashMap<MagicSchool, u8>,          // School -> accumulated pips
+    #[serde(default)]
+    pub spell_ready_round: HashMap<String, u32>,        // Spell -> combat round it can be cast again
+    #[serde(default)]
+    pub casts_since_rest: HashMap<String, u8>,          // Spell -> times cast since the last rest
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,6 +82,13 @@ pub enum SpellEffect {
     },
 }
 
+impl SpellEffect {
+    // Healing and buffs are meant for the caster's side
+    pub fn targets_allies(&self) -> bool {
+        matches!(self, SpellEffect::Heal { .. } | SpellEffect::Buff { .. })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Spell {
     pub name: String,
@@ -72,6 +100,10 @@ pub struct Spell {
     pub description: String,
     pub success_chance_base: u8,  // Base success chance (modified by skill)
     pub backfire_chance: u8,      // Chance of backfire on failure
+    #[serde(default)]
+    pub cooldown_rounds: u8,      // Rounds to wait before casting it again
+    #[serde(default)]
+    pub uses_per_rest: Option<u8>, // Casts allowed between rests, if limited
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,6 +120,87 @@ pub enum SpellResult {
     },
 }
 
+pub const MAX_SPELL_SUCCESS_CHANCE: u8 = 95; // Even masters can fumble a casting
+
+// What goes wrong when a spell backfires
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackfireOutcome {
+    Feedback { damage: u32 }, // The spell's power turns on the caster
+    Misdirected,              // The spell lands on the caster's own side
+    SkillDrained,             // The caster loses a level in the school until the fight ends
+    Fizzled,                  // The spell points are gone and nothing happens
+}
+
+// Look up a d100 roll on the backfire table. Feedback is the most common mishap;
+// a caster with no skill in the school to lose gets feedback instead.
+pub fn resolve_backfire(spell: &Spell, caster: &MagicSystem, roll: u32) -> BackfireOutcome {
+    match roll {
+        41..=65 => BackfireOutcome::Misdirected,
+        66..=80 if caster.get_school_skill(&spell.school) > 0 => BackfireOutcome::SkillDrained,
+        81..=100 => BackfireOutcome::Fizzled,
+        _ => BackfireOutcome::Feedback { damage: spell.roll_backfire_damage() },
+    }
+}
+
+impl Spell {
+    // Whether the player picks the target from their own side. The first effect
+    // decides, so Drain Life still aims at an enemy and heals the caster.
+    pub fn targets_allies(&self) -> bool {
+        self.effects.first().map(|effect| effect.targets_allies()).unwrap_or(false)
+            || matches!(self.target, SpellTarget::Self_ | SpellTarget::SingleAlly | SpellTarget::AllAllies)
+    }
+
+    // Success chance with school skill (+2% per level), capped so failure stays possible
+    pub fn success_chance(&self, school_skill: u8) -> u8 {
+        (self.success_chance_base as u32 + school_skill as u32 * 2)
+            .min(MAX_SPELL_SUCCESS_CHANCE as u32) as u8
+    }
+
+    // Upper bound of the backfire band on a d100 roll. Rolls up to the success chance
+    // succeed, rolls above this fail harmlessly; backfire takes at most half of the
+    // non-success range so plain failure is always possible too.
+    pub fn backfire_threshold(&self, school_skill: u8) -> u8 {
+        let success_chance = self.success_chance(school_skill);
+        let remaining = 100 - success_chance;
+        success_chance + self.backfire_chance.min(remaining / 2)
+    }
+
+    // Backfire model: the spell's energy turns on the caster. Damage and healing
+    // spells hit the caster for half of what they would have rolled (healing
+    // inverts into harm); other spells feed back damage equal to their cost.
+    // This is internal magical feedback, so physical armor never absorbs it.
+    pub fn roll_backfire_damage(&self) -> u32 {
+        let rolled = self.effects.iter().find_map(|effect| match effect {
+            SpellEffect::Damage { dice, bonus, .. } | SpellEffect::Heal { dice, bonus } => Some(roll_dice(dice, *bonus)),
+            _ => None,
+        });
+
+        match rolled {
+            Some(amount) => amount.div_ceil(2).max(1),
+            None => (self.cost as u32).max(1),
+        }
+    }
+}
+
+// Roll a dice expression such as "2d6" and add a (possibly negative) bonus
+pub fn roll_dice(dice: &str, bonus: i8) -> u32 {
+    let mut rng = crate::rng::session_rng();
+    let total = match dice.split_once('d') {
+        Some((num_dice, die_size)) => {
+            let dice_count: u32 = num_dice.parse().unwrap_or(1);
+            let die_size: u32 = die_size.parse().unwrap_or(4);
+            (0..dice_count).map(|_| rng.gen_range(1..=die_size)).sum()
+        }
+        None => 4, // Default damage
+    };
+
+    if bonus >= 0 {
+        total + bonus as u32
+    } else {
+        total.saturating_sub(bonus.unsigned_abs() as u32)
+    }
+}
+
 impl MagicSystem {
     pub fn new(power: u8) -> Self {
         let max_spell_points = (power as u32) * 2; // Power × 2 starting spell points
@@ -97,29 +210,130 @@ impl MagicSystem {
                 current: max_spell_points,
                 max: max_spell_points,
             },
+            point_model: SpellPointModel::default(),
+            reserve_spent: HashMap::new(),
             known_spells: HashMap::new(),
             school_skills: HashMap::new(),
             school_pips: HashMap::new(),
+            spell_ready_round: HashMap::new(),
+            casts_since_rest: HashMap::new(),
         }
     }
     
     pub fn can_cast_spell(&self, spell: &Spell) -> bool {
-        self.spell_points.current >= spell.cost as u32
+        self.available_points(&spell.school) >= spell.cost as u32
+    }
+    
+    // Points a spell of this school can draw on, its reserve included
+    pub fn available_points(&self, school: &MagicSchool) -> u32 {
+        self.spell_points.current + self.school_reserve(school).current
+    }
+    
+    pub fn school_reserve(&self, school: &MagicSchool) -> SpellPoints {
+        let max = match self.point_model {
+            SpellPointModel::Shared => 0,
+            SpellPointModel::SchoolReserves => self.get_school_skill(school) as u32,
+        };
+        let spent = self.reserve_spent.get(school).copied().unwrap_or(0);
+        SpellPoints { current: max.saturating_sub(spent), max }
+    }
+    
+    // e.g. "Divine Magic reserve: 2/3" for each school with a reserve
+    pub fn describe_reserves(&self) -> Vec<String> {
+        let mut schools: Vec<&MagicSchool> = self.school_skills.keys().collect();
+        schools.sort_by_key(|school| school.to_string());
+        schools.into_iter()
+            .map(|school| (school, self.school_reserve(school)))
+            .filter(|(_, reserve)| reserve.max > 0)
+            .map(|(school, reserve)| format!("{} reserve: {}/{}", school, reserve.current, reserve.max))
+            .collect()
+    }
+    
+    // Why a spell can't be cast this round because of its cooldown or rest limit
+    pub fn spell_limit_reason(&self, spell: &Spell, round: u32) -> Option<String> {
+        if let Some(&ready_round) = self.spell_ready_round.get(&spell.name) {
+            if round < ready_round {
+                let wait = ready_round - round;
+                return Some(format!("{} is still recovering ({} more round{})", spell.name, wait, if wait == 1 { "" } else { "s" }));
+            }
+        }
+        if let Some(limit) = spell.uses_per_rest {
+            if self.casts_since_rest.get(&spell.name).copied().unwrap_or(0) >= limit {
+                return Some(format!("{} can only be cast {} times between rests", spell.name, limit));
+            }
+        }
+        None
+    }
+    
+    // Why a spell can't be cast right now: too few points, a cooldown or a rest limit
+    pub fn cast_refusal(&self, spell: &Spell, round: u32) -> Option<String> {
+        if !self.can_cast_spell(spell) {
+            return Some(format!("Not enough spell points to cast {} ({} required, {} available)",
+                spell.name, spell.cost, self.available_points(&spell.school)));
+        }
+        self.spell_limit_reason(spell, round)
+    }
+    
+    pub fn record_spell_cast(&mut self, spell: &Spell, round: u32) {
+        if spell.cooldown_rounds > 0 {
+            self.spell_ready_round.insert(spell.name.clone(), round + spell.cooldown_rounds as u32 + 1);
+        }
+        if spell.uses_per_rest.is_some() {
+            *self.casts_since_rest.entry(spell.name.clone()).or_insert(0) += 1;
+        }
     }
     
-    pub fn spend_spell_points(&mut self, cost: u8) -> bool {
-        if self.spell_points.current >= cost as u32 {
-            self.spell_points.current -= cost as u32;
-            true
-        } else {
-            false
+    // Cooldowns are counted in rounds of a single fight
+    pub fn reset_cooldowns(&mut self) {
+        self.spell_ready_round.clear();
+    }
+    
+    pub fn reset_rest_limits(&mut self) {
+        self.casts_since_rest.clear();
+    }
+    
+    // Pay for a spell out of its school's reserve first, then the general pool
+    pub fn spend_spell_points(&mut self, spell: &Spell) -> bool {
+        let cost = spell.cost as u32;
+        if self.available_points(&spell.school) < cost {
+            return false;
+        }
+        
+        let from_reserve = cost.min(self.school_reserve(&spell.school).current);
+        if from_reserve > 0 {
+            *self.reserve_spent.entry(spell.school.clone()).or_insert(0) += from_reserve;
         }
+        self.spell_points.current -= cost - from_reserve;
+        true
     }
     
     pub fn restore_spell_points(&mut self, amount: u32) {
         self.spell_points.current = (self.spell_points.current + amount).min(self.spell_points.max);
     }
     
+    // A full rest refills the general pool and every school reserve
+    pub fn restore_all_spell_points(&mut self) {
+        self.rest(1.0);
+    }
+    
+    // Recover a share of the pool and of each spent reserve, at least a point of
+    // each. Returns the points recovered.
+    pub fn rest(&mut self, fraction: f32) -> u32 {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let share = |amount: u32| ((amount as f32 * fraction).ceil() as u32).min(amount);
+        
+        let before = self.spell_points.current;
+        self.restore_spell_points(share(self.spell_points.max).max(1));
+        let mut recovered = self.spell_points.current - before;
+        for spent in self.reserve_spent.values_mut() {
+            let restored = share(*spent).max(1).min(*spent);
+            *spent -= restored;
+            recovered += restored;
+        }
+        self.reserve_spent.retain(|_, spent| *spent > 0);
+        recovered
+    }
+    
     pub fn get_school_skill(&self, school: &MagicSchool) -> u8 {
         self.school_skills.get(school).copied().unwrap_or(0)
     }
@@ -158,6 +372,12 @@ impl std::fmt::Display for MagicSchool {
     }
 }
 
+// Every spell in the game, built once and shared by the screens that look spells up
+pub fn spell_book() -> &'static HashMap<String, Spell> {
+    static SPELL_BOOK: OnceLock<HashMap<String, Spell>> = OnceLock::new();
+    SPELL_BOOK.get_or_init(create_starter_spells)
+}
+
 // Create starter spells for each school
 pub fn create_starter_spells() -> HashMap<String, Spell> {
     let mut spells = HashMap::new();
@@ -176,6 +396,8 @@ pub fn create_starter_spells() -> HashMap<String, Spell> {
         description: "Allows the caster to speak with and understand animals.".to_string(),
         success_chance_base: 70,
         backfire_chance: 10,
+        cooldown_rounds: 0,
+        uses_per_rest: None,
     });
     
     spells.insert("Bear Strength".to_string(), Spell {
@@ -192,6 +414,8 @@ pub fn create_starter_spells() -> HashMap<String, Spell> {
         description: "Grants the strength of a bear, increasing damage for 5 rounds.".to_string(),
         success_chance_base: 65,
         backfire_chance: 15,
+        cooldown_rounds: 0,
+        uses_per_rest: None,
     });
     
     // Elemental Magic Spells
@@ -209,6 +433,8 @@ pub fn create_starter_spells() -> HashMap<String, Spell> {
         description: "Hurls a bolt of fire at a single enemy.".to_string(),
         success_chance_base: 75,
         backfire_chance: 10,
+        cooldown_rounds: 1,
+        uses_per_rest: None,
     });
     
     spells.insert("Lightning Strike".to_string(), Spell {
@@ -225,6 +451,8 @@ pub fn create_starter_spells() -> HashMap<String, Spell> {
         description: "Calls down a lightning bolt on a single enemy.".to_string(),
         success_chance_base: 65,
         backfire_chance: 20,
+        cooldown_rounds: 3,
+        uses_per_rest: None,
     });
     
     // Enchantment Magic Spells
@@ -242,6 +470,8 @@ pub fn create_starter_spells() -> HashMap<String, Spell> {
         description: "Blesses a weapon, increasing attack accuracy for 8 rounds.".to_string(),
         success_chance_base: 80,
         backfire_chance: 5,
+        cooldown_rounds: 0,
+        uses_per_rest: None,
     });
     
     spells.insert("Shield of Faith".to_string(), Spell {
@@ -258,6 +488,8 @@ pub fn create_starter_spells() -> HashMap<String, Spell> {
         description: "Creates a magical shield that increases defense for 6 rounds.".to_string(),
         success_chance_base: 75,
         backfire_chance: 10,
+        cooldown_rounds: 0,
+        uses_per_rest: None,
     });
     
     // Necromancer Magic Spells
@@ -281,6 +513,8 @@ pub fn create_starter_spells() -> HashMap<String, Spell> {
         description: "Drains life from an enemy and heals the caster.".to_string(),
         success_chance_base: 60,
         backfire_chance: 25,
+        cooldown_rounds: 2,
+        uses_per_rest: Some(3),
     });
     
     spells.insert("Weaken".to_string(), Spell {
@@ -297,6 +531,8 @@ pub fn create_starter_spells() -> HashMap<String, Spell> {
         description: "Weakens an enemy, reducing their attack for 4 rounds.".to_string(),
         success_chance_base: 70,
         backfire_chance: 15,
+        cooldown_rounds: 0,
+        uses_per_rest: None,
     });
     
     // Divine Magic Spells
@@ -313,6 +549,8 @@ pub fn create_starter_spells() -> HashMap<String, Spell> {
         description: "Channels divine energy to heal wounds.".to_string(),
         success_chance_base: 85,
         backfire_chance: 5,
+        cooldown_rounds: 0,
+        uses_per_rest: Some(3),
     });
     
     spells.insert("Turn Undead".to_string(), Spell {
@@ -328,7 +566,103 @@ pub fn create_starter_spells() -> HashMap<String, Spell> {
         description: "Channels divine power to turn away undead creatures.".to_string(),
         success_chance_base: 70,
         backfire_chance: 10,
+        cooldown_rounds: 2,
+        uses_per_rest: Some(2),
     });
     
     spells
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limited_spells_are_refused_until_they_recover() {
+        let spells = create_starter_spells();
+        let lightning = &spells["Lightning Strike"];
+        let heal = &spells["Heal Wounds"];
+        let mut magic = MagicSystem::new(20);
+        assert_eq!(magic.cast_refusal(lightning, 1), None);
+
+        magic.record_spell_cast(lightning, 1);
+        assert!(magic.cast_refusal(lightning, 2).is_some());
+        assert_eq!(magic.cast_refusal(lightning, 1 + lightning.cooldown_rounds as u32 + 1), None);
+        magic.reset_cooldowns();
+        assert_eq!(magic.cast_refusal(lightning, 2), None);
+
+        for round in 1..=3 {
+            magic.record_spell_cast(heal, round);
+        }
+        assert!(magic.cast_refusal(heal, 10).is_some());
+        magic.reset_rest_limits();
+        assert_eq!(magic.cast_refusal(heal, 10), None);
+    }
+
+    #[test]
+    fn spells_are_refused_without_enough_points() {
+        let spells = create_starter_spells();
+        let lightning = &spells["Lightning Strike"];
+        let mut magic = MagicSystem::new(20);
+        magic.spell_points.current = lightning.cost as u32 - 1;
+        assert!(magic.cast_refusal(lightning, 1).is_some());
+    }
+
+    #[test]
+    fn every_spell_can_succeed_backfire_or_fail_at_any_skill() {
+        for spell in create_starter_spells().values() {
+            for skill in [0, 20] {
+                let success = spell.success_chance(skill);
+                let backfire = spell.backfire_threshold(skill);
+                assert!((1..=MAX_SPELL_SUCCESS_CHANCE).contains(&success), "{} at skill {}", spell.name, skill);
+                assert!(backfire > success, "{} can't backfire at skill {}", spell.name, skill);
+                assert!(backfire < 100, "{} can't fail at skill {}", spell.name, skill);
+            }
+            assert!(spell.success_chance(20) >= spell.success_chance(0));
+        }
+    }
+
+    #[test]
+    fn dice_rolls_stay_within_their_range() {
+        for _ in 0..200 {
+            assert!((5..=15).contains(&roll_dice("2d6", 3)));
+            assert_eq!(roll_dice("1d4", -10), 0);
+        }
+    }
+
+    #[test]
+    fn each_band_of_the_backfire_table_has_its_mishap() {
+        let spells = create_starter_spells();
+        let spell = spells.values()
+            .find(|spell| matches!(spell.effects.first(), Some(SpellEffect::Damage { .. })))
+            .expect("no damage spell");
+        let mut skilled = MagicSystem::new(10);
+        skilled.school_skills.insert(spell.school.clone(), 3);
+
+        for roll in [1, 40] {
+            assert!(matches!(resolve_backfire(spell, &skilled, roll), BackfireOutcome::Feedback { damage } if damage >= 1));
+        }
+        for roll in [41, 65] {
+            assert_eq!(resolve_backfire(spell, &skilled, roll), BackfireOutcome::Misdirected);
+        }
+        for roll in [66, 80] {
+            assert_eq!(resolve_backfire(spell, &skilled, roll), BackfireOutcome::SkillDrained);
+        }
+        for roll in [81, 100] {
+            assert_eq!(resolve_backfire(spell, &skilled, roll), BackfireOutcome::Fizzled);
+        }
+    }
+
+    #[test]
+    fn a_caster_with_no_skill_to_lose_takes_feedback_instead() {
+        let spells = create_starter_spells();
+        let spell = &spells["Heal Wounds"];
+        let novice = MagicSystem::new(10);
+        assert!(matches!(resolve_backfire(spell, &novice, 70), BackfireOutcome::Feedback { .. }));
+        // Spells without dice feed back their cost
+        let buff = spells.values()
+            .find(|spell| spell.effects.iter().all(|effect| !matches!(effect, SpellEffect::Damage { .. } | SpellEffect::Heal { .. })))
+            .expect("no spell without dice");
+        assert_eq!(resolve_backfire(buff, &novice, 10), BackfireOutcome::Feedback { damage: (buff.cost as u32).max(1) });
+    }
+}
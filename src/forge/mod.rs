@@ -2,10 +2,28 @@ use serde::{Deserialize, Serialize};
 use rand::Rng;
 use std::collections::HashMap;
 
+pub mod ai;
 pub mod combat;
+pub mod difficulty;
+pub mod encumbrance;
 pub mod magic;
+pub mod stats;
+pub mod status;
+pub use ai::*;
 pub use combat::*;
+pub use difficulty::*;
+pub use encumbrance::*;
 pub use magic::*;
+pub use stats::*;
+pub use status::*;
+
+pub const TORCH_LIGHT: u8 = 8; // Light level a lit torch gives, enough to see 4 tiles
+pub const PRACTICE_XP: u32 = 5;       // Using a skill or school well, once per fight
+pub const SKILL_ADVANCE_XP: u32 = 10; // A skill or school going up a level
+pub const MAX_FOLLOWERS: usize = 3;   // Undead a necromancer can hold to their will at once
+pub const COMBAT_POTIONS: [&str; 2] = ["Health Potion", "Spell Point Potion"]; // Drinkable mid-fight
+pub const SPELL_POTION_POINTS: u32 = 10;
+pub const HEALTH_POTION_HP: u32 = 10;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForgeCharacteristics {
@@ -28,6 +46,18 @@ pub struct CombatStats {
     pub damage_bonus: i8,
 }
 
+// Values worked out from the characteristics, see ForgeCharacterCreation::derive_secondary_stats
+#[derive(Debug, Clone)]
+pub struct SecondaryStats {
+    pub hit_points: u32,
+    pub attack_value: u8,
+    pub defensive_value: u8,
+    pub damage_bonus: i8,
+    pub spell_points: u32,
+    pub carry_capacity: u32, // Pounds carried without slowing down
+    pub movement: u32,       // Feet per combat round
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthPoints {
     pub current: u32,
@@ -53,6 +83,51 @@ pub struct ForgeCharacter {
     pub current_position: Option<crate::world::LocalCoord>,
     pub vision_radius: u8,              // Base vision radius in tiles
     pub torch_lit: bool,                // Whether a torch is currently lit
+    #[serde(default)]
+    pub reputation: HashMap<String, i32>, // Faction name -> reputation (-100 to 100)
+    #[serde(default)]
+    pub map_memory: crate::world::MapMemory, // Overworld tiles the character has seen
+    #[serde(default)]
+    pub active_dungeon: Option<crate::world::SavedDungeon>, // Dungeon the character was in when the game was saved
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub hours_elapsed: u32, // In-game time since the adventure began
+    #[serde(default)]
+    pub bestiary: crate::world::Bestiary, // Creatures the character has fought
+    #[serde(default)]
+    pub resource_nodes: crate::world::ResourceNodes, // Gathering spots picked over recently
+    #[serde(default)]
+    pub status_effects: Vec<StatusEffect>, // Curses, poison and blessings that outlast a fight
+    #[serde(default)]
+    pub fatigue: u32, // Stamina spent in fights and not yet recovered
+    #[serde(default)]
+    pub stats: PlayStats, // Playtime and campaign totals
+    #[serde(default = "starting_weapon")]
+    pub equipped_weapon: Option<Weapon>, // None fights bare-handed
+    #[serde(default = "starting_armor")]
+    pub equipped_armor: Option<Armor>,
+    #[serde(default)]
+    pub equipped_shield: Option<Armor>,
+    #[serde(default)]
+    pub followers: Vec<CombatParticipant>, // Raised undead that join the character's fights
+    #[serde(default)]
+    pub travel_steps: u32, // Steps taken toward the next hour of travel
+    #[serde(default)]
+    pub active_quests: Vec<crate::world::Quest>,
+    #[serde(default)]
+    pub completed_quests: Vec<String>, // Ids of quests already done, which aren't offered again
+    #[serde(default)]
+    pub quests_to_report: Vec<String>, // Done, but the giver hasn't heard about it yet
+}
+
+// Characters saved before equipment slots fought with this gear
+fn starting_weapon() -> Option<Weapon> {
+    Some(Weapon::rusty_sword())
+}
+
+fn starting_armor() -> Option<Armor> {
+    Some(Armor::leather())
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -97,14 +172,31 @@ pub struct RolledCharacteristics {
     pub luck: CharacterRoll,
 }
 
+// Point-buy gives this many points to spread over the characteristics, about
+// what an average roll comes to
+pub const POINT_BUY_POOL: u8 = 42;
+// Each characteristic's lowest and highest possible roll, in display order.
+// Point-buy starts everything at the minimum.
+pub const CHARACTERISTIC_RANGES: [(&str, u8, u8); 9] = [
+    ("Strength", 3, 13),
+    ("Stamina", 3, 13),
+    ("Intellect", 3, 13),
+    ("Insight", 3, 13),
+    ("Dexterity", 3, 13),
+    ("Awareness", 3, 13),
+    ("Speed", 2, 5),
+    ("Power", 2, 20),
+    ("Luck", 6, 16),
+];
+
 pub struct ForgeCharacterCreation;
 
 impl ForgeCharacterCreation {
     pub fn roll_characteristics() -> RolledCharacteristics {
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::rng::session_rng();
         
         // Helper function for 2d6 + 1d10 rolls
-        let roll_2d6_1d10 = |rng: &mut rand::rngs::ThreadRng| -> CharacterRoll {
+        let roll_2d6_1d10 = |rng: &mut crate::rng::SessionRng| -> CharacterRoll {
             let d6_1 = rng.gen_range(1..=6);
             let d6_2 = rng.gen_range(1..=6);
             let d10_raw = rng.gen_range(0..=9); // 0-9, where 0 = 1.0
@@ -158,6 +250,32 @@ impl ForgeCharacterCreation {
         }
     }
 
+    // Characteristics bought with points instead of rolled. `allocation` holds the
+    // points put into each one, in the order of CHARACTERISTIC_RANGES.
+    pub fn point_buy_characteristics(allocation: &[u8; 9]) -> RolledCharacteristics {
+        let bought = |index: usize| {
+            let (_, min, max) = CHARACTERISTIC_RANGES[index];
+            let total = (min + allocation[index]).min(max);
+            CharacterRoll {
+                d6_1: 0, d6_2: 0, d10: 0,
+                total: total as f32,
+                formula: format!("{} + {} bought", min, allocation[index]),
+            }
+        };
+
+        RolledCharacteristics {
+            strength: bought(0),
+            stamina: bought(1),
+            intellect: bought(2),
+            insight: bought(3),
+            dexterity: bought(4),
+            awareness: bought(5),
+            speed: bought(6),
+            power: bought(7),
+            luck: bought(8),
+        }
+    }
+
     pub fn get_available_races() -> Vec<ForgeRace> {
         vec![
             // 1. Berserkers
@@ -350,8 +468,16 @@ impl ForgeCharacterCreation {
         characteristics: ForgeCharacteristics,
         race: ForgeRace,
     ) -> ForgeCharacter {
-        let hit_points = Self::calculate_hit_points(&characteristics);
-        let combat_stats = Self::calculate_combat_stats(&characteristics, hit_points);
+        let derived = Self::derive_secondary_stats(&characteristics);
+        let combat_stats = CombatStats {
+            hit_points: HealthPoints {
+                current: derived.hit_points,
+                max: derived.hit_points,
+            },
+            attack_value: derived.attack_value,
+            defensive_value: derived.defensive_value,
+            damage_bonus: derived.damage_bonus,
+        };
         
         let mut character = ForgeCharacter {
             name,
@@ -379,6 +505,24 @@ impl ForgeCharacterCreation {
             current_position: Some(crate::world::LocalCoord::new(32, 32)), // Center of zone
             vision_radius: 2, // Will be set by racial abilities
             torch_lit: false,
+            reputation: HashMap::new(),
+            map_memory: crate::world::MapMemory::new(),
+            active_dungeon: None,
+            difficulty: Difficulty::Normal,
+            hours_elapsed: 0,
+            bestiary: crate::world::Bestiary::new(),
+            resource_nodes: crate::world::ResourceNodes::new(),
+            status_effects: Vec::new(),
+            fatigue: 0,
+            stats: PlayStats::default(),
+            equipped_weapon: starting_weapon(),
+            equipped_armor: starting_armor(),
+            equipped_shield: None,
+            followers: Vec::new(),
+            travel_steps: 0,
+            active_quests: Vec::new(),
+            completed_quests: Vec::new(),
+            quests_to_report: Vec::new(),
         };
         
         // Set racial vision radius
@@ -386,20 +530,23 @@ impl ForgeCharacterCreation {
         character
     }
 
-    fn calculate_hit_points(characteristics: &ForgeCharacteristics) -> u32 {
-        // Hit Points = (STR + STA) / 2, minimum 1
-        ((characteristics.strength + characteristics.stamina) / 2.0).max(1.0) as u32
-    }
-
-    fn calculate_combat_stats(characteristics: &ForgeCharacteristics, hit_points: u32) -> CombatStats {
-        CombatStats {
-            hit_points: HealthPoints {
-                current: hit_points,
-                max: hit_points,
-            },
+    // Everything derived from the characteristics (racial modifiers already applied):
+    //   Hit Points     = (STR + STA) / 2, minimum 1
+    //   Attack Value   = DEX + STR / 2
+    //   Defense Value  = DEX + AWR / 2
+    //   Damage Bonus   = (STR - 10) / 3
+    //   Spell Points   = POW x 2
+    //   Carry Capacity = STR x 15 lbs
+    //   Movement       = SPD x 10 ft per round
+    pub fn derive_secondary_stats(characteristics: &ForgeCharacteristics) -> SecondaryStats {
+        SecondaryStats {
+            hit_points: ((characteristics.strength + characteristics.stamina) / 2.0).max(1.0) as u32,
             attack_value: (characteristics.dexterity + characteristics.strength / 2.0) as u8,
             defensive_value: (characteristics.dexterity + characteristics.awareness / 2.0) as u8,
             damage_bonus: ((characteristics.strength - 10.0) / 3.0) as i8,
+            spell_points: characteristics.power as u32 * 2,
+            carry_capacity: (characteristics.strength * 15.0) as u32,
+            movement: characteristics.speed as u32 * 10,
         }
     }
     
@@ -420,11 +567,41 @@ impl ForgeCharacterCreation {
             _ => None, // Fallback for unknown races
         }
     }
+
+    // Minimum characteristics needed to use a piece of gear effectively
+    pub fn gear_requirements(item: &str) -> Vec<(&'static str, f32)> {
+        match item {
+            "Long Sword" => vec![("STR", 7.0)],
+            "Battle Axe" | "War Hammer" => vec![("STR", 9.0)],
+            "Two-Handed Sword" => vec![("STR", 10.0)],
+            "Short Bow" => vec![("DEX", 7.0)],
+            "Longbow" => vec![("STR", 7.0), ("DEX", 9.0)],
+            "Crossbow" => vec![("STR", 6.0)],
+            "Chain Mail" | "Scale Mail" | "Large Shield" => vec![("STR", 8.0)],
+            "Plate Mail" => vec![("STR", 10.0)],
+            "Thieves' Tools" => vec![("DEX", 8.0)],
+            _ => Vec::new(),
+        }
+    }
+
+    // Requirements from gear_requirements that the characteristics fall short of
+    pub fn unmet_gear_requirements(item: &str, characteristics: &ForgeCharacteristics) -> Vec<(&'static str, f32)> {
+        Self::gear_requirements(item).into_iter()
+            .filter(|(stat, minimum)| {
+                let value = match *stat {
+                    "STR" => characteristics.strength,
+                    "DEX" => characteristics.dexterity,
+                    _ => return false,
+                };
+                value < *minimum
+            })
+            .collect()
+    }
 }
 
 impl ForgeCharacter {
     pub fn get_display_info(&self) -> Vec<String> {
-        vec![
+        let mut info = vec![
             format!("Name: {}", self.name),
             format!("Race: {} (Level {})", self.race.name, self.level),
             format!("Experience: {}", self.experience),
@@ -445,18 +622,301 @@ impl ForgeCharacter {
             format!("Attack Val:  {}", self.combat_stats.attack_value),
             format!("Defense Val: {}", self.combat_stats.defensive_value),
             format!("Dmg Bonus:   {:+}", self.combat_stats.damage_bonus),
+            format!("Stamina:     {}/{}", self.current_stamina(), self.max_stamina()),
+            format!("Carry:       {} lbs", self.secondary_stats().carry_capacity),
+            format!("Movement:    {} ft/round", self.secondary_stats().movement),
+            format!("Conditions:  {}", if self.status_effects.is_empty() {
+                "None".to_string()
+            } else {
+                self.status_effects.iter().map(|effect| effect.describe()).collect::<Vec<_>>().join(", ")
+            }),
             "".to_string(),
             "=== MAGIC ===".to_string(),
             format!("Spell Points: {}/{}", self.magic.spell_points.current, self.magic.spell_points.max),
+        ];
+        info.extend(self.magic.describe_reserves());
+        info.extend([
             format!("Known Spells: {}", self.magic.get_all_known_spells().len()),
             "".to_string(),
             format!("Gold: {}", self.gold),
-        ]
+            "".to_string(),
+            "=== CAMPAIGN ===".to_string(),
+        ]);
+        info.extend(self.stats.describe());
+        info
+    }
+
+    pub fn secondary_stats(&self) -> SecondaryStats {
+        ForgeCharacterCreation::derive_secondary_stats(&self.characteristics)
     }
 
     pub fn update_last_played(&mut self) {
         self.last_played = chrono::Utc::now();
     }
+
+    pub fn advance_time(&mut self, hours: u32) {
+        self.hours_elapsed = self.hours_elapsed.saturating_add(hours);
+    }
+
+    // Every so many steps of travel another hour goes by. Returns the new time of
+    // day when the step crosses into one.
+    pub fn take_step(&mut self) -> Option<crate::world::TimeOfDay> {
+        self.travel_steps += 1;
+        if self.travel_steps < crate::world::STEPS_PER_HOUR {
+            return None;
+        }
+        self.travel_steps = 0;
+        let before = self.time_of_day();
+        self.advance_time(1);
+        Some(self.time_of_day()).filter(|&now| now != before)
+    }
+
+    pub fn hour_of_day(&self) -> u32 {
+        (self.hours_elapsed + crate::world::START_HOUR) % 24
+    }
+
+    pub fn time_of_day(&self) -> crate::world::TimeOfDay {
+        crate::world::TimeOfDay::from_hour(self.hour_of_day())
+    }
+
+    // A full day if it's morning already
+    pub fn hours_until_morning(&self) -> u32 {
+        match (crate::world::MORNING_HOUR + 24 - self.hour_of_day()) % 24 {
+            0 => 24,
+            hours => hours,
+        }
+    }
+
+    // All gold found or won goes through here so the campaign total stays right
+    pub fn earn_gold(&mut self, amount: u32) {
+        self.gold += amount;
+        self.stats.gold_earned += amount;
+    }
+    
+    // Breath for heavy combat actions, one point per point of STA
+    pub fn max_stamina(&self) -> u32 {
+        self.characteristics.stamina.max(1.0) as u32
+    }
+    
+    pub fn current_stamina(&self) -> u32 {
+        self.max_stamina().saturating_sub(self.fatigue)
+    }
+    
+    pub fn recover_stamina(&mut self, amount: u32) {
+        self.fatigue = self.fatigue.saturating_sub(amount);
+    }
+    
+    // Move a weapon, armor or shield from the pack into its slot. Whatever it
+    // replaces goes back into the pack, and the names of those items are returned.
+    pub fn equip_item(&mut self, item_name: &str) -> anyhow::Result<Vec<String>> {
+        let index = self.inventory.iter().position(|item| item == item_name)
+            .ok_or_else(|| anyhow::anyhow!("There's no {} in your pack", item_name))?;
+
+        let mut unequipped = Vec::new();
+        if let Some(weapon) = Weapon::from_item_name(item_name) {
+            // Two hands on the weapon leave none for a shield
+            if weapon.two_handed {
+                unequipped.extend(self.equipped_shield.take().map(|shield| shield.name));
+            }
+            unequipped.extend(self.equipped_weapon.replace(weapon).map(|weapon| weapon.name));
+        } else if let Some(armor) = Armor::from_item_name(item_name) {
+            if armor.is_shield() {
+                if self.equipped_weapon.as_ref().is_some_and(|weapon| weapon.two_handed) {
+                    unequipped.extend(self.equipped_weapon.take().map(|weapon| weapon.name));
+                }
+                unequipped.extend(self.equipped_shield.replace(armor).map(|shield| shield.name));
+            } else {
+                unequipped.extend(self.equipped_armor.replace(armor).map(|armor| armor.name));
+            }
+        } else {
+            anyhow::bail!("{} can't be equipped", item_name);
+        }
+
+        self.inventory.remove(index);
+        self.inventory.extend(unequipped.iter().cloned());
+        Ok(unequipped)
+    }
+
+    // e.g. "Iron Sword, Chain Mail, no shield"
+    pub fn describe_equipment(&self) -> String {
+        format!("{}, {}, {}",
+            self.equipped_weapon.as_ref().map(|weapon| weapon.name.as_str()).unwrap_or("bare hands"),
+            self.equipped_armor.as_ref().map(|armor| armor.name.as_str()).unwrap_or("no armor"),
+            self.equipped_shield.as_ref().map(|shield| shield.name.as_str()).unwrap_or("no shield"))
+    }
+
+    // Count lasting effects down by one exploration turn. Poison hurts but never kills.
+    pub fn tick_status_effects(&mut self) -> Vec<String> {
+        let mut messages = Vec::new();
+        let hit_points = &mut self.combat_stats.hit_points;
+        for effect in &mut self.status_effects {
+            effect.turns_remaining = effect.turns_remaining.saturating_sub(1);
+            if effect.kind == StatusKind::Poison && effect.turns_remaining.is_multiple_of(POISON_TICK_TURNS) {
+                let lost = (effect.potency as u32).min(hit_points.current.saturating_sub(1));
+                if lost > 0 {
+                    hit_points.current -= lost;
+                    messages.push(format!("🤢 Poison burns in your veins (-{} HP).", lost));
+                }
+            }
+            if effect.turns_remaining == 0 {
+                messages.push(format!("✨ You are no longer {}.", effect.kind.get_name().to_lowercase()));
+            }
+        }
+        self.status_effects.retain(|effect| effect.turns_remaining > 0);
+        messages
+    }
+
+    pub fn xp_for_next_level(&self) -> u32 {
+        (self.level as u32 + 1) * 100
+    }
+
+    // Add experience and return any levels reached. Each level adds hit points
+    // and heals the character fully.
+    pub fn gain_experience(&mut self, xp: u32) -> Vec<u8> {
+        let mut new_levels = Vec::new();
+        self.experience += xp;
+        while self.experience >= self.xp_for_next_level() {
+            self.experience -= self.xp_for_next_level();
+            self.level += 1;
+            self.combat_stats.hit_points.max += 5;
+            self.combat_stats.hit_points.current = self.combat_stats.hit_points.max;
+            new_levels.push(self.level);
+        }
+        new_levels
+    }
+
+    // Take the share of experience a death costs on the character's difficulty.
+    // Returns how much was lost.
+    pub fn apply_death_penalty(&mut self) -> u32 {
+        let lost = self.experience * self.difficulty.death_xp_penalty_percent() / 100;
+        self.experience -= lost;
+        lost
+    }
+
+    // Back on their feet after a lost fight, with only a quarter of their health
+    pub fn revive(&mut self) {
+        let max_hp = self.combat_stats.hit_points.max;
+        self.combat_stats.hit_points.current = (max_hp / 4).max(1);
+    }
+
+    // Award a pip for putting a skill to good use. It takes (level + 1) pips to
+    // reach the next level. Returns the new level when the skill advances.
+    pub fn award_skill_pip(&mut self, skill: &str) -> Option<u8> {
+        let current_pips = self.skill_pips.get(skill).copied().unwrap_or(0);
+        let current_level = self.skills.get(skill).copied().unwrap_or(0);
+        let new_pips = current_pips + 1;
+        
+        if new_pips > current_level {
+            self.skills.insert(skill.to_string(), current_level + 1);
+            self.skill_pips.insert(skill.to_string(), 0);
+            Some(current_level + 1)
+        } else {
+            self.skill_pips.insert(skill.to_string(), new_pips);
+            None
+        }
+    }
+
+    // Count a defeated foe towards every quest it fits. Quests finished by it are
+    // taken off the active list and returned for their rewards.
+    pub fn record_quest_kill(&mut self, foe_name: &str) -> Vec<crate::world::Quest> {
+        let mut finished = Vec::new();
+        for quest in &mut self.active_quests {
+            if quest.record_kill(foe_name) && quest.is_complete() {
+                finished.push(quest.clone());
+            }
+        }
+        self.active_quests.retain(|quest| !quest.is_complete());
+        self.completed_quests.extend(finished.iter().map(|quest| quest.id.clone()));
+        self.quests_to_report.extend(finished.iter().map(|quest| quest.id.clone()));
+        finished
+    }
+
+    // Take one of an item out of the pack, splitting it off a stack if need be.
+    // Returns false if there was none.
+    pub fn remove_one_item(&mut self, name: &str) -> bool {
+        let Some(index) = self.inventory.iter().position(|item| crate::world::split_stack(item).0 == name) else {
+            return false;
+        };
+        let (_, count) = crate::world::split_stack(&self.inventory[index]);
+        if count > 1 {
+            self.inventory[index] = format!("{} ({})", name, count - 1);
+        } else {
+            self.inventory.remove(index);
+        }
+        true
+    }
+
+    // The pack with stacks and repeated items counted together, in the order they
+    // were picked up
+    pub fn inventory_summary(&self) -> Vec<(String, u32)> {
+        let mut summary: Vec<(String, u32)> = Vec::new();
+        for item in &self.inventory {
+            let (name, count) = crate::world::split_stack(item);
+            match summary.iter_mut().find(|(existing, _)| existing == name) {
+                Some((_, total)) => *total += count,
+                None => summary.push((name.to_string(), count)),
+            }
+        }
+        summary
+    }
+
+    pub fn is_consumable(name: &str) -> bool {
+        matches!(name, "Health Potion" | "Spell Point Potion" | "Herbal Remedy")
+    }
+
+    // Drink or eat one of an item from the pack and say what it did
+    pub fn consume_item(&mut self, name: &str) -> anyhow::Result<String> {
+        if !Self::is_consumable(name) {
+            anyhow::bail!("You can't make use of the {} like that", name);
+        }
+        if !self.remove_one_item(name) {
+            anyhow::bail!("There's no {} in your pack", name);
+        }
+
+        let message = match name {
+            "Health Potion" => {
+                let hp = &mut self.combat_stats.hit_points;
+                let healed = HEALTH_POTION_HP.min(hp.max - hp.current);
+                hp.current += healed;
+                format!("🧪 You drink the health potion and recover {} HP.", healed)
+            }
+            "Spell Point Potion" => {
+                let before = self.magic.spell_points.current;
+                self.magic.restore_spell_points(SPELL_POTION_POINTS);
+                format!("🔷 You drink the spell point potion and recover {} spell points.", self.magic.spell_points.current - before)
+            }
+            _ => {
+                let poisoned = self.status_effects.iter().any(|effect| effect.kind == StatusKind::Poison);
+                self.status_effects.retain(|effect| effect.kind != StatusKind::Poison);
+                if poisoned {
+                    "🌿 The bitter herbs draw the poison out.".to_string()
+                } else {
+                    "🌿 The bitter herbs do you no harm, and no good either.".to_string()
+                }
+            }
+        };
+        Ok(message)
+    }
+
+    // Stackable supplies are kept as one entry each, e.g. "Fresh Fish (3)"
+    pub fn add_stacked_item(&mut self, name: &str, quantity: u32) {
+        let prefix = format!("{} (", name);
+        let existing = self.inventory.iter()
+            .position(|item| item.starts_with(&prefix) && item.ends_with(')'));
+        let count = existing
+            .and_then(|index| self.inventory[index][prefix.len()..self.inventory[index].len() - 1].parse::<u32>().ok())
+            .unwrap_or(0);
+        let entry = format!("{} ({})", name, count + quantity);
+        match existing {
+            Some(index) => self.inventory[index] = entry,
+            None => self.inventory.push(entry),
+        }
+    }
+
+    // Days are counted from 1
+    pub fn current_day(&self) -> u32 {
+        (self.hours_elapsed + crate::world::START_HOUR) / 24 + 1
+    }
     
     pub fn get_vision_radius(&self) -> u8 {
         let mut vision = self.vision_radius;
@@ -470,6 +930,27 @@ impl ForgeCharacter {
         vision
     }
     
+    // The light around the character: their surroundings plus a lit torch
+    pub fn effective_light(&self, ambient_light: u8) -> u8 {
+        if self.torch_lit {
+            (ambient_light + TORCH_LIGHT).min(10)
+        } else {
+            ambient_light
+        }
+    }
+    
+    // Racial vision works in the dark; light lets anyone see further
+    pub fn get_vision_radius_in(&self, ambient_light: u8) -> u8 {
+        self.get_vision_radius().max(self.effective_light(ambient_light) / 2)
+    }
+    
+    // How far the character sees across open country at this hour. After dark
+    // only racial vision or a torch keeps it from shrinking to a few tiles.
+    pub fn overworld_sight_radius(&self) -> i32 {
+        let ambient_light = self.time_of_day().ambient_light();
+        (crate::world::OVERWORLD_SIGHT_RADIUS * ambient_light as i32 / 10).max(self.get_vision_radius_in(ambient_light) as i32)
+    }
+    
     pub fn can_light_torch(&self) -> bool {
         !self.torch_lit && self.inventory.iter().any(|item| item.contains("Torch"))
     }
@@ -501,6 +982,26 @@ impl ForgeCharacter {
     pub fn extinguish_torch(&mut self) {
         self.torch_lit = false;
     }
+
+    pub fn get_reputation(&self, faction: &str) -> i32 {
+        self.reputation.get(faction).copied().unwrap_or(0)
+    }
+
+    pub fn adjust_reputation(&mut self, faction: &str, amount: i32) -> i32 {
+        let reputation = self.reputation.entry(faction.to_string()).or_insert(0);
+        *reputation = (*reputation + amount).clamp(-100, 100);
+        *reputation
+    }
+
+    // Trained in Telepathy, or born to it like the Jher-em
+    pub fn has_telepathy(&self) -> bool {
+        self.skills.get("Telepathy").is_some_and(|&level| level > 0)
+            || self.race.special_abilities.iter().any(|ability| ability.starts_with("Telepathy"))
+    }
+
+    pub fn get_standing(&self, faction: &str) -> crate::world::Standing {
+        crate::world::Standing::from_reputation(self.get_reputation(faction))
+    }
     
     fn get_racial_vision_radius(&self) -> u8 {
         // Extract vision radius from race special abilities
@@ -515,4 +1016,54 @@ impl ForgeCharacter {
         }
         2 // Default human vision radius in dungeons
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+pub(crate) fn sample_character(name: &str) -> ForgeCharacter {
+    let rolled = ForgeCharacterCreation::point_buy_characteristics(&[5; 9]);
+    let race = ForgeCharacterCreation::get_available_races().remove(0);
+    let characteristics = ForgeCharacterCreation::apply_racial_modifiers(&rolled, &race);
+    ForgeCharacterCreation::create_character(name.to_string(), characteristics, race)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn death_costs_more_experience_on_harder_difficulties() {
+        let lost: Vec<u32> = Difficulty::all().into_iter().map(|difficulty| {
+            let mut character = sample_character("Fallen");
+            character.difficulty = difficulty;
+            character.experience = 80;
+            let lost = character.apply_death_penalty();
+            assert_eq!(character.experience, 80 - lost);
+            lost
+        }).collect();
+        assert_eq!(lost, vec![8, 20, 80]);
+    }
+
+    #[test]
+    fn revived_characters_wake_with_a_quarter_of_their_health() {
+        let mut character = sample_character("Fallen");
+        character.combat_stats.hit_points.max = 40;
+        character.combat_stats.hit_points.current = 0;
+        character.revive();
+        assert_eq!(character.combat_stats.hit_points.current, 10);
+
+        character.combat_stats.hit_points.max = 3;
+        character.combat_stats.hit_points.current = 0;
+        character.revive();
+        assert_eq!(character.combat_stats.hit_points.current, 1);
+    }
+
+    #[test]
+    fn a_big_experience_award_levels_up_and_heals() {
+        let mut character = sample_character("Finder");
+        character.combat_stats.hit_points.current = 1;
+        let needed = character.xp_for_next_level();
+
+        assert_eq!(character.gain_experience(needed), vec![character.level]);
+        assert_eq!(character.combat_stats.hit_points.current, character.combat_stats.hit_points.max);
+        assert_eq!(character.experience, 0);
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+// Running totals for a character's whole campaign
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayStats {
+    #[serde(default)]
+    pub playtime_seconds: u64,
+    #[serde(default)]
+    pub enemies_defeated: u32,
+    #[serde(default)]
+    pub dungeons_cleared: u32,
+    #[serde(default)]
+    pub gold_earned: u32,
+    #[serde(default)]
+    pub tiles_traveled: u32, // Steps taken in the overworld and in dungeons
+}
+
+impl PlayStats {
+    // e.g. "3h 12m" or "45m"
+    pub fn describe_playtime(&self) -> String {
+        let minutes = self.playtime_seconds / 60;
+        if minutes >= 60 {
+            format!("{}h {}m", minutes / 60, minutes % 60)
+        } else {
+            format!("{}m", minutes)
+        }
+    }
+
+    pub fn describe(&self) -> Vec<String> {
+        vec![
+            format!("Playtime:    {}", self.describe_playtime()),
+            format!("Foes Slain:  {}", self.enemies_defeated),
+            format!("Dungeons:    {} cleared", self.dungeons_cleared),
+            format!("Gold Earned: {}", self.gold_earned),
+            format!("Traveled:    {} tiles", self.tiles_traveled),
+        ]
+    }
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+pub const POISON_TICK_TURNS: u32 = 5; // Poison bites once every this many turns
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatusKind {
+    Poison,   // Loses hit points as time passes
+    Curse,    // Weakened attack
+    Blessing, // Strengthened attack and defense
+}
+
+impl StatusKind {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            StatusKind::Poison => "Poisoned",
+            StatusKind::Curse => "Cursed",
+            StatusKind::Blessing => "Blessed",
+        }
+    }
+}
+
+// A lasting effect on a character, counted down in exploration turns
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub source: String,    // What caused it, e.g. "a dark altar"
+    pub potency: u8,       // Hit points per poison bite, or the attack/defense modifier
+    pub turns_remaining: u32,
+}
+
+impl StatusEffect {
+    pub fn new(kind: StatusKind, source: &str, potency: u8, turns: u32) -> Self {
+        Self {
+            kind,
+            source: source.to_string(),
+            potency,
+            turns_remaining: turns,
+        }
+    }
+
+    pub fn attack_modifier(&self) -> i8 {
+        match self.kind {
+            StatusKind::Curse => -(self.potency as i8),
+            StatusKind::Blessing => self.potency as i8,
+            StatusKind::Poison => 0,
+        }
+    }
+
+    pub fn defense_modifier(&self) -> i8 {
+        match self.kind {
+            StatusKind::Blessing => self.potency as i8,
+            _ => 0,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        format!("{} ({} turns left)", self.kind.get_name(), self.turns_remaining)
+    }
+}
+
+// Add an effect, replacing a weaker or shorter one of the same kind
+pub fn apply_status_effect(effects: &mut Vec<StatusEffect>, effect: StatusEffect) {
+    match effects.iter_mut().find(|existing| existing.kind == effect.kind) {
+        Some(existing) => {
+            existing.potency = existing.potency.max(effect.potency);
+            existing.turns_remaining = existing.turns_remaining.max(effect.turns_remaining);
+            existing.source = effect.source;
+        }
+        None => effects.push(effect),
+    }
+}
+
+pub fn status_attack_modifier(effects: &[StatusEffect]) -> i8 {
+    effects.iter().map(|effect| effect.attack_modifier()).sum()
+}
+
+pub fn status_defense_modifier(effects: &[StatusEffect]) -> i8 {
+    effects.iter().map(|effect| effect.defense_modifier()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_active_curse_survives_a_save_and_load() {
+        let mut character = crate::forge::sample_character("Cursed");
+        apply_status_effect(&mut character.status_effects, StatusEffect::new(StatusKind::Curse, "a dark altar", 2, 30));
+        character.tick_status_effects();
+
+        let json = serde_json::to_string(&character).unwrap();
+        let loaded: crate::forge::ForgeCharacter = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.status_effects, vec![StatusEffect::new(StatusKind::Curse, "a dark altar", 2, 29)]);
+        assert_eq!(status_attack_modifier(&loaded.status_effects), -2);
+    }
+
+    #[test]
+    fn reapplying_an_effect_keeps_the_stronger_one() {
+        let mut effects = Vec::new();
+        apply_status_effect(&mut effects, StatusEffect::new(StatusKind::Blessing, "a shrine", 1, 50));
+        apply_status_effect(&mut effects, StatusEffect::new(StatusKind::Blessing, "an altar", 2, 10));
+        assert_eq!(effects, vec![StatusEffect::new(StatusKind::Blessing, "an altar", 2, 50)]);
+    }
+}
@@ -0,0 +1,244 @@
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::time::Instant;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use anyhow::{Context, Result};
+
+// Logged in place of a character typed into a password field
+const REDACTED_KEY: &str = "Redacted";
+
+// Something worth knowing about when reading back a playthrough. The seed and
+// key events are what a replay feeds back in, the rest describe what those keys did.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum GameEvent {
+    Seed { seed: u64 }, // The dice rolls of the session, logged first
+    Key { key: String },
+    StateChanged { from: String, to: String },
+    Moved { area: String, x: i32, y: i32 },
+    Combat { line: String },
+    Loot { item: String },
+    Gold { change: i64, total: u32 },
+    LevelUp { level: u8 },
+}
+
+#[derive(Serialize, Deserialize)]
+struct LoggedEvent {
+    ms: u64, // Since recording started
+    #[serde(flatten)]
+    event: GameEvent,
+}
+
+// The parts of the game the log keeps an eye on, taken before and after each key
+#[derive(Debug, Clone, Default)]
+pub struct Snapshot {
+    pub state: &'static str,
+    pub position: Option<(String, i32, i32)>, // Area name and coordinates
+    pub combat_lines: usize,
+    pub level: u8,
+    pub gold: u32,
+    pub inventory: Vec<String>,
+    pub typing_secret: bool, // A password field has focus, so typed characters stay out of the log
+}
+
+// Writes game events to a file as JSON lines
+pub struct EventLog {
+    writer: BufWriter<fs::File>,
+    started: Instant,
+}
+
+impl EventLog {
+    // Start a log for a session whose rolls come from `seed`
+    pub fn create(path: &Path, seed: u64) -> Result<Self> {
+        let file = fs::File::create(path)
+            .with_context(|| format!("Failed to create event log {}", path.display()))?;
+        let mut log = Self {
+            writer: BufWriter::new(file),
+            started: Instant::now(),
+        };
+        log.record(GameEvent::Seed { seed })?;
+        Ok(log)
+    }
+
+    pub fn record(&mut self, event: GameEvent) -> Result<()> {
+        let logged = LoggedEvent {
+            ms: self.started.elapsed().as_millis() as u64,
+            event,
+        };
+        serde_json::to_writer(&mut self.writer, &logged)?;
+        self.writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    // Log a key press and whatever it changed. Flushed right away so a crash
+    // still leaves the keys that led up to it.
+    pub fn record_turn(&mut self, key: &KeyEvent, before: &Snapshot, after: &Snapshot, combat_log: &[String]) -> Result<()> {
+        let secret = before.typing_secret && matches!(key.code, KeyCode::Char(_)) && !key.modifiers.contains(KeyModifiers::CONTROL);
+        if secret {
+            self.record(GameEvent::Key { key: REDACTED_KEY.to_string() })?;
+        } else if let Some(key) = encode_key(key) {
+            self.record(GameEvent::Key { key })?;
+        }
+
+        if before.state != after.state {
+            self.record(GameEvent::StateChanged { from: before.state.to_string(), to: after.state.to_string() })?;
+        }
+        if let Some((area, x, y)) = after.position.as_ref().filter(|_| before.position != after.position) {
+            self.record(GameEvent::Moved { area: area.clone(), x: *x, y: *y })?;
+        }
+        // A fresh fight starts a fresh log
+        let first_new_line = if before.state == after.state { before.combat_lines.min(combat_log.len()) } else { 0 };
+        for line in &combat_log[first_new_line..] {
+            self.record(GameEvent::Combat { line: line.clone() })?;
+        }
+
+        let mut previous = before.inventory.clone();
+        for item in &after.inventory {
+            match previous.iter().position(|old| old == item) {
+                Some(index) => {
+                    previous.remove(index);
+                }
+                None => self.record(GameEvent::Loot { item: item.clone() })?,
+            }
+        }
+        if before.gold != after.gold {
+            self.record(GameEvent::Gold { change: after.gold as i64 - before.gold as i64, total: after.gold })?;
+        }
+        if after.level > before.level {
+            self.record(GameEvent::LevelUp { level: after.level })?;
+        }
+
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+// What a replay needs from an event log: the seed its dice were rolled from and the key presses in order
+pub struct Replay {
+    pub seed: Option<u64>, // Logs written before seeds were recorded don't have one
+    pub keys: VecDeque<KeyEvent>,
+}
+
+pub fn load_replay(path: &Path) -> Result<Replay> {
+    let file = fs::File::open(path)
+        .with_context(|| format!("Failed to open replay {}", path.display()))?;
+
+    let mut seed = None;
+    let mut keys = VecDeque::new();
+    for (number, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let logged: LoggedEvent = serde_json::from_str(&line)
+            .with_context(|| format!("Line {} of {} is not a game event", number + 1, path.display()))?;
+        if let GameEvent::Seed { seed: logged_seed } = logged.event {
+            seed = Some(logged_seed);
+        } else if let GameEvent::Key { key } = logged.event {
+            // The password was never written down, so the player takes over from here
+            if key == REDACTED_KEY {
+                break;
+            }
+            let key_event = decode_key(&key)
+                .ok_or_else(|| anyhow::anyhow!("Unknown key '{}' on line {} of {}", key, number + 1, path.display()))?;
+            keys.push_back(key_event);
+        }
+    }
+    Ok(Replay { seed, keys })
+}
+
+// Keys are stored by name, e.g. "a", "Enter", "PageUp" or "Ctrl+q"
+pub fn encode_key(key: &KeyEvent) -> Option<String> {
+    let name = match key.code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => "BackTab".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        KeyCode::F(number) => format!("F{}", number),
+        _ => return None,
+    };
+
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        Some(format!("Ctrl+{}", name))
+    } else {
+        Some(name)
+    }
+}
+
+pub fn decode_key(text: &str) -> Option<KeyEvent> {
+    let (name, modifiers) = match text.strip_prefix("Ctrl+") {
+        Some(name) if !name.is_empty() => (name, KeyModifiers::CONTROL),
+        _ => (text, KeyModifiers::NONE),
+    };
+
+    let mut chars = name.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match name {
+            "Enter" => KeyCode::Enter,
+            "Esc" => KeyCode::Esc,
+            "Backspace" => KeyCode::Backspace,
+            "Tab" => KeyCode::Tab,
+            "BackTab" => KeyCode::BackTab,
+            "Up" => KeyCode::Up,
+            "Down" => KeyCode::Down,
+            "Left" => KeyCode::Left,
+            "Right" => KeyCode::Right,
+            "PageUp" => KeyCode::PageUp,
+            "PageDown" => KeyCode::PageDown,
+            "Home" => KeyCode::Home,
+            "End" => KeyCode::End,
+            "Delete" => KeyCode::Delete,
+            _ => KeyCode::F(name.strip_prefix('F')?.parse().ok()?),
+        },
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn passwords_never_reach_the_log_and_replays_stop_there() {
+        let path = std::env::temp_dir().join(format!("warlords-events-{}.jsonl", uuid::Uuid::new_v4()));
+        let login = Snapshot { state: "Character Login", ..Snapshot::default() };
+        let password_field = Snapshot { typing_secret: true, ..login.clone() };
+        {
+            let mut log = EventLog::create(&path, 42).unwrap();
+            for c in "Aria:".chars() {
+                log.record_turn(&typed(c), &login, &login, &[]).unwrap();
+            }
+            for c in "hunter2".chars() {
+                log.record_turn(&typed(c), &password_field, &password_field, &[]).unwrap();
+            }
+            log.record_turn(&KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE), &password_field, &login, &[]).unwrap();
+        }
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("hunter2") && !content.contains("\"h\""));
+        assert_eq!(content.matches(REDACTED_KEY).count(), 7);
+        let replay = load_replay(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(replay.seed, Some(42));
+        assert_eq!(replay.keys.into_iter().collect::<Vec<_>>(), "Aria:".chars().map(typed).collect::<Vec<_>>());
+    }
+}
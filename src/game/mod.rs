@@ -1,4 +1,4 @@
-use crate::forge::{ForgeCharacter, ForgeCharacterCreation, CombatEncounter, CombatParticipant, CombatAction, Weapon, Armor, 
+use crate::forge::{ForgeCharacter, ForgeCharacterCreation, CombatEncounter, CombatParticipant, CombatAction, Weapon, Armor, AmmoType, 
     create_wild_boar, create_wolf, create_goblin, create_bandit, create_orc, create_giant_spider, create_mountain_lion, create_skeleton, create_zombie};
 use rand::Rng;
 use crate::ui::{GameUI, UIState, CharacterCreationState, CreationStep, CombatState, WorldExplorationState, DungeonExplorationState, CombatPhase};
@@ -7,43 +7,205 @@ use crate::world::{WorldManager, WorldCoord, LocalCoord};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::path::PathBuf;
 
+pub mod events;
+
+const ASSESSMENT_MIN_AWARENESS: f32 = 8.0; // Awareness needed to size up a fight without Tactics
+const CAMP_REST_HOURS: u32 = 8;
+const HUNTING_HOURS: u32 = 3;
+const HUNTING_DIFFICULTY: u32 = 14; // d20 + skill x2 needed to bring down game
+const FISHING_DIFFICULTY: u32 = 11;
+const UNSAFE_CAMP_SPELL_RECOVERY: f32 = 0.5; // Share of spell points a fitful night's rest brings back
+const DUNGEON_SPELL_REGEN_TURNS: u32 = 20;   // Moves in a dungeon per spell point regained
+const DEFAULT_MESSAGE_HISTORY: usize = 200; // Messages kept for scrolling back, older ones are dropped
+const MESSAGE_PAGE: usize = 5;
+const DEFAULT_REROLLS: u8 = 3; // Re-rolls allowed after the first roll of characteristics
+const FLEE_RETREAT_TILES: i32 = 3; // How far the player runs after fleeing a dungeon fight
+const WORLD_MAP_ROWS: i32 = 32;    // Lines the world map aims to fill
+const FLEE_GRACE_TURNS: u32 = 4;   // Moves before creatures will pick a fight again
+const POST_COMBAT_GRACE_TURNS: u32 = 2; // Breathing room after a dungeon fight is won
+const ASSAULT_DISPOSITION_DROP: i32 = -4; // Enough to turn anyone hostile
+const FAIR_TRADE_GOODWILL_CHANCE: f64 = 0.2; // Chance a completed trade warms a merchant to the player
+
+// Where the player goes once a fight is over
+enum CombatReturn {
+    World(Option<Box<WorldExplorationState>>),
+    Dungeon(Box<DungeonExplorationState>),
+}
+
+// How a fight came about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CombatOpening {
+    Engaged,    // The player picked the fight and may size it up first
+    Ambushed,   // The enemy struck first, so there's no backing away
+    RangedShot, // The player opened from range and takes the first turn
+}
+
+impl CombatOpening {
+    // Only a fight the player walks into leaves time to judge it and back away
+    fn allows_assessment(self) -> bool {
+        self == CombatOpening::Engaged
+    }
+}
+
 pub struct Game {
     ui: GameUI,
     state: UIState,
     database: CharacterDatabase,
     db_path: PathBuf,
+    world_dir: PathBuf, // Where the world of this save profile is kept
     current_character: Option<ForgeCharacter>,
     input_buffer: String,
     world_manager: Option<WorldManager>,
     player_position: WorldCoord,
     saved_world_state: Option<WorldExplorationState>,
+    default_difficulty: crate::forge::Difficulty, // Preselected for new characters
+    world_bounds: crate::world::WorldBounds, // Size used when a new world is generated
+    play_clock: std::time::Instant, // Playtime since the character's stats were last brought up to date
+    message_history: usize, // How many messages the exploration logs keep
+    reroll_budget: u8,      // Re-rolls each new character gets
+    spell_point_model: crate::forge::SpellPointModel, // Applied to each character as they start playing
+    practiced: Vec<String>, // Skills and schools that have earned practice XP this fight
+    practice_xp: u32,       // Paid out with the rest of the XP if the fight is won
+    drained_schools: Vec<crate::forge::MagicSchool>, // School levels lost to backfires, given back when the fight ends
+    event_log: Option<events::EventLog>,
+    replay: Option<std::collections::VecDeque<KeyEvent>>, // Recorded keys still to be played back
+    trudging: bool, // An overloaded character's last step went nowhere
+    server_address: Option<String>, // Multiplayer server to join once in the world
+    server_password: Option<String>, // What the character logged in with, to join as them
+    chat: Option<crate::network::ChatClient>,
+    poi_fight: Option<LocalCoord>, // Point of interest whose guardians the player is fighting
 }
 
 impl Game {
-    pub fn new() -> anyhow::Result<Self> {
-        let ui = GameUI::new()?;
-        let db_path = PathBuf::from("characters.json");
+    // Characters and world are read from and saved to the given profile
+    pub fn new(profile: &crate::database::SaveProfile) -> anyhow::Result<Self> {
+        profile.prepare()?;
+        let db_path = profile.database_path();
         let database = CharacterDatabase::load_or_create(&db_path)?;
+        let ui = GameUI::new()?;
         
         Ok(Game {
             ui,
             state: UIState::Welcome,
             database,
             db_path,
+            world_dir: profile.world_dir(),
             current_character: None,
             input_buffer: String::new(),
             world_manager: None,
-            player_position: WorldCoord::new(256, 256), // Start in center of world
+            player_position: crate::world::WorldBounds::default().center(), // Start in center of world
             saved_world_state: None,
+            default_difficulty: crate::forge::Difficulty::default(),
+            world_bounds: crate::world::WorldBounds::default(),
+            play_clock: std::time::Instant::now(),
+            message_history: DEFAULT_MESSAGE_HISTORY,
+            reroll_budget: DEFAULT_REROLLS,
+            spell_point_model: crate::forge::SpellPointModel::default(),
+            practiced: Vec::new(),
+            practice_xp: 0,
+            drained_schools: Vec::new(),
+            event_log: None,
+            replay: None,
+            trudging: false,
+            server_address: None,
+            server_password: None,
+            chat: None,
+            poi_fight: None,
         })
     }
 
+    // Draw with plain ASCII instead of emoji and box drawing
+    pub fn enable_ascii_mode(&mut self) {
+        self.ui.enable_ascii_mode();
+    }
+
+    // Difficulty preselected when creating a new character
+    pub fn set_default_difficulty(&mut self, difficulty: crate::forge::Difficulty) {
+        self.default_difficulty = difficulty;
+    }
+
+    // World size in zones, only used when the world is generated for the first time
+    pub fn set_world_size(&mut self, width_zones: i32, height_zones: i32) {
+        let wrap = self.world_bounds.wrap;
+        self.world_bounds = crate::world::WorldBounds::new(width_zones, height_zones);
+        self.world_bounds.wrap = wrap;
+        self.player_position = self.world_bounds.center();
+    }
+
+    // Whether a new world wraps around at its edges instead of ending
+    pub fn set_world_wrap(&mut self, wrap: bool) {
+        self.world_bounds.wrap = wrap;
+    }
+
+    // How many messages the exploration logs keep for scrolling back
+    pub fn set_message_history(&mut self, messages: usize) {
+        self.message_history = messages.max(MESSAGE_PAGE);
+    }
+
+    // How many times a new character may re-roll their characteristics
+    pub fn set_reroll_budget(&mut self, rerolls: u8) {
+        self.reroll_budget = rerolls;
+    }
+
+    // Whether casters get a reserve per school or draw on a single spell point pool
+    pub fn set_spell_point_model(&mut self, model: crate::forge::SpellPointModel) {
+        self.spell_point_model = model;
+    }
+
+    // Record every key and the major events it causes to a file. The dice start
+    // over from the session seed here so a replay can roll them the same way.
+    pub fn enable_event_log(&mut self, path: &std::path::Path) -> anyhow::Result<()> {
+        let seed = crate::rng::seed();
+        crate::rng::restart(seed);
+        self.event_log = Some(events::EventLog::create(path, seed)?);
+        Ok(())
+    }
+
+    // Play back the keys from a recorded event log before handing control to the
+    // player, rolling the dice from the seed the log was recorded with
+    pub fn load_replay(&mut self, path: &std::path::Path) -> anyhow::Result<()> {
+        let replay = events::load_replay(path)?;
+        if let Some(seed) = replay.seed {
+            crate::rng::restart(seed);
+        }
+        self.replay = Some(replay.keys);
+        Ok(())
+    }
+
+    // Join a multiplayer server for chat once the character is out in the world
+    pub fn set_server(&mut self, address: &str) {
+        self.server_address = Some(address.to_string());
+    }
+
+    // Bounds of the loaded world, or the configured size before one is loaded
+    fn world_bounds(&self) -> crate::world::WorldBounds {
+        self.world_manager.as_ref().map(|world_manager| world_manager.bounds()).unwrap_or(self.world_bounds)
+    }
+    
     pub fn run(&mut self) -> anyhow::Result<()> {
         loop {
             self.ui.draw(&self.state, &self.input_buffer, self.current_character.as_ref())?;
             
-            if let Some(key) = self.ui.handle_input()? {
-                if self.handle_key_event(key)? {
+            let mut key = self.ui.handle_input()?;
+            if let Some(replay) = &mut self.replay {
+                // Any key stops a replay early, otherwise it goes on one key per poll
+                key = if key.is_some() { None } else { replay.pop_front() };
+                if key.is_none() {
+                    self.replay = None;
+                }
+            }
+            
+            self.poll_network();
+            
+            if let Some(key) = key {
+                let before = self.event_log.as_ref().map(|_| self.observe());
+                let exit = self.handle_key_event(key)?;
+                if let (Some(before), Some(mut event_log)) = (before, self.event_log.take()) {
+                    let after = self.observe();
+                    event_log.record_turn(&key, &before, &after, self.combat_log())?;
+                    self.event_log = Some(event_log);
+                }
+                if exit {
                     break; // Exit game
                 }
             }
@@ -54,26 +216,170 @@ impl Game {
         Ok(())
     }
     
-    fn shutdown(&mut self) -> anyhow::Result<()> {
-        // Save world data if it exists
-        if let Some(world_manager) = &mut self.world_manager {
-            world_manager.save_if_dirty()?;
+    // Show what other players said and did, and keep the server posted on where
+    // the player is. Both wait while the player is away from the overworld.
+    fn poll_network(&mut self) {
+        let Some(chat) = &mut self.chat else {
+            return;
+        };
+        let UIState::WorldExploration(world_state) = &mut self.state else {
+            return;
+        };
+        
+        let mut events = chat.poll();
+        if chat.update_position(WorldCoord::from_zone_local(world_state.current_zone, world_state.player_local_pos)).is_err() {
+            events.push(crate::network::NetworkEvent::Disconnected);
+        }
+        world_state.other_players = chat.players().values().copied().collect();
+        if events.contains(&crate::network::NetworkEvent::Disconnected) {
+            self.chat = None;
+            world_state.other_players.clear();
+        }
+        
+        // Positions only show on the map
+        let lines: Vec<String> = events.iter()
+            .filter(|event| !event.is_position())
+            .map(crate::network::NetworkEvent::describe)
+            .collect();
+        if lines.is_empty() {
+            return;
+        }
+        world_state.messages.extend(lines);
+        Self::trim_messages(&mut world_state.messages, self.message_history);
+        world_state.message_scroll = 0;
+    }
+    
+    // Connect to the multiplayer server, if there is one, as the current character
+    fn connect_to_server(&mut self, world_state: &mut WorldExplorationState) {
+        let (Some(address), Some(character), Some(password), None) = (&self.server_address, &self.current_character, &self.server_password, &self.chat) else {
+            return;
+        };
+        let message = match crate::network::ChatClient::connect(address, &character.name, password) {
+            Ok(client) => {
+                self.chat = Some(client);
+                format!("📡 Connected to {}. Press Shift+T to chat.", address)
+            }
+            Err(e) => format!("📡 Couldn't reach the server at {}: {}", address, e),
+        };
+        self.add_message(world_state, message);
+    }
+    
+    fn send_chat(&mut self, world_state: &mut WorldExplorationState, text: &str) {
+        let message = crate::network::sanitize_chat(text);
+        let (Some(chat), Some(character)) = (&mut self.chat, &self.current_character) else {
+            return;
+        };
+        if message.is_empty() {
+            return;
+        }
+        let line = match chat.send_chat(&message) {
+            Ok(()) => crate::network::NetworkEvent::Chat { from: character.name.clone(), message }.describe(),
+            Err(_) => {
+                self.chat = None;
+                crate::network::NetworkEvent::Disconnected.describe()
+            }
+        };
+        self.add_message(world_state, line);
+    }
+    
+    // What the event log compares before and after each key
+    fn observe(&self) -> events::Snapshot {
+        let position = match &self.state {
+            UIState::WorldExploration(world_state) => {
+                let world_pos = WorldCoord::from_zone_local(world_state.current_zone, world_state.player_local_pos);
+                Some(("world".to_string(), world_pos.x, world_pos.y))
+            }
+            UIState::DungeonExploration(dungeon_state) => {
+                let area = format!("{} floor {}", dungeon_state.dungeon.name, dungeon_state.dungeon.current_floor);
+                Some((area, dungeon_state.player_pos.x, dungeon_state.player_pos.y))
+            }
+            _ => None,
+        };
+        
+        let character = self.current_character.as_ref();
+        events::Snapshot {
+            state: self.state.get_name(),
+            position,
+            combat_lines: self.combat_log().len(),
+            level: character.map(|character| character.level).unwrap_or(0),
+            gold: character.map(|character| character.gold).unwrap_or(0),
+            inventory: character.map(|character| character.inventory.clone()).unwrap_or_default(),
+            // Login takes "name:password", the other prompts ask for just the password
+            typing_secret: match &self.state {
+                UIState::CharacterLogin => self.input_buffer.contains(':'),
+                UIState::CharacterDelete(..) | UIState::CharacterUnlock(..) => true,
+                UIState::CharacterCreation(creation_state) => matches!(creation_state.step, CreationStep::PasswordEntry),
+                _ => false,
+            },
+        }
+    }
+    
+    fn combat_log(&self) -> &[String] {
+        match &self.state {
+            UIState::Combat(combat_state) => &combat_state.encounter.combat_log,
+            _ => &[],
+        }
+    }
+    
+    // Add the time played since the last save to the character's total
+    fn record_playtime(&mut self) {
+        if let Some(character) = &mut self.current_character {
+            character.stats.playtime_seconds += self.play_clock.elapsed().as_secs();
         }
+        self.play_clock = std::time::Instant::now();
+    }
+
+    fn shutdown(&mut self) -> anyhow::Result<()> {
+        // Remember an unfinished dungeon visit so the next session resumes inside it
+        self.save_dungeon_progress();
         
-        // Save character data
+        // Save character data first so a world save problem can't lose it
+        self.record_playtime();
         if let Some(character) = &mut self.current_character {
             character.update_last_played();
             self.database.update_character(&character.name, character.clone())?;
             self.database.save(&self.db_path)?;
         }
         
-        // Cleanup UI
+        // Save world data if it exists
+        let world_saved = match &mut self.world_manager {
+            Some(world_manager) => world_manager.save_if_dirty(),
+            None => Ok(()),
+        };
+        
+        // Cleanup UI before reporting a failed world save, so the terminal is usable
         self.ui.cleanup()?;
+        world_saved?;
         
         println!("Game saved and exited gracefully. Thank you for playing Warlords!");
         Ok(())
     }
 
+    // Store the dungeon the player is in (or fighting in) with the character. The
+    // explored map also goes to the world cache so both copies agree on reload.
+    fn save_dungeon_progress(&mut self) {
+        let dungeon_state = match &self.state {
+            UIState::DungeonExploration(dungeon_state) => Some(dungeon_state),
+            UIState::Combat(combat_state) => combat_state.return_to_dungeon.as_ref(),
+            _ => None,
+        };
+        
+        let saved = dungeon_state.map(|dungeon_state| crate::world::SavedDungeon {
+            dungeon: dungeon_state.dungeon.clone(),
+            player_pos: dungeon_state.player_pos,
+            zone: dungeon_state.zone,
+            poi_position: dungeon_state.poi_position,
+            turn_count: dungeon_state.turn_count,
+        });
+        
+        if let (Some(saved), Some(world_manager)) = (&saved, &mut self.world_manager) {
+            world_manager.store_dungeon_map(saved.zone, saved.poi_position, saved.dungeon.explored_map());
+        }
+        if let Some(character) = &mut self.current_character {
+            character.active_dungeon = saved;
+        }
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
         // Handle Ctrl+C globally for graceful shutdown
         if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('q') {
@@ -129,6 +435,7 @@ impl Game {
                                 rolled_data: None,
                                 selected_race: None,
                                 character_name: None,
+                                password: None,
                                 selected_skills: Vec::new(),
                                 available_skill_points: 0,
                                 selected_spells: Vec::new(),
@@ -140,6 +447,10 @@ impl Game {
                                 available_gear_list: Vec::new(),
                                 starting_gold: 100, // Base starting gold per Forge rules
                                 spent_gold: 0,
+                                difficulty: self.default_difficulty,
+                                list_filter: String::new(),
+                                point_buy: None,
+                                rerolls_left: self.reroll_budget,
                             });
                         }
                         KeyCode::Char('3') => {
@@ -183,6 +494,12 @@ impl Game {
             UIState::CharacterList(character_list, selected_index) => {
                 self.handle_character_list_input(key, character_list.clone(), *selected_index)?;
             }
+            UIState::CharacterDelete(name, index, _) => {
+                self.handle_character_delete_input(key, name.clone(), *index)?;
+            }
+            UIState::CharacterUnlock(name, index, _) => {
+                self.handle_character_unlock_input(key, name.clone(), *index)?;
+            }
             UIState::Playing => {
                 match key.code {
                     KeyCode::Char('m') => {
@@ -207,7 +524,7 @@ impl Game {
                         // Start a test combat encounter
                         if self.current_character.is_some() {
                             let character = self.current_character.as_ref().unwrap().clone();
-                            self.start_combat_encounter(&character)?;
+                            self.start_combat_encounter(&character, None, CombatOpening::Engaged)?;
                         }
                     }
                     // Add movement and game commands here
@@ -229,6 +546,15 @@ impl Game {
                     KeyCode::Esc | KeyCode::Char('m') => {
                         self.state = UIState::Playing;
                     }
+                    KeyCode::Char('b') => {
+                        self.state = UIState::Bestiary;
+                    }
+                    KeyCode::Char('i') => {
+                        self.state = UIState::Inventory(crate::ui::InventoryState::default());
+                    }
+                    KeyCode::Char('j') => {
+                        self.state = UIState::Quests;
+                    }
                     KeyCode::Char('q') => {
                         return Ok(true); // Exit
                     }
@@ -236,7 +562,68 @@ impl Game {
                 }
             }
             UIState::Combat(combat_state) => {
-                self.handle_combat_input(key, combat_state.clone())?;
+                let log_length = combat_state.encounter.combat_log.len();
+                match key.code {
+                    KeyCode::PageUp | KeyCode::PageDown => {
+                        let mut combat_state = combat_state.clone();
+                        combat_state.log_scroll = Self::scroll_messages(key.code, combat_state.log_scroll, log_length);
+                        self.state = UIState::Combat(combat_state);
+                    }
+                    _ => self.handle_combat_input(key, combat_state.clone())?,
+                }
+                // A player reading back through the log keeps their place as new lines arrive
+                if let UIState::Combat(combat_state) = &mut self.state {
+                    if combat_state.log_scroll > 0 {
+                        combat_state.log_scroll += combat_state.encounter.combat_log.len().saturating_sub(log_length);
+                    }
+                }
+            }
+            UIState::Bestiary | UIState::Quests => {
+                match key.code {
+                    KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char('j') => {
+                        self.state = UIState::CharacterMenu;
+                    }
+                    KeyCode::Char('q') => {
+                        return Ok(true); // Exit
+                    }
+                    _ => {}
+                }
+            }
+            UIState::WorldMap(map_state) => {
+                match key.code {
+                    KeyCode::Esc | KeyCode::Char('M') | KeyCode::Char('m') => {
+                        self.state = UIState::WorldExploration(map_state.world_state.clone());
+                    }
+                    KeyCode::Char('q') => {
+                        return Ok(true); // Exit
+                    }
+                    _ => {}
+                }
+            }
+            UIState::Inventory(inventory_state) => {
+                let mut inventory_state = inventory_state.clone();
+                match key.code {
+                    KeyCode::Esc | KeyCode::Char('i') => {
+                        self.state = UIState::CharacterMenu;
+                        return Ok(false);
+                    }
+                    KeyCode::Char('q') => {
+                        return Ok(true); // Exit
+                    }
+                    _ => self.handle_inventory_input(key, &mut inventory_state),
+                }
+                self.state = UIState::Inventory(inventory_state);
+            }
+            UIState::Death(_) => {
+                match key.code {
+                    KeyCode::Enter => {
+                        self.state = UIState::MainMenu;
+                    }
+                    KeyCode::Char('q') => {
+                        return Ok(true); // Exit
+                    }
+                    _ => {}
+                }
             }
         }
         Ok(false)
@@ -258,7 +645,11 @@ impl Game {
                 character.update_last_played();
                 self.database.update_character(name, character.clone())?;
                 self.database.save(&self.db_path)?;
+                character.magic.point_model = self.spell_point_model;
                 self.current_character = Some(character);
+                self.server_password = Some(password.to_string());
+                self.chat = None; // Rejoins as this character
+                self.play_clock = std::time::Instant::now();
                 self.state = UIState::Playing;
                 self.input_buffer.clear();
             }
@@ -270,12 +661,70 @@ impl Game {
         Ok(())
     }
 
+    fn handle_point_buy_input(&mut self, key: KeyEvent, mut creation_state: CharacterCreationState) {
+        let Some(mut allocation) = creation_state.point_buy else {
+            return;
+        };
+        let selected = creation_state.current_selection_index.min(allocation.len() - 1);
+        let (_, min, max) = crate::forge::CHARACTERISTIC_RANGES[selected];
+        let spent: u8 = allocation.iter().sum();
+        
+        match key.code {
+            KeyCode::Up => {
+                creation_state.current_selection_index = selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                creation_state.current_selection_index = (selected + 1).min(allocation.len() - 1);
+            }
+            KeyCode::Right | KeyCode::Char('+') if spent < crate::forge::POINT_BUY_POOL && min + allocation[selected] < max => {
+                allocation[selected] += 1;
+            }
+            KeyCode::Left | KeyCode::Char('-') => {
+                allocation[selected] = allocation[selected].saturating_sub(1);
+            }
+            KeyCode::Char('p') => {
+                // Back to the dice
+                creation_state.point_buy = None;
+                self.state = UIState::CharacterCreation(creation_state);
+                return;
+            }
+            KeyCode::Char('c') => {
+                creation_state.rolled_data = Some(ForgeCharacterCreation::point_buy_characteristics(&allocation));
+                creation_state.step = CreationStep::RaceSelection;
+            }
+            KeyCode::Esc => {
+                self.state = UIState::MainMenu;
+                return;
+            }
+            _ => {}
+        }
+        
+        creation_state.point_buy = Some(allocation);
+        self.state = UIState::CharacterCreation(creation_state);
+    }
+
     fn handle_character_creation_input(&mut self, key: KeyEvent, mut creation_state: CharacterCreationState) -> anyhow::Result<()> {
         match creation_state.step {
+            CreationStep::Rolling if creation_state.point_buy.is_some() => {
+                self.handle_point_buy_input(key, creation_state);
+            }
             CreationStep::Rolling => {
                 match key.code {
+                    KeyCode::Char('p') => {
+                        // Spread a fixed pool of points instead of trusting the dice
+                        creation_state.point_buy = Some([0; 9]);
+                        creation_state.current_selection_index = 0;
+                        self.state = UIState::CharacterCreation(creation_state);
+                    }
                     KeyCode::Enter | KeyCode::Char('r') => {
-                        // Roll characteristics
+                        // The first roll is free, every one after it comes out of the budget
+                        if creation_state.rolled_data.is_some() {
+                            if creation_state.rerolls_left == 0 {
+                                self.state = UIState::CharacterCreation(creation_state);
+                                return Ok(());
+                            }
+                            creation_state.rerolls_left -= 1;
+                        }
                         let rolled_data = ForgeCharacterCreation::roll_characteristics();
                         creation_state.rolled_data = Some(rolled_data);
                         self.state = UIState::CharacterCreation(creation_state);
@@ -324,11 +773,7 @@ impl Game {
                     KeyCode::Enter => {
                         if self.input_buffer.len() >= 2 {
                             creation_state.character_name = Some(self.input_buffer.clone());
-                            // Calculate available skill points based on race and characteristics
-                            creation_state.available_skill_points = self.calculate_skill_points(&creation_state);
-                            creation_state.available_skills_list = self.get_available_skills(&creation_state);
-                            creation_state.current_selection_index = 0;
-                            creation_state.step = CreationStep::SkillSelection;
+                            creation_state.step = CreationStep::PasswordEntry;
                             self.state = UIState::CharacterCreation(creation_state);
                             self.input_buffer.clear();
                         }
@@ -347,7 +792,33 @@ impl Game {
                     _ => {}
                 }
             }
+            CreationStep::PasswordEntry => {
+                match key.code {
+                    KeyCode::Enter if !self.input_buffer.is_empty() => {
+                        creation_state.password = Some(std::mem::take(&mut self.input_buffer));
+                        // Calculate available skill points based on race and characteristics
+                        creation_state.available_skill_points = self.calculate_skill_points(&creation_state);
+                        creation_state.available_skills_list = self.get_available_skills(&creation_state);
+                        creation_state.current_selection_index = 0;
+                        creation_state.step = CreationStep::SkillSelection;
+                        self.state = UIState::CharacterCreation(creation_state);
+                    }
+                    KeyCode::Char(c) => {
+                        self.input_buffer.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        self.input_buffer.pop();
+                    }
+                    KeyCode::Esc => {
+                        creation_state.step = CreationStep::NameEntry;
+                        self.state = UIState::CharacterCreation(creation_state);
+                        self.input_buffer.clear();
+                    }
+                    _ => {}
+                }
+            }
             CreationStep::SkillSelection => {
+                let skills = creation_state.filtered_skills();
                 match key.code {
                     KeyCode::Up => {
                         if creation_state.current_selection_index > 0 {
@@ -356,15 +827,14 @@ impl Game {
                         self.state = UIState::CharacterCreation(creation_state);
                     }
                     KeyCode::Down => {
-                        if creation_state.current_selection_index < creation_state.available_skills_list.len().saturating_sub(1) {
+                        if creation_state.current_selection_index < skills.len().saturating_sub(1) {
                             creation_state.current_selection_index += 1;
                         }
                         self.state = UIState::CharacterCreation(creation_state);
                     }
                     KeyCode::Enter => {
                         // Select/deselect skill
-                        if creation_state.current_selection_index < creation_state.available_skills_list.len() {
-                            let skill = creation_state.available_skills_list[creation_state.current_selection_index].clone();
+                        if let Some(skill) = skills.get(creation_state.current_selection_index).cloned() {
                             if creation_state.selected_skills.contains(&skill) {
                                 // Deselect skill
                                 creation_state.selected_skills.retain(|s| s != &skill);
@@ -377,19 +847,28 @@ impl Game {
                         }
                         self.state = UIState::CharacterCreation(creation_state);
                     }
-                    KeyCode::Char('c') => {
+                    KeyCode::Tab => {
                         // Continue to spell selection
                         creation_state.available_spell_picks = self.calculate_spell_picks(&creation_state);
                         creation_state.available_spells_list = self.get_available_spells(&creation_state);
                         creation_state.current_selection_index = 0;
+                        creation_state.list_filter.clear();
                         creation_state.step = CreationStep::SpellSelection;
                         self.state = UIState::CharacterCreation(creation_state);
                     }
+                    KeyCode::Esc if !creation_state.list_filter.is_empty() => {
+                        creation_state.list_filter.clear();
+                        creation_state.current_selection_index = 0;
+                        self.state = UIState::CharacterCreation(creation_state);
+                    }
                     KeyCode::Esc => {
-                        creation_state.step = CreationStep::NameEntry;
+                        creation_state.step = CreationStep::PasswordEntry;
+                        self.state = UIState::CharacterCreation(creation_state);
+                    }
+                    _ => {
+                        Self::edit_list_filter(&mut creation_state, key.code);
                         self.state = UIState::CharacterCreation(creation_state);
                     }
-                    _ => {}
                 }
             }
             CreationStep::SpellSelection => {
@@ -438,6 +917,7 @@ impl Game {
                 }
             }
             CreationStep::GearSelection => {
+                let gear = creation_state.filtered_gear();
                 match key.code {
                     KeyCode::Up => {
                         if creation_state.current_selection_index > 0 {
@@ -446,50 +926,49 @@ impl Game {
                         self.state = UIState::CharacterCreation(creation_state);
                     }
                     KeyCode::Down => {
-                        if creation_state.current_selection_index < creation_state.available_gear_list.len().saturating_sub(1) {
+                        if creation_state.current_selection_index < gear.len().saturating_sub(1) {
                             creation_state.current_selection_index += 1;
                         }
                         self.state = UIState::CharacterCreation(creation_state);
                     }
                     KeyCode::Enter => {
                         // Select/deselect gear
-                        if creation_state.current_selection_index < creation_state.available_gear_list.len() {
-                            let (gear_name, cost) = creation_state.available_gear_list[creation_state.current_selection_index].clone();
-                            if creation_state.selected_gear.contains(&gear_name) {
-                                // Deselect gear - refund the gold
-                                creation_state.selected_gear.retain(|g| g != &gear_name);
-                                creation_state.spent_gold -= cost;
-                            } else {
-                                // Select gear if we can afford it
-                                if creation_state.spent_gold + cost <= creation_state.starting_gold {
-                                    creation_state.selected_gear.push(gear_name);
-                                    creation_state.spent_gold += cost;
-                                }
-                            }
+                        if let Some((gear_name, cost)) = gear.get(creation_state.current_selection_index).cloned() {
+                            creation_state.toggle_gear(&gear_name, cost);
                         }
                         self.state = UIState::CharacterCreation(creation_state);
                     }
-                    KeyCode::Char('c') => {
+                    KeyCode::Tab => {
                         // Continue to confirmation
+                        creation_state.list_filter.clear();
                         creation_state.step = CreationStep::Confirmation;
                         self.state = UIState::CharacterCreation(creation_state);
                     }
+                    KeyCode::Esc if !creation_state.list_filter.is_empty() => {
+                        creation_state.list_filter.clear();
+                        creation_state.current_selection_index = 0;
+                        self.state = UIState::CharacterCreation(creation_state);
+                    }
                     KeyCode::Esc => {
                         creation_state.step = CreationStep::SpellSelection;
                         creation_state.current_selection_index = 0;
                         self.state = UIState::CharacterCreation(creation_state);
                     }
-                    _ => {}
+                    _ => {
+                        Self::edit_list_filter(&mut creation_state, key.code);
+                        self.state = UIState::CharacterCreation(creation_state);
+                    }
                 }
             }
             CreationStep::Confirmation => {
                 match key.code {
                     KeyCode::Enter => {
                         // Finalize character creation
-                        if let (Some(rolled_data), Some(race), Some(name)) = (
+                        if let (Some(rolled_data), Some(race), Some(name), Some(password)) = (
                             &creation_state.rolled_data,
                             &creation_state.selected_race,
                             &creation_state.character_name,
+                            &creation_state.password,
                         ) {
                             let characteristics = ForgeCharacterCreation::apply_racial_modifiers(rolled_data, race);
                             let mut character = ForgeCharacterCreation::create_character(
@@ -500,14 +979,21 @@ impl Game {
                             
                             // Apply selected skills, spells, and gear
                             self.apply_character_selections(&mut character, &creation_state);
-
-                            // For now, use a default password - in a real implementation, you'd ask for it
-                            let password = "temp123";
+                            character.difficulty = creation_state.difficulty;
                             
-                            match self.database.create_character(name.clone(), password.to_string(), character.clone()) {
+                            // New characters start in the middle of the world, whatever its size
+                            let start = self.world_bounds().center();
+                            character.current_zone = Some(start.to_zone());
+                            character.current_position = Some(start.to_local());
+
+                            match self.database.create_character(name.clone(), password.clone(), character.clone()) {
                                 Ok(()) => {
                                     self.database.save(&self.db_path)?;
+                                    character.magic.point_model = self.spell_point_model;
                                     self.current_character = Some(character);
+                                    self.server_password = Some(password.clone());
+                                    self.chat = None; // Rejoins as this character
+                                    self.play_clock = std::time::Instant::now();
                                     self.state = UIState::Playing;
                                 }
                                 Err(_) => {
@@ -517,6 +1003,10 @@ impl Game {
                             }
                         }
                     }
+                    KeyCode::Char('d') | KeyCode::Char('D') => {
+                        creation_state.difficulty = creation_state.difficulty.next();
+                        self.state = UIState::CharacterCreation(creation_state);
+                    }
                     KeyCode::Esc => {
                         creation_state.step = CreationStep::GearSelection;
                         self.state = UIState::CharacterCreation(creation_state);
@@ -528,6 +1018,20 @@ impl Game {
         Ok(())
     }
 
+    // Typing narrows the skill or gear list, backspace widens it again
+    fn edit_list_filter(creation_state: &mut CharacterCreationState, code: KeyCode) {
+        match code {
+            KeyCode::Char(c) if c.is_alphanumeric() || c == ' ' || c == '\'' || c == '-' => {
+                creation_state.list_filter.push(c);
+            }
+            KeyCode::Backspace => {
+                creation_state.list_filter.pop();
+            }
+            _ => return,
+        }
+        creation_state.current_selection_index = 0;
+    }
+
     fn calculate_skill_points(&self, creation_state: &CharacterCreationState) -> u8 {
         // Base skill points = 3, plus bonus from race or high intellect
         let mut points = 3;
@@ -583,8 +1087,10 @@ impl Game {
             "Stealth".to_string(),
             
             // General Skills
+            "Lockpicking".to_string(),
             "Survival".to_string(),
             "Perception".to_string(),
+            "Tactics".to_string(),
             "Investigation".to_string(),
             "Medicine".to_string(),
             "Crafting".to_string(),
@@ -684,6 +1190,8 @@ impl Game {
             ("Spear".to_string(), 5),
             ("Short Bow".to_string(), 25),
             ("Crossbow".to_string(), 35),
+            ("Arrows (20)".to_string(), 1),
+            ("Bolts (20)".to_string(), 2),
             ("Staff".to_string(), 5),
             
             // Armor
@@ -774,10 +1282,10 @@ impl Game {
         }
         
         // Set remaining gold (starting gold - spent gold)
-        character.gold = creation_state.starting_gold - creation_state.spent_gold;
+        character.gold = creation_state.remaining_gold();
     }
 
-    fn handle_character_list_input(&mut self, key: KeyEvent, character_list: Vec<(String, chrono::DateTime<chrono::Utc>)>, selected_index: Option<usize>) -> anyhow::Result<()> {
+    fn handle_character_list_input(&mut self, key: KeyEvent, character_list: Vec<(String, chrono::DateTime<chrono::Utc>, crate::forge::PlayStats)>, selected_index: Option<usize>) -> anyhow::Result<()> {
         if character_list.is_empty() {
             // No characters, any key returns to main menu
             self.state = UIState::MainMenu;
@@ -809,31 +1317,20 @@ impl Game {
                         // Sort characters by last played (same as UI)
                         let mut sorted_chars = character_list.clone();
                         sorted_chars.sort_by(|a, b| b.1.cmp(&a.1));
-                        
-                        let character_name = &sorted_chars[idx].0;
-                        
-                        // For now, we need to ask for password. In a more sophisticated system,
-                        // we could implement session tokens or remember login
-                        // But for now, let's auto-login with a default password for demo purposes
-                        let default_password = "temp123"; // This matches what we set in character creation
-                        
-                        match self.database.authenticate(character_name, default_password) {
-                            Ok(mut character) => {
-                                character.update_last_played();
-                                self.database.update_character(character_name, character.clone())?;
-                                self.database.save(&self.db_path)?;
-                                self.current_character = Some(character);
-                                self.state = UIState::Playing;
-                            }
-                            Err(_) => {
-                                // Authentication failed, return to main menu
-                                // In a real system, we'd show an error message
-                                self.state = UIState::MainMenu;
-                            }
-                        }
+                        self.input_buffer.clear();
+                        self.state = UIState::CharacterUnlock(sorted_chars[idx].0.clone(), idx, false);
                     }
                 }
             }
+            KeyCode::Char('d') => {
+                if let Some(idx) = selected_index.filter(|&idx| idx < character_list.len()) {
+                    // Same order the list is drawn in
+                    let mut sorted_chars = character_list.clone();
+                    sorted_chars.sort_by_key(|character| std::cmp::Reverse(character.1));
+                    self.input_buffer.clear();
+                    self.state = UIState::CharacterDelete(sorted_chars[idx].0.clone(), idx, false);
+                }
+            }
             KeyCode::Esc => {
                 self.state = UIState::MainMenu;
             }
@@ -850,66 +1347,191 @@ impl Game {
         Ok(())
     }
 
-    fn start_combat_encounter(&mut self, character: &ForgeCharacter) -> anyhow::Result<()> {
-        // Create player combatant with basic equipment
-        let mut player = CombatParticipant::from_character(character, Some(Weapon::rusty_sword()));
-        player.armor = Some(Armor::leather());
-        
-        // Generate enemies based on current terrain
-        let enemies = self.generate_enemies_for_location()?;
-        
-        // Create encounter with player and enemies
-        let mut participants = vec![player];
-        participants.extend(enemies);
-        let encounter = CombatEncounter::new(participants);
-        
-        // Get available skills for the character
-        let available_skills = self.get_available_combat_skills(character);
-        
-        // Create combat state and auto-advance past initiative phase for better UX
-        let mut combat_state = CombatState {
-            encounter,
-            selected_action: None,
-            available_skills,
-            selected_skill: None,
-            combat_phase: CombatPhase::InitiativeRoll,
-            return_to_dungeon: None,
-            current_skill_index: 0,
-            skill_list_offset: 0,
-        };
-        
-        // Auto-advance past initiative roll for smoother gameplay
-        combat_state.encounter.add_log("=== COMBAT BEGINS ===".to_string());
-        combat_state.encounter.add_log("Rolling initiative...".to_string());
-        
-        // Display initiative results
-        let init_results: Vec<String> = combat_state.encounter.participants.iter()
-            .map(|p| format!("{} rolled {} for initiative", p.name, p.initiative))
-            .collect();
-        for result in init_results {
-            combat_state.encounter.add_log(result);
-        }
-        
-        combat_state.encounter.add_log(format!("=== ROUND {} ===", combat_state.encounter.round));
-        combat_state.combat_phase = CombatPhase::DeclaringActions;
-        
-        // Process AI turns immediately if the first participant is an enemy
-        if let Some(current) = combat_state.encounter.get_current_participant() {
-            if !current.is_player && current.is_alive() {
-                // Process AI turns right away
-                self.process_ai_turns(&mut combat_state)?;
+    // Deleting a character asks for its password first so a stray key can't lose it
+    fn handle_character_unlock_input(&mut self, key: KeyEvent, name: String, index: usize) -> anyhow::Result<()> {
+        match key.code {
+            KeyCode::Enter => {
+                let password = std::mem::take(&mut self.input_buffer);
+                let Ok(mut character) = self.database.authenticate(&name, &password) else {
+                    self.state = UIState::CharacterUnlock(name, index, true);
+                    return Ok(());
+                };
+                character.update_last_played();
+                self.database.update_character(&name, character.clone())?;
+                self.database.save(&self.db_path)?;
+                character.magic.point_model = self.spell_point_model;
+                self.current_character = Some(character);
+                self.server_password = Some(password);
+                self.chat = None; // Rejoins as this character
+                self.play_clock = std::time::Instant::now();
+                self.state = UIState::Playing;
             }
-        }
-        
-        self.state = UIState::Combat(combat_state);
-        
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            KeyCode::Esc => {
+                self.input_buffer.clear();
+                let character_list = self.database.list_characters();
+                self.state = UIState::CharacterList(character_list, Some(index));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_character_delete_input(&mut self, key: KeyEvent, name: String, index: usize) -> anyhow::Result<()> {
+        match key.code {
+            KeyCode::Enter => {
+                let password = std::mem::take(&mut self.input_buffer);
+                if !delete_with_password(&mut self.database, &self.db_path, &name, &password)? {
+                    self.state = UIState::CharacterDelete(name, index, true);
+                    return Ok(());
+                }
+                if self.current_character.as_ref().is_some_and(|character| character.name == name) {
+                    self.current_character = None;
+                }
+
+                // Keep the cursor on the list when the last entry goes
+                let character_list = self.database.list_characters();
+                let selected_index = if character_list.is_empty() { None } else { Some(index.min(character_list.len() - 1)) };
+                self.state = UIState::CharacterList(character_list, selected_index);
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            KeyCode::Esc => {
+                self.input_buffer.clear();
+                let character_list = self.database.list_characters();
+                self.state = UIState::CharacterList(character_list, Some(index));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn start_combat_encounter(&mut self, character: &ForgeCharacter, return_to_world: Option<WorldExplorationState>, opening: CombatOpening) -> anyhow::Result<()> {
+        // Generate enemies based on current terrain
+        let enemies = self.generate_enemies_for_location(character)?;
+        self.start_combat_with_enemies(character, enemies, return_to_world, opening)
+    }
+
+    fn start_combat_with_enemies(&mut self, character: &ForgeCharacter, enemies: Vec<CombatParticipant>, return_to_world: Option<WorldExplorationState>, opening: CombatOpening) -> anyhow::Result<()> {
+        // Create encounter with player and enemies
+        let mut participants = vec![self.create_player_combat_participant(character)?];
+        participants.extend(enemies.into_iter().map(|enemy| Self::scale_for_difficulty(enemy, character.difficulty)));
+        self.begin_combat(participants, CombatReturn::World(return_to_world.map(Box::new)), opening)
+    }
+
+    // Every fight starts here. Perceptive characters may size up a fight they chose;
+    // otherwise initiative is rolled and enemies act until it's the player's turn.
+    // A ranged opener hands the player the first turn outright.
+    fn begin_combat(&mut self, mut participants: Vec<CombatParticipant>, return_to: CombatReturn, opening: CombatOpening) -> anyhow::Result<()> {
+        // Raised undead follow the character into every fight
+        if let Some(character) = &self.current_character {
+            participants.extend(character.followers.iter().cloned());
+        }
+        let encounter = open_encounter(participants, opening);
+        let available_skills = self.current_character.as_ref()
+            .map(|character| self.get_available_combat_skills(character))
+            .unwrap_or_default();
+        let mut combat_state = new_combat_state(encounter, available_skills, return_to);
+        self.practiced.clear();
+        self.practice_xp = 0;
+        self.drained_schools.clear();
+        self.poi_fight = None;
+        
+        // No time to size anyone up when you've already loosed an arrow or been jumped
+        if !opening.allows_assessment() || !self.offer_combat_assessment(&mut combat_state) {
+            self.begin_combat_rounds(&mut combat_state)?;
+        }
+        
+        self.state = UIState::Combat(combat_state);
+        
+        Ok(())
+    }
+
+    // Announce initiative and play out enemy turns until the player is up
+    fn begin_combat_rounds(&mut self, combat_state: &mut CombatState) -> anyhow::Result<()> {
+        combat_state.encounter.add_log("=== COMBAT BEGINS ===".to_string());
+        combat_state.encounter.add_log("Rolling initiative...".to_string());
+        
+        // Display initiative results
+        let init_results: Vec<String> = combat_state.encounter.participants.iter()
+            .map(|p| format!("{} rolled {} for initiative", p.name, p.initiative))
+            .collect();
+        for result in init_results {
+            combat_state.encounter.add_log(result);
+        }
+        
+        // Note each kind of creature in the fight in the bestiary
+        if let Some(character) = &mut self.current_character {
+            character.magic.reset_cooldowns();
+            let day = character.current_day();
+            let mut creature_types: Vec<crate::world::CreatureType> = Vec::new();
+            for creature_type in combat_state.encounter.participants.iter().filter_map(|p| p.creature_type.as_ref()) {
+                if !creature_types.iter().any(|seen| seen.get_name() == creature_type.get_name()) {
+                    creature_types.push(creature_type.clone());
+                }
+            }
+            for creature_type in creature_types {
+                if character.bestiary.record_encounter(&creature_type, day) {
+                    combat_state.encounter.add_log(format!("📖 New bestiary entry: {}", creature_type.get_name()));
+                }
+            }
+        }
+        
+        combat_state.encounter.add_log(format!("=== ROUND {} ===", combat_state.encounter.round));
+        combat_state.combat_phase = CombatPhase::DeclaringActions;
+        
+        // Process AI turns immediately if the first participant is an enemy
+        if let Some(current) = combat_state.encounter.get_current_participant() {
+            if !current.is_player && current.is_alive() {
+                self.process_ai_turns(combat_state)?;
+            }
+        }
+        
         Ok(())
     }
 
-    fn generate_enemies_for_location(&self) -> anyhow::Result<Vec<CombatParticipant>> {
-        let mut rng = rand::thread_rng();
-        let mut enemies = Vec::new();
+    // Characters with a keen eye (Awareness) or training (Tactics) can judge the
+    // odds before a fight. Returns false when the fight should start right away.
+    fn offer_combat_assessment(&self, combat_state: &mut CombatState) -> bool {
+        let character = match &self.current_character {
+            Some(character) => character,
+            None => return false,
+        };
+        let tactics = character.skills.get("Tactics").copied().unwrap_or(0);
+        if character.characteristics.awareness < ASSESSMENT_MIN_AWARENESS && tactics == 0 {
+            return false;
+        }
+        
+        let (players, enemies): (Vec<CombatParticipant>, Vec<CombatParticipant>) = combat_state.encounter.participants
+            .iter()
+            .cloned()
+            .partition(|p| p.side == crate::forge::CombatSide::Players);
+        let player = match players.first() {
+            Some(player) => player,
+            None => return false,
+        };
         
+        let threat = crate::forge::ThreatLevel::assess(player, &enemies);
+        combat_state.encounter.add_log("👁️ You size up the opposition before they notice you...".to_string());
+        combat_state.encounter.add_log(format!("You see {}.", crate::forge::describe_enemies(&enemies)));
+        combat_state.encounter.add_log(format!("This looks like {}.", threat.get_name()));
+        if matches!(threat, crate::forge::ThreatLevel::Deadly) {
+            combat_state.encounter.add_log("⚠️ Backing away might be wise.".to_string());
+        }
+        combat_state.combat_phase = CombatPhase::Assessment;
+        true
+    }
+
+    fn generate_enemies_for_location(&self, character: &ForgeCharacter) -> anyhow::Result<Vec<CombatParticipant>> {
         // Get current terrain type if in world exploration
         let terrain_type = if let UIState::WorldExploration(ref world_state) = self.state {
             if let Some(ref zone_data) = world_state.zone_data {
@@ -924,76 +1546,41 @@ impl Game {
             crate::world::terrain::TerrainType::Plains
         };
         
-        // Generate enemies based on terrain
+        // Creatures that roam each terrain, weakest first
         use crate::world::terrain::TerrainType;
-        match terrain_type {
-            TerrainType::Forest => {
-                // Forest creatures: wolves, spiders, boars
-                match rng.gen_range(0..10) {
-                    0..=3 => enemies.push(create_wolf()),
-                    4..=6 => enemies.push(create_wild_boar()),
-                    7..=8 => enemies.push(create_giant_spider()),
-                    _ => {
-                        // Wolf pack
-                        enemies.push(create_wolf());
-                        enemies.push(create_wolf());
-                    }
-                }
-            }
-            TerrainType::Mountain | TerrainType::Hill => {
-                // Mountain creatures: mountain lions, orcs, goblins
-                match rng.gen_range(0..10) {
-                    0..=2 => enemies.push(create_mountain_lion()),
-                    3..=5 => enemies.push(create_goblin()),
-                    6..=7 => enemies.push(create_orc()),
-                    _ => {
-                        // Goblin group
-                        enemies.push(create_goblin());
-                        enemies.push(create_goblin());
-                    }
-                }
-            }
-            TerrainType::Plains | TerrainType::Grassland => {
-                // Plains creatures: bandits, wolves, boars
-                match rng.gen_range(0..10) {
-                    0..=3 => enemies.push(create_bandit()),
-                    4..=6 => enemies.push(create_wolf()),
-                    7..=8 => enemies.push(create_wild_boar()),
-                    _ => {
-                        // Bandit group
-                        enemies.push(create_bandit());
-                        if rng.gen_bool(0.5) {
-                            enemies.push(create_bandit());
-                        }
-                    }
-                }
-            }
-            TerrainType::Swamp => {
-                // Swamp creatures: spiders, skeletons
-                match rng.gen_range(0..10) {
-                    0..=4 => enemies.push(create_giant_spider()),
-                    5..=7 => enemies.push(create_skeleton()),
-                    _ => {
-                        // Spider nest
-                        enemies.push(create_giant_spider());
-                        enemies.push(create_giant_spider());
-                    }
-                }
-            }
-            TerrainType::Desert | TerrainType::Tundra => {
-                // Harsh terrain: bandits, skeletons
-                match rng.gen_range(0..6) {
-                    0..=2 => enemies.push(create_bandit()),
-                    _ => enemies.push(create_skeleton()),
-                }
-            }
-            _ => {
-                // Default: single wild boar for water/snow/etc
-                enemies.push(create_wild_boar());
-            }
-        }
+        let creatures: &[fn() -> CombatParticipant] = match terrain_type {
+            TerrainType::Forest => &[create_wild_boar, create_wolf, create_giant_spider],
+            TerrainType::Mountain | TerrainType::Hill => &[create_goblin, create_mountain_lion, create_orc],
+            TerrainType::Plains | TerrainType::Grassland => &[create_wild_boar, create_wolf, create_bandit],
+            TerrainType::Swamp => &[create_giant_spider, create_skeleton],
+            TerrainType::Desert | TerrainType::Tundra => &[create_bandit, create_skeleton],
+            // Water, snow and the like only turn up the odd boar
+            _ => &[create_wild_boar],
+        };
         
-        Ok(enemies)
+        Ok(Self::roll_enemy_group(character, &Self::with_night_undead(character, creatures)))
+    }
+
+    // After dark skeletons and zombies join whatever else prowls about
+    fn with_night_undead(character: &ForgeCharacter, creatures: &[fn() -> CombatParticipant]) -> Vec<fn() -> CombatParticipant> {
+        let mut pool = creatures.to_vec();
+        if character.time_of_day().undead_abroad() {
+            pool.extend([create_skeleton as fn() -> CombatParticipant, create_zombie]);
+        }
+        pool
+    }
+
+    // A group sized for the character's level and difficulty. Seasoned characters
+    // stop running into the weakest creatures of the area.
+    fn roll_enemy_group(character: &ForgeCharacter, creatures: &[fn() -> CombatParticipant]) -> Vec<CombatParticipant> {
+        let mut rng = crate::rng::session_rng();
+        let (min, max) = character.difficulty.enemy_group_range(character.level);
+        let outgrown = (character.level / crate::forge::WEAK_CREATURE_LEVELS) as usize;
+        let pool = &creatures[outgrown.min(creatures.len() - 1)..];
+        
+        (0..rng.gen_range(min..=max))
+            .map(|_| pool[rng.gen_range(0..pool.len())]())
+            .collect()
     }
 
     fn skill_requires_target(&self, skill_name: &str) -> bool {
@@ -1006,7 +1593,7 @@ impl Game {
     }
     
     fn get_available_combat_skills(&self, character: &ForgeCharacter) -> Vec<String> {
-        let mut skills = vec!["Basic Attack".to_string()];
+        let mut skills = vec!["Basic Attack".to_string(), crate::forge::POWER_ATTACK.to_string()];
         
         // Add character's combat skills
         for (skill_name, &skill_level) in &character.skills {
@@ -1024,9 +1611,11 @@ impl Game {
         skills.push("Defend".to_string());
         skills.push("Flee".to_string());
         
-        // Add item usage if character has healing items
-        if character.inventory.iter().any(|item| item.contains("Potion")) {
-            skills.push("Use Item".to_string());
+        // One entry for each kind of potion on hand
+        for potion in crate::forge::COMBAT_POTIONS {
+            if character.inventory.iter().any(|item| crate::world::split_stack(item).0 == potion) {
+                skills.push(format!("Use {}", potion));
+            }
         }
         
         // Add known spells
@@ -1043,24 +1632,37 @@ impl Game {
         if combat_state.encounter.is_combat_over() {
             match key.code {
                 KeyCode::Enter => {
-                    // Return to dungeon exploration if we came from there
-                    // Apply any combat results (XP gain, loot, etc.)
-                    if let Some(winner) = combat_state.encounter.get_winner() {
-                        if winner == "Player" {
-                            self.award_combat_experience(&combat_state)?;
-                        }
+                    // The first ENTER hands out the rewards and shows the results screen
+                    if combat_state.summary.is_none() {
+                        let summary = self.collect_combat_rewards(&combat_state)?;
+                        combat_state.summary = Some(summary);
+                        self.state = UIState::Combat(combat_state);
+                        return Ok(());
+                    }
+                    
+                    // A fallen player doesn't go back to where they were
+                    if combat_state.summary.as_ref().is_some_and(|summary| !summary.victory) {
+                        return self.handle_player_death(&combat_state);
                     }
                     
+                    // Return to dungeon exploration if we came from there
                     // Extract defeated enemy information before modifying state
                     let defeated_enemy_names: Vec<String> = combat_state.encounter.participants.iter()
-                        .filter(|p| !p.is_player && !p.is_alive())
+                        .filter(|p| p.side == crate::forge::CombatSide::Enemies && p.is_defeated())
                         .map(|p| p.name.clone())
                         .collect();
                     
                     if let Some(mut dungeon_state) = combat_state.return_to_dungeon {
                         // Remove defeated enemies from the dungeon floor
                         self.remove_defeated_enemies_by_names(&mut dungeon_state, defeated_enemy_names)?;
+                        // Nothing else jumps the player before they get to act
+                        dungeon_state.safe_turns = dungeon_state.safe_turns.max(POST_COMBAT_GRACE_TURNS);
                         self.state = UIState::DungeonExploration(dungeon_state);
+                    } else if let Some(mut world_state) = combat_state.return_to_world {
+                        // Defeated townsfolk and guards are gone from the zone
+                        self.remove_defeated_npcs(&mut world_state, &defeated_enemy_names);
+                        self.clear_poi_encounter(&mut world_state);
+                        self.state = UIState::WorldExploration(world_state);
                     } else {
                         self.state = UIState::Playing;
                     }
@@ -1072,6 +1674,30 @@ impl Game {
         
         // Handle different combat phases
         match combat_state.combat_phase {
+            CombatPhase::Assessment => {
+                match key.code {
+                    KeyCode::Enter => {
+                        self.begin_combat_rounds(&mut combat_state)?;
+                        self.state = UIState::Combat(combat_state);
+                        return Ok(());
+                    }
+                    KeyCode::Esc => {
+                        // Slip away before the enemy notices
+                        let message = "🚶 You back away quietly and avoid the fight.".to_string();
+                        if let Some(mut dungeon_state) = combat_state.return_to_dungeon {
+                            self.add_dungeon_message(&mut dungeon_state, message);
+                            self.state = UIState::DungeonExploration(dungeon_state);
+                        } else if let Some(mut world_state) = combat_state.return_to_world {
+                            self.add_message(&mut world_state, message);
+                            self.state = UIState::WorldExploration(world_state);
+                        } else {
+                            self.state = UIState::Playing;
+                        }
+                        return Ok(());
+                    }
+                    _ => {}
+                }
+            }
             CombatPhase::InitiativeRoll => {
                 match key.code {
                     KeyCode::Enter => {
@@ -1145,6 +1771,33 @@ impl Game {
                             }
                             KeyCode::Enter => {
                                 if combat_state.current_skill_index < combat_state.available_skills.len() {
+                                    // Ranged Combat needs something to shoot with
+                                    if combat_state.available_skills[combat_state.current_skill_index] == "Ranged Combat" {
+                                        let (has_ranged_weapon, ammo) = combat_state.encounter.get_current_participant()
+                                            .map(|p| (p.ranged_weapon.is_some(), p.ammo))
+                                            .unwrap_or((false, 0));
+                                        let warning = if !has_ranged_weapon {
+                                            Some("🏹 You have no ranged weapon equipped! Pick another action.")
+                                        } else if ammo == 0 {
+                                            Some("🏹 You are out of ammunition! Fight in melee or flee.")
+                                        } else {
+                                            None
+                                        };
+                                        if let Some(warning) = warning {
+                                            combat_state.encounter.add_log(warning.to_string());
+                                            self.state = UIState::Combat(combat_state);
+                                            return Ok(());
+                                        }
+                                    }
+                                    
+                                    // Spells still recovering or short on points can't be picked either
+                                    let round = combat_state.encounter.round;
+                                    if let Some(reason) = self.spell_unavailable_reason(&combat_state.available_skills[combat_state.current_skill_index], round) {
+                                        combat_state.encounter.add_log(format!("⏳ {}! Pick another action.", reason));
+                                        self.state = UIState::Combat(combat_state);
+                                        return Ok(());
+                                    }
+                                    
                                     combat_state.selected_skill = Some(combat_state.available_skills[combat_state.current_skill_index].clone());
                                     
                                     // Check if this is a targeted skill/spell
@@ -1161,12 +1814,28 @@ impl Game {
                                             _ => CombatAction::Defend, // Default to defend
                                         };
                                         
-                                        let result = combat_state.encounter.perform_action(action);
+                                        let result = match skill_name.strip_prefix("Use ") {
+                                            Some(item) => match self.use_combat_item(&mut combat_state, item) {
+                                                Some(result) => result,
+                                                // Nothing was drunk, so the turn isn't spent
+                                                None => {
+                                                    self.state = UIState::Combat(combat_state);
+                                                    return Ok(());
+                                                }
+                                            },
+                                            None => combat_state.encounter.perform_action(action),
+                                        };
                                         
                                         // Check if player successfully fled
                                         if skill_name == "Flee" && result.success {
+                                            // Arrows loosed before running are lost
+                                            self.settle_ammo(&combat_state, false);
+                                            self.settle_player_condition(&combat_state);
                                             if let Some(dungeon_state) = combat_state.return_to_dungeon {
-                                                self.state = UIState::DungeonExploration(dungeon_state);
+                                                self.begin_dungeon_retreat(dungeon_state);
+                                                return Ok(());
+                                            } else if let Some(world_state) = combat_state.return_to_world {
+                                                self.state = UIState::WorldExploration(world_state);
                                                 return Ok(());
                                             } else {
                                                 self.state = UIState::Playing;
@@ -1193,59 +1862,65 @@ impl Game {
                     CombatPhase::SelectingTarget => {
                         match key.code {
                             KeyCode::Char(c) if c.is_ascii_digit() => {
-                                let target_index = c.to_digit(10).unwrap() as usize - 1;
-                                let enemy_count = combat_state.encounter.participants
-                                    .iter()
-                                    .filter(|p| !p.is_player && p.is_alive())
-                                    .count();
-                                    
-                                if target_index < enemy_count {
-                                    // Find the actual target index in the participants list
-                                    let mut enemy_counter = 0;
-                                    let mut actual_target_index = 0;
-                                    
-                                    for (i, participant) in combat_state.encounter.participants.iter().enumerate() {
-                                        if !participant.is_player && participant.is_alive() {
-                                            if enemy_counter == target_index {
-                                                actual_target_index = i;
-                                                break;
-                                            }
-                                            enemy_counter += 1;
-                                        }
-                                    }
-                                    
-                                    // Execute the skill-based attack or spell
-                                    let skill_name = combat_state.selected_skill.clone().unwrap_or("Melee Combat".to_string());
-                                    if skill_name.starts_with("Cast ") {
-                                        let spell_name = skill_name.strip_prefix("Cast ").unwrap_or(&skill_name);
-                                        self.execute_spell_cast(&mut combat_state, actual_target_index, spell_name)?;
-                                    } else {
-                                        self.execute_skill_attack(&mut combat_state, actual_target_index, &skill_name)?;
-                                    }
+                                // Healing and buffs list the player's side, everything else the enemies
+                                let chosen = (c.to_digit(10).unwrap() as usize).checked_sub(1)
+                                    .and_then(|number| combat_state.target_candidates().get(number).copied());
                                     
-                                    combat_state.encounter.next_turn();
-                                    combat_state.selected_skill = None;
+                                if let Some(target_index) = chosen {
+                                    // Show the odds and wait for the player to commit
+                                    let preview = self.preview_player_action(&combat_state, target_index);
+                                    combat_state.combat_phase = CombatPhase::ConfirmingAction { target_index, preview };
+                                }
+                            }
+                            KeyCode::Esc => {
+                                combat_state.combat_phase = CombatPhase::SelectingSkill;
+                                combat_state.selected_skill = None;
+                            }
+                            _ => {}
+                        }
+                    }
+                    CombatPhase::ConfirmingAction { target_index, .. } => {
+                        match key.code {
+                            KeyCode::Enter | KeyCode::Char('y') => {
+                                // Execute the skill-based attack or spell
+                                let skill_name = combat_state.selected_skill.clone().unwrap_or("Melee Combat".to_string());
+                                let acted = if skill_name.starts_with("Cast ") {
+                                    let spell_name = skill_name.strip_prefix("Cast ").unwrap_or(&skill_name);
+                                    self.execute_spell_cast(&mut combat_state, target_index, spell_name)?
+                                } else {
+                                    self.execute_skill_attack(&mut combat_state, target_index, &skill_name)?;
+                                    true
+                                };
+                                
+                                combat_state.combat_phase = CombatPhase::SelectingSkill;
+                                combat_state.selected_skill = None;
+                                if !acted {
+                                    // A refused spell doesn't cost the turn
+                                    self.state = UIState::Combat(combat_state);
+                                    return Ok(());
+                                }
+                                combat_state.encounter.next_turn();
+                                
+                                // Check if all participants have had their turn
+                                if combat_state.encounter.current_turn == 0 {
+                                    // Round complete - start new round
+                                    combat_state.encounter.round += 1;
+                                    combat_state.encounter.add_log(format!("=== ROUND {} ===", combat_state.encounter.round));
+                                    combat_state.combat_phase = CombatPhase::DeclaringActions;
+                                } else {
+                                    // Process next participant's turn
+                                    self.process_ai_turns(&mut combat_state)?;
                                     
-                                    // Check if all participants have had their turn
-                                    if combat_state.encounter.current_turn == 0 {
-                                        // Round complete - start new round
-                                        combat_state.encounter.round += 1;
-                                        combat_state.encounter.add_log(format!("=== ROUND {} ===", combat_state.encounter.round));
-                                        combat_state.combat_phase = CombatPhase::DeclaringActions;
-                                    } else {
-                                        // Process next participant's turn
-                                        self.process_ai_turns(&mut combat_state)?;
-                                        
-                                        // Check if it's a player's turn again
-                                        if let Some(next_participant) = combat_state.encounter.get_current_participant() {
-                                            if next_participant.is_player {
-                                                combat_state.combat_phase = CombatPhase::SelectingSkill;
-                                            }
+                                    // Check if it's a player's turn again
+                                    if let Some(next_participant) = combat_state.encounter.get_current_participant() {
+                                        if next_participant.is_player {
+                                            combat_state.combat_phase = CombatPhase::SelectingSkill;
                                         }
                                     }
                                 }
                             }
                             KeyCode::Esc => {
+                                // Back to picking an action, nothing has been spent yet
                                 combat_state.combat_phase = CombatPhase::SelectingSkill;
                                 combat_state.selected_skill = None;
                             }
@@ -1262,29 +1937,148 @@ impl Game {
         Ok(())
     }
 
+    // Drink a potion from the character's pack on the current participant's turn.
+    // None if there is none left to drink.
+    fn use_combat_item(&mut self, combat_state: &mut CombatState, item: &str) -> Option<crate::forge::CombatResult> {
+        let character = self.current_character.as_mut()?;
+        let consumed = character.remove_one_item(item);
+        
+        // The list only offers what's in the pack, so refresh it either way
+        let skills = self.get_available_combat_skills(self.current_character.as_ref()?);
+        combat_state.current_skill_index = combat_state.current_skill_index.min(skills.len().saturating_sub(1));
+        combat_state.skill_list_offset = combat_state.skill_list_offset.min(combat_state.current_skill_index);
+        combat_state.available_skills = skills;
+        combat_state.selected_skill = None;
+        
+        if !consumed {
+            combat_state.encounter.add_log(format!("🧪 You reach for a {}, but there are none left!", item));
+            return None;
+        }
+        
+        match item {
+            "Spell Point Potion" => {
+                let character = self.current_character.as_mut()?;
+                let before = character.magic.spell_points.current;
+                character.magic.restore_spell_points(crate::forge::SPELL_POTION_POINTS);
+                let restored = character.magic.spell_points.current - before;
+                let name = combat_state.encounter.participants[combat_state.encounter.current_turn].name.clone();
+                combat_state.encounter.add_log(format!("🔷 {} drinks a spell point potion and recovers {} spell points!", name, restored));
+                Some(crate::forge::CombatResult {
+                    success: true,
+                    damage: None,
+                    message: format!("Restored {} spell points", restored),
+                    critical: false,
+                })
+            }
+            _ => Some(combat_state.encounter.perform_action(CombatAction::UseItem { item: item.to_string() })),
+        }
+    }
+
+    // What the selected action is likely to do to the chosen target
+    fn preview_player_action(&self, combat_state: &CombatState, target_index: usize) -> Vec<String> {
+        let skill_name = combat_state.selected_skill.clone().unwrap_or("Melee Combat".to_string());
+        let attacker = &combat_state.encounter.participants[combat_state.encounter.current_turn];
+        let target = &combat_state.encounter.participants[target_index];
+        let mut lines = vec![format!("{} ➜ {} (HP {}/{})", skill_name, target.name,
+            target.combat_stats.hit_points.current, target.combat_stats.hit_points.max)];
+        
+        if let Some(spell_name) = skill_name.strip_prefix("Cast ") {
+            let (Some(spell), Some(character)) = (crate::forge::magic::spell_book().get(spell_name), &self.current_character) else {
+                return lines;
+            };
+            let school_skill = character.magic.get_school_skill(&spell.school);
+            let success = spell.success_chance(school_skill) as u32;
+            let backfire = spell.backfire_threshold(school_skill) as u32 - success;
+            lines.push(format!("🔮 {}% to cast, {}% risk of backfire ({} SP)", success, backfire, spell.cost));
+            for effect in &spell.effects {
+                match effect {
+                    crate::forge::magic::SpellEffect::Damage { dice, bonus, .. } => lines.push(format!("💥 Damage: {}{:+}", dice, bonus)),
+                    crate::forge::magic::SpellEffect::Heal { dice, bonus } => lines.push(format!("💚 Healing: {}{:+}", dice, bonus)),
+                    crate::forge::magic::SpellEffect::Buff { stat, modifier, duration } => lines.push(format!("⬆️ +{} {} for {} rounds", modifier, stat, duration)),
+                    crate::forge::magic::SpellEffect::Debuff { stat, modifier, duration } => lines.push(format!("⬇️ {} {} for {} rounds", modifier, stat, duration)),
+                    crate::forge::magic::SpellEffect::Special { effect, .. } => lines.push(format!("🌟 {}", effect)),
+                }
+            }
+            return lines;
+        }
+        
+        let Some(weapon) = attacker.weapon_for_skill(&skill_name) else {
+            lines.push(format!("🏹 No ranged weapon for {}!", skill_name));
+            return lines;
+        };
+        let odds = crate::forge::AttackOdds::for_skill(attacker, &weapon, &skill_name, self.current_character.as_ref(), target);
+        lines.push(format!("🎯 {}% to hit (attack {} vs DV {})", odds.hit_chance, odds.attack_total, odds.defense));
+        lines.push(format!("⚔️ Damage: {}-{} before armor, with {}", odds.min_damage, odds.max_damage, weapon.name));
+        if let (crate::forge::POWER_ATTACK, Some(character)) = (skill_name.as_str(), &self.current_character) {
+            let power_attack = crate::forge::PowerAttack::for_strength(character.characteristics.strength);
+            lines.push(format!("💪 Power attack: {}", power_attack.describe()));
+        }
+        if skill_name != "Basic Attack" && attacker.max_stamina > 0 {
+            let cost = Self::stamina_cost(&skill_name);
+            let warning = if attacker.stamina <= cost { " - this will leave you exhausted" } else { "" };
+            lines.push(format!("💨 Costs {} stamina ({} left){}", cost, attacker.stamina, warning));
+        }
+        lines
+    }
+
+    // Stamina a trained attack takes out of the player
+    fn stamina_cost(skill_name: &str) -> u32 {
+        if skill_name == crate::forge::POWER_ATTACK {
+            crate::forge::STAMINA_COST_POWER_ATTACK
+        } else {
+            crate::forge::STAMINA_COST_SKILL_ATTACK
+        }
+    }
+
     fn execute_skill_attack(&mut self, combat_state: &mut CombatState, target_index: usize, skill_name: &str) -> anyhow::Result<()> {
         use rand::Rng;
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::rng::session_rng();
         
         let attacker_index = combat_state.encounter.current_turn;
         
-        // Get skill level for the player
+        // Get skill level for the player; a power attack is a Melee Combat swing
+        let trained_skill = crate::forge::trained_skill(skill_name);
         let skill_level = if let Some(character) = &self.current_character {
-            character.skills.get(skill_name).copied().unwrap_or(0)
+            character.skills.get(trained_skill).copied().unwrap_or(0)
         } else {
             0
         };
         
-        // Calculate attack bonus based on skill
-        let skill_bonus = skill_level / 2; // Every 2 skill levels = +1 to attack
+        // Ranged Combat shoots the ranged weapon, everything else swings the melee weapon
+        let attacker = &mut combat_state.encounter.participants[attacker_index];
+        let weapon = match attacker.weapon_for_skill(skill_name) {
+            Some(weapon) => weapon,
+            None => {
+                let message = format!("🏹 {} has no ranged weapon for {}!", attacker.name, skill_name);
+                combat_state.encounter.add_log(message);
+                return Ok(());
+            }
+        };
+        
+        // Every shot uses up an arrow or bolt
+        if let Some(ammo_type) = weapon.ammo_type() {
+            if !attacker.spend_ammo() {
+                let message = format!("🏹 {} is out of {}!", attacker.name, ammo_type.get_name().to_lowercase());
+                combat_state.encounter.add_log(message);
+                return Ok(());
+            }
+        }
         
-        // Get base stats
-        let attack_value = combat_state.encounter.participants[attacker_index].get_total_attack_value();
-        let defense_value = combat_state.encounter.participants[target_index].get_total_defense_value();
+        // Same numbers the combat preview shows
+        let odds = crate::forge::AttackOdds::for_skill(
+            &combat_state.encounter.participants[attacker_index], &weapon, skill_name,
+            self.current_character.as_ref(), &combat_state.encounter.participants[target_index]);
+        if let (crate::forge::POWER_ATTACK, Some(character)) = (skill_name, &self.current_character) {
+            let power_attack = crate::forge::PowerAttack::for_strength(character.characteristics.strength);
+            combat_state.encounter.add_log(format!("💪 You wind up for a mighty blow: {}", power_attack.describe()));
+        }
+        if skill_name != "Basic Attack" {
+            combat_state.encounter.spend_stamina(attacker_index, Self::stamina_cost(skill_name));
+        }
         
         // Roll attack with skill bonus
         let attack_roll = rng.gen_range(1..=20);
-        let total_attack = attack_roll + attack_value + skill_bonus;
+        let total_attack = attack_roll + odds.attack_total;
         
         let attacker_name = combat_state.encounter.participants[attacker_index].name.clone();
         let target_name = combat_state.encounter.participants[target_index].name.clone();
@@ -1292,43 +2086,31 @@ impl Game {
         // Check for critical hit (natural 20)
         let critical = attack_roll == 20;
         
-        let log_message = format!("{} uses {} (skill level {}) against {}!", 
-            attacker_name, skill_name, skill_level, target_name);
+        let log_message = match weapon.range {
+            Some(range) if weapon.ranged => format!("{} uses {} (skill level {}), firing a {} at {} from {} ft!",
+                attacker_name, skill_name, skill_level, weapon.name, target_name, range),
+            _ => format!("{} uses {} (skill level {}) against {} with {}!",
+                attacker_name, skill_name, skill_level, target_name, weapon.name),
+        };
         combat_state.encounter.add_log(log_message);
         
         // Check for hit
-        if total_attack > defense_value || critical {
-            // Roll damage
-            let weapon = combat_state.encounter.participants[attacker_index].weapon.clone()
-                .unwrap_or_else(Weapon::unarmed);
-            let (mut damage, dice_count) = weapon.roll_damage();
-            
-            // Add damage bonus from character and skill
-            let damage_bonus = combat_state.encounter.participants[attacker_index].get_total_damage_bonus();
-            let skill_damage_bonus = if skill_level >= 5 { 1 } else { 0 }; // Bonus damage at higher skill levels
-            
-            if damage_bonus >= 0 {
-                damage += damage_bonus as u32 + skill_damage_bonus;
-            } else {
-                damage = damage.saturating_sub(damage_bonus.abs() as u32);
-            }
+        if odds.is_hit(attack_roll) {
+            // Roll damage, adding the weapon, strength and skill bonuses
+            let (damage, dice_count) = weapon.roll_damage();
+            let damage = crate::forge::apply_damage_modifier(damage, odds.damage_modifier);
             
             // Double damage on critical
-            let final_dice_count = if critical { dice_count * 2 } else { dice_count };
-            if critical {
-                damage *= 2;
-            }
+            let (damage, final_dice_count) = crate::forge::combat::apply_critical(damage, dice_count, critical);
             
             // Apply damage using Forge rules
-            let (actual_damage, armor_damage) = combat_state.encounter.participants[target_index]
-                .take_damage(damage, final_dice_count);
+            let report = combat_state.encounter.participants[target_index]
+                .receive_hit(damage, final_dice_count);
             
             let message = if critical {
-                format!("CRITICAL HIT! {} damage ({} actual, {} absorbed)!", 
-                    damage, actual_damage, armor_damage)
+                format!("CRITICAL HIT! {}!", report.describe(&target_name))
             } else {
-                format!("Hit for {} damage ({} actual, {} absorbed)!", 
-                    damage, actual_damage, armor_damage)
+                format!("Hit! {}!", report.describe(&target_name))
             };
             
             combat_state.encounter.add_log(message);
@@ -1339,44 +2121,59 @@ impl Game {
             }
             
             // Award skill pip for successful attack
-            let skill_name_clone = skill_name.to_string();
+            let skill_name_clone = trained_skill.to_string();
             if let Some(character) = &mut self.current_character {
-                // Award a skill pip for successful use (simplified Forge advancement)
-                let current_pips = character.skill_pips.get(&skill_name_clone).copied().unwrap_or(0);
-                let current_level = character.skills.get(&skill_name_clone).copied().unwrap_or(0);
-                
-                // Need (current_level + 1) pips to advance to next level
-                let pips_needed = current_level + 1;
-                let new_pips = current_pips + 1;
-                
-                if new_pips >= pips_needed {
-                    // Level up the skill
-                    character.skills.insert(skill_name_clone.clone(), current_level + 1);
-                    character.skill_pips.insert(skill_name_clone.clone(), 0); // Reset pips
-                    combat_state.encounter.add_log(format!("Skill {} increased to level {}!", skill_name_clone, current_level + 1));
-                } else {
-                    character.skill_pips.insert(skill_name_clone, new_pips);
+                let new_level = character.award_skill_pip(&skill_name_clone);
+                if let Some(level) = new_level {
+                    combat_state.encounter.add_log(format!("Skill {} increased to level {}!", skill_name_clone, level));
                 }
+                self.record_practice(combat_state, &skill_name_clone, new_level.is_some());
             }
         } else {
-            let message = format!("Attack missed! (rolled {} + {} + {} = {} vs DV {})", 
-                attack_roll, attack_value, skill_bonus, total_attack, defense_value);
+            let message = format!("Attack missed! (rolled {} + {} = {} vs DV {})", 
+                attack_roll, odds.attack_total, total_attack, odds.defense);
             combat_state.encounter.add_log(message);
         }
         
         Ok(())
     }
 
-    fn execute_spell_cast(&mut self, combat_state: &mut CombatState, target_index: usize, spell_name: &str) -> anyhow::Result<()> {
+    // Character XP for putting a skill or school to good use. Each one only pays
+    // once per fight so leaning on a single skill doesn't level the character.
+    fn record_practice(&mut self, combat_state: &mut CombatState, skill: &str, advanced: bool) {
+        let mut xp = 0;
+        if !self.practiced.iter().any(|practiced| practiced == skill) {
+            self.practiced.push(skill.to_string());
+            xp += crate::forge::PRACTICE_XP;
+        }
+        if advanced {
+            xp += crate::forge::SKILL_ADVANCE_XP;
+        }
+        if xp > 0 {
+            self.practice_xp += xp;
+            combat_state.encounter.add_log(format!("📚 +{} XP for practicing {}.", xp, skill));
+        }
+    }
+
+    // Why a spell in the action list can't be cast right now, if it can't
+    fn spell_unavailable_reason(&self, skill_name: &str, round: u32) -> Option<String> {
+        let spell_name = skill_name.strip_prefix("Cast ")?;
+        let character = self.current_character.as_ref()?;
+        let spell = crate::forge::magic::spell_book().get(spell_name)?;
+        character.magic.cast_refusal(spell, round)
+    }
+
+    // Returns false when the spell was refused before anything was spent, so the
+    // player still has their turn
+    fn execute_spell_cast(&mut self, combat_state: &mut CombatState, target_index: usize, spell_name: &str) -> anyhow::Result<bool> {
         use rand::Rng;
         
         // Get the spell data
-        let spells = crate::forge::magic::create_starter_spells();
-        let spell = match spells.get(spell_name) {
+        let spell = match crate::forge::magic::spell_book().get(spell_name) {
             Some(spell) => spell.clone(),
             None => {
                 combat_state.encounter.add_log(format!("Unknown spell: {}", spell_name));
-                return Ok(());
+                return Ok(false);
             }
         };
         
@@ -1392,26 +2189,35 @@ impl Game {
         
         if !knows_spell {
             combat_state.encounter.add_log(format!("You don't know the spell: {}", spell_name));
-            return Ok(());
+            return Ok(false);
         }
         
         if !has_spell_points {
             if let Some(character) = &self.current_character {
                 combat_state.encounter.add_log(format!("Not enough spell points to cast {}! ({} required, {} available)", 
-                    spell_name, spell.cost, character.magic.spell_points.current));
+                    spell_name, spell.cost, character.magic.available_points(&spell.school)));
             }
-            return Ok(());
+            return Ok(false);
+        }
+        
+        // Signature spells need time to recover between casts
+        let round = combat_state.encounter.round;
+        if let Some(reason) = self.current_character.as_ref().and_then(|character| character.magic.spell_limit_reason(&spell, round)) {
+            combat_state.encounter.add_log(format!("⏳ {}!", reason));
+            return Ok(false);
         }
         
         // Spend spell points
         if let Some(character) = &mut self.current_character {
-            character.magic.spend_spell_points(spell.cost);
+            character.magic.spend_spell_points(&spell);
+            character.magic.record_spell_cast(&spell, round);
         }
         
         // Calculate success chance and roll
-        let success_chance = spell.success_chance_base + (school_skill * 2); // +2% per skill level
+        let success_chance = spell.success_chance(school_skill);
+        let backfire_threshold = spell.backfire_threshold(school_skill);
         
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::rng::session_rng();
         let roll = rng.gen_range(1..=100);
         
         if roll <= success_chance {
@@ -1419,9 +2225,12 @@ impl Game {
             combat_state.encounter.add_log(format!("🔮 {} successfully casts {}!", 
                 combat_state.encounter.participants[combat_state.encounter.current_turn].name, spell_name));
             
-            // Apply spell effects
+            // Apply spell effects. Side effects meant for the other side, like the
+            // healing half of Drain Life, land on the caster instead
+            let caster_index = combat_state.encounter.current_turn;
             for effect in &spell.effects {
-                self.apply_spell_effect(combat_state, target_index, effect, spell_name)?;
+                let effect_target = if effect.targets_allies() == spell.targets_allies() { target_index } else { caster_index };
+                self.apply_spell_effect(combat_state, effect_target, effect, spell_name)?;
             }
             
             // Award magic skill advancement
@@ -1430,7 +2239,8 @@ impl Game {
                 let current_pips = character.magic.school_pips.get(&spell_school).copied().unwrap_or(0);
                 let new_pips = current_pips + 1;
                 
-                if new_pips >= 10 {
+                let advanced = new_pips >= 10;
+                if advanced {
                     // Advance skill level
                     let new_skill = (current_skill + 1).min(20);
                     character.magic.school_skills.insert(spell_school.clone(), new_skill);
@@ -1441,20 +2251,18 @@ impl Game {
                 } else {
                     character.magic.school_pips.insert(spell_school.clone(), new_pips);
                 }
+                self.record_practice(combat_state, &format!("{} magic", spell_school), advanced);
             }
             
-        } else if roll <= success_chance + spell.backfire_chance {
+        } else if roll <= backfire_threshold {
             // Backfire!
             combat_state.encounter.add_log(format!("💥 {} casts {} but it backfires!", 
                 combat_state.encounter.participants[combat_state.encounter.current_turn].name, spell_name));
             
-            // Simple backfire: take damage
-            let backfire_damage = spell.level as u32 * 2;
-            let caster_index = combat_state.encounter.current_turn;
-            let (actual_damage, _) = combat_state.encounter.participants[caster_index]
-                .take_damage(backfire_damage, 1);
-            
-            combat_state.encounter.add_log(format!("Magical energy courses through your body! {} damage!", actual_damage));
+            let outcome = self.current_character.as_ref().map(|character| crate::forge::resolve_backfire(&spell, &character.magic, crate::rng::session_rng().gen_range(1..=100)));
+            if let Some(outcome) = outcome {
+                self.apply_backfire(combat_state, &spell, outcome)?;
+            }
             
         } else {
             // Simple failure
@@ -1462,40 +2270,75 @@ impl Game {
                 combat_state.encounter.participants[combat_state.encounter.current_turn].name, spell_name));
         }
         
-        Ok(())
+        Ok(true)
     }
     
-    fn apply_spell_effect(&mut self, combat_state: &mut CombatState, target_index: usize, effect: &crate::forge::magic::SpellEffect, _spell_name: &str) -> anyhow::Result<()> {
+    fn apply_backfire(&mut self, combat_state: &mut CombatState, spell: &crate::forge::Spell, outcome: crate::forge::BackfireOutcome) -> anyhow::Result<()> {
+        use crate::forge::BackfireOutcome;
         use rand::Rng;
-        let mut rng = rand::thread_rng();
+        let caster_index = combat_state.encounter.current_turn;
+        let caster_name = combat_state.encounter.participants[caster_index].name.clone();
+        
+        match outcome {
+            BackfireOutcome::Feedback { damage } => {
+                // The spell's own power turns on the caster, straight past any armor
+                let report = combat_state.encounter.participants[caster_index].take_direct_damage(damage);
+                combat_state.encounter.add_log(format!("Magical energy courses through your body! {}!", report.describe(&caster_name)));
+                
+                // The feedback leaves the caster shaken for the rest of the fight
+                let caster = &mut combat_state.encounter.participants[caster_index];
+                if caster.is_alive() && caster.combat_stats.attack_value > 0 {
+                    caster.combat_stats.attack_value -= 1;
+                    combat_state.encounter.add_log(format!("⬇️ {} is shaken by the backlash (-1 attack for this fight).", caster_name));
+                }
+                if !combat_state.encounter.participants[caster_index].is_alive() {
+                    combat_state.encounter.add_log(format!("{} has been consumed by their own magic!", caster_name));
+                }
+            }
+            BackfireOutcome::Misdirected => {
+                let own_side = combat_state.encounter.participants[caster_index].side;
+                let friends: Vec<usize> = combat_state.encounter.participants.iter().enumerate()
+                    .filter(|(_, p)| p.side == own_side && p.is_alive())
+                    .map(|(i, _)| i)
+                    .collect();
+                let victim = friends[crate::rng::session_rng().gen_range(0..friends.len())];
+                let victim_name = combat_state.encounter.participants[victim].name.clone();
+                if victim == caster_index {
+                    combat_state.encounter.add_log(format!("🌀 The spell twists back and lands on {} instead!", caster_name));
+                } else {
+                    combat_state.encounter.add_log(format!("🌀 The spell goes astray and strikes {}!", victim_name));
+                }
+                for effect in &spell.effects {
+                    self.apply_spell_effect(combat_state, victim, effect, &spell.name)?;
+                }
+            }
+            BackfireOutcome::SkillDrained => {
+                if let Some(character) = &mut self.current_character {
+                    if let Some(skill) = character.magic.school_skills.get_mut(&spell.school) {
+                        *skill = skill.saturating_sub(1);
+                        self.drained_schools.push(spell.school.clone());
+                        combat_state.encounter.add_log(format!("🧠 The backlash scrambles {}'s grasp of {} magic (-1 level until the fight ends).", caster_name, spell.school));
+                    }
+                }
+            }
+            BackfireOutcome::Fizzled => {
+                combat_state.encounter.add_log(format!("💨 The gathered power fizzles away. {}'s spell points are wasted.", caster_name));
+            }
+        }
         
+        Ok(())
+    }
+    
+    fn apply_spell_effect(&mut self, combat_state: &mut CombatState, target_index: usize, effect: &crate::forge::magic::SpellEffect, spell_name: &str) -> anyhow::Result<()> {
         match effect {
             crate::forge::magic::SpellEffect::Damage { dice, bonus, damage_type: _ } => {
-                // Parse dice string and roll damage
-                let damage = if let Some((num_dice, die_size)) = dice.split_once('d') {
-                    let dice_count: u32 = num_dice.parse().unwrap_or(1);
-                    let die_size: u32 = die_size.parse().unwrap_or(4);
-                    
-                    let mut total = 0;
-                    for _ in 0..dice_count {
-                        total += rng.gen_range(1..=die_size);
-                    }
-                    
-                    if *bonus >= 0 {
-                        total + (*bonus as u32)
-                    } else {
-                        total.saturating_sub(bonus.abs() as u32)
-                    }
-                } else {
-                    4 // Default damage
-                };
+                let damage = crate::forge::magic::roll_dice(dice, *bonus);
                 
                 let target_name = combat_state.encounter.participants[target_index].name.clone();
-                let (actual_damage, armor_damage) = combat_state.encounter.participants[target_index]
-                    .take_damage(damage, 1); // Spells typically pierce some armor
+                let report = combat_state.encounter.participants[target_index]
+                    .receive_hit(damage, 1); // Spells typically pierce some armor
                 
-                combat_state.encounter.add_log(format!("✨ {} takes {} magical damage ({} actual, {} absorbed)!", 
-                    target_name, damage, actual_damage, armor_damage));
+                combat_state.encounter.add_log(format!("✨ Magical damage: {}!", report.describe(&target_name)));
                 
                 if !combat_state.encounter.participants[target_index].is_alive() {
                     combat_state.encounter.add_log(format!("{} has been defeated by magic!", target_name));
@@ -1503,44 +2346,51 @@ impl Game {
             }
             
             crate::forge::magic::SpellEffect::Heal { dice, bonus } => {
-                // Parse dice string and roll healing
-                let healing = if let Some((num_dice, die_size)) = dice.split_once('d') {
-                    let dice_count: u32 = num_dice.parse().unwrap_or(1);
-                    let die_size: u32 = die_size.parse().unwrap_or(4);
-                    
-                    let mut total = 0;
-                    for _ in 0..dice_count {
-                        total += rng.gen_range(1..=die_size);
-                    }
-                    
-                    if *bonus >= 0 {
-                        total + (*bonus as u32)
-                    } else {
-                        total.saturating_sub(bonus.abs() as u32)
-                    }
+                let healing = crate::forge::magic::roll_dice(dice, *bonus);
+                
+                let caster_index = combat_state.encounter.current_turn;
+                let caster_name = combat_state.encounter.participants[caster_index].name.clone();
+                let target_name = if target_index == caster_index {
+                    "themselves".to_string()
                 } else {
-                    4 // Default healing
+                    combat_state.encounter.participants[target_index].name.clone()
                 };
+                let restored = combat_state.encounter.participants[target_index].heal(healing);
+                let wasted = healing - restored;
                 
-                let target_name = combat_state.encounter.participants[target_index].name.clone();
-                combat_state.encounter.participants[target_index].heal(healing);
-                
-                combat_state.encounter.add_log(format!("💚 {} heals {} for {} points!", 
-                    target_name, target_name, healing));
+                if wasted > 0 {
+                    combat_state.encounter.add_log(format!("💚 {} heals {} for {} points ({} wasted at full health)!", 
+                        caster_name, target_name, restored, wasted));
+                } else {
+                    combat_state.encounter.add_log(format!("💚 {} heals {} for {} points!", 
+                        caster_name, target_name, restored));
+                }
             }
             
             crate::forge::magic::SpellEffect::Buff { stat, modifier, duration } => {
-                let target_name = combat_state.encounter.participants[target_index].name.clone();
-                combat_state.encounter.add_log(format!("⬆️ {} gains +{} {} for {} rounds!", 
-                    target_name, modifier, stat, duration));
-                // TODO: Implement buff tracking system
+                let target = &mut combat_state.encounter.participants[target_index];
+                let refreshed = target.add_effect(crate::forge::combat::ActiveEffect::new(spell_name, stat, *modifier, *duration));
+                let target_name = target.name.clone();
+                if refreshed {
+                    combat_state.encounter.add_log(format!("⬆️ {}'s {} is renewed for {} rounds!", target_name, spell_name, duration));
+                } else {
+                    combat_state.encounter.add_log(format!("⬆️ {} gains +{} {} for {} rounds!", 
+                        target_name, modifier, stat, duration));
+                }
             }
             
             crate::forge::magic::SpellEffect::Debuff { stat, modifier, duration } => {
-                let target_name = combat_state.encounter.participants[target_index].name.clone();
-                combat_state.encounter.add_log(format!("⬇️ {} suffers {} {} for {} rounds!", 
-                    target_name, modifier, stat, duration));
-                // TODO: Implement debuff tracking system
+                // Spell data may list the penalty as positive or negative
+                let target = &mut combat_state.encounter.participants[target_index];
+                let penalty = -(modifier.unsigned_abs().min(i8::MAX as u8) as i8);
+                let refreshed = target.add_effect(crate::forge::combat::ActiveEffect::new(spell_name, stat, penalty, *duration));
+                let target_name = target.name.clone();
+                if refreshed {
+                    combat_state.encounter.add_log(format!("⬇️ {}'s {} is renewed for {} rounds!", target_name, spell_name, duration));
+                } else {
+                    combat_state.encounter.add_log(format!("⬇️ {} suffers {} {} for {} rounds!", 
+                        target_name, penalty, stat, duration));
+                }
             }
             
             crate::forge::magic::SpellEffect::Special { effect, duration: _ } => {
@@ -1553,36 +2403,248 @@ impl Game {
         Ok(())
     }
     
-    fn award_combat_experience(&mut self, combat_state: &CombatState) -> anyhow::Result<()> {
+    // Lasting effects, fatigue and wear on armor go back onto the character
+    fn settle_player_condition(&mut self, combat_state: &CombatState) {
+        let Some(player) = combat_state.encounter.participants.iter().find(|p| p.is_player) else {
+            return;
+        };
         if let Some(character) = &mut self.current_character {
-            // Award experience based on defeated enemies
-            let mut total_xp = 0;
+            for school in self.drained_schools.drain(..) {
+                *character.magic.school_skills.entry(school).or_insert(0) += 1;
+            }
+            character.status_effects = player.status_effects.clone();
+            character.fatigue = player.max_stamina.saturating_sub(player.stamina);
+            character.equipped_armor = player.armor.clone();
+            character.equipped_shield = player.shield.clone();
+            // Followers that were cut down stay down
+            character.followers = combat_state.encounter.participants.iter()
+                .filter(|p| !p.is_player && p.side == crate::forge::CombatSide::Players && !p.is_defeated())
+                .map(|p| CombatParticipant { fled: false, active_effects: Vec::new(), ..p.clone() })
+                .collect();
+        }
+    }
+
+    // Lasting effects wear off (and poison bites) as the player explores, and
+    // they slowly get their breath back
+    fn tick_exploration_turn(&mut self) -> Vec<String> {
+        self.current_character.as_mut()
+            .map(|character| {
+                character.recover_stamina(1);
+                character.tick_status_effects()
+            })
+            .unwrap_or_default()
+    }
+
+    // Write the player's remaining ammo back to the inventory. After a win about
+    // half of the arrows or bolts that were shot can be collected again.
+    fn settle_ammo(&mut self, combat_state: &CombatState, recover: bool) -> Option<String> {
+        use rand::Rng;
+        let player = combat_state.encounter.participants.iter().find(|p| p.is_player)?;
+        let ammo_type = player.ranged_weapon.as_ref()?.ammo_type()?;
+        if player.ammo_spent == 0 {
+            return None;
+        }
+
+        let mut rng = crate::rng::session_rng();
+        let recovered = if recover {
+            (0..player.ammo_spent).filter(|_| rng.gen_bool(0.5)).count() as u32
+        } else {
+            0
+        };
+
+        let character = self.current_character.as_mut()?;
+        ammo_type.set_count_in(&mut character.inventory, player.ammo + recovered);
+
+        let name = ammo_type.get_name().to_lowercase();
+        Some(if recovered > 0 {
+            format!("🏹 You recover {} of the {} {} you shot ({} left).", recovered, player.ammo_spent, name, player.ammo + recovered)
+        } else {
+            format!("🏹 None of the {} {} you shot could be recovered ({} left).", player.ammo_spent, name, player.ammo)
+        })
+    }
+
+    // Hand out XP, loot and recovered ammo for a finished fight. Creatures that
+    // fell on a dungeon floor leave corpses to search instead of dropping loot.
+    fn collect_combat_rewards(&mut self, combat_state: &CombatState) -> anyhow::Result<crate::ui::CombatSummary> {
+        let victory = combat_state.encounter.get_winner() == Some(crate::forge::CombatSide::Players);
+        let mut summary = crate::ui::CombatSummary {
+            victory,
+            ..Default::default()
+        };
+        
+        if victory {
+            let (experience, new_levels) = self.award_combat_experience(combat_state)?;
+            summary.experience = experience;
+            summary.new_levels = new_levels;
+            
+            // People fought in the overworld drop what they carried
+            let world_npcs: Vec<crate::world::NPC> = combat_state.return_to_world.as_ref()
+                .and_then(|world_state| world_state.zone_data.as_ref())
+                .map(|zone_data| zone_data.npcs.clone())
+                .unwrap_or_default();
+            let mut searched_npcs = 0;
             
+            let floor_creatures: Vec<String> = combat_state.return_to_dungeon.as_ref()
+                .and_then(|dungeon_state| dungeon_state.dungeon.get_current_floor())
+                .map(|floor| floor.creatures.iter().map(|creature| creature.name.clone()).collect())
+                .unwrap_or_default();
+            
+            let mut corpses = 0;
+            let defeated = combat_state.encounter.participants.iter()
+                .filter(|p| p.side == crate::forge::CombatSide::Enemies && p.is_defeated());
+            for enemy in defeated {
+                if floor_creatures.contains(&enemy.name) {
+                    corpses += 1;
+                    continue;
+                }
+                let loot = if let Some(npc) = world_npcs.iter().find(|npc| npc.name == enemy.name) {
+                    searched_npcs += 1;
+                    npc.generate_loot()
+                } else {
+                    enemy.creature_type.as_ref().map(|creature_type| creature_type.generate_loot()).unwrap_or_default()
+                };
+                for item in loot {
+                    match item.item_type {
+                        crate::world::LootItemType::Gold => summary.gold += item.quantity * item.value,
+                        crate::world::LootItemType::Ammo => {
+                            self.add_ammo_to_inventory(&item.name, item.quantity);
+                            summary.items.push(format!("{} x{}", item.name, item.quantity));
+                        }
+                        _ => {
+                            if let Some(character) = &mut self.current_character {
+                                for _ in 0..item.quantity {
+                                    character.inventory.push(item.name.clone());
+                                }
+                            }
+                            if item.quantity > 1 {
+                                summary.items.push(format!("{} x{}", item.name, item.quantity));
+                            } else {
+                                summary.items.push(item.name.clone());
+                            }
+                        }
+                    }
+                }
+            }
+            
+            if let Some(character) = &mut self.current_character {
+                character.earn_gold(summary.gold);
+            }
+            if corpses > 0 {
+                summary.notes.push(format!("🪦 {} bodies lie where they fell. Search them for loot.", corpses));
+            }
+            if searched_npcs > 0 {
+                summary.notes.push(format!("🪦 You search {} fallen foe(s) and take what they carried.", searched_npcs));
+            }
+            self.advance_quests(combat_state, &mut summary);
+        } else if combat_state.encounter.is_combat_over() {
+            if let Some(character) = &mut self.current_character {
+                let lost = character.apply_death_penalty();
+                summary.notes.push(format!("💀 Death costs you {} experience ({}% on {}).", lost, character.difficulty.death_xp_penalty_percent(), character.difficulty.get_name()));
+            }
+        }
+        
+        if let Some(message) = self.settle_ammo(combat_state, victory) {
+            summary.notes.push(message);
+        }
+        self.settle_player_condition(combat_state);
+        
+        Ok(summary)
+    }
+
+    // The player lost a fight. Depending on difficulty the character is either gone for
+    // good or wakes up in the nearest settlement; either way the adventure ends here.
+    fn handle_player_death(&mut self, combat_state: &CombatState) -> anyhow::Result<()> {
+        self.record_playtime();
+        let Some(mut character) = self.current_character.take() else {
+            self.state = UIState::MainMenu;
+            return Ok(());
+        };
+        
+        // Nothing of the interrupted world or dungeon visit survives
+        self.saved_world_state = None;
+        character.active_dungeon = None;
+        
+        let mut messages = Vec::new();
+        let permadeath = character.difficulty.is_permadeath();
+        if permadeath {
+            messages.push(format!("⚰️ {} the {} fell in battle at level {}.", character.name, character.race.name, character.level));
+            messages.push(format!("🪦 Playing on {} difficulty, death is permanent.", character.difficulty.get_name()));
+            messages.push(format!("⏳ Their campaign lasted {} and felled {} foes.", character.stats.describe_playtime(), character.stats.enemies_defeated));
+        } else {
+            let zone = combat_state.return_to_dungeon.as_ref().map(|dungeon_state| dungeon_state.zone)
+                .or_else(|| combat_state.return_to_world.as_ref().map(|world_state| world_state.current_zone))
+                .or(character.current_zone)
+                .unwrap_or(self.player_position.to_zone());
+            let settlement = self.world_manager.as_ref()
+                .and_then(|world_manager| world_manager.find_nearest_settlement(zone, 3))
+                .map(|(settlement_zone, settlement)| (settlement_zone, settlement.position, settlement.name.clone()));
+            
+            messages.push("💀 Darkness takes you as you fall...".to_string());
+            match settlement {
+                Some((settlement_zone, position, name)) => {
+                    character.current_zone = Some(settlement_zone);
+                    character.current_position = Some(position);
+                    self.player_position = WorldCoord::from_zone_local(settlement_zone, position);
+                    messages.push(format!("🏘️ Strangers carried you to {}, where you wake up in a borrowed bed.", name));
+                }
+                None => {
+                    messages.push("🏕️ You wake up alone, far from any settlement.".to_string());
+                }
+            }
+            
+            character.revive();
+            messages.push(format!("❤️ You are weak, with only {}/{} health.", character.combat_stats.hit_points.current, character.combat_stats.hit_points.max));
+            if let Some(note) = combat_state.summary.as_ref().and_then(|summary| summary.notes.iter().find(|note| note.starts_with("💀"))) {
+                messages.push(note.clone());
+            }
+            character.update_last_played();
+        }
+        settle_fallen_character(&mut self.database, &character)?;
+        self.database.save(&self.db_path)?;
+        
+        // The character is already safe, so a world that won't save mustn't skip the death screen
+        if let Some(world_manager) = &mut self.world_manager {
+            if let Err(error) = world_manager.save_if_dirty() {
+                messages.push(format!("⚠️ The world could not be saved: {}", error));
+            }
+        }
+        
+        self.state = UIState::Death(crate::ui::DeathState {
+            character_name: character.name.clone(),
+            permadeath,
+            messages,
+        });
+        Ok(())
+    }
+
+    // Returns the XP gained and any new levels reached
+    fn award_combat_experience(&mut self, combat_state: &CombatState) -> anyhow::Result<(u32, Vec<u8>)> {
+        let mut total_xp = 0;
+        let mut new_levels = Vec::new();
+        if let Some(character) = &mut self.current_character {
+            // Award experience based on defeated enemies
             for participant in &combat_state.encounter.participants {
-                if !participant.is_player && !participant.is_alive() {
+                if participant.side == crate::forge::CombatSide::Enemies && participant.is_defeated() {
+                    character.stats.enemies_defeated += 1;
                     // XP based on creature difficulty (HP + attack/defense values)
                     let creature_xp = participant.combat_stats.hit_points.max + 
                         (participant.combat_stats.attack_value as u32) + 
                         (participant.combat_stats.defensive_value as u32);
                     total_xp += creature_xp;
+                    
+                    if let Some(creature_type) = &participant.creature_type {
+                        let day = character.current_day();
+                        character.bestiary.record_kill(creature_type, &participant.combat_stats, day);
+                    }
                 }
             }
             
-            character.experience += total_xp;
-            
-            // Check for level advancement (simplified)
-            let xp_for_next_level = (character.level as u32 + 1) * 100;
-            if character.experience >= xp_for_next_level {
-                character.level += 1;
-                character.experience -= xp_for_next_level;
-                
-                // Increase hit points on level up
-                character.combat_stats.hit_points.max += 5;
-                character.combat_stats.hit_points.current = character.combat_stats.hit_points.max;
-            }
+            // Skills and spells used well along the way count too
+            total_xp += self.practice_xp;
+            new_levels = character.gain_experience(total_xp);
         }
         
-        Ok(())
+        Ok((total_xp, new_levels))
     }
 
     fn remove_defeated_enemies_by_names(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, defeated_enemy_names: Vec<String>) -> anyhow::Result<()> {
@@ -1632,6 +2694,13 @@ impl Game {
             });
             
             let removed_count = initial_count - floor.creatures.len();
+            // Killing the last creature anywhere in the dungeon clears it
+            let cleared = removed_count > 0 && dungeon_state.dungeon.floors.values().all(|floor| floor.creatures.is_empty());
+            if cleared {
+                if let Some(character) = &mut self.current_character {
+                    character.stats.dungeons_cleared += 1;
+                }
+            }
             if removed_count > 0 {
                 self.add_dungeon_message(dungeon_state, format!("💀 {} enemies defeated.", removed_count));
                 self.add_dungeon_message(dungeon_state, format!("🪦 {} corpses left behind.", corpses_created));
@@ -1652,14 +2721,8 @@ impl Game {
             
             if let Some(current) = combat_state.encounter.get_current_participant() {
                 if !current.is_player && current.is_alive() {
-                    // Simple AI: always attack the first alive player
-                    let target_index = combat_state.encounter.participants
-                        .iter()
-                        .position(|p| p.is_player && p.is_alive())
-                        .unwrap_or(0);
-                    
-                    let action = CombatAction::Attack { target_index };
-                    combat_state.encounter.perform_action(action);
+                    // Each creature fights according to its AI profile
+                    combat_state.encounter.take_ai_turn();
                     combat_state.encounter.next_turn();
                 } else {
                     // It's a player's turn, stop processing
@@ -1676,11 +2739,9 @@ impl Game {
     fn enter_world_exploration(&mut self) -> anyhow::Result<()> {
         // Initialize world manager if not already done
         if self.world_manager.is_none() {
-            let world_name = "default_world";
-            let master_seed = 12345; // You could derive this from character or make it configurable
-            let save_dir = std::path::Path::new("./world_data");
-            
-            self.world_manager = Some(WorldManager::new(world_name, master_seed, save_dir)?);
+            let world_name = crate::world::DEFAULT_WORLD_NAME;
+            let master_seed = 12345; // Only seeds a brand new world; an existing one reads its seed from the manifest
+            self.world_manager = Some(WorldManager::new(world_name, master_seed, &self.world_dir, self.world_bounds)?);
         }
         
         // Load player position from character data if available
@@ -1689,30 +2750,135 @@ impl Game {
                 // Convert zone/local coordinates back to world coordinates
                 self.player_position = WorldCoord::from_zone_local(*zone, *position);
             }
+            // A saved dungeon visit puts the player back at its entrance
+            if let Some(saved) = &character.active_dungeon {
+                self.player_position = WorldCoord::from_zone_local(saved.zone, saved.poi_position);
+            }
+        }
+        
+        // Characters from a larger world start over in the middle of this one
+        let bounds = self.world_bounds();
+        if !bounds.contains(self.player_position.to_zone()) {
+            self.player_position = bounds.center();
         }
         
         // Get current zone and generate if needed
         let current_zone = self.player_position.to_zone();
         let local_pos = self.player_position.to_local();
         
-        let zone_data = if let Some(world_manager) = &mut self.world_manager {
-            world_manager.get_zone(current_zone).ok().cloned()
+        let (zone_data, region) = if let Some(world_manager) = &mut self.world_manager {
+            (world_manager.get_zone(current_zone).ok().cloned(), Some(world_manager.get_region(current_zone)))
         } else {
-            None
+            (None, None)
         };
         
         self.state = UIState::WorldExploration(WorldExplorationState {
             current_zone,
             player_local_pos: local_pos,
             zone_data,
+            region,
             messages: vec!["Welcome to the world! Press L to look around, H for help, or start exploring with WASD.".to_string()],
+            message_scroll: 0,
+            look_prompt: false,
+            conversation: None,
+            chat_prompt: false,
+            other_players: Vec::new(),
         });
+        self.reveal_surroundings(current_zone, local_pos);
+        if let UIState::WorldExploration(mut world_state) = self.state.clone() {
+            self.connect_to_server(&mut world_state);
+        }
+        
+        // Resume the dungeon the player was in when they last quit
+        if let Some(saved) = self.current_character.as_mut().and_then(|character| character.active_dungeon.take()) {
+            if let UIState::WorldExploration(world_state) = &self.state {
+                self.saved_world_state = Some(world_state.clone());
+            }
+            let messages = vec![
+                format!("You resume exploring {} on floor {}.", saved.dungeon.name, saved.dungeon.current_floor + 1),
+                "Type 'H' for help with dungeon exploration.".to_string(),
+            ];
+            self.state = UIState::DungeonExploration(DungeonExplorationState {
+                dungeon: saved.dungeon,
+                player_pos: saved.player_pos,
+                zone: saved.zone,
+                poi_position: saved.poi_position,
+                messages,
+                turn_count: saved.turn_count,
+                stair_prompt: false,
+                retreat_prompt: false,
+                ranged_targets: Vec::new(),
+                safe_turns: 0,
+                message_scroll: 0,
+                look_prompt: false,
+                corpse_menu: None,
+            });
+        }
         
         Ok(())
     }
+    
+    // Record the tiles around the player in the character's map memory
+    fn reveal_surroundings(&mut self, zone: crate::world::ZoneCoord, pos: LocalCoord) {
+        if let Some(character) = &mut self.current_character {
+            let radius = character.overworld_sight_radius();
+            character.map_memory.reveal_around(zone, pos, radius);
+        }
+    }
 
     fn handle_world_exploration_input(&mut self, key: KeyEvent, mut world_state: WorldExplorationState) -> anyhow::Result<bool> {
+        // A conversation takes the keys until the player leaves it
+        if let Some(conversation) = world_state.conversation {
+            match key.code {
+                KeyCode::PageUp | KeyCode::PageDown => {
+                    world_state.message_scroll = Self::scroll_messages(key.code, world_state.message_scroll, world_state.messages.len());
+                }
+                _ => self.handle_conversation_input(key, &mut world_state, conversation)?,
+            }
+            self.state = UIState::WorldExploration(world_state);
+            return Ok(false);
+        }
+        
+        // A chat line takes the keys until it's sent or dropped
+        if world_state.chat_prompt {
+            match key.code {
+                KeyCode::Enter => {
+                    world_state.chat_prompt = false;
+                    let line = std::mem::take(&mut self.input_buffer);
+                    self.send_chat(&mut world_state, &line);
+                }
+                KeyCode::Esc => {
+                    world_state.chat_prompt = false;
+                    self.input_buffer.clear();
+                }
+                KeyCode::Backspace => {
+                    self.input_buffer.pop();
+                }
+                KeyCode::Char(c) if !c.is_control() && self.input_buffer.chars().count() < crate::network::MAX_CHAT_LENGTH => {
+                    self.input_buffer.push(c);
+                }
+                _ => {}
+            }
+            self.state = UIState::WorldExploration(world_state);
+            return Ok(false);
+        }
+        
+        // Answer the look prompt before anything else
+        if world_state.look_prompt {
+            world_state.look_prompt = false;
+            match Self::look_direction(key.code) {
+                Some((dx, dy, direction)) => self.look_in_direction(dx, dy, direction, &mut world_state)?,
+                None if matches!(key.code, KeyCode::Char('l') | KeyCode::Enter) => self.look_at_tile(&mut world_state)?,
+                None => self.add_message(&mut world_state, "You stop looking around.".to_string()),
+            }
+            self.state = UIState::WorldExploration(world_state);
+            return Ok(false);
+        }
+
         match key.code {
+            KeyCode::PageUp | KeyCode::PageDown => {
+                world_state.message_scroll = Self::scroll_messages(key.code, world_state.message_scroll, world_state.messages.len());
+            }
             KeyCode::Char('w') | KeyCode::Up => {
                 // Move north
                 self.move_player(0, -1, &mut world_state)?;
@@ -1733,11 +2899,18 @@ impl Game {
                 // Return to main menu
                 self.state = UIState::Playing;
             }
+            KeyCode::Char('M') => {
+                self.open_world_map(&world_state);
+                return Ok(false);
+            }
             KeyCode::Char('f') => {
-                // Start combat at current location
-                if let Some(character) = &self.current_character {
-                    let character = character.clone();
-                    self.start_combat_encounter(&character)?;
+                // Attack an adjacent person, or start combat at current location
+                let attacked_npc = self.attack_adjacent_npc(&mut world_state)?;
+                if !attacked_npc {
+                    if let Some(character) = &self.current_character {
+                        let character = character.clone();
+                        self.start_combat_encounter(&character, Some(world_state.clone()), CombatOpening::Engaged)?;
+                    }
                 }
             }
             KeyCode::Char('q') => {
@@ -1754,6 +2927,15 @@ impl Game {
                 // Talk to NPCs at current location
                 self.talk_to_npcs(&mut world_state)?;
             }
+            KeyCode::Char('T') => {
+                // Chat with other players in the zone
+                if self.chat.is_some() {
+                    world_state.chat_prompt = true;
+                    self.input_buffer.clear();
+                } else {
+                    self.add_message(&mut world_state, "📡 You're not connected to a server. Start the game with --server HOST:PORT to chat.".to_string());
+                }
+            }
             KeyCode::Char('r') => {
                 // Search current location
                 self.search_location(&mut world_state)?;
@@ -1763,8 +2945,9 @@ impl Game {
                 self.interact_with_poi(&mut world_state)?;
             }
             KeyCode::Char('l') => {
-                // Look at current tile in detail
-                self.look_at_tile(&mut world_state)?;
+                // Look at a neighbouring tile, or this one
+                world_state.look_prompt = true;
+                self.add_message(&mut world_state, "👁️ Look which way? (WASD/arrows, L/Enter for here)".to_string());
             }
             KeyCode::Char('c') => {
                 // Make camp / rest
@@ -1782,6 +2965,18 @@ impl Game {
                 // Find nearby POIs
                 self.find_nearby_pois(&mut world_state)?;
             }
+            KeyCode::Char('u') => {
+                // Hunt for game
+                self.hunt_or_fish(&mut world_state, false)?;
+            }
+            KeyCode::Char('n') => {
+                // Fish in a lake or river
+                self.hunt_or_fish(&mut world_state, true)?;
+            }
+            KeyCode::Char('o') => {
+                // Put on the next piece of gear in the pack
+                self.equip_next_item(&mut world_state);
+            }
             // Handle any other character input to prevent random text from appearing
             KeyCode::Char(c) => {
                 // Add a message for unrecognized commands
@@ -1807,7 +3002,111 @@ impl Game {
     }
 
     fn handle_dungeon_exploration_input(&mut self, key: KeyEvent, mut dungeon_state: DungeonExplorationState) -> anyhow::Result<bool> {
+        // Choose who to shoot at
+        if !dungeon_state.ranged_targets.is_empty() {
+            let targets = std::mem::take(&mut dungeon_state.ranged_targets);
+            let chosen = match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => (c.to_digit(10).unwrap() as usize).checked_sub(1)
+                    .and_then(|number| targets.get(number)),
+                KeyCode::Enter => targets.first(),
+                _ => None,
+            };
+            let target_creature = chosen.and_then(|pos| dungeon_state.dungeon.get_current_floor()?
+                .creatures.iter().find(|creature| creature.position == *pos).cloned());
+            match target_creature {
+                Some(creature) => {
+                    self.add_dungeon_message(&mut dungeon_state, format!("💥 You loose a shot at the {} before it can close in!", creature.name));
+                    self.start_ranged_dungeon_combat(&mut dungeon_state, &creature)?;
+                }
+                None if key.code == KeyCode::Esc => self.add_dungeon_message(&mut dungeon_state, "You lower your weapon.".to_string()),
+                None => {
+                    // Keep aiming until a listed target or Esc is pressed
+                    dungeon_state.ranged_targets = targets;
+                    self.add_dungeon_message(&mut dungeon_state, "Pick a target by number, Enter for the nearest, or Esc to cancel.".to_string());
+                }
+            }
+            if matches!(self.state, UIState::DungeonExploration(_)) {
+                self.state = UIState::DungeonExploration(dungeon_state);
+            }
+            return Ok(false);
+        }
+
+        // Choose what to do with a corpse
+        if let Some(corpse_pos) = dungeon_state.corpse_menu.take() {
+            let corpse = dungeon_state.dungeon.get_current_floor()
+                .and_then(|floor| floor.corpses.iter().find(|corpse| corpse.position == corpse_pos).cloned());
+            // Only the actions listed for this corpse can be picked
+            let chosen = match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => (c.to_digit(10).unwrap() as usize).checked_sub(1)
+                    .and_then(|number| corpse.as_ref()?.interactions.get(number).cloned()),
+                _ => None,
+            };
+            match (corpse, chosen) {
+                (Some(corpse), Some(interaction)) => self.perform_corpse_interaction(&mut dungeon_state, &corpse, interaction)?,
+                (Some(_), None) if key.code != KeyCode::Esc => {
+                    dungeon_state.corpse_menu = Some(corpse_pos);
+                    self.add_dungeon_message(&mut dungeon_state, "Pick one of the listed actions by number, or Esc to leave it be.".to_string());
+                }
+                _ => {}
+            }
+            if matches!(self.state, UIState::DungeonExploration(_)) {
+                self.state = UIState::DungeonExploration(dungeon_state);
+            }
+            return Ok(false);
+        }
+
+        // Pick which way to run after fleeing a fight
+        if dungeon_state.retreat_prompt {
+            let options = Self::retreat_options(&dungeon_state);
+            let chosen = Self::look_direction(key.code)
+                .and_then(|(dx, dy, _)| options.iter().find(|option| (option.0, option.1) == (dx, dy)));
+            match chosen {
+                Some(&(_, _, direction, destination)) => {
+                    dungeon_state.retreat_prompt = false;
+                    self.retreat(&mut dungeon_state, Some((direction, destination)));
+                }
+                None => self.add_dungeon_message(&mut dungeon_state, "You can't run that way!".to_string()),
+            }
+            self.state = UIState::DungeonExploration(dungeon_state);
+            return Ok(false);
+        }
+
+        // Answer the spiral staircase prompt before anything else
+        if dungeon_state.stair_prompt {
+            dungeon_state.stair_prompt = false;
+            match key.code {
+                KeyCode::Char('u') | KeyCode::Char('<') | KeyCode::Up => self.change_floor(&mut dungeon_state, -1),
+                KeyCode::Char('d') | KeyCode::Char('>') | KeyCode::Down => self.change_floor(&mut dungeon_state, 1),
+                _ => self.add_dungeon_message(&mut dungeon_state, "You step away from the staircase.".to_string()),
+            }
+            self.update_dungeon_creatures(&mut dungeon_state)?;
+            if matches!(self.state, UIState::DungeonExploration(_)) {
+                self.state = UIState::DungeonExploration(dungeon_state);
+            }
+            return Ok(false);
+        }
+
+        // Answer the look prompt; looking doesn't use up a turn
+        if dungeon_state.look_prompt {
+            dungeon_state.look_prompt = false;
+            match Self::look_direction(key.code) {
+                Some((dx, dy, direction)) => self.look_in_dungeon_direction(dx, dy, direction, &mut dungeon_state)?,
+                None if matches!(key.code, KeyCode::Char('l') | KeyCode::Enter) => self.look_at_dungeon_tile(&mut dungeon_state)?,
+                None => self.add_dungeon_message(&mut dungeon_state, "You stop looking around.".to_string()),
+            }
+            if matches!(self.state, UIState::DungeonExploration(_)) {
+                self.state = UIState::DungeonExploration(dungeon_state);
+            }
+            return Ok(false);
+        }
+        
         match key.code {
+            KeyCode::PageUp | KeyCode::PageDown => {
+                // Reading the log doesn't use up a turn
+                dungeon_state.message_scroll = Self::scroll_messages(key.code, dungeon_state.message_scroll, dungeon_state.messages.len());
+                self.state = UIState::DungeonExploration(dungeon_state);
+                return Ok(false);
+            }
             KeyCode::Char('w') | KeyCode::Up => {
                 // Move north
                 self.move_player_in_dungeon(0, -1, &mut dungeon_state)?;
@@ -1841,8 +3140,9 @@ impl Game {
                 self.interact_with_feature(&mut dungeon_state)?;
             }
             KeyCode::Char('l') => {
-                // Look at current tile in detail
-                self.look_at_dungeon_tile(&mut dungeon_state)?;
+                // Look at a neighbouring tile, or this one
+                dungeon_state.look_prompt = true;
+                self.add_dungeon_message(&mut dungeon_state, "👁️ Look which way? (WASD/arrows, L/Enter for here)".to_string());
             }
             KeyCode::Char('h') => {
                 // Show help
@@ -1853,8 +3153,13 @@ impl Game {
                 self.initiate_dungeon_combat(&mut dungeon_state)?;
             }
             KeyCode::Char('r') => {
-                // Ranged attack - target visible enemies at distance
+                // Ranged attack - pick among visible enemies at distance
                 self.initiate_ranged_combat(&mut dungeon_state)?;
+                // Taking aim doesn't give the creatures a turn
+                if !dungeon_state.ranged_targets.is_empty() {
+                    self.state = UIState::DungeonExploration(dungeon_state);
+                    return Ok(false);
+                }
             }
             KeyCode::Char('t') => {
                 // Toggle torch
@@ -1886,6 +3191,11 @@ impl Game {
     }
 
     fn move_player(&mut self, dx: i32, dy: i32, world_state: &mut WorldExplorationState) -> anyhow::Result<()> {
+        if self.overload_stalls_step() {
+            self.add_message(world_state, "🐢 You're overloaded and move slowly.".to_string());
+            return Ok(());
+        }
+        
         let new_local_x = world_state.player_local_pos.x + dx;
         let new_local_y = world_state.player_local_pos.y + dy;
         
@@ -1910,13 +3220,66 @@ impl Game {
             final_local_y = 0;
         }
         
+        // The world edge is either impassable or wraps around to the other side
+        let bounds = self.world_bounds();
+        match bounds.resolve(new_zone) {
+            Some(zone) => {
+                if zone != new_zone {
+                    self.add_message(world_state, "🧭 The wilderness folds back on itself and you find yourself on the far side of the world.".to_string());
+                }
+                new_zone = zone;
+            }
+            None => {
+                self.add_message(world_state, "🏔️ Impassable wilderness blocks the way. This is the edge of the known world.".to_string());
+                return Ok(());
+            }
+        }
+        
+        // Water can only be crossed by bridge. Players already standing in
+        // water (e.g. from older saves) may always wade out.
+        let destination = LocalCoord::new(final_local_x, final_local_y);
+        let from_water = world_state.zone_data.as_ref()
+            .map(|zone| Self::is_water_without_bridge(zone, world_state.player_local_pos))
+            .unwrap_or(false);
+        let target_zone = if new_zone == world_state.current_zone {
+            world_state.zone_data.as_ref()
+        } else if let Some(world_manager) = &mut self.world_manager {
+            world_manager.get_zone(new_zone).ok()
+        } else {
+            None
+        };
+        let blocked_by = target_zone
+            .filter(|zone| !from_water && Self::is_water_without_bridge(zone, destination))
+            .map(|zone| zone.terrain.get_tile(destination).terrain_type.clone());
+
+        if let Some(terrain_type) = blocked_by {
+            let water = match terrain_type {
+                crate::world::TerrainType::Ocean => "the ocean",
+                crate::world::TerrainType::Lake => "the lake",
+                _ => "the river",
+            };
+            self.add_message(world_state, format!("🌊 You can't cross {} here. Look for a bridge.", water));
+            return Ok(());
+        }
+
         // Generate new zone if we're transitioning
         if new_zone != world_state.current_zone {
+            let mut entered_region = None;
             if let Some(world_manager) = &mut self.world_manager {
                 world_manager.get_zone(new_zone)?; // Generate if needed
                 world_state.zone_data = world_manager.get_zone(new_zone).ok().cloned();
+                
+                let region = world_manager.get_region(new_zone);
+                if world_state.region.as_ref().map(|r| r.coord) != Some(region.coord) {
+                    entered_region = Some(region.clone());
+                }
+                world_state.region = Some(region);
             }
             world_state.current_zone = new_zone;
+            
+            if let Some(region) = entered_region {
+                self.add_message(world_state, format!("🏰 You enter {}, held by {} under {}.", region.name, region.faction, region.ruler));
+            }
         } else {
             // Update zone data for current zone if we don't have it
             if world_state.zone_data.is_none() {
@@ -1934,15 +3297,83 @@ impl Game {
         if let Some(character) = &mut self.current_character {
             character.current_zone = Some(new_zone);
             character.current_position = Some(world_state.player_local_pos);
+            character.stats.tiles_traveled += 1;
+            if let Some(time) = character.take_step() {
+                self.add_message(world_state, time.arrival_message().to_string());
+            }
+        }
+        
+        self.reveal_surroundings(world_state.current_zone, world_state.player_local_pos);
+        for message in self.tick_exploration_turn() {
+            self.add_message(world_state, message);
         }
         
         // Update the UI state
         self.state = UIState::WorldExploration(world_state.clone());
         
+        // Guards close in on wanted players
+        self.check_hostile_npcs(world_state, true)?;
+        
         Ok(())
     }
 
+    // Carrying more than their capacity, a character only makes headway on every other step
+    fn overload_stalls_step(&mut self) -> bool {
+        let overloaded = self.current_character.as_ref().is_some_and(|character| character.is_overloaded());
+        self.trudging = overloaded && !self.trudging;
+        self.trudging
+    }
+
+    // Sample the terrain of every zone the character has set foot in. Each zone is
+    // drawn twice as wide as it is tall so it comes out roughly square.
+    fn open_world_map(&mut self, world_state: &WorldExplorationState) {
+        let bounds = self.world_bounds();
+        let rows_per_zone = (WORLD_MAP_ROWS / bounds.height_zones).clamp(1, 8);
+        let columns_per_zone = rows_per_zone * 2;
+        let row_step = crate::world::ZONE_SIZE / rows_per_zone;
+        let column_step = crate::world::ZONE_SIZE / columns_per_zone;
+        
+        let mut cells = vec![vec![None; (bounds.width_zones * columns_per_zone) as usize]; (bounds.height_zones * rows_per_zone) as usize];
+        if let (Some(character), Some(world_manager)) = (&self.current_character, &mut self.world_manager) {
+            for zone_y in 0..bounds.height_zones {
+                for zone_x in 0..bounds.width_zones {
+                    let coord = crate::world::ZoneCoord::new(zone_x, zone_y);
+                    if !character.map_memory.has_visited_zone(coord) {
+                        continue;
+                    }
+                    let Ok(zone) = world_manager.get_zone(coord) else {
+                        continue;
+                    };
+                    for row in 0..rows_per_zone {
+                        for column in 0..columns_per_zone {
+                            let sample = LocalCoord::new(column * column_step + column_step / 2, row * row_step + row_step / 2);
+                            cells[(zone_y * rows_per_zone + row) as usize][(zone_x * columns_per_zone + column) as usize] =
+                                Some(zone.terrain.get_tile(sample).terrain_type.clone());
+                        }
+                    }
+                }
+            }
+        }
+        
+        let pos = world_state.player_local_pos;
+        let player_cell = (
+            (world_state.current_zone.x * columns_per_zone + (pos.x / column_step).min(columns_per_zone - 1)) as usize,
+            (world_state.current_zone.y * rows_per_zone + (pos.y / row_step).min(rows_per_zone - 1)) as usize,
+        );
+        self.state = UIState::WorldMap(crate::ui::WorldMapState { world_state: world_state.clone(), cells, player_cell });
+    }
+
+    fn is_water_without_bridge(zone: &crate::world::WorldZone, pos: LocalCoord) -> bool {
+        zone.terrain.is_valid_coord(pos)
+            && zone.terrain.get_tile(pos).terrain_type.is_water()
+            && !zone.roads.is_bridge_at(pos)
+    }
+
     fn examine_location(&mut self, world_state: &mut WorldExplorationState) -> anyhow::Result<()> {
+        // Looking around too long near someone hostile gets you jumped
+        if self.check_hostile_npcs(world_state, false)? {
+            return Ok(());
+        }
         if let Some(zone_data) = &world_state.zone_data {
             let player_pos = world_state.player_local_pos;
             let mut examination_text = Vec::new();
@@ -2004,7 +3435,7 @@ impl Game {
             if !nearby_pois.is_empty() {
                 examination_text.push("You notice interesting locations nearby:".to_string());
                 for poi in nearby_pois {
-                    let status = if poi.explored { " (explored)" } else { "" };
+                    let status = if poi.treasure_taken { " (looted)" } else if poi.explored { " (explored)" } else { "" };
                     examination_text.push(format!("- {}: {}{}", poi.name, poi.description, status));
                 }
             }
@@ -2019,113 +3450,672 @@ impl Game {
     }
 
     fn talk_to_npcs(&mut self, world_state: &mut WorldExplorationState) -> anyhow::Result<()> {
-        if let Some(zone_data) = &world_state.zone_data {
-            let player_pos = world_state.player_local_pos;
-            
-            // Find NPCs at the exact same position or adjacent
-            let nearby_npcs: Vec<&crate::world::NPC> = zone_data.npcs.iter()
+        // Anyone hostile won't talk - they attack
+        if self.check_hostile_npcs(world_state, false)? {
+            return Ok(());
+        }
+        
+        // Talk to whoever is closest, on the player's tile or next to it
+        let player_pos = world_state.player_local_pos;
+        let nearest = world_state.zone_data.as_ref().and_then(|zone_data| {
+            zone_data.npcs.iter().enumerate()
+                .filter(|(_, npc)| (npc.position.x - player_pos.x).abs() <= 1 && (npc.position.y - player_pos.y).abs() <= 1)
+                .min_by_key(|(_, npc)| (npc.position.x - player_pos.x).abs() + (npc.position.y - player_pos.y).abs())
+                .map(|(index, npc)| (index, npc.clone()))
+        });
+        let Some((npc_index, npc)) = nearest else {
+            self.add_message(world_state, "There's no one here to talk to.".to_string());
+            return Ok(());
+        };
+        
+        self.add_message(world_state, format!("--- Talking to {} ---", npc.name));
+        // A quest giver warms to whoever did the job they asked for
+        let reported = crate::world::Quest::offered_by(&npc).and_then(|quest| {
+            let character = self.current_character.as_mut()?;
+            let index = character.quests_to_report.iter().position(|id| *id == quest.id)?;
+            character.quests_to_report.remove(index);
+            Some(quest)
+        });
+        if reported.is_some() {
+            self.add_message(world_state, format!("{}: \"Word reached me of what you did. You have my thanks.\"", npc.name));
+            self.adjust_disposition(world_state, npc_index, 1);
+        }
+        let npc = world_state.zone_data.as_ref().and_then(|zone_data| zone_data.npcs.get(npc_index)).cloned().unwrap_or(npc);
+        
+        let mut messages = vec![
+            format!("Disposition: {}", npc.disposition.get_name()),
+        ];
+        
+        // Townsfolk react to the player's standing with the local faction
+        let standing = self.local_standing(world_state);
+        match (&npc.npc_type, standing) {
+            (crate::world::NPCType::Guard, crate::world::Standing::Hated | crate::world::Standing::Disliked) => {
+                messages.push(format!("{} rests a hand on their weapon. \"We're watching you.\"", npc.name));
+            }
+            (crate::world::NPCType::Guard, crate::world::Standing::Honored) => {
+                messages.push(format!("{} salutes you. \"An honor, friend of the realm.\"", npc.name));
+            }
+            _ => {}
+        }
+        
+        // Only those in good standing are trusted with work
+        if npc.services.iter().any(|service| matches!(service, crate::world::NPCService::Quests)) {
+            match standing {
+                crate::world::Standing::Hated | crate::world::Standing::Disliked => {
+                    messages.push(format!("{}: \"I've no work for the likes of you.\"", npc.name));
+                }
+                crate::world::Standing::Liked | crate::world::Standing::Honored => {
+                    messages.push(format!("{}: \"Someone of your reputation? I may have special work for you.\"", npc.name));
+                }
+                crate::world::Standing::Neutral => {}
+            }
+        }
+        
+        for message in messages {
+            self.add_message(world_state, message);
+        }
+        
+        world_state.conversation = Some(crate::world::Conversation::new(npc_index, 0));
+        self.say_dialogue_node(world_state, &npc, 0);
+        Ok(())
+    }
+    
+    // The player's reputation with the local faction and whether they can read minds,
+    // which decide the responses offered in conversation
+    fn dialogue_context(&self, world_state: &WorldExplorationState) -> (i32, bool) {
+        match &self.current_character {
+            Some(character) => {
+                let reputation = world_state.region.as_ref().map(|region| character.get_reputation(&region.faction)).unwrap_or(0);
+                (reputation, character.has_telepathy())
+            }
+            None => (0, false),
+        }
+    }
+    
+    fn say_dialogue_node(&mut self, world_state: &mut WorldExplorationState, npc: &crate::world::NPC, node: usize) {
+        if let Some(node) = npc.conversation().node(node) {
+            self.add_message(world_state, format!("{}: \"{}\"", npc.name, node.speech));
+        }
+    }
+    
+    // Pick a numbered response, or leave with Esc
+    fn handle_conversation_input(&mut self, key: KeyEvent, world_state: &mut WorldExplorationState, conversation: crate::world::Conversation) -> anyhow::Result<()> {
+        let npc = world_state.zone_data.as_ref().and_then(|zone_data| zone_data.npcs.get(conversation.npc_index as usize)).cloned();
+        let Some(npc) = npc else {
+            world_state.conversation = None;
+            return Ok(());
+        };
+        
+        let (reputation, telepathy) = self.dialogue_context(world_state);
+        // The trade screen takes the keys while it's open
+        if let Some(cursor) = conversation.trade {
+            self.handle_trade_input(key, world_state, conversation, cursor, &npc);
+            return Ok(());
+        }
+        
+        let tree = npc.conversation();
+        let chosen = match key.code {
+            KeyCode::Esc => Some(crate::world::DialogueAction::Leave),
+            // Straight to business with anyone who trades
+            KeyCode::Char('b') if npc.services.iter().any(|service| matches!(service, crate::world::NPCService::Trade)) => {
+                Some(crate::world::DialogueAction::Trade)
+            }
+            KeyCode::Char(c) => c.to_digit(10)
+                .filter(|&digit| digit > 0)
+                .and_then(|digit| tree.node(conversation.node as usize)
+                    .and_then(|node| node.available_options(reputation, telepathy).get(digit as usize - 1).map(|option| option.action))),
+            _ => None,
+        };
+        let Some(action) = chosen else {
+            return Ok(());
+        };
+        
+        match action {
+            crate::world::DialogueAction::Goto(node) => {
+                world_state.conversation = Some(crate::world::Conversation::new(conversation.npc_index as usize, node));
+                self.say_dialogue_node(world_state, &npc, node);
+            }
+            crate::world::DialogueAction::ShowWares => self.show_npc_wares(world_state, &npc),
+            crate::world::DialogueAction::Trade => {
+                if self.local_standing(world_state) == crate::world::Standing::Hated {
+                    self.add_message(world_state, format!("{}: \"Your coin's no good here.\"", npc.name));
+                } else {
+                    world_state.conversation = Some(crate::world::Conversation {
+                        trade: Some(crate::world::TradeCursor { selling: false, selected: 0 }),
+                        ..conversation
+                    });
+                    self.add_message(world_state, format!("🛒 {} lays out their wares.", npc.name));
+                }
+            }
+            crate::world::DialogueAction::Directions => self.give_directions(world_state, &npc),
+            crate::world::DialogueAction::QuestDetails => self.discuss_quest(world_state, &npc, false),
+            crate::world::DialogueAction::AcceptQuest => self.discuss_quest(world_state, &npc, true),
+            crate::world::DialogueAction::Leave => {
+                world_state.conversation = None;
+                self.add_message(world_state, format!("You take your leave of {}.", npc.name));
+            }
+        }
+        Ok(())
+    }
+    
+    // Describe the job an NPC is offering, or take it on
+    fn discuss_quest(&mut self, world_state: &mut WorldExplorationState, npc: &crate::world::NPC, accept: bool) {
+        let (Some(quest), Some(character)) = (crate::world::Quest::offered_by(npc), &mut self.current_character) else {
+            self.add_message(world_state, format!("{}: \"Nothing I need doing right now.\"", npc.name));
+            return;
+        };
+        let message = if character.completed_quests.contains(&quest.id) {
+            format!("{}: \"You've done enough for me already. Thank you.\"", npc.name)
+        } else if let Some(active) = character.active_quests.iter().find(|active| active.id == quest.id) {
+            format!("{}: \"How goes the hunt? ({})\"", npc.name, active.describe_progress())
+        } else if accept {
+            let message = format!("📜 New quest from {}: {}. Reward: {}.", npc.name, quest.objective.describe(), quest.reward.describe());
+            character.active_quests.push(quest);
+            message
+        } else {
+            format!("{}: \"{}. Do that and {} is yours.\"", npc.name, quest.objective.describe(), quest.reward.describe())
+        };
+        self.add_message(world_state, message);
+    }
+
+    // Count the fallen towards the character's quests and pay out any that are done
+    fn advance_quests(&mut self, combat_state: &CombatState, summary: &mut crate::ui::CombatSummary) {
+        let Some(character) = &mut self.current_character else {
+            return;
+        };
+        let defeated = combat_state.encounter.participants.iter()
+            .filter(|p| p.side == crate::forge::CombatSide::Enemies && p.is_defeated());
+        let mut finished = Vec::new();
+        for enemy in defeated {
+            finished.extend(character.record_quest_kill(&enemy.name));
+        }
+        for quest in finished {
+            character.earn_gold(quest.reward.gold);
+            summary.new_levels.extend(character.gain_experience(quest.reward.experience));
+            if let Some(item) = &quest.reward.item {
+                character.inventory.push(item.clone());
+            }
+            summary.notes.push(format!("📜 Quest complete for {}: {}! You receive {}.",
+                quest.giver, quest.objective.describe(), quest.reward.describe()));
+        }
+        let progress: Vec<String> = character.active_quests.iter()
+            .filter(|quest| quest.progress > 0)
+            .map(|quest| format!("📜 {} ({})", quest.describe_progress(), quest.giver))
+            .collect();
+        summary.notes.extend(progress);
+    }
+
+    // The player's standing with the faction holding the current region
+    fn local_standing(&self, world_state: &WorldExplorationState) -> crate::world::Standing {
+        match (&world_state.region, &self.current_character) {
+            (Some(region), Some(character)) => character.get_standing(&region.faction),
+            _ => crate::world::Standing::Neutral,
+        }
+    }
+    
+    // Buy from the merchant's pack or sell from the player's, one item at a time
+    fn handle_trade_input(&mut self, key: KeyEvent, world_state: &mut WorldExplorationState, conversation: crate::world::Conversation,
+                          cursor: crate::world::TradeCursor, npc: &crate::world::NPC) {
+        let npc_index = conversation.npc_index as usize;
+        let modifier = crate::world::trade_modifier(self.local_standing(world_state), &npc.disposition);
+        let listed = if cursor.selling {
+            self.current_character.as_ref().map(|character| character.inventory.len()).unwrap_or(0)
+        } else {
+            npc.inventory.len()
+        };
+        let selected = cursor.selected as usize;
+        
+        let cursor = match key.code {
+            KeyCode::Up => crate::world::TradeCursor { selected: cursor.selected.saturating_sub(1), ..cursor },
+            KeyCode::Down if selected + 1 < listed => crate::world::TradeCursor { selected: cursor.selected + 1, ..cursor },
+            KeyCode::Tab | KeyCode::Left | KeyCode::Right => crate::world::TradeCursor { selling: !cursor.selling, selected: 0 },
+            KeyCode::Esc => {
+                world_state.conversation = Some(crate::world::Conversation { trade: None, ..conversation });
+                self.add_message(world_state, format!("{}: \"Anything else?\"", npc.name));
+                return;
+            }
+            KeyCode::Enter if selected < listed => {
+                let gold_before = self.current_character.as_ref().map(|character| character.gold);
+                let message = if cursor.selling {
+                    self.sell_to_npc(world_state, npc_index, selected, modifier)
+                } else {
+                    self.buy_from_npc(world_state, npc_index, selected, modifier)
+                };
+                self.add_message(world_state, message);
+                // Honest dealing now and then earns a merchant's goodwill
+                let traded = self.current_character.as_ref().map(|character| character.gold) != gold_before;
+                if traded {
+                    self.practice_skill(world_state, "Persuasion");
+                }
+                if traded && crate::rng::session_rng().gen_bool(FAIR_TRADE_GOODWILL_CHANCE) {
+                    self.adjust_disposition(world_state, npc_index, 1);
+                }
+                cursor
+            }
+            _ => cursor,
+        };
+        
+        // Keep the cursor on the list as items come and go
+        let listed = if cursor.selling {
+            self.current_character.as_ref().map(|character| character.inventory.len()).unwrap_or(0)
+        } else {
+            world_state.zone_data.as_ref().and_then(|zone_data| zone_data.npcs.get(npc_index)).map(|npc| npc.inventory.len()).unwrap_or(0)
+        };
+        let cursor = crate::world::TradeCursor { selected: cursor.selected.min(listed.saturating_sub(1) as u16), ..cursor };
+        world_state.conversation = Some(crate::world::Conversation { trade: Some(cursor), ..conversation });
+    }
+    
+    fn buy_from_npc(&mut self, world_state: &mut WorldExplorationState, npc_index: usize, item_index: usize, modifier: i32) -> String {
+        let Some(npc) = world_state.zone_data.as_mut().and_then(|zone_data| zone_data.npcs.get_mut(npc_index)) else {
+            return String::new();
+        };
+        let Some(character) = &mut self.current_character else {
+            return String::new();
+        };
+        
+        let item = npc.inventory[item_index].clone();
+        let price = crate::world::buy_price(&item, modifier);
+        if character.gold < price {
+            return format!("💰 You can't afford {} ({} gold, you have {}).", item, price, character.gold);
+        }
+        
+        character.gold -= price;
+        character.inventory.push(item.clone());
+        npc.inventory.remove(item_index);
+        self.store_zone_changes(world_state);
+        format!("🛒 You buy {} for {} gold.", item, price)
+    }
+    
+    fn sell_to_npc(&mut self, world_state: &mut WorldExplorationState, npc_index: usize, item_index: usize, modifier: i32) -> String {
+        let Some(npc) = world_state.zone_data.as_mut().and_then(|zone_data| zone_data.npcs.get_mut(npc_index)) else {
+            return String::new();
+        };
+        let Some(character) = &mut self.current_character else {
+            return String::new();
+        };
+        
+        // Stacks are sold one at a time
+        let item = character.inventory[item_index].clone();
+        let (name, count) = crate::world::split_stack(&item);
+        let price = crate::world::sell_price(&item, modifier);
+        if price == 0 {
+            return format!("{}: \"I've no use for {}.\"", npc.name, name);
+        }
+        
+        if count > 1 {
+            character.inventory[item_index] = format!("{} ({})", name, count - 1);
+        } else {
+            character.inventory.remove(item_index);
+        }
+        character.earn_gold(price);
+        npc.inventory.push(name.to_string());
+        let message = format!("💰 You sell {} for {} gold.", name, price);
+        self.store_zone_changes(world_state);
+        message
+    }
+    
+    fn show_npc_wares(&mut self, world_state: &mut WorldExplorationState, npc: &crate::world::NPC) {
+        let mut messages = Vec::new();
+        
+        // Merchants price their goods by the player's standing
+        let standing = self.local_standing(world_state);
+        if npc.npc_type == crate::world::NPCType::Merchant && standing != crate::world::Standing::Neutral {
+            let modifier = standing.price_modifier();
+            if modifier > 0 {
+                messages.push(format!("{} eyes you warily. \"Prices are {}% higher for your kind.\"", npc.name, modifier));
+            } else {
+                messages.push(format!("{} smiles. \"For a friend of the realm, {}% off.\"", npc.name, -modifier));
+            }
+        }
+        
+        if !npc.services.is_empty() {
+            messages.push("Services offered:".to_string());
+            for service in &npc.services {
+                messages.push(format!("- {:?}", service));
+            }
+        }
+        
+        if npc.inventory.is_empty() {
+            messages.push(format!("{}: \"I've nothing to trade, I'm afraid.\"", npc.name));
+        } else {
+            messages.push("Items for trade:".to_string());
+            for item in &npc.inventory {
+                messages.push(format!("- {}", item));
+            }
+        }
+        
+        for message in messages {
+            self.add_message(world_state, message);
+        }
+    }
+    
+    // Point the player at the nearest place in the zone they haven't explored yet
+    fn give_directions(&mut self, world_state: &mut WorldExplorationState, npc: &crate::world::NPC) {
+        let player_pos = world_state.player_local_pos;
+        let nearest = world_state.zone_data.as_ref().and_then(|zone_data| {
+            zone_data.points_of_interest.iter()
+                .filter(|poi| !poi.explored)
+                .min_by_key(|poi| (poi.position.x - player_pos.x).pow(2) + (poi.position.y - player_pos.y).pow(2))
+        });
+        
+        let message = match nearest {
+            Some(poi) => {
+                let distance = (((poi.position.x - player_pos.x).pow(2) + (poi.position.y - player_pos.y).pow(2)) as f64).sqrt() as i32;
+                format!("{}: \"There's {} off to the {}, some {} paces from here. Tread carefully.\"",
+                    npc.name, poi.name, player_pos.direction_to(poi.position), distance)
+            }
+            None => format!("{}: \"You've seen all there is to see around here, I reckon.\"", npc.name),
+        };
+        self.add_message(world_state, message);
+    }
+
+    fn guards_hostile_to_player(&self, world_state: &WorldExplorationState) -> bool {
+        match (&world_state.region, &self.current_character) {
+            (Some(region), Some(character)) => character.get_standing(&region.faction) == crate::world::Standing::Hated,
+            _ => false,
+        }
+    }
+
+    // People near the player who are after them. Guards come for players their
+    // faction hates, and anyone turned hostile attacks on sight.
+    fn find_hostile_npcs(&self, world_state: &WorldExplorationState, radius: i32, guards_only: bool) -> Vec<crate::world::NPC> {
+        let hated = self.guards_hostile_to_player(world_state);
+        let player_pos = world_state.player_local_pos;
+
+        match &world_state.zone_data {
+            Some(zone_data) => zone_data.npcs.iter()
+                .filter(|npc| !guards_only || npc.npc_type == crate::world::NPCType::Guard)
+                .filter(|npc| (hated && npc.npc_type == crate::world::NPCType::Guard) || npc.disposition == crate::world::NPCDisposition::Hostile)
                 .filter(|npc| {
                     let dx = (npc.position.x - player_pos.x).abs();
                     let dy = (npc.position.y - player_pos.y).abs();
-                    dx <= 1 && dy <= 1
+                    dx <= radius && dy <= radius
                 })
-                .collect();
-            
-            if nearby_npcs.is_empty() {
-                self.add_message(world_state, "There's no one here to talk to.".to_string());
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    // Returns true if someone attacked and combat started
+    fn check_hostile_npcs(&mut self, world_state: &mut WorldExplorationState, guards_only: bool) -> anyhow::Result<bool> {
+        self.settle_grudges(world_state);
+        let hostiles = self.find_hostile_npcs(world_state, 2, guards_only);
+        if hostiles.is_empty() {
+            return Ok(false);
+        }
+
+        for npc in &hostiles {
+            let message = if npc.npc_type == crate::world::NPCType::Guard {
+                format!("🛡️ {} shouts: \"Halt, criminal!\"", npc.name)
             } else {
-                // Collect all messages first to avoid borrowing conflicts
-                let mut messages = Vec::new();
-                
-                for npc in nearby_npcs {
-                    messages.push(format!("--- Talking to {} ---", npc.name));
-                    messages.push(format!("Disposition: {:?}", npc.disposition));
-                    for dialogue_line in &npc.dialogue {
-                        messages.push(format!("{}: \"{}\"", npc.name, dialogue_line));
-                    }
-                    
-                    if !npc.services.is_empty() {
-                        messages.push("Services offered:".to_string());
-                        for service in &npc.services {
-                            messages.push(format!("- {:?}", service));
-                        }
-                    }
-                    
-                    if !npc.inventory.is_empty() {
-                        messages.push("Items for trade:".to_string());
-                        for item in &npc.inventory {
-                            messages.push(format!("- {}", item));
-                        }
+                format!("⚔️ {} comes at you!", npc.name)
+            };
+            self.add_message(world_state, message);
+        }
+
+        let enemies = hostiles.iter().map(Self::create_npc_combat_participant).collect();
+        if let Some(character) = &self.current_character {
+            let character = character.clone();
+            self.start_combat_with_enemies(&character, enemies, Some(world_state.clone()), CombatOpening::Ambushed)?;
+        }
+
+        Ok(true)
+    }
+
+    // People angered by an assault let it go after a day or so
+    fn settle_grudges(&mut self, world_state: &mut WorldExplorationState) {
+        let (Some(character), Some(zone_data)) = (&self.current_character, &mut world_state.zone_data) else {
+            return;
+        };
+        let now = character.hours_elapsed;
+        let mut settled = false;
+        for npc in &mut zone_data.npcs {
+            settled |= npc.settle_grudge(now);
+        }
+        if settled {
+            self.store_zone_changes(world_state);
+        }
+    }
+
+    // Attack an adjacent NPC. Assaulting anyone but bandits is a crime against
+    // the region's faction and brings nearby guards running.
+    fn attack_adjacent_npc(&mut self, world_state: &mut WorldExplorationState) -> anyhow::Result<bool> {
+        let player_pos = world_state.player_local_pos;
+        let target = world_state.zone_data.as_ref().and_then(|zone_data| {
+            zone_data.npcs.iter()
+                .find(|npc| (npc.position.x - player_pos.x).abs() <= 1 && (npc.position.y - player_pos.y).abs() <= 1)
+                .cloned()
+        });
+
+        let target = match target {
+            Some(target) => target,
+            None => return Ok(false),
+        };
+
+        let mut enemies = vec![Self::create_npc_combat_participant(&target)];
+
+        if target.npc_type != crate::world::NPCType::Bandit {
+            self.add_message(world_state, format!("⚠️ You attack {}! The townsfolk cry out for the guard.", target.name));
+            if let Some(message) = self.adjust_regional_reputation(world_state, -25) {
+                self.add_message(world_state, message);
+            }
+
+            // The victim and any guards who witness the assault turn hostile,
+            // and the guards join the fight
+            let mut alerted = Vec::new();
+            let now = self.current_character.as_ref().map_or(0, |character| character.hours_elapsed);
+            if let Some(zone_data) = &mut world_state.zone_data {
+                for npc in &mut zone_data.npcs {
+                    let dx = (npc.position.x - player_pos.x).abs();
+                    let dy = (npc.position.y - player_pos.y).abs();
+                    if npc.name == target.name {
+                        npc.adjust_disposition(ASSAULT_DISPOSITION_DROP);
+                        npc.hold_grudge(now);
+                    } else if npc.npc_type == crate::world::NPCType::Guard && dx <= 6 && dy <= 6 {
+                        npc.adjust_disposition(ASSAULT_DISPOSITION_DROP);
+                        npc.hold_grudge(now);
+                        alerted.push(npc.clone());
                     }
                 }
-                
-                // Add all collected messages to the world state
-                for message in messages {
-                    self.add_message(world_state, message);
-                }
             }
+            self.store_zone_changes(world_state);
+
+            for guard in &alerted {
+                self.add_message(world_state, format!("🛡️ {} rushes to stop you!", guard.name));
+            }
+            enemies.extend(alerted.iter().map(Self::create_npc_combat_participant));
+        } else {
+            self.add_message(world_state, format!("⚔️ You attack the bandit {}!", target.name));
+        }
+
+        if let Some(character) = &self.current_character {
+            let character = character.clone();
+            self.start_combat_with_enemies(&character, enemies, Some(world_state.clone()), CombatOpening::Engaged)?;
+        }
+
+        Ok(true)
+    }
+
+    fn create_npc_combat_participant(npc: &crate::world::NPC) -> CombatParticipant {
+        let level = npc.level as u32;
+        match npc.npc_type {
+            crate::world::NPCType::Guard => {
+                let mut guard = CombatParticipant::create_enemy(&npc.name, 14 + level * 2, 8 + npc.level / 2, 8, Some(Weapon::rusty_sword()));
+                guard.armor = Some(Armor::chain_mail());
+                guard.shield = Some(Armor::medium_shield());
+                guard
+            }
+            crate::world::NPCType::Warrior | crate::world::NPCType::Bandit | crate::world::NPCType::Ranger => {
+                let mut fighter = CombatParticipant::create_enemy(&npc.name, 10 + level * 2, 7 + npc.level / 2, 6, Some(Weapon::rusty_sword()));
+                fighter.armor = Some(Armor::leather());
+                fighter
+            }
+            _ => CombatParticipant::create_enemy(&npc.name, 6 + level, 4, 4, Some(Weapon::unarmed())),
+        }
+    }
+
+    fn remove_defeated_npcs(&mut self, world_state: &mut WorldExplorationState, defeated_names: &[String]) {
+        if defeated_names.is_empty() {
+            return;
+        }
+
+        let mut removed = false;
+        if let Some(zone_data) = &mut world_state.zone_data {
+            let before = zone_data.npcs.len();
+            zone_data.npcs.retain(|npc| !defeated_names.contains(&npc.name));
+            removed = zone_data.npcs.len() != before;
+        }
+
+        if removed {
+            self.store_zone_changes(world_state);
         }
-        
-        Ok(())
     }
 
     fn search_location(&mut self, world_state: &mut WorldExplorationState) -> anyhow::Result<()> {
         let mut messages = Vec::new();
         let mut found_treasure = false;
+        let mut zone_changed = false;
+        let mut gained_gold = 0u32;
+        let mut gained_xp = 0u32;
+        let mut gained_items = Vec::new();
         
-        if let Some(zone_data) = &world_state.zone_data {
+        if let Some(zone_data) = &mut world_state.zone_data {
             let player_pos = world_state.player_local_pos;
             
             // Search for hidden treasures in POIs
-            for poi in &zone_data.points_of_interest {
+            for poi in &mut zone_data.points_of_interest {
                 let dx = (poi.position.x - player_pos.x).abs();
                 let dy = (poi.position.y - player_pos.y).abs();
                 
-                if dx <= 2 && dy <= 2 {
-                    if let Some(treasure) = &poi.treasure {
-                        if treasure.hidden && !poi.explored {
-                            messages.push(format!("🔍 You search {} and find hidden treasures!", poi.name));
-                            messages.push(format!("💰 Gold: {}", treasure.gold));
-                            messages.push(format!("⭐ Experience: {}", treasure.experience));
-                            if !treasure.items.is_empty() {
-                                messages.push("🎒 Items found:".to_string());
-                                for item in &treasure.items {
-                                    messages.push(format!("  - {}", item));
-                                }
-                            }
-                            found_treasure = true;
-                            
-                            // Mark POI as explored
-                            // poi.explored = true; // This would require mutable access to zone_data
-                        } else if poi.explored {
-                            messages.push(format!("You've already searched {} thoroughly.", poi.name));
-                        } else if let Some(_treasure) = &poi.treasure {
-                            messages.push(format!("You find some treasures at {} that weren't hidden.", poi.name));
-                            found_treasure = true;
+                if dx <= 2 && dy <= 2 && poi.treasure.is_some() {
+                    let Some(treasure) = poi.take_treasure() else {
+                        messages.push(format!("You've already searched {} thoroughly.", poi.name));
+                        continue;
+                    };
+                    
+                    if treasure.hidden {
+                        messages.push(format!("🔍 You search {} and find hidden treasures!", poi.name));
+                    } else {
+                        messages.push(format!("You find some treasures at {} that weren't hidden.", poi.name));
+                    }
+                    messages.push(format!("💰 Gold: {}", treasure.gold));
+                    messages.push(format!("⭐ Experience: {}", treasure.experience));
+                    if !treasure.items.is_empty() {
+                        messages.push("🎒 Items found:".to_string());
+                        for item in &treasure.items {
+                            messages.push(format!("  - {}", item));
                         }
                     }
+                    
+                    gained_gold += treasure.gold;
+                    gained_xp += treasure.experience;
+                    gained_items.extend(treasure.items);
+                    found_treasure = true;
+                    zone_changed = true;
                 }
             }
             
-            if !found_treasure {
+            if !found_treasure && messages.is_empty() {
                 messages.push("🔍 You search the area but find nothing of interest.".to_string());
             }
         }
         
+        if let Some(character) = &mut self.current_character {
+            character.earn_gold(gained_gold);
+            for level in character.gain_experience(gained_xp) {
+                messages.push(format!("🎉 You reached level {}! Your wounds are healed.", level));
+            }
+            character.inventory.extend(gained_items);
+        }
+        
+        if zone_changed {
+            self.store_zone_changes(world_state);
+            // Clearing out ruins and lairs earns the respect of the local lord
+            if let Some(message) = self.adjust_regional_reputation(world_state, 5) {
+                messages.push(message);
+            }
+        }
+        
         // Add all collected messages to the world state
         for message in messages {
             self.add_message(world_state, message);
         }
-        
-        Ok(())
+        if found_treasure {
+            self.practice_skill(world_state, "Perception");
+        }
+        
+        Ok(())
+    }
+
+    // Change the player's reputation with the faction holding the current region
+    fn adjust_regional_reputation(&mut self, world_state: &WorldExplorationState, amount: i32) -> Option<String> {
+        let region = world_state.region.as_ref()?;
+        let character = self.current_character.as_mut()?;
+        let reputation = character.adjust_reputation(&region.faction, amount);
+        let verb = if amount >= 0 { "improves" } else { "worsens" };
+        Some(format!("🏰 Your standing with {} {} ({}: {}).",
+            region.faction, verb, character.get_standing(&region.faction).get_name(), reputation))
+    }
+
+    // Push the player's copy of the current zone back to the world manager so
+    // POI state survives zone changes and reloads
+    fn store_zone_changes(&mut self, world_state: &WorldExplorationState) {
+        if let (Some(world_manager), Some(zone_data)) = (&mut self.world_manager, &world_state.zone_data) {
+            world_manager.update_zone(zone_data.clone());
+        }
+    }
+
+    // A pip for using a skill outside of combat, with a note if it advances
+    fn practice_skill(&mut self, world_state: &mut WorldExplorationState, skill: &str) {
+        let Some(level) = self.current_character.as_mut().and_then(|character| character.award_skill_pip(skill)) else {
+            return;
+        };
+        self.add_message(world_state, format!("📈 Your {} skill increased to level {}!", skill, level));
+    }
+
+    // Shift how an NPC of the current zone feels about the player, keeping the
+    // change in the zone data so it outlasts reloads
+    fn adjust_disposition(&mut self, world_state: &mut WorldExplorationState, npc_index: usize, delta: i32) {
+        let Some(npc) = world_state.zone_data.as_mut().and_then(|zone_data| zone_data.npcs.get_mut(npc_index)) else {
+            return;
+        };
+        if !npc.adjust_disposition(delta) {
+            return;
+        }
+        let message = format!("💭 {} now seems {}.", npc.name, npc.disposition.get_name().to_lowercase());
+        self.store_zone_changes(world_state);
+        self.add_message(world_state, message);
+    }
+
+    // The guardians of a point of interest are beaten, so its encounter is over
+    fn clear_poi_encounter(&mut self, world_state: &mut WorldExplorationState) {
+        let Some(position) = self.poi_fight.take() else {
+            return;
+        };
+        let Some(poi) = world_state.zone_data.as_mut()
+            .and_then(|zone_data| zone_data.points_of_interest.iter_mut().find(|poi| poi.position == position)) else {
+            return;
+        };
+        poi.encounter_resolved = true;
+        poi.explored = true;
+        let message = format!("🏆 You have cleared {}.", poi.name);
+        self.store_zone_changes(world_state);
+        self.add_message(world_state, message);
+        if let Some(message) = self.adjust_regional_reputation(world_state, 3) {
+            self.add_message(world_state, message);
+        }
     }
 
     fn interact_with_poi(&mut self, world_state: &mut WorldExplorationState) -> anyhow::Result<()> {
-        if let Some(zone_data) = &world_state.zone_data {
+        let mut zone_changed = false;
+        let mut battle = None;
+        
+        if let Some(zone_data) = &mut world_state.zone_data {
             let player_pos = world_state.player_local_pos;
             
             // Find POIs at current position
-            let nearby_pois: Vec<&crate::world::PointOfInterest> = zone_data.points_of_interest.iter()
+            let nearby_pois: Vec<&mut crate::world::PointOfInterest> = zone_data.points_of_interest.iter_mut()
                 .filter(|poi| {
                     let dx = (poi.position.x - player_pos.x).abs();
                     let dy = (poi.position.y - player_pos.y).abs();
@@ -2145,31 +4135,43 @@ impl Game {
                     messages.push(format!("Difficulty: {}/10", poi.difficulty));
                     
                     if let Some(encounter) = &poi.encounter {
-                        messages.push(format!("🎲 Encounter: {}", encounter.description));
-                        match &encounter.encounter_type {
-                            crate::world::EncounterType::Combat(enemies) => {
-                                messages.push(format!("⚔️ Prepare for battle against: {}", enemies.join(", ")));
-                                // TODO: Start combat encounter
-                            }
-                            crate::world::EncounterType::Puzzle(puzzle) => {
-                                messages.push(format!("🧩 Puzzle: {}", puzzle));
-                                messages.push("This requires careful thought to solve...".to_string());
-                            }
-                            crate::world::EncounterType::Trap(trap) => {
-                                messages.push(format!("⚠️ Trap: {}", trap));
-                                messages.push("You need to be careful not to trigger it!".to_string());
-                            }
-                            crate::world::EncounterType::Discovery(discovery) => {
-                                messages.push(format!("✨ Discovery: {}", discovery));
-                            }
-                            crate::world::EncounterType::NPC(npc_name) => {
-                                messages.push(format!("👤 You encounter: {}", npc_name));
+                        if poi.encounter_resolved {
+                            messages.push("Nothing else stirs here anymore.".to_string());
+                        } else {
+                            messages.push(format!("🎲 Encounter: {}", encounter.description));
+                            match &encounter.encounter_type {
+                                crate::world::EncounterType::Combat(enemies) => {
+                                    messages.push(format!("⚔️ Prepare for battle against: {}", enemies.join(", ")));
+                                    if battle.is_none() {
+                                        battle = Some((poi.position, enemies.clone(), poi.difficulty));
+                                    }
+                                }
+                                crate::world::EncounterType::Puzzle(puzzle) => {
+                                    messages.push(format!("🧩 Puzzle: {}", puzzle));
+                                    messages.push("This requires careful thought to solve...".to_string());
+                                }
+                                crate::world::EncounterType::Trap(trap) => {
+                                    messages.push(format!("⚠️ Trap: {}", trap));
+                                    messages.push("You need to be careful not to trigger it!".to_string());
+                                }
+                                crate::world::EncounterType::Discovery(discovery) => {
+                                    messages.push(format!("✨ Discovery: {}", discovery));
+                                    poi.encounter_resolved = true;
+                                    zone_changed = true;
+                                }
+                                crate::world::EncounterType::NPC(npc_name) => {
+                                    messages.push(format!("👤 You encounter: {}", npc_name));
+                                    poi.encounter_resolved = true;
+                                    zone_changed = true;
+                                }
                             }
                         }
                     }
                     
                     if let Some(treasure) = &poi.treasure {
-                        if !treasure.hidden || poi.explored {
+                        if poi.treasure_taken {
+                            messages.push("The treasures here have already been taken.".to_string());
+                        } else if !treasure.hidden || poi.explored {
                             messages.push("💎 Treasures available:".to_string());
                             messages.push(format!("💰 Gold: {}", treasure.gold));
                             messages.push(format!("⭐ Experience: {}", treasure.experience));
@@ -2193,21 +4195,51 @@ impl Game {
                 }
             }
             
+            if zone_changed {
+                self.store_zone_changes(world_state);
+                if let Some(message) = self.adjust_regional_reputation(world_state, 3) {
+                    messages.push(message);
+                }
+            }
+            
             // Add all collected messages to the world state
             for message in messages {
                 self.add_message(world_state, message);
             }
         }
         
+        // Whatever guards the place attacks. Winning settles the encounter for good.
+        if let (Some((position, names, difficulty)), Some(character)) = (battle, self.current_character.clone()) {
+            let enemies = names.iter()
+                .flat_map(|name| crate::forge::create_named_enemies(name, difficulty))
+                .collect();
+            self.start_combat_with_enemies(&character, enemies, Some(world_state.clone()), CombatOpening::Ambushed)?;
+            self.poi_fight = Some(position);
+        }
+        
         Ok(())
     }
 
+    // Page back through older messages, never past the oldest one
+    fn scroll_messages(key: KeyCode, scroll: usize, message_count: usize) -> usize {
+        match key {
+            KeyCode::PageUp => (scroll + MESSAGE_PAGE).min(message_count.saturating_sub(1)),
+            _ => scroll.saturating_sub(MESSAGE_PAGE),
+        }
+    }
+
+    // Drop the oldest messages beyond the history limit
+    fn trim_messages(messages: &mut Vec<String>, limit: usize) {
+        let excess = messages.len().saturating_sub(limit);
+        messages.drain(..excess);
+    }
+
     fn add_message(&mut self, world_state: &mut WorldExplorationState, message: String) {
         world_state.messages.push(message);
-        // Keep only the last 20 messages to prevent memory growth
-        if world_state.messages.len() > 20 {
-            world_state.messages.remove(0);
-        }
+        // Keep a bounded history to prevent memory growth
+        Self::trim_messages(&mut world_state.messages, self.message_history);
+        // Anything new jumps the log back to the bottom
+        world_state.message_scroll = 0;
         // Update the UI state
         self.state = UIState::WorldExploration(world_state.clone());
     }
@@ -2293,7 +4325,9 @@ impl Game {
                 road.path.contains(&player_pos)
             });
             
-            if on_road {
+            if zone_data.roads.is_bridge_at(player_pos) {
+                messages.push("🌉 You are standing on a sturdy bridge. Water rushes below.".to_string());
+            } else if on_road {
                 messages.push("🛤️ You are standing on a well-traveled road.".to_string());
             }
             
@@ -2309,11 +4343,70 @@ impl Game {
         Ok(())
     }
 
+    // Direction keys accepted by the look prompt
+    fn look_direction(code: KeyCode) -> Option<(i32, i32, &'static str)> {
+        match code {
+            KeyCode::Char('w') | KeyCode::Up => Some((0, -1, "north")),
+            KeyCode::Char('s') | KeyCode::Down => Some((0, 1, "south")),
+            KeyCode::Char('a') | KeyCode::Left => Some((-1, 0, "west")),
+            KeyCode::Char('d') | KeyCode::Right => Some((1, 0, "east")),
+            _ => None,
+        }
+    }
+
+    // Describe the tile next to the player so they can scout before moving
+    fn look_in_direction(&mut self, dx: i32, dy: i32, direction: &str, world_state: &mut WorldExplorationState) -> anyhow::Result<()> {
+        let player_pos = world_state.player_local_pos;
+        let target = LocalCoord::new(player_pos.x + dx, player_pos.y + dy);
+        let mut messages = vec![format!("--- Looking {} ---", direction)];
+
+        match &world_state.zone_data {
+            Some(zone_data) if zone_data.terrain.is_valid_coord(target) => {
+                let tile = zone_data.terrain.get_tile(target);
+                messages.push(format!("🌍 {} lies to the {}.", tile.terrain_type.get_name(), direction));
+
+                if let Some(settlement) = zone_data.settlements.iter().find(|s| s.position == target) {
+                    messages.push(format!("🏘️ {}, a {:?} of {} people.", settlement.name, settlement.settlement_type, settlement.population));
+                }
+                if let Some(poi) = zone_data.points_of_interest.iter().find(|p| p.position == target) {
+                    let status = if poi.explored { "already explored" } else { "unexplored" };
+                    messages.push(format!("🏛️ {} ({})", poi.name, status));
+                }
+                for npc in zone_data.npcs.iter().filter(|n| n.position == target) {
+                    messages.push(format!("👤 {} stands there. They seem {:?}.", npc.name, npc.disposition));
+                }
+
+                if zone_data.roads.is_bridge_at(target) {
+                    messages.push("🌉 A bridge crosses the water.".to_string());
+                } else if zone_data.roads.roads.iter().any(|road| road.path.contains(&target)) {
+                    messages.push("🛤️ A road runs through it.".to_string());
+                }
+
+                // Same rule as movement: you can't walk from land into unbridged water
+                if !Self::is_water_without_bridge(zone_data, player_pos) && Self::is_water_without_bridge(zone_data, target) {
+                    messages.push("🚫 You can't cross there without a bridge.".to_string());
+                } else {
+                    messages.push("✅ You could walk there.".to_string());
+                }
+            }
+            Some(_) => messages.push("🌫️ The land past the edge of this area is too far to make out.".to_string()),
+            None => messages.push("The world is still loading...".to_string()),
+        }
+
+        for message in messages {
+            self.add_message(world_state, message);
+        }
+
+        Ok(())
+    }
+
     fn make_camp(&mut self, world_state: &mut WorldExplorationState) -> anyhow::Result<()> {
         // Determine safety and gather info first
         let mut messages = vec!["🏕️ Making camp...".to_string()];
         let mut can_camp = true;
         let mut is_safe = true;
+        let mut is_cold = false;
+        let mut encounter_chance = 0.0;
         
         if let Some(zone_data) = &world_state.zone_data {
             let player_pos = world_state.player_local_pos;
@@ -2321,6 +4414,7 @@ impl Game {
             // Check terrain safety
             if let Some(row) = zone_data.terrain.tiles.get(player_pos.y as usize) {
                 if let Some(tile) = row.get(player_pos.x as usize) {
+                    encounter_chance = tile.terrain_type.camp_encounter_chance();
                     match tile.terrain_type {
                         crate::world::TerrainType::Ocean | crate::world::TerrainType::Lake => {
                             messages.push("❌ You can't camp on water!".to_string());
@@ -2329,11 +4423,16 @@ impl Game {
                         crate::world::TerrainType::Mountain => {
                             messages.push("⚠️ Camping on a mountain is dangerous but possible...".to_string());
                             is_safe = false;
+                            is_cold = true;
                         }
                         crate::world::TerrainType::Swamp => {
                             messages.push("⚠️ The swamp is not an ideal camping spot...".to_string());
                             is_safe = false;
                         }
+                        crate::world::TerrainType::Snow | crate::world::TerrainType::Tundra => {
+                            messages.push("🥶 The biting cold makes for a restless night...".to_string());
+                            is_cold = true;
+                        }
                         _ => {}
                     }
                 }
@@ -2347,14 +4446,37 @@ impl Game {
             return Ok(());
         }
         
+        // Something may find the camp before anyone gets any rest
+        if crate::rng::session_rng().gen::<f32>() < encounter_chance {
+            messages.push("👹 Your rest is interrupted by a hostile encounter!".to_string());
+            for message in messages {
+                self.add_message(world_state, message);
+            }
+            if let Some(character) = self.current_character.clone() {
+                self.start_combat_encounter(&character, Some(world_state.clone()), CombatOpening::Ambushed)?;
+            }
+            return Ok(());
+        }
+        
         // Handle character healing
         if let Some(character) = &mut self.current_character {
-            let hp_recovered = if is_safe { 
+            let base_recovery = if is_safe { 
                 character.combat_stats.hit_points.max / 4 
             } else { 
                 character.combat_stats.hit_points.max / 8 
             };
             
+            // Hardy characters recover more: Stamina 7 rests at the base rate
+            let mut rest_percent = 50 + (character.characteristics.stamina * 7.0) as u32;
+            let has_bedroll = character.inventory.iter().any(|item| item == "Bedroll");
+            if has_bedroll {
+                rest_percent += 25;
+            }
+            if is_cold {
+                rest_percent = rest_percent.saturating_sub(25);
+            }
+            let hp_recovered = (base_recovery * rest_percent / 100).max(1) * character.difficulty.camp_healing_percent() / 100;
+            
             let old_hp = character.combat_stats.hit_points.current;
             character.combat_stats.hit_points.current = 
                 (character.combat_stats.hit_points.current + hp_recovered)
@@ -2362,6 +4484,27 @@ impl Game {
             
             let actual_recovery = character.combat_stats.hit_points.current - old_hp;
             
+            if has_bedroll {
+                messages.push("🛏️ Your bedroll keeps you off the cold ground.".to_string());
+            }
+            if character.fatigue > 0 {
+                character.fatigue = 0;
+                messages.push("💨 The rest puts the spring back in your step.".to_string());
+            }
+            if !character.magic.casts_since_rest.is_empty() {
+                character.magic.reset_rest_limits();
+                messages.push("🔮 Your mind clears. Your limited spells are ready again.".to_string());
+            }
+            // Magic comes back like health does: fully in a safe camp, by half otherwise
+            if character.magic.spell_points.current < character.magic.spell_points.max || !character.magic.reserve_spent.is_empty() {
+                if is_safe {
+                    character.magic.restore_all_spell_points();
+                    messages.push("✨ Your spell points are restored.".to_string());
+                } else {
+                    let recovered = character.magic.rest(UNSAFE_CAMP_SPELL_RECOVERY);
+                    messages.push(format!("✨ You recover {} spell points.", recovered));
+                }
+            }
             if is_safe {
                 messages.push("😴 You set up a comfortable camp and rest peacefully.".to_string());
                 messages.push(format!("❤️ You recover {} health points.", actual_recovery));
@@ -2370,10 +4513,18 @@ impl Game {
                 messages.push(format!("❤️ You recover {} health points (reduced).", actual_recovery));
             }
             
-            // Small chance of random encounter while camping
-            if !is_safe && rand::random::<f32>() < 0.2 {
-                messages.push("👹 Your rest is interrupted by a hostile encounter!".to_string());
-                // TODO: Trigger random encounter
+            // Bedding down after dark means sleeping through to morning
+            let day_before = character.current_day();
+            let rest_hours = match character.time_of_day() {
+                crate::world::TimeOfDay::Dusk | crate::world::TimeOfDay::Night => character.hours_until_morning(),
+                _ => CAMP_REST_HOURS,
+            };
+            character.advance_time(rest_hours);
+            character.travel_steps = 0;
+            if character.current_day() > day_before {
+                messages.push(format!("🌅 {} hours pass. You wake on day {} at {:02}:00.", rest_hours, character.current_day(), character.hour_of_day()));
+            } else {
+                messages.push(format!("🕰️ {} hours pass. It's now {:02}:00.", rest_hours, character.hour_of_day()));
             }
         }
         
@@ -2385,37 +4536,115 @@ impl Game {
         Ok(())
     }
 
+    fn handle_inventory_input(&mut self, key: KeyEvent, inventory_state: &mut crate::ui::InventoryState) {
+        let Some(character) = &mut self.current_character else {
+            return;
+        };
+        let summary = character.inventory_summary();
+        let Some((name, _)) = summary.get(inventory_state.selected).cloned() else {
+            inventory_state.selected = 0;
+            return;
+        };
+        
+        match key.code {
+            KeyCode::Up => {
+                inventory_state.selected = inventory_state.selected.saturating_sub(1);
+            }
+            KeyCode::Down if inventory_state.selected + 1 < summary.len() => {
+                inventory_state.selected += 1;
+            }
+            KeyCode::Enter => {
+                let equippable = Weapon::from_item_name(&name).is_some() || Armor::from_item_name(&name).is_some();
+                let result = if equippable {
+                    character.equip_item(&name).map(|unequipped| if unequipped.is_empty() {
+                        format!("🛡️ You equip the {}.", name)
+                    } else {
+                        format!("🛡️ You equip the {} and stow the {}.", name, unequipped.join(" and the "))
+                    })
+                } else {
+                    character.consume_item(&name)
+                };
+                inventory_state.message = Some(result.unwrap_or_else(|error| format!("❌ {}", error)));
+            }
+            KeyCode::Char('d') => {
+                character.remove_one_item(&name);
+                inventory_state.message = Some(format!("🗑️ You drop the {}.", name));
+            }
+            _ => {}
+        }
+        
+        // Emptying the last of a stack takes its line off the list
+        let remaining = character.inventory_summary().len();
+        inventory_state.selected = inventory_state.selected.min(remaining.saturating_sub(1));
+    }
+
+    // Gear swapped out goes to the back of the pack, so pressing O again cycles
+    // through everything the player carries
+    fn equip_next_item(&mut self, world_state: &mut WorldExplorationState) {
+        let Some(character) = &mut self.current_character else {
+            return;
+        };
+        let Some(item) = character.inventory.iter()
+            .find(|item| Weapon::from_item_name(item).is_some() || Armor::from_item_name(item).is_some())
+            .cloned()
+        else {
+            self.add_message(world_state, "🎒 You carry no weapons or armor to change into.".to_string());
+            return;
+        };
+        
+        let message = match character.equip_item(&item) {
+            Ok(unequipped) if unequipped.is_empty() => format!("🛡️ You equip the {}.", item),
+            Ok(unequipped) => format!("🛡️ You equip the {} and stow the {}.", item, unequipped.join(" and the ")),
+            Err(error) => format!("❌ {}", error),
+        };
+        let outfit = format!("   Now wielding: {}", character.describe_equipment());
+        self.add_message(world_state, message);
+        self.add_message(world_state, outfit);
+    }
+
     fn show_help(&mut self, world_state: &mut WorldExplorationState) -> anyhow::Result<()> {
+        let tileset = self.ui.tileset();
         let help_messages = vec![
             "=== WARLORDS HELP ===".to_string(),
             "🗺️ MOVEMENT:".to_string(),
             "  WASD or Arrow Keys - Move around the world".to_string(),
             "  M - Return to main menu".to_string(),
+            "  Shift+M - World map of the zones you've explored".to_string(),
             "  Q - Quit game".to_string(),
             "".to_string(),
             "🔍 EXPLORATION:".to_string(),
-            "  L - Look at current tile in detail".to_string(),
+            "  L - Look: then WASD to scout the next tile, L/Enter for here".to_string(),
+            "  PgUp/PgDn - Scroll back through older messages".to_string(),
             "  E - Enter dungeons OR examine surroundings".to_string(),
             "  P - Find nearby Points of Interest".to_string(),
             "  R - Search for hidden items".to_string(),
             "  I - Interact with Points of Interest".to_string(),
             "".to_string(),
             "👥 SOCIAL:".to_string(),
-            "  T - Talk to nearby NPCs".to_string(),
+            "  T - Talk to nearby NPCs (number keys to answer, Esc to leave)".to_string(),
+            "  Shift+T - Chat with players in this zone (when playing with --server)".to_string(),
+            "      B opens a merchant's wares: ↑↓ and Enter to trade, Tab switches buying and selling".to_string(),
             "".to_string(),
             "⚔️ SURVIVAL:".to_string(),
             "  C - Make camp and rest".to_string(),
-            "  F - Fight (start combat encounter)".to_string(),
+            "  F - Fight (attack an adjacent person, or start an encounter)".to_string(),
             "  G - Gather resources".to_string(),
+            "  U - Hunt game (plains, grassland, forest, hills)".to_string(),
+            "  N - Fish (lakes and rivers)".to_string(),
+            "  O - Outfit: equip the next weapon, armor or shield in your pack".to_string(),
             "  H - Show this help".to_string(),
             "".to_string(),
             "📍 SYMBOLS:".to_string(),
-            "  @ - You".to_string(),
-            "  █●○◦· - Settlements (Capital/City/Town/Village/Outpost)".to_string(),
-            "  MGTHR! - NPCs (Merchant/Guard/Traveler/Hermit/Ranger/Bandit)".to_string(),
-            "  ⌂◊♜♠♦ - POIs (Ruins/Cave/Tower/Shrine/Dragon Lair)".to_string(),
-            "  ♣^▲.,~ - Terrain (Forest/Hill/Mountain/Plains/Grass/Water)".to_string(),
-            "  ═ - Roads".to_string(),
+            format!("  {} - You", tileset.glyph("player")),
+            format!("  {} - Settlements (Capital/City/Town/Village/Outpost)",
+                tileset.glyphs(&["settlement.capital", "settlement.city", "settlement.town", "settlement.village", "settlement.outpost"])),
+            format!("  {} - NPCs (Merchant/Guard/Traveler/Hermit/Ranger/Bandit)",
+                tileset.glyphs(&["npc.merchant", "npc.guard", "npc.traveler", "npc.hermit", "npc.ranger", "npc.bandit"])),
+            format!("  {} - POIs (Ruins/Cave/Tower/Shrine/Dragon Lair)",
+                tileset.glyphs(&["poi.ancientruins", "poi.cave", "poi.abandonedtower", "poi.mysticshrine", "poi.dragonlair"])),
+            format!("  {} - Terrain (Forest/Hill/Mountain/Plains/Grass/Water)",
+                tileset.glyphs(&["terrain.forest", "terrain.hill", "terrain.mountain", "terrain.plains", "terrain.grassland", "terrain.ocean"])),
+            format!("  {} - Roads   {} - Bridges (the only way across water)", tileset.glyph("road"), tileset.glyph("bridge")),
         ];
         
         // Add all help messages to the game state
@@ -2428,6 +4657,7 @@ impl Game {
 
     fn gather_resources(&mut self, world_state: &mut WorldExplorationState) -> anyhow::Result<()> {
         let mut messages = vec!["🔨 Gathering resources...".to_string()];
+        let mut gathered = false;
         
         if let Some(zone_data) = &world_state.zone_data {
             let player_pos = world_state.player_local_pos;
@@ -2446,8 +4676,8 @@ impl Game {
                             messages.push("⛏️ You chip away at the rock face and find some useful minerals.".to_string());
                         }
                         crate::world::TerrainType::Plains | crate::world::TerrainType::Grassland => {
-                            gathered_items.extend(["Wild Grain", "Flowers", "Small Game"]);
-                            messages.push("🌾 You gather wild grains and catch some small game.".to_string());
+                            gathered_items.extend(["Wild Grain", "Flowers", "Edible Roots"]);
+                            messages.push("🌾 You gather wild grains and dig up some roots.".to_string());
                         }
                         crate::world::TerrainType::Desert => {
                             gathered_items.extend(["Cactus Water", "Desert Herbs", "Sand"]);
@@ -2458,8 +4688,8 @@ impl Game {
                             messages.push("🍄 You collect some unusual swamp vegetation (handle with care!).".to_string());
                         }
                         crate::world::TerrainType::Lake | crate::world::TerrainType::River => {
-                            gathered_items.extend(["Fresh Water", "Fish", "Reeds"]);
-                            messages.push("🐟 You catch some fish and collect fresh water.".to_string());
+                            gathered_items.extend(["Fresh Water", "Reeds"]);
+                            messages.push("💧 You collect fresh water and cut some reeds.".to_string());
                         }
                         crate::world::TerrainType::Snow | crate::world::TerrainType::Tundra => {
                             gathered_items.extend(["Ice", "Arctic Moss", "Animal Tracks"]);
@@ -2475,12 +4705,43 @@ impl Game {
                         }
                     }
                     
+                    // Each spot only holds so much before it needs time to grow back
+                    let capacity = crate::world::ResourceNodes::capacity(&tile.terrain_type, tile.fertility);
+                    let zone = world_state.current_zone;
+                    let remaining = match &mut self.current_character {
+                        Some(character) => take_gathering(character, zone, player_pos, capacity),
+                        None => Ok(capacity),
+                    };
+                    
+                    let remaining = match remaining {
+                        Ok(remaining) => remaining,
+                        Err(refusal) => {
+                            messages.truncate(1);
+                            messages.push(refusal.to_string());
+                            for message in messages {
+                                self.add_message(world_state, message);
+                            }
+                            return Ok(());
+                        }
+                    };
+                    
+                    // Rich ground gives two of everything, poor ground leaves the last find behind
+                    let quantity = if tile.fertility > 0.7 { 2 } else { 1 };
+                    if tile.fertility < 0.3 {
+                        gathered_items.truncate(gathered_items.len().saturating_sub(1).max(1));
+                    }
+                    
                     if !gathered_items.is_empty() {
                         messages.push("🎒 Resources gathered:".to_string());
-                        for item in gathered_items {
-                            messages.push(format!("  - {}", item));
+                        for item in &gathered_items {
+                            messages.push(format!("  - {} x{}", item, quantity));
+                        }
+                        if let Some(character) = &mut self.current_character {
+                            for item in gathered_items {
+                                character.add_stacked_item(item, quantity);
+                            }
                         }
-                        // TODO: Add items to player inventory
+                        gathered = true;
                     }
                     
                     // Fertility affects gathering success
@@ -2489,6 +4750,12 @@ impl Game {
                     } else if tile.fertility < 0.3 {
                         messages.push("😞 The poor conditions limit what you can find.".to_string());
                     }
+                    
+                    match remaining {
+                        0 => messages.push("🍂 You've stripped this area bare.".to_string()),
+                        1 => messages.push("🌱 There's little left to gather here.".to_string()),
+                        _ => {}
+                    }
                 }
             }
         }
@@ -2497,6 +4764,92 @@ impl Game {
         for message in messages {
             self.add_message(world_state, message);
         }
+        if gathered {
+            self.practice_skill(world_state, "Survival");
+        }
+        
+        Ok(())
+    }
+
+    // Hunting and fishing take a few hours and a Survival or Animal Handling roll.
+    // A good roll brings back more food; a failed one wastes the time.
+    fn hunt_or_fish(&mut self, world_state: &mut WorldExplorationState, fishing: bool) -> anyhow::Result<()> {
+        use rand::Rng;
+        let player_pos = world_state.player_local_pos;
+        let zone = world_state.current_zone;
+        let tile = match &world_state.zone_data {
+            Some(zone_data) if zone_data.terrain.is_valid_coord(player_pos) => zone_data.terrain.get_tile(player_pos).clone(),
+            _ => return Ok(()),
+        };
+        
+        let suitable = if fishing {
+            matches!(tile.terrain_type, crate::world::TerrainType::Lake | crate::world::TerrainType::River)
+        } else {
+            matches!(tile.terrain_type,
+                crate::world::TerrainType::Plains | crate::world::TerrainType::Grassland |
+                crate::world::TerrainType::Forest | crate::world::TerrainType::Hill)
+        };
+        if !suitable {
+            let message = if fishing {
+                "🎣 There's nowhere to fish here. Find a lake or river."
+            } else {
+                "🏹 There's no game to hunt here. Try plains, grassland, forest or hills."
+            };
+            self.add_message(world_state, message.to_string());
+            return Ok(());
+        }
+        
+        let Some(character) = &mut self.current_character else {
+            return Ok(());
+        };
+        let (food, difficulty) = if fishing { ("Fresh Fish", FISHING_DIFFICULTY) } else { ("Game Meat", HUNTING_DIFFICULTY) };
+        // The better of the two skills does the work, and gets the practice
+        let (skill_name, skill) = ["Survival", "Animal Handling"].iter()
+            .map(|name| (*name, character.skills.get(*name).copied().unwrap_or(0)))
+            .max_by_key(|(_, level)| *level)
+            .map(|(name, level)| (name, level as u32))
+            .unwrap_or(("Survival", 0));
+        let mut caught = false;
+        let roll = crate::rng::session_rng().gen_range(1..=20);
+        let total = roll + skill * 2;
+        
+        let capacity = crate::world::ResourceNodes::capacity(&tile.terrain_type, tile.fertility);
+        let now = character.hours_elapsed;
+        character.advance_time(HUNTING_HOURS);
+        
+        let mut messages = vec![if fishing {
+            format!("🎣 You spend {} hours fishing... (rolled {} + {} skill vs {})", HUNTING_HOURS, roll, skill * 2, difficulty)
+        } else {
+            format!("🏹 You spend {} hours hunting... (rolled {} + {} skill vs {})", HUNTING_HOURS, roll, skill * 2, difficulty)
+        }];
+        
+        if total < difficulty {
+            messages.push(if fishing {
+                "😞 Nothing bites. You come back empty-handed.".to_string()
+            } else {
+                "😞 The game slips away. You come back empty-handed.".to_string()
+            });
+        } else if !character.resource_nodes.harvest(zone, player_pos, capacity, now) {
+            messages.push("🍂 You've stripped this area bare. Move on, or come back in a day or two.".to_string());
+        } else {
+            let quantity = (1 + (total - difficulty) / 4).min(4);
+            character.add_stacked_item(food, quantity);
+            messages.push(format!("🍖 You bring back {} {}.", quantity, food));
+            caught = true;
+            
+            // A stripped area stops paying out, so this can't be farmed in one spot
+            messages.push(format!("📚 +{} XP for practicing your woodcraft.", crate::forge::PRACTICE_XP));
+            for level in character.gain_experience(crate::forge::PRACTICE_XP) {
+                messages.push(format!("🎉 You reached level {}! Your wounds are healed.", level));
+            }
+        }
+        
+        for message in messages {
+            self.add_message(world_state, message);
+        }
+        if caught {
+            self.practice_skill(world_state, skill_name);
+        }
         
         Ok(())
     }
@@ -2563,18 +4916,37 @@ impl Game {
         // Generate dungeon layout
         let seed = world_state.current_zone.x as u64 * 1000 + world_state.current_zone.y as u64 * 100 + poi.position.x as u64 * 10 + poi.position.y as u64;
         let generator = crate::world::DungeonGenerator::new();
-        let dungeon = generator.generate_dungeon(poi.poi_type.clone(), poi.name.clone(), seed);
+        let mut dungeon = generator.generate_dungeon(poi.poi_type.clone(), poi.name.clone(), seed);
+        
+        let mut messages = vec![
+            format!("You enter {}...", poi.name),
+            "The air grows thick as you step inside.".to_string(),
+        ];
+        
+        // Restore what the player mapped on earlier visits
+        if let Some(world_manager) = &self.world_manager {
+            if let Some(map) = world_manager.get_dungeon_map(world_state.current_zone, poi.position) {
+                dungeon.restore_explored_map(map);
+                messages.push("🗺️ You recall the passages you mapped before.".to_string());
+            }
+        }
+        messages.push("Type 'H' for help with dungeon exploration.".to_string());
         
         // Create dungeon exploration state
         let dungeon_state = crate::ui::DungeonExplorationState {
             dungeon,
             player_pos: crate::world::LocalCoord::new(crate::world::DUNGEON_WIDTH / 2, crate::world::DUNGEON_HEIGHT - 2), // Entrance
-            messages: vec![
-                format!("You enter {}...", poi.name),
-                "The air grows thick as you step inside.".to_string(),
-                "Type 'H' for help with dungeon exploration.".to_string(),
-            ],
+            zone: world_state.current_zone,
+            poi_position: poi.position,
+            messages,
             turn_count: 0,
+            stair_prompt: false,
+            retreat_prompt: false,
+            ranged_targets: Vec::new(),
+            safe_turns: 0,
+            message_scroll: 0,
+            look_prompt: false,
+            corpse_menu: None,
         };
         
         // Switch to dungeon exploration mode
@@ -2584,6 +4956,11 @@ impl Game {
     }
 
     fn move_player_in_dungeon(&mut self, dx: i32, dy: i32, dungeon_state: &mut crate::ui::DungeonExplorationState) -> anyhow::Result<()> {
+        if self.overload_stalls_step() {
+            self.add_dungeon_message(dungeon_state, "🐢 You're overloaded and move slowly.".to_string());
+            return Ok(());
+        }
+        
         let new_x = dungeon_state.player_pos.x + dx;
         let new_y = dungeon_state.player_pos.y + dy;
         
@@ -2605,11 +4982,11 @@ impl Game {
                     match state {
                         crate::world::DoorState::Open => true,
                         crate::world::DoorState::Closed => {
-                            self.add_dungeon_message(dungeon_state, "The door is closed. Try interacting with it.".to_string());
+                            self.add_dungeon_message(dungeon_state, "The door is closed. Press I to open it.".to_string());
                             false
                         },
                         crate::world::DoorState::Locked => {
-                            self.add_dungeon_message(dungeon_state, "The door is locked.".to_string());
+                            self.add_dungeon_message(dungeon_state, "The door is locked. Press I to try a key or lockpicks.".to_string());
                             false
                         },
                         crate::world::DoorState::Secret => {
@@ -2636,10 +5013,24 @@ impl Game {
                         return Ok(());
                     }
                 }
+                if self.step_onto_trap(dungeon_state, crate::world::LocalCoord::new(new_x, new_y)) {
+                    return Ok(());
+                }
                 
                 // Move player
                 dungeon_state.player_pos = crate::world::LocalCoord::new(new_x, new_y);
                 dungeon_state.turn_count += 1;
+                if let Some(character) = &mut self.current_character {
+                    character.stats.tiles_traveled += 1;
+                    character.take_step(); // No telling the sky from down here
+                    if dungeon_state.turn_count.is_multiple_of(DUNGEON_SPELL_REGEN_TURNS) {
+                        character.magic.restore_spell_points(1);
+                    }
+                }
+                dungeon_state.safe_turns = dungeon_state.safe_turns.saturating_sub(1);
+                for message in self.tick_exploration_turn() {
+                    self.add_dungeon_message(dungeon_state, message);
+                }
                 
                 // Update visibility around player
                 self.update_visibility(dungeon_state);
@@ -2658,13 +5049,19 @@ impl Game {
         Ok(())
     }
 
-    fn update_visibility(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState) {
-        let player_pos = dungeon_state.player_pos;
-        let visibility_radius = if let Some(character) = &self.current_character {
-            character.get_vision_radius() as i32
+    // How far the player sees on the current floor, given its light and their torch
+    fn dungeon_vision_radius(&self, dungeon_state: &crate::ui::DungeonExplorationState) -> i32 {
+        let ambient_light = dungeon_state.dungeon.get_current_floor().map(|floor| floor.ambient_light).unwrap_or(0);
+        if let Some(character) = &self.current_character {
+            character.get_vision_radius_in(ambient_light) as i32
         } else {
             3 // Default fallback
-        };
+        }
+    }
+
+    fn update_visibility(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState) {
+        let player_pos = dungeon_state.player_pos;
+        let visibility_radius = self.dungeon_vision_radius(dungeon_state);
         
         if let Some(floor) = dungeon_state.dungeon.get_current_floor_mut() {
             // Reset visibility
@@ -2674,15 +5071,19 @@ impl Game {
                 }
             }
             
-            // Set visibility around player
-            for dy in -visibility_radius..=visibility_radius {
-                for dx in -visibility_radius..=visibility_radius {
+            // Set visibility around player, and further out wherever wall torches light the way
+            let torches = floor.torch_positions();
+            let range = visibility_radius.max(crate::world::LIT_SIGHT_RANGE);
+            for dy in -range..=range {
+                for dx in -range..=range {
                     let x = player_pos.x + dx;
                     let y = player_pos.y + dy;
                     
                     if x >= 0 && x < crate::world::DUNGEON_WIDTH && y >= 0 && y < crate::world::DUNGEON_HEIGHT {
                         let distance = ((dx * dx + dy * dy) as f32).sqrt();
-                        if distance <= visibility_radius as f32 {
+                        let lit = distance <= crate::world::LIT_SIGHT_RANGE as f32
+                            && floor.light_at(crate::world::LocalCoord::new(x, y), &torches) >= crate::world::WELL_LIT;
+                        if distance <= visibility_radius as f32 || lit {
                             if let Some(tile) = floor.tiles.get_mut(y as usize).and_then(|row| row.get_mut(x as usize)) {
                                 tile.visible = true;
                                 tile.explored = true;
@@ -2694,7 +5095,61 @@ impl Game {
         }
     }
 
+    // Ways open to run from a fight: direction, its name and where the run ends
+    fn retreat_options(dungeon_state: &DungeonExplorationState) -> Vec<(i32, i32, &'static str, LocalCoord)> {
+        let Some(floor) = dungeon_state.dungeon.get_current_floor() else {
+            return Vec::new();
+        };
+        let start = dungeon_state.player_pos;
+        [KeyCode::Up, KeyCode::Down, KeyCode::Left, KeyCode::Right].into_iter()
+            .filter_map(Self::look_direction)
+            .filter_map(|(dx, dy, direction)| {
+                let mut pos = start;
+                for _ in 0..FLEE_RETREAT_TILES {
+                    let next = LocalCoord::new(pos.x + dx, pos.y + dy);
+                    let open = floor.tile_at(next).is_some_and(|tile| tile.tile_type.is_passable());
+                    if !open || floor.is_occupied(next, start) {
+                        break;
+                    }
+                    pos = next;
+                }
+                (pos != start).then_some((dx, dy, direction, pos))
+            })
+            .collect()
+    }
+
+    // Back in the dungeon after fleeing. With several ways open the player picks one.
+    fn begin_dungeon_retreat(&mut self, mut dungeon_state: DungeonExplorationState) {
+        let options = Self::retreat_options(&dungeon_state);
+        if options.len() > 1 {
+            let directions: Vec<&str> = options.iter().map(|option| option.2).collect();
+            dungeon_state.retreat_prompt = true;
+            self.add_dungeon_message(&mut dungeon_state, format!("🏃 You break away! Which way do you run? ({}, WASD)", directions.join(", ")));
+        } else {
+            let only_way = options.first().map(|option| (option.2, option.3));
+            self.retreat(&mut dungeon_state, only_way);
+        }
+        self.state = UIState::DungeonExploration(dungeon_state);
+    }
+
+    fn retreat(&mut self, dungeon_state: &mut DungeonExplorationState, destination: Option<(&str, LocalCoord)>) {
+        let message = match destination {
+            Some((direction, pos)) => {
+                dungeon_state.player_pos = pos;
+                format!("🏃 You run {} and put some distance between you and the fight.", direction)
+            }
+            None => "🏃 There's nowhere to run, but your foes hesitate for a moment.".to_string(),
+        };
+        self.add_dungeon_message(dungeon_state, message);
+        dungeon_state.safe_turns = FLEE_GRACE_TURNS;
+        self.update_visibility(dungeon_state);
+    }
+
     fn check_enemy_aggro(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState) -> anyhow::Result<bool> {
+        // Creatures give a player who just fled a moment to get away
+        if dungeon_state.safe_turns > 0 {
+            return Ok(false);
+        }
         let player_pos = dungeon_state.player_pos;
         let aggro_range = 2; // Enemies attack when player gets within 2 tiles
         
@@ -2727,7 +5182,7 @@ impl Game {
         // If we found an aggro creature, start combat
         if let Some(creature) = aggro_creature {
             self.add_dungeon_message(dungeon_state, format!("🚨 {} notices you and attacks!", creature.name));
-            self.start_dungeon_combat(dungeon_state, &creature)?;
+            self.start_dungeon_combat(dungeon_state, &creature, CombatOpening::Ambushed)?;
             return Ok(true); // Combat started
         }
         
@@ -2764,7 +5219,7 @@ impl Game {
         
         // Check for features at current position
         if let Some(floor) = dungeon_state.dungeon.get_current_floor() {
-            if let Some(feature) = floor.features.iter().find(|f| f.position == player_pos) {
+            if let Some(feature) = floor.features.iter().find(|f| f.position == player_pos && f.is_noticed()) {
                 self.add_dungeon_message(dungeon_state, format!("You notice: {}", feature.description));
             }
         }
@@ -2772,52 +5227,167 @@ impl Game {
         Ok(())
     }
 
+    // A trap on the next tile may be spotted in time, which stops the step.
+    // Otherwise it goes off unless the character gets clear. Returns true when
+    // the player stays where they are.
+    fn step_onto_trap(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, pos: crate::world::LocalCoord) -> bool {
+        use rand::Rng;
+        let floor_number = dungeon_state.dungeon.current_floor;
+        let Some(character) = &self.current_character else {
+            return false;
+        };
+        let Some(floor) = dungeon_state.dungeon.get_current_floor_mut() else {
+            return false;
+        };
+        let Some(feature) = floor.features.iter_mut().find(|feature| feature.position == pos && feature.armed_trap().is_some()) else {
+            return false;
+        };
+        let Some(trap) = feature.armed_trap().cloned() else {
+            return false;
+        };
+
+        let mut rng = crate::rng::session_rng();
+        let difficulty = crate::world::trap_difficulty(floor_number);
+        let known = feature.detected;
+        if !known && crate::world::spots_trap(character.characteristics.awareness, rng.gen_range(1..=20), difficulty) {
+            feature.detected = true;
+            self.add_dungeon_message(dungeon_state, format!("🪤 You spot a {} just ahead! Step again to edge past it.", trap.get_name()));
+            return true;
+        }
+
+        feature.detected = true;
+        if crate::world::avoids_trap(character.characteristics.dexterity, rng.gen_range(1..=20), difficulty, known) {
+            let message = if known {
+                format!("🪤 You edge carefully past the {}.", trap.get_name())
+            } else {
+                feature.sprung = true;
+                format!("🪤 A {} springs, but you throw yourself clear!", trap.get_name())
+            };
+            self.add_dungeon_message(dungeon_state, message);
+            return false;
+        }
+
+        feature.sprung = true;
+        let mut messages = vec![format!("🪤 {}", trap.sprung_message())];
+        match trap.roll_effect(floor_number) {
+            crate::world::TrapEffect::Damage(damage) => {
+                if let Some(character) = &mut self.current_character {
+                    // Like poison, a trap leaves the character on their feet
+                    let hit_points = &mut character.combat_stats.hit_points;
+                    let lost = damage.min(hit_points.current.saturating_sub(1));
+                    hit_points.current -= lost;
+                    messages.push(format!("💥 You take {} damage ({}/{} HP).", lost, hit_points.current, hit_points.max));
+                }
+            }
+            crate::world::TrapEffect::Poison(potency) => {
+                if let Some(character) = &mut self.current_character {
+                    character.status_effects.push(crate::forge::StatusEffect::new(
+                        crate::forge::StatusKind::Poison, trap.get_name(), potency, crate::world::TRAP_POISON_TURNS));
+                    messages.push("🤢 You feel poison creeping up your leg.".to_string());
+                }
+            }
+            crate::world::TrapEffect::Alarm => {
+                // Everything on the floor comes looking
+                for creature in &mut floor.creatures {
+                    creature.aggro_radius = creature.aggro_radius.max(crate::world::DUNGEON_WIDTH);
+                }
+                messages.push("👂 Whatever lurks on this floor is coming for you.".to_string());
+            }
+        }
+        for message in messages {
+            self.add_dungeon_message(dungeon_state, message);
+        }
+        false
+    }
+
     fn update_dungeon_creatures(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState) -> anyhow::Result<()> {
         let turn = dungeon_state.turn_count;
+        let player_pos = dungeon_state.player_pos;
+        let mut messages = Vec::new();
         
         if let Some(floor) = dungeon_state.dungeon.get_current_floor_mut() {
-            for creature in &mut floor.creatures {
+            for index in 0..floor.creatures.len() {
+                let creature = &mut floor.creatures[index];
                 // Update creature movement based on cooldown
-                if turn >= creature.last_move_time + creature.movement_cooldown {
-                    creature.last_move_time = turn;
-                    
-                    // Simple AI: move along patrol route
-                    if !creature.patrol_route.is_empty() {
+                if turn < creature.last_move_time + creature.movement_cooldown {
+                    continue;
+                }
+                creature.last_move_time = turn;
+                
+                // Chase the player once they come close, otherwise walk the patrol route
+                let distance = (creature.position.x - player_pos.x).abs().max((creature.position.y - player_pos.y).abs());
+                let target = if distance <= creature.aggro_radius {
+                    player_pos
+                } else if !creature.patrol_route.is_empty() {
+                    if creature.position == creature.patrol_route[creature.current_patrol_index] {
                         creature.current_patrol_index = (creature.current_patrol_index + 1) % creature.patrol_route.len();
-                        let target = creature.patrol_route[creature.current_patrol_index];
-                        
-                        // Move towards patrol point
-                        if creature.position.x < target.x { creature.position.x += 1; }
-                        else if creature.position.x > target.x { creature.position.x -= 1; }
-                        else if creature.position.y < target.y { creature.position.y += 1; }
-                        else if creature.position.y > target.y { creature.position.y -= 1; }
+                    }
+                    creature.patrol_route[creature.current_patrol_index]
+                } else {
+                    continue;
+                };
+                
+                let intelligence = crate::forge::CreatureIntelligence::for_creature(Some(&creature.creature_type));
+                match floor.creature_step(index, target, intelligence.opens_doors(), player_pos) {
+                    crate::world::CreatureMove::Step(next) => floor.creatures[index].position = next,
+                    crate::world::CreatureMove::OpenDoor(door) => {
+                        if let Some(tile) = floor.tiles.get_mut(door.y as usize).and_then(|row| row.get_mut(door.x as usize)) {
+                            tile.tile_type = crate::world::DungeonTileType::Door(crate::world::DoorState::Open);
+                            if tile.visible {
+                                messages.push(format!("🚪 The {} pulls a door open.", floor.creatures[index].name));
+                            }
+                        }
+                    }
+                    // Queue up behind whoever is in the way
+                    crate::world::CreatureMove::Wait => {}
+                    crate::world::CreatureMove::Blocked => {
+                        // Give up on an unreachable patrol point and try the next one
+                        let creature = &mut floor.creatures[index];
+                        if target != player_pos && !creature.patrol_route.is_empty() {
+                            creature.current_patrol_index = (creature.current_patrol_index + 1) % creature.patrol_route.len();
+                        }
                     }
                 }
             }
         }
         
+        for message in messages {
+            self.add_dungeon_message(dungeon_state, message);
+        }
+        
         Ok(())
     }
 
-    fn exit_dungeon(&mut self, _dungeon_state: &mut crate::ui::DungeonExplorationState) -> anyhow::Result<()> {
+    fn exit_dungeon(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState) -> anyhow::Result<()> {
+        // Remember the explored map for the next visit
+        if let Some(world_manager) = &mut self.world_manager {
+            world_manager.store_dungeon_map(dungeon_state.zone, dungeon_state.poi_position, dungeon_state.dungeon.explored_map());
+        }
+        
         // Restore the saved world state
         if let Some(mut world_state) = self.saved_world_state.take() {
             // Add an exit message
             world_state.messages.push("You exit the dungeon and return to the world.".to_string());
             
-            // Keep only the last 20 messages to prevent memory growth
-            if world_state.messages.len() > 20 {
-                world_state.messages.remove(0);
-            }
+            // Keep a bounded history to prevent memory growth
+            Self::trim_messages(&mut world_state.messages, self.message_history);
+            world_state.message_scroll = 0;
             
             self.state = crate::ui::UIState::WorldExploration(world_state);
         } else {
             // Fallback if no saved state (shouldn't happen)
+            let center = self.world_bounds().center();
             let world_state = crate::ui::WorldExplorationState {
-                current_zone: crate::world::ZoneCoord::new(4, 4), // Default center
-                player_local_pos: crate::world::LocalCoord::new(32, 32),
+                current_zone: center.to_zone(),
+                player_local_pos: center.to_local(),
                 zone_data: None, // Will be regenerated
+                region: None,
                 messages: vec!["You exit the dungeon and return to the world.".to_string()],
+                message_scroll: 0,
+                look_prompt: false,
+                conversation: None,
+                chat_prompt: false,
+                other_players: Vec::new(),
             };
             
             self.state = crate::ui::UIState::WorldExploration(world_state);
@@ -2832,31 +5402,14 @@ impl Game {
         if let Some(tile) = dungeon_state.dungeon.get_tile_at(player_pos) {
             if let crate::world::DungeonTileType::Stairs(stair_type) = &tile.tile_type {
                 match stair_type {
-                    crate::world::StairType::Up => {
-                        if dungeon_state.dungeon.current_floor > 0 {
-                            dungeon_state.dungeon.current_floor -= 1;
-                            self.add_dungeon_message(dungeon_state, format!("You climb up to floor {}.", dungeon_state.dungeon.current_floor + 1));
-                        } else {
-                            self.add_dungeon_message(dungeon_state, "You can't go up any further.".to_string());
-                        }
-                    },
-                    crate::world::StairType::Down => {
-                        let max_floor = dungeon_state.dungeon.floors.len() as i32 - 1;
-                        if dungeon_state.dungeon.current_floor < max_floor {
-                            dungeon_state.dungeon.current_floor += 1;
-                            self.add_dungeon_message(dungeon_state, format!("You descend to floor {}.", dungeon_state.dungeon.current_floor + 1));
-                        } else {
-                            self.add_dungeon_message(dungeon_state, "The stairs end here.".to_string());
-                        }
-                    },
+                    crate::world::StairType::Up => self.change_floor(dungeon_state, -1),
+                    crate::world::StairType::Down => self.change_floor(dungeon_state, 1),
                     crate::world::StairType::UpDown => {
-                        // For spiral staircases, allow choosing direction
-                        self.add_dungeon_message(dungeon_state, "This staircase goes both ways. Use 'U' again to go up, or move to go down.".to_string());
+                        // Spiral staircases ask which way to go
+                        dungeon_state.stair_prompt = true;
+                        self.add_dungeon_message(dungeon_state, "🌀 The spiral staircase winds both ways. Go up (U or <) or down (D or >)?".to_string());
                     },
                 }
-                
-                // Update visibility after floor change
-                self.update_visibility(dungeon_state);
             } else {
                 self.add_dungeon_message(dungeon_state, "There are no stairs here.".to_string());
             }
@@ -2865,6 +5418,41 @@ impl Game {
         Ok(())
     }
 
+    // Climb (-1) or descend (+1) one floor, staying within the dungeon
+    fn change_floor(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, direction: i32) {
+        let max_floor = dungeon_state.dungeon.floors.len() as i32 - 1;
+        let target_floor = dungeon_state.dungeon.current_floor + direction;
+        
+        if target_floor < 0 {
+            self.add_dungeon_message(dungeon_state, "You can't go up any further.".to_string());
+            return;
+        }
+        if target_floor > max_floor {
+            self.add_dungeon_message(dungeon_state, "The stairs end here.".to_string());
+            return;
+        }
+        
+        dungeon_state.dungeon.current_floor = target_floor;
+        let message = if direction < 0 {
+            format!("You climb up to floor {}.", target_floor + 1)
+        } else {
+            format!("You descend to floor {}.", target_floor + 1)
+        };
+        self.add_dungeon_message(dungeon_state, message);
+        
+        // Each floor has its own light
+        let well_lit = dungeon_state.dungeon.get_current_floor().is_some_and(|floor| floor.is_well_lit());
+        let torch_lit = self.current_character.as_ref().is_some_and(|character| character.torch_lit);
+        if well_lit {
+            self.add_dungeon_message(dungeon_state, "☀️ Light reaches every corner here; no torch needed.".to_string());
+        } else if !torch_lit {
+            self.add_dungeon_message(dungeon_state, "🌑 It's dark on this floor. Press T to light a torch.".to_string());
+        }
+        
+        // Update visibility after floor change
+        self.update_visibility(dungeon_state);
+    }
+
     fn examine_dungeon_location(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState) -> anyhow::Result<()> {
         let player_pos = dungeon_state.player_pos;
         let mut messages = Vec::new();
@@ -2894,13 +5482,26 @@ impl Game {
                 };
                 messages.push(description.to_string());
                 
-                if tile.light_level > 5 {
-                    messages.push("The area is well-lit.".to_string());
-                } else if tile.light_level > 2 {
-                    messages.push("Dim light illuminates the surroundings.".to_string());
+                // What the player actually sees by: the floor's light or their torch
+                let ambient_light = floor.light_at(player_pos, &floor.torch_positions());
+                let (light, torch_lit) = self.current_character.as_ref()
+                    .map(|character| (character.effective_light(ambient_light), character.torch_lit))
+                    .unwrap_or((ambient_light, false));
+                let source = if ambient_light >= crate::world::WELL_LIT {
+                    "no torch needed"
+                } else if torch_lit {
+                    "by torchlight"
                 } else {
-                    messages.push("The area is shrouded in darkness.".to_string());
-                }
+                    "a torch would help"
+                };
+                let description = if light >= crate::world::WELL_LIT {
+                    "The area is well-lit"
+                } else if light > 2 {
+                    "Dim light illuminates the surroundings"
+                } else {
+                    "The area is shrouded in darkness"
+                };
+                messages.push(format!("💡 {} (light {}/10, {}).", description, light, source));
             }
             
             // Look for creatures in view
@@ -2922,6 +5523,7 @@ impl Game {
             
             // Look for features
             let nearby_features: Vec<&crate::world::DungeonFeature> = floor.features.iter()
+                .filter(|feature| feature.is_noticed())
                 .filter(|feature| {
                     let dx = (feature.position.x - player_pos.x).abs();
                     let dy = (feature.position.y - player_pos.y).abs();
@@ -2944,6 +5546,93 @@ impl Game {
         Ok(())
     }
 
+    // A closed, locked or hidden door next to the player
+    fn adjacent_shut_door(dungeon: &crate::world::DungeonLayout, pos: crate::world::LocalCoord) -> Option<crate::world::LocalCoord> {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)].into_iter()
+            .map(|(dx, dy)| crate::world::LocalCoord::new(pos.x + dx, pos.y + dy))
+            .find(|next| matches!(dungeon.get_tile_at(*next).map(|tile| &tile.tile_type),
+                Some(crate::world::DungeonTileType::Door(crate::world::DoorState::Closed | crate::world::DoorState::Locked | crate::world::DoorState::Secret))))
+    }
+
+    // A key opens a locked door outright. Otherwise it takes lockpicks and the
+    // skill to use them, against a lock that gets harder deeper down.
+    fn unlock_dungeon_door(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, pos: crate::world::LocalCoord) {
+        let difficulty = crate::world::lock_difficulty(dungeon_state.dungeon.current_floor);
+        let Some(character) = &mut self.current_character else {
+            return;
+        };
+        
+        let message = if let Some(index) = character.inventory.iter().position(|item| item == crate::world::DUNGEON_KEY) {
+            character.inventory.remove(index);
+            format!("🗝️ The {} turns with a heavy clunk and the door swings open.", crate::world::DUNGEON_KEY)
+        } else {
+            let Some(picks) = character.inventory.iter().position(|item| item == crate::world::LOCKPICKS) else {
+                self.add_dungeon_message(dungeon_state, "🔒 The door is locked. You need a key, or lockpicks.".to_string());
+                return;
+            };
+            let Some(skill) = crate::world::LOCKPICK_SKILLS.iter().filter_map(|name| character.skills.get(*name).copied()).max() else {
+                self.add_dungeon_message(dungeon_state, "🔒 You fiddle with the lock, but without Lockpicking or Stealth you're getting nowhere.".to_string());
+                return;
+            };
+            
+            match crate::world::attempt_lockpick(skill, crate::rng::session_rng().gen_range(1..=20), difficulty) {
+                crate::world::LockpickResult::Opened => "🔓 The last pin gives and the lock clicks open.".to_string(),
+                crate::world::LockpickResult::Failed => {
+                    self.add_dungeon_message(dungeon_state, "🔒 The lock holds. You could try again.".to_string());
+                    return;
+                }
+                crate::world::LockpickResult::ToolsBroken => {
+                    character.inventory.remove(picks);
+                    self.add_dungeon_message(dungeon_state, "💥 A pick snaps off in the lock! Your lockpicks are ruined.".to_string());
+                    return;
+                }
+            }
+        };
+        
+        if let Some(tile) = dungeon_state.dungeon.get_tile_at_mut(pos) {
+            tile.tile_type = crate::world::DungeonTileType::Door(crate::world::DoorState::Open);
+        }
+        self.add_dungeon_message(dungeon_state, message);
+        self.update_visibility(dungeon_state);
+    }
+
+    // Swing a closed door open, or open up a hidden one once it's found. The
+    // doorway is passable from then on.
+    fn open_dungeon_door(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, pos: crate::world::LocalCoord) {
+        let message = match dungeon_state.dungeon.open_door(pos) {
+            Some(crate::world::DoorState::Secret) => "🔍 You search carefully and find a hidden mechanism! A section of wall swings open.",
+            Some(_) => "🚪 You push open the door.",
+            None => return,
+        };
+        self.add_dungeon_message(dungeon_state, message.to_string());
+        self.update_visibility(dungeon_state);
+    }
+
+    // Call on a dungeon altar, which only answers once. The use is kept on the
+    // overworld POI, both in the cached zone and the world the player returns to.
+    fn use_dungeon_altar(&mut self, dungeon_state: &crate::ui::DungeonExplorationState, position: crate::world::LocalCoord) -> bool {
+        let floor = dungeon_state.dungeon.current_floor;
+        let use_altar = |zone: &mut crate::world::WorldZone| {
+            zone.points_of_interest.iter_mut()
+                .find(|poi| poi.position == dungeon_state.poi_position)
+                .map(|poi| poi.use_altar(floor, position))
+        };
+        
+        let mut answered = true;
+        if let Some(world_manager) = &mut self.world_manager {
+            if let Some(mut zone) = world_manager.get_zone_if_exists(dungeon_state.zone).cloned() {
+                answered = use_altar(&mut zone).unwrap_or(true);
+                world_manager.update_zone(zone);
+            }
+        }
+        if let Some(zone_data) = self.saved_world_state.as_mut().and_then(|world_state| world_state.zone_data.as_mut()) {
+            if zone_data.coord == dungeon_state.zone {
+                use_altar(zone_data);
+            }
+        }
+        answered
+    }
+
     fn interact_with_feature(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState) -> anyhow::Result<()> {
         let player_pos = dungeon_state.player_pos;
         
@@ -2956,30 +5645,51 @@ impl Game {
                 },
                 crate::world::DungeonTileType::Door(state) => {
                     match state {
-                        crate::world::DoorState::Closed => {
-                            self.add_dungeon_message(dungeon_state, "You push open the door.".to_string());
-                            // TODO: Actually change door state to open
+                        crate::world::DoorState::Closed | crate::world::DoorState::Secret => {
+                            self.open_dungeon_door(dungeon_state, player_pos);
                         },
                         crate::world::DoorState::Open => {
                             self.add_dungeon_message(dungeon_state, "The door is already open.".to_string());
                         },
                         crate::world::DoorState::Locked => {
-                            self.add_dungeon_message(dungeon_state, "The door is locked. You need a key.".to_string());
-                        },
-                        crate::world::DoorState::Secret => {
-                            self.add_dungeon_message(dungeon_state, "You search carefully and find a hidden mechanism!".to_string());
-                            // TODO: Reveal secret door
+                            self.unlock_dungeon_door(dungeon_state, player_pos);
                         },
                     }
                 },
                 crate::world::DungeonTileType::Altar => {
+                    use rand::Rng;
+                    if !self.use_dungeon_altar(dungeon_state, player_pos) {
+                        self.add_dungeon_message(dungeon_state, "The altar's runes are dark. Whatever dwelt here has already answered you.".to_string());
+                        return Ok(());
+                    }
                     self.add_dungeon_message(dungeon_state, "You examine the ancient altar. Ancient runes glow faintly as you approach.".to_string());
-                    self.add_dungeon_message(dungeon_state, "You feel a mysterious energy emanating from it.".to_string());
+                    // Old gods are fickle: the altar may bless, curse or ignore whoever touches it
+                    let roll = crate::rng::session_rng().gen_range(1..=10);
+                    let effect = match roll {
+                        1..=3 => Some(crate::forge::StatusEffect::new(crate::forge::StatusKind::Curse, "a dark altar", 2, 200)),
+                        4..=6 => Some(crate::forge::StatusEffect::new(crate::forge::StatusKind::Blessing, "an ancient altar", 1, 150)),
+                        _ => None,
+                    };
+                    match effect {
+                        Some(effect) => {
+                            let message = match effect.kind {
+                                crate::forge::StatusKind::Curse => "💀 A chill seeps into your bones. You have been cursed! (-2 attack)",
+                                _ => "✨ Warm light washes over you. You feel blessed! (+1 attack and defense)",
+                            };
+                            if let Some(character) = &mut self.current_character {
+                                crate::forge::apply_status_effect(&mut character.status_effects, effect);
+                            }
+                            self.add_dungeon_message(dungeon_state, message.to_string());
+                        }
+                        None => {
+                            self.add_dungeon_message(dungeon_state, "You feel a mysterious energy emanating from it.".to_string());
+                        }
+                    }
                 },
                 _ => {
                     // Check for features at this position
                     if let Some(floor) = dungeon_state.dungeon.get_current_floor() {
-                        if let Some(feature) = floor.features.iter().find(|f| f.position == player_pos) {
+                        if let Some(feature) = floor.features.iter().find(|f| f.position == player_pos && f.is_noticed()) {
                             match &feature.feature_type {
                                 crate::world::FeatureType::Bookshelf => {
                                     self.add_dungeon_message(dungeon_state, "You browse the ancient books. Most are too damaged to read, but you find a useful spell scroll.".to_string());
@@ -3012,6 +5722,15 @@ impl Game {
                                 self.interact_with_corpse(dungeon_state, &corpse)?;
                             } else if let Some(loot_pile) = loot_pile_found {
                                 self.interact_with_loot_pile(dungeon_state, &loot_pile)?;
+                            } else if let Some(door) = Self::adjacent_shut_door(&dungeon_state.dungeon, player_pos) {
+                                // Doors stop the player, so the ones to open are beside them
+                                let locked = dungeon_state.dungeon.get_tile_at(door)
+                                    .is_some_and(|tile| tile.tile_type == crate::world::DungeonTileType::Door(crate::world::DoorState::Locked));
+                                if locked {
+                                    self.unlock_dungeon_door(dungeon_state, door);
+                                } else {
+                                    self.open_dungeon_door(dungeon_state, door);
+                                }
                             } else {
                                 self.add_dungeon_message(dungeon_state, "There's nothing special to interact with here.".to_string());
                             }
@@ -3028,30 +5747,116 @@ impl Game {
         self.examine_dungeon_location(dungeon_state)
     }
 
+    // Describe the tile next to the player, and what lies past a visible door
+    fn look_in_dungeon_direction(&mut self, dx: i32, dy: i32, direction: &str, dungeon_state: &mut crate::ui::DungeonExplorationState) -> anyhow::Result<()> {
+        let target = crate::world::LocalCoord::new(dungeon_state.player_pos.x + dx, dungeon_state.player_pos.y + dy);
+        let mut messages = vec![format!("--- Looking {} ---", direction)];
+
+        match dungeon_state.dungeon.get_tile_at(target) {
+            None => messages.push("Nothing but solid rock that way.".to_string()),
+            Some(tile) if !tile.visible && !tile.explored => {
+                messages.push("🌑 It's too dark to make anything out.".to_string());
+            }
+            Some(tile) => {
+                messages.push(format!("You see {} to the {}.", tile.tile_type.get_name(), direction));
+                messages.extend(Self::describe_dungeon_tile_contents(&dungeon_state.dungeon, target, tile.visible));
+
+                let creature = dungeon_state.dungeon.get_current_floor()
+                    .and_then(|floor| floor.creatures.iter().find(|c| c.position == target))
+                    .filter(|_| tile.visible);
+                match &tile.tile_type {
+                    _ if creature.is_some() => {
+                        messages.push(format!("⚔️ The {} blocks the way.", creature.map(|c| c.name.as_str()).unwrap_or("creature")));
+                    }
+                    crate::world::DungeonTileType::Door(crate::world::DoorState::Closed) => {
+                        messages.push("🚪 You'd have to open it first.".to_string());
+                    }
+                    crate::world::DungeonTileType::Door(crate::world::DoorState::Locked) => {
+                        messages.push("🔒 It's locked.".to_string());
+                    }
+                    tile_type if tile_type.is_passable() => messages.push("✅ You could step there.".to_string()),
+                    _ => messages.push("🚫 You can't pass that way.".to_string()),
+                }
+
+                // Peek past a door the player can see
+                if tile.visible && matches!(tile.tile_type, crate::world::DungeonTileType::Door(_)) {
+                    let beyond = crate::world::LocalCoord::new(target.x + dx, target.y + dy);
+                    if let Some(beyond_tile) = dungeon_state.dungeon.get_tile_at(beyond) {
+                        if tile.tile_type == crate::world::DungeonTileType::Door(crate::world::DoorState::Open) {
+                            messages.push(format!("👁️ Through the doorway: {}.", beyond_tile.tile_type.get_name()));
+                            messages.extend(Self::describe_dungeon_tile_contents(&dungeon_state.dungeon, beyond, true));
+                        } else {
+                            let occupied = dungeon_state.dungeon.get_current_floor()
+                                .map(|floor| floor.creatures.iter().any(|c| c.position == beyond))
+                                .unwrap_or(false);
+                            if occupied {
+                                messages.push("👂 Something is moving on the other side.".to_string());
+                            } else {
+                                messages.push("👂 All is quiet on the other side.".to_string());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for message in messages {
+            self.add_dungeon_message(dungeon_state, message);
+        }
+
+        Ok(())
+    }
+
+    // Creatures, corpses and loot on a dungeon tile. Creatures wander, so only
+    // report them on tiles the player can currently see
+    fn describe_dungeon_tile_contents(dungeon: &crate::world::DungeonLayout, pos: crate::world::LocalCoord, visible: bool) -> Vec<String> {
+        let mut lines = Vec::new();
+        let Some(floor) = dungeon.get_current_floor() else {
+            return lines;
+        };
+
+        if visible {
+            for creature in floor.creatures.iter().filter(|c| c.position == pos) {
+                lines.push(format!("👹 A {} ({}) is there.", creature.name, creature.creature_type.get_name()));
+            }
+        }
+        for corpse in floor.corpses.iter().filter(|c| c.position == pos) {
+            lines.push(format!("💀 The remains of a {}.", corpse.name));
+        }
+        for pile in floor.loot_piles.iter().filter(|p| p.position == pos && p.discovered) {
+            lines.push(format!("💰 A pile of loot ({} items) dropped by {}.", pile.items.len(), pile.source));
+        }
+
+        lines
+    }
+
     fn show_dungeon_help(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState) -> anyhow::Result<()> {
+        let t = |key: &str| self.ui.tileset().glyph(key);
         let help_messages = vec![
             "=== DUNGEON EXPLORATION HELP ===".to_string(),
             "Movement: W/A/S/D or Arrow Keys".to_string(),
             "E - Examine current location in detail".to_string(),
             "I - Interact with objects and features".to_string(),
-            "U - Use stairs to change floors".to_string(),
+            "U - Use stairs to change floors (spiral stairs ask up or down)".to_string(),
             "F - Attack nearby creatures (melee)".to_string(),
-            "R - Ranged attack (spells/arrows at distance)".to_string(),
+            "R - Ranged attack: pick a visible target at distance".to_string(),
             "T - Toggle torch (light/extinguish)".to_string(),
-            "L - Look around (same as examine)".to_string(),
+            "L - Look: then a direction to scout the next tile, L/Enter for here".to_string(),
             "X - Exit dungeon and return to world".to_string(),
             "H - Show this help".to_string(),
+            "PgUp/PgDn - Scroll back through older messages".to_string(),
             "Ctrl+Q - Quit game".to_string(),
             "".to_string(),
             "Symbols:".to_string(),
-            "@  - You        # - Wall      . - Floor".to_string(),
-            "+  - Open Door  | - Closed Door".to_string(),
-            "<  - Stairs Up  > - Stairs Down".to_string(),
-            "C  - Chest      A - Altar     I - Pillar".to_string(),
-            "S  - Skeleton   Z - Zombie    G - Ghost".to_string(),
-            "b  - Bat        r - Rat       s - Spider".to_string(),
-            "g  - Goblin     O - Orc       B - Bandit".to_string(),
-            "%  - Corpse     $  - Loot     ?  - Undiscovered Loot".to_string(),
+            format!("{}  - You        {} - Wall      {} - Floor", t("player"), t("dungeon.wall"), t("dungeon.floor")),
+            format!("{}  - Open Door  {} - Closed Door", t("dungeon.door_open"), t("dungeon.door_closed")),
+            format!("{}  - Stairs Up  {} - Stairs Down", t("dungeon.stairs_up"), t("dungeon.stairs_down")),
+            format!("{}  - Chest      {} - Altar     {} - Pillar", t("dungeon.chest"), t("dungeon.altar"), t("dungeon.pillar")),
+            format!("{}  - Skeleton   {} - Zombie    {} - Ghost", t("creature.skeleton"), t("creature.zombie"), t("creature.ghost")),
+            format!("{}  - Bat        {} - Rat       {} - Spider", t("creature.bat"), t("creature.rat"), t("creature.spider")),
+            format!("{}  - Goblin     {} - Orc       {} - Bandit", t("creature.goblin"), t("creature.orc"), t("creature.bandit")),
+            format!("{}  - Corpse     {}  - Loot     {}  - Undiscovered Loot", t("feature.corpse"), t("feature.loot"), t("feature.hidden_loot")),
+            format!("{}  - Spotted Trap", t("feature.trap")),
         ];
         
         for message in help_messages {
@@ -3105,21 +5910,106 @@ impl Game {
             };
             interaction_messages.push(format!("  {} - {}", i + 1, description));
         }
-        interaction_messages.push("Press I again to select an action...".to_string());
+        interaction_messages.push("Press a number to choose, or Esc to leave it be.".to_string());
         
         for message in interaction_messages {
             self.add_dungeon_message(dungeon_state, message);
         }
+        dungeon_state.corpse_menu = Some(corpse.position);
+        
+        Ok(())
+    }
+    
+    fn perform_corpse_interaction(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, corpse: &crate::world::DungeonCorpse,
+                                  interaction: crate::world::CorpseInteraction) -> anyhow::Result<()> {
+        use crate::world::{CorpseInteraction, CreatureType};
+        let Some(character) = &mut self.current_character else {
+            return Ok(());
+        };
         
-        // TODO: Implement action selection UI
-        // For now, just auto-loot if possible
-        if corpse.interactions.contains(&crate::world::CorpseInteraction::Loot) && !corpse.loot_generated {
-            self.auto_loot_corpse(dungeon_state, corpse)?;
+        match interaction {
+            CorpseInteraction::Loot if corpse.loot_generated => {
+                self.add_dungeon_message(dungeon_state, "You've already picked this corpse clean.".to_string());
+            }
+            CorpseInteraction::Loot => self.auto_loot_corpse(dungeon_state, corpse)?,
+            CorpseInteraction::Examine => {
+                self.add_dungeon_message(dungeon_state, format!("🔎 The {} ({}). {}", corpse.name, corpse.creature_type.get_name(), corpse.creature_type.get_weakness()));
+            }
+            CorpseInteraction::Skin => {
+                let parts: &[&str] = match corpse.creature_type {
+                    CreatureType::WildAnimal => &["Animal Hide", "Raw Meat"],
+                    CreatureType::Rat => &["Rat Pelt"],
+                    CreatureType::Bat => &["Bat Wing"],
+                    CreatureType::Spider => &["Spider Chitin"],
+                    _ => &["Scraps of Hide"],
+                };
+                character.inventory.extend(parts.iter().map(|part| part.to_string()));
+                self.add_dungeon_message(dungeon_state, format!("🔪 You skin the {} and take: {}", corpse.name, parts.join(", ")));
+                Self::use_corpse_interaction(dungeon_state, corpse, &interaction);
+            }
+            CorpseInteraction::Harvest => {
+                // Only someone who works magic knows which parts are worth keeping
+                let is_caster = character.magic.school_skills.values().any(|&skill| skill > 0);
+                if !is_caster {
+                    self.add_dungeon_message(dungeon_state, "🤔 You poke at the remains, but have no idea what a spellcaster would want from them.".to_string());
+                    return Ok(());
+                }
+                let component = match corpse.creature_type {
+                    CreatureType::Skeleton => "Bone Dust",
+                    CreatureType::Ghost => "Ectoplasm",
+                    CreatureType::Spider => "Venom Sac",
+                    CreatureType::Construct => "Arcane Core",
+                    _ => "Vial of Blood",
+                };
+                character.inventory.push(component.to_string());
+                self.add_dungeon_message(dungeon_state, format!("🧪 You carefully harvest {} from the {}.", component, corpse.name));
+                Self::use_corpse_interaction(dungeon_state, corpse, &interaction);
+            }
+            CorpseInteraction::RaiseSkeleton | CorpseInteraction::RaiseZombie => {
+                if character.magic.get_school_skill(&crate::forge::MagicSchool::Necromancer) == 0 {
+                    self.add_dungeon_message(dungeon_state, "🚫 Raising the dead takes training in Necromancer magic.".to_string());
+                    return Ok(());
+                }
+                if character.followers.len() >= crate::forge::MAX_FOLLOWERS {
+                    self.add_dungeon_message(dungeon_state, format!("💀 You can't hold more than {} undead to your will.", crate::forge::MAX_FOLLOWERS));
+                    return Ok(());
+                }
+                
+                let (mut risen, kind) = if interaction == CorpseInteraction::RaiseZombie {
+                    (crate::forge::create_zombie(), "zombie")
+                } else {
+                    (crate::forge::create_skeleton(), "skeleton")
+                };
+                risen.name = format!("Risen {}", corpse.creature_type.get_name());
+                risen.creature_type = None;
+                character.followers.push(risen.with_side(crate::forge::CombatSide::Players));
+                Self::remove_corpse(dungeon_state, corpse);
+                self.add_dungeon_message(dungeon_state, format!("💀 Dark words drag the {} back up as a {}. It will fight at your side.", corpse.name, kind));
+            }
+            CorpseInteraction::Burn => {
+                Self::remove_corpse(dungeon_state, corpse);
+                self.add_dungeon_message(dungeon_state, format!("🔥 You burn the remains of the {}.", corpse.name));
+            }
         }
         
         Ok(())
     }
     
+    // Skinning or harvesting a corpse can only be done once
+    fn use_corpse_interaction(dungeon_state: &mut crate::ui::DungeonExplorationState, corpse: &crate::world::DungeonCorpse, interaction: &crate::world::CorpseInteraction) {
+        if let Some(floor) = dungeon_state.dungeon.get_current_floor_mut() {
+            for used in floor.corpses.iter_mut().filter(|c| c.position == corpse.position && c.name == corpse.name) {
+                used.interactions.retain(|i| i != interaction);
+            }
+        }
+    }
+    
+    fn remove_corpse(dungeon_state: &mut crate::ui::DungeonExplorationState, corpse: &crate::world::DungeonCorpse) {
+        if let Some(floor) = dungeon_state.dungeon.get_current_floor_mut() {
+            floor.corpses.retain(|c| c.position != corpse.position || c.name != corpse.name);
+        }
+    }
+    
     fn interact_with_loot_pile(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, loot_pile: &crate::world::LootPile) -> anyhow::Result<()> {
         self.add_dungeon_message(dungeon_state, format!("💰 You find a loot pile: {}", loot_pile.source));
         
@@ -3140,7 +6030,7 @@ impl Game {
         
         // TODO: Implement item selection UI
         // For now, auto-take all items
-        self.auto_take_loot(dungeon_state, loot_pile)?;
+        self.auto_take_loot(dungeon_state, loot_pile.position)?;
         
         Ok(())
     }
@@ -3159,6 +6049,10 @@ impl Game {
                     crate::world::LootItemType::Gold => {
                         total_gold += item.quantity * item.value;
                     }
+                    crate::world::LootItemType::Ammo => {
+                        self.add_dungeon_message(dungeon_state, format!("  {} x{}", item.name, item.quantity));
+                        self.add_ammo_to_inventory(&item.name, item.quantity);
+                    }
                     _ => {
                         let item_desc = if item.quantity > 1 {
                             format!("  {} x{}", item.name, item.quantity)
@@ -3179,15 +6073,26 @@ impl Game {
                 self.add_dungeon_message(dungeon_state, format!("  {} gold coins", total_gold));
                 // Add gold to character
                 if let Some(character) = &mut self.current_character {
-                    character.gold += total_gold;
+                    character.earn_gold(total_gold);
                 }
             }
         }
         
+        // A corpse is only searched once
+        if let Some(floor) = dungeon_state.dungeon.get_current_floor_mut() {
+            for looted in floor.corpses.iter_mut().filter(|c| c.position == corpse.position && c.name == corpse.name) {
+                looted.loot_generated = true;
+            }
+        }
+        
         Ok(())
     }
     
-    fn auto_take_loot(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, loot_pile: &crate::world::LootPile) -> anyhow::Result<()> {
+    fn auto_take_loot(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, pos: crate::world::LocalCoord) -> anyhow::Result<()> {
+        // Nothing is left behind to pick up again
+        let Some(loot_pile) = dungeon_state.dungeon.take_loot_pile(pos) else {
+            return Ok(());
+        };
         self.add_dungeon_message(dungeon_state, "You take all the items.".to_string());
         let mut total_gold = 0u32;
         
@@ -3196,6 +6101,9 @@ impl Game {
                 crate::world::LootItemType::Gold => {
                     total_gold += item.quantity * item.value;
                 }
+                crate::world::LootItemType::Ammo => {
+                    self.add_ammo_to_inventory(&item.name, item.quantity);
+                }
                 _ => {
                     // Add to character inventory
                     if let Some(character) = &mut self.current_character {
@@ -3210,22 +6118,32 @@ impl Game {
         if total_gold > 0 {
             // Add gold to character
             if let Some(character) = &mut self.current_character {
-                character.gold += total_gold;
+                character.earn_gold(total_gold);
                 self.add_dungeon_message(dungeon_state, format!("💰 You gained {} gold!", total_gold));
             }
         }
         
-        // TODO: Remove the loot pile from the floor after taking items
-        
         Ok(())
     }
 
+    // Ammo is kept as one stack per type, e.g. "Arrows (20)"
+    fn add_ammo_to_inventory(&mut self, name: &str, quantity: u32) {
+        if let Some(character) = &mut self.current_character {
+            match AmmoType::from_name(name) {
+                Some(ammo_type) => {
+                    let count = ammo_type.count_in(&character.inventory);
+                    ammo_type.set_count_in(&mut character.inventory, count + quantity);
+                }
+                None => character.inventory.push(name.to_string()),
+            }
+        }
+    }
+
     fn add_dungeon_message(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, message: String) {
         dungeon_state.messages.push(message);
-        // Keep only the last 20 messages to prevent memory growth
-        if dungeon_state.messages.len() > 20 {
-            dungeon_state.messages.remove(0);
-        }
+        // Keep a bounded history to prevent memory growth
+        Self::trim_messages(&mut dungeon_state.messages, self.message_history);
+        dungeon_state.message_scroll = 0;
     }
 
     fn find_nearby_pois(&mut self, world_state: &mut WorldExplorationState) -> anyhow::Result<()> {
@@ -3263,11 +6181,23 @@ impl Game {
                                       else { "Here" };
                         
                         let distance = ((dx * dx + dy * dy) as f32).sqrt();
+                        
+                        // Only name places the character has actually laid eyes on
+                        let discovered = self.current_character.as_ref()
+                            .map(|c| c.map_memory.is_seen(world_state.current_zone, poi.position))
+                            .unwrap_or(true);
+                        if !discovered {
+                            messages.push(format!("❔ Something unexplored - {} ({:.1} tiles)", direction, distance));
+                            pois_found = true;
+                            continue;
+                        }
+                        
                         let can_enter = self.can_enter_poi(&poi.poi_type);
                         let enter_text = if can_enter { " [ENTERABLE]" } else { "" };
+                        let status = if poi.explored { " (visited)" } else { " (discovered)" };
                         
-                        messages.push(format!("📍 {} - {} ({:.1} tiles){}", 
-                            poi.name, direction, distance, enter_text));
+                        messages.push(format!("📍 {}{} - {} ({:.1} tiles){}", 
+                            poi.name, status, direction, distance, enter_text));
                         pois_found = true;
                     }
                 }
@@ -3313,7 +6243,7 @@ impl Game {
             // Attack the first nearby creature
             let target_creature = &nearby_creatures[0];
             self.add_dungeon_message(dungeon_state, format!("⚔️ Engaging {} in combat!", target_creature.name));
-            self.start_dungeon_combat(dungeon_state, target_creature)?;
+            self.start_dungeon_combat(dungeon_state, target_creature, CombatOpening::Engaged)?;
         } else {
             // Check if there are any creatures on the floor at all for debugging
             let (has_creatures, creature_info) = if let Some(floor) = dungeon_state.dungeon.get_current_floor() {
@@ -3355,48 +6285,54 @@ impl Game {
     }
 
     fn initiate_ranged_combat(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState) -> anyhow::Result<()> {
+        // No first shot without a bow or crossbow in hand and something to loose from it
+        let Some(character) = &self.current_character else {
+            return Ok(());
+        };
+        let ammo_type = character.equipped_weapon.as_ref().filter(|weapon| weapon.ranged).and_then(|weapon| weapon.ammo_type());
+        let warning = match ammo_type {
+            None => Some("🏹 You need a bow or crossbow equipped to shoot.".to_string()),
+            Some(ammo_type) if ammo_type.count_in(&character.inventory) == 0 => {
+                Some(format!("🏹 You have no {} left. Use F for melee combat.", ammo_type.get_name().to_lowercase()))
+            }
+            Some(_) => None,
+        };
+        if let Some(warning) = warning {
+            self.add_dungeon_message(dungeon_state, warning);
+            return Ok(());
+        }
+
         let player_pos = dungeon_state.player_pos;
         
         // Get the player's vision radius to determine ranged attack range
-        let vision_radius = if let Some(character) = &self.current_character {
-            character.get_vision_radius() as i32
-        } else {
-            3 // Default fallback
-        };
+        let vision_radius = self.dungeon_vision_radius(dungeon_state);
         
-        // Find creatures within vision range (but exclude adjacent ones for ranged preference)
-        let ranged_creatures: Vec<crate::world::DungeonCreature> = if let Some(floor) = dungeon_state.dungeon.get_current_floor() {
+        // Find visible creatures within vision range (but exclude adjacent ones for ranged preference)
+        let mut ranged_creatures: Vec<(f32, crate::world::DungeonCreature)> = if let Some(floor) = dungeon_state.dungeon.get_current_floor() {
             floor.creatures.iter()
-                .filter(|creature| {
+                .filter(|creature| floor.tile_at(creature.position).is_some_and(|tile| tile.visible))
+                .filter_map(|creature| {
                     let dx = (creature.position.x - player_pos.x).abs();
                     let dy = (creature.position.y - player_pos.y).abs();
                     let distance = ((dx * dx + dy * dy) as f32).sqrt();
                     
-                    // Only visible creatures within vision range but further than adjacent
-                    distance > 1.5 && distance <= vision_radius as f32
+                    // Only creatures within vision range but further than adjacent
+                    (distance > 1.5 && distance <= vision_radius as f32).then(|| (distance, creature.clone()))
                 })
-                .cloned()
                 .collect()
         } else {
             Vec::new()
         };
+        ranged_creatures.sort_by(|a, b| a.0.total_cmp(&b.0));
         
         if !ranged_creatures.is_empty() {
-            // Attack the first visible creature at range
-            let target_creature = &ranged_creatures[0];
-            let distance = {
-                let dx = (target_creature.position.x - player_pos.x).abs();
-                let dy = (target_creature.position.y - player_pos.y).abs();
-                ((dx * dx + dy * dy) as f32).sqrt()
-            };
-            
-            self.add_dungeon_message(dungeon_state, 
-                format!("🏹 Targeting {} at range! (distance: {:.1} tiles)", target_creature.name, distance));
-            self.add_dungeon_message(dungeon_state, 
-                "💥 You get the drop on them with a ranged attack!".to_string());
-            
-            // Start combat with ranged advantage - player gets to act first
-            self.start_ranged_dungeon_combat(dungeon_state, target_creature)?;
+            // List the targets and wait for the player to pick one
+            self.add_dungeon_message(dungeon_state, "🏹 Take aim at:".to_string());
+            for (number, (distance, creature)) in ranged_creatures.iter().enumerate().take(9) {
+                self.add_dungeon_message(dungeon_state, format!("  {}. {} ({:.1} tiles away)", number + 1, creature.name, distance));
+            }
+            self.add_dungeon_message(dungeon_state, "Press a number to shoot, Enter for the nearest, or Esc to cancel.".to_string());
+            dungeon_state.ranged_targets = ranged_creatures.iter().take(9).map(|(_, creature)| creature.position).collect();
         } else {
             // Check for any visible creatures at all
             let visible_creatures: Vec<crate::world::DungeonCreature> = if let Some(floor) = dungeon_state.dungeon.get_current_floor() {
@@ -3423,218 +6359,47 @@ impl Game {
         Ok(())
     }
 
-    fn start_dungeon_combat(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, target_creature: &crate::world::DungeonCreature) -> anyhow::Result<()> {
-        if let Some(character) = &self.current_character {
-            // Create player combat participant
-            let player_participant = self.create_player_combat_participant(character)?;
-            
-            // Create creature combat participant
-            let creature_participant = self.create_creature_combat_participant(target_creature);
-            
-            // Create participants vector
-            let participants = vec![player_participant, creature_participant];
-            
-            // Get player's available skills
-            let available_skills = self.get_player_skills(character);
-            
-            // Create combat encounter (this will roll initiative and sort participants)
-            let encounter = CombatEncounter::new(participants);
-            
-            // Create combat state and auto-advance past initiative phase for better UX
-            let mut combat_state = CombatState {
-                encounter,
-                selected_action: None,
-                available_skills,
-                selected_skill: None,
-                combat_phase: CombatPhase::InitiativeRoll,
-                return_to_dungeon: Some(dungeon_state.clone()),
-                current_skill_index: 0,
-                skill_list_offset: 0,
-            };
-            
-            // Auto-advance past initiative roll for smoother gameplay
-            combat_state.encounter.add_log("=== COMBAT BEGINS ===".to_string());
-            combat_state.encounter.add_log("Rolling initiative...".to_string());
-            
-            // Display initiative results
-            let init_results: Vec<String> = combat_state.encounter.participants.iter()
-                .map(|p| format!("{} rolled {} for initiative", p.name, p.initiative))
-                .collect();
-            for result in init_results {
-                combat_state.encounter.add_log(result);
-            }
-            
-            combat_state.encounter.add_log(format!("=== ROUND {} ===", combat_state.encounter.round));
-            combat_state.combat_phase = CombatPhase::DeclaringActions;
-            
-            // Process AI turns immediately if the first participant is an enemy
-            if let Some(current) = combat_state.encounter.get_current_participant() {
-                if !current.is_player && current.is_alive() {
-                    // Process AI turns right away
-                    self.process_ai_turns(&mut combat_state)?;
-                }
-            }
-            
-            // Switch to combat mode
-            self.state = UIState::Combat(combat_state);
-        }
-        
-        Ok(())
+    fn start_dungeon_combat(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, target_creature: &crate::world::DungeonCreature, opening: CombatOpening) -> anyhow::Result<()> {
+        let Some(character) = &self.current_character else {
+            return Ok(());
+        };
+        let participants = vec![
+            self.create_player_combat_participant(character)?,
+            self.create_creature_combat_participant(target_creature),
+        ];
+        self.begin_combat(participants, CombatReturn::Dungeon(Box::new(dungeon_state.clone())), opening)
     }
 
+    // A shot from range means the player acts first
     fn start_ranged_dungeon_combat(&mut self, dungeon_state: &mut crate::ui::DungeonExplorationState, target_creature: &crate::world::DungeonCreature) -> anyhow::Result<()> {
-        if let Some(character) = &self.current_character {
-            // Create player combat participant
-            let player_participant = self.create_player_combat_participant(character)?;
-            
-            // Create enemy from the dungeon creature
-            let enemy_participant = self.create_creature_combat_participant(target_creature);
-            
-            // Create encounter with player and enemy
-            let participants = vec![player_participant, enemy_participant];
-            let mut encounter = CombatEncounter::new(participants);
-            
-            // RANGED ADVANTAGE: Player always goes first regardless of initiative
-            // Force player to have highest initiative
-            if let Some(player) = encounter.participants.get_mut(0) {
-                player.initiative = 20; // Max initiative
-            }
-            // Set enemy initiative lower
-            if let Some(enemy) = encounter.participants.get_mut(1) {
-                enemy.initiative = 1; // Min initiative 
-            }
-            // Re-sort by initiative
-            encounter.participants.sort_by(|a, b| b.initiative.cmp(&a.initiative));
-            
-            // Get available skills for the character
-            let available_skills = self.get_available_combat_skills(character);
-            
-            // Create combat state with advantage note
-            let mut combat_state = CombatState {
-                encounter,
-                selected_action: None,
-                available_skills,
-                selected_skill: None,
-                combat_phase: CombatPhase::InitiativeRoll,
-                return_to_dungeon: Some(dungeon_state.clone()),
-                current_skill_index: 0,
-                skill_list_offset: 0,
-            };
-            
-            // Auto-advance past initiative roll for smoother gameplay
-            combat_state.encounter.add_log("=== RANGED COMBAT BEGINS ===".to_string());
-            combat_state.encounter.add_log("🏹 You struck first with a ranged attack!".to_string());
-            combat_state.encounter.add_log("Rolling initiative...".to_string());
-            
-            // Display initiative results
-            let init_results: Vec<String> = combat_state.encounter.participants.iter()
-                .map(|p| format!("{} rolled {} for initiative", p.name, p.initiative))
-                .collect();
-            for result in init_results {
-                combat_state.encounter.add_log(result);
-            }
-            
-            combat_state.encounter.add_log("🎯 Player gets tactical advantage!".to_string());
-            combat_state.encounter.add_log(format!("=== ROUND {} ===", combat_state.encounter.round));
-            combat_state.combat_phase = CombatPhase::DeclaringActions;
-            
-            // Since player has advantage, they always go first - no need to process AI turns
-            
-            // Switch to combat mode
-            self.state = UIState::Combat(combat_state);
-        }
-        
-        Ok(())
+        let Some(character) = &self.current_character else {
+            return Ok(());
+        };
+        let participants = vec![
+            self.create_player_combat_participant(character)?,
+            self.create_creature_combat_participant(target_creature),
+        ];
+        self.begin_combat(participants, CombatReturn::Dungeon(Box::new(dungeon_state.clone())), CombatOpening::RangedShot)
     }
 
     fn start_dungeon_random_encounter(&mut self, character: &ForgeCharacter, dungeon_state: &crate::ui::DungeonExplorationState) -> anyhow::Result<()> {
-        // Create player combatant with basic equipment
-        let mut player = CombatParticipant::from_character(character, Some(Weapon::rusty_sword()));
-        player.armor = Some(Armor::leather());
-        
         // Generate random dungeon enemies
-        let enemies = self.generate_dungeon_enemies()?;
-        
-        // Create encounter with player and enemies
-        let mut participants = vec![player];
-        participants.extend(enemies);
-        let encounter = CombatEncounter::new(participants);
-        
-        // Get available skills for the character
-        let available_skills = self.get_available_combat_skills(character);
-        
-        // Create combat state and auto-advance past initiative phase for better UX
-        let mut combat_state = CombatState {
-            encounter,
-            selected_action: None,
-            available_skills,
-            selected_skill: None,
-            combat_phase: CombatPhase::InitiativeRoll,
-            return_to_dungeon: Some(dungeon_state.clone()),
-            current_skill_index: 0,
-            skill_list_offset: 0,
-        };
-        
-        // Auto-advance past initiative roll for smoother gameplay
-        combat_state.encounter.add_log("=== COMBAT BEGINS ===".to_string());
-        combat_state.encounter.add_log("Rolling initiative...".to_string());
-        
-        // Display initiative results
-        let init_results: Vec<String> = combat_state.encounter.participants.iter()
-            .map(|p| format!("{} rolled {} for initiative", p.name, p.initiative))
-            .collect();
-        for result in init_results {
-            combat_state.encounter.add_log(result);
-        }
-        
-        combat_state.encounter.add_log(format!("=== ROUND {} ===", combat_state.encounter.round));
-        combat_state.combat_phase = CombatPhase::DeclaringActions;
-        
-        // Process AI turns immediately if the first participant is an enemy
-        if let Some(current) = combat_state.encounter.get_current_participant() {
-            if !current.is_player && current.is_alive() {
-                // Process AI turns right away
-                self.process_ai_turns(&mut combat_state)?;
-            }
-        }
-        
-        self.state = UIState::Combat(combat_state);
+        let enemies = self.generate_dungeon_enemies(character)?;
         
-        Ok(())
+        let mut participants = vec![self.create_player_combat_participant(character)?];
+        participants.extend(enemies.into_iter().map(|enemy| Self::scale_for_difficulty(enemy, character.difficulty)));
+        // Unseen creatures fall on the player before they can be sized up
+        self.begin_combat(participants, CombatReturn::Dungeon(Box::new(dungeon_state.clone())), CombatOpening::Ambushed)
     }
 
-    fn generate_dungeon_enemies(&self) -> anyhow::Result<Vec<CombatParticipant>> {
-        let mut rng = rand::thread_rng();
-        let mut enemies = Vec::new();
-        
-        // Generate enemies typical for dungeon environments
-        match rng.gen_range(0..10) {
-            0..=2 => enemies.push(create_skeleton()),
-            3..=4 => enemies.push(create_zombie()),
-            5..=6 => enemies.push(create_goblin()),
-            7..=8 => enemies.push(create_giant_spider()),
-            _ => {
-                // Multiple enemies
-                enemies.push(create_skeleton());
-                if rng.gen_bool(0.5) {
-                    enemies.push(create_skeleton());
-                }
-            }
-        }
-        
-        Ok(enemies)
+    fn generate_dungeon_enemies(&self, character: &ForgeCharacter) -> anyhow::Result<Vec<CombatParticipant>> {
+        // Creatures typical for dungeon environments, weakest first
+        Ok(Self::roll_enemy_group(character, &Self::with_night_undead(character, &[create_giant_spider, create_goblin, create_skeleton, create_zombie])))
     }
 
     fn create_player_combat_participant(&self, character: &ForgeCharacter) -> anyhow::Result<CombatParticipant> {
-        Ok(CombatParticipant {
-            name: character.name.clone(),
-            combat_stats: character.combat_stats.clone(),
-            weapon: Some(Weapon::unarmed()), // TODO: Get actual equipped weapon
-            armor: None, // TODO: Get actual equipped armor
-            shield: None, // TODO: Get actual equipped shield
-            initiative: 0, // Will be rolled
-            is_player: true,
-        })
+        // Equipped gear, with ammo from the inventory for an equipped bow or crossbow
+        Ok(CombatParticipant::from_character(character))
     }
 
     fn create_creature_combat_participant(&self, creature: &crate::world::DungeonCreature) -> CombatParticipant {
@@ -3664,36 +6429,37 @@ impl Game {
             }
         };
         
-        CombatParticipant {
+        let difficulty = self.current_character.as_ref().map(|character| character.difficulty).unwrap_or_default();
+        let participant = CombatParticipant {
             name: creature.name.clone(),
             combat_stats: stats,
             weapon,
+            ranged_weapon: None,
+            ammo: 0,
+            ammo_spent: 0,
+            creature_type: Some(creature.creature_type.clone()),
             armor: None,
             shield: None,
             initiative: 0, // Will be rolled
             is_player: false,
-        }
+            side: crate::forge::CombatSide::Enemies,
+            fled: false,
+            status_effects: Vec::new(),
+            active_effects: Vec::new(),
+            stamina: 0,
+            max_stamina: 0,
+            overloaded: false,
+            guarding: false,
+        };
+        Self::scale_for_difficulty(participant, difficulty)
     }
 
-    fn get_player_skills(&self, character: &ForgeCharacter) -> Vec<String> {
-        let mut skills = vec!["Melee Combat".to_string()]; // Everyone has basic melee
-        
-        // Add skills from character
-        for (skill_name, skill_level) in &character.skills {
-            if *skill_level > 0 {
-                skills.push(skill_name.clone());
-            }
-        }
-        
-        // Add racial abilities
-        for ability in &character.race.special_abilities {
-            skills.push(ability.clone());
-        }
-        
-        skills
+    // Stronger or weaker enemies depending on the character's difficulty setting
+    fn scale_for_difficulty(mut enemy: CombatParticipant, difficulty: crate::forge::Difficulty) -> CombatParticipant {
+        difficulty.scale_enemy_stats(&mut enemy.combat_stats);
+        enemy
     }
 
-
     // Creature stat creation functions based on Forge rules
     fn create_rat_stats(&self) -> crate::forge::CombatStats {
         use crate::forge::{CombatStats, HealthPoints};
@@ -3840,4 +6606,183 @@ impl Game {
             range: None,
         }
     }
-}
\ No newline at end of file
+}
+// Roll initiative for a new fight. A ranged opener puts the player's side first.
+fn open_encounter(participants: Vec<CombatParticipant>, opening: CombatOpening) -> CombatEncounter {
+    let mut encounter = CombatEncounter::new(participants);
+    if opening == CombatOpening::RangedShot {
+        for participant in &mut encounter.participants {
+            participant.initiative = if participant.side == crate::forge::CombatSide::Players { 20 } else { 1 };
+        }
+        encounter.participants.sort_by_key(|p| std::cmp::Reverse(p.initiative));
+        encounter.add_log("🏹 You struck first with a ranged attack! You have the tactical advantage.".to_string());
+    }
+    encounter
+}
+
+// A fight as it looks before anyone has acted
+fn new_combat_state(encounter: CombatEncounter, available_skills: Vec<String>, return_to: CombatReturn) -> CombatState {
+    let (return_to_world, return_to_dungeon) = match return_to {
+        CombatReturn::World(world_state) => (world_state.map(|state| *state), None),
+        CombatReturn::Dungeon(dungeon_state) => (None, Some(*dungeon_state)),
+    };
+    CombatState {
+        encounter,
+        selected_action: None,
+        available_skills,
+        selected_skill: None,
+        combat_phase: CombatPhase::InitiativeRoll,
+        return_to_dungeon,
+        return_to_world,
+        current_skill_index: 0,
+        skill_list_offset: 0,
+        log_scroll: 0,
+        summary: None,
+    }
+}
+
+// Record the outcome of a lost fight: a character on a permadeath difficulty is
+// removed, anyone else is stored as they were revived
+fn settle_fallen_character(database: &mut CharacterDatabase, character: &crate::forge::ForgeCharacter) -> anyhow::Result<()> {
+    if character.difficulty.is_permadeath() {
+        database.delete_character(&character.name)
+    } else {
+        database.update_character(&character.name, character.clone())
+    }
+}
+
+// Gather once from a tile, spending the time it takes. Returns what the tile has
+// left, or why nothing could be gathered.
+fn take_gathering(character: &mut crate::forge::ForgeCharacter, zone: crate::world::ZoneCoord, pos: crate::world::LocalCoord, capacity: u32) -> Result<u32, &'static str> {
+    let now = character.hours_elapsed;
+    if character.resource_nodes.gathered_recently(zone, pos, now) {
+        return Err("🍂 You've already stripped this area recently.");
+    }
+    if !character.resource_nodes.harvest(zone, pos, capacity, now) {
+        return Err("🍂 You've stripped this area bare. Move on, or come back in a day or two.");
+    }
+    character.resource_nodes.mark_gathered(zone, pos, now);
+    character.advance_time(crate::world::GATHER_HOURS);
+    character.resource_nodes.prune(character.hours_elapsed);
+    Ok(character.resource_nodes.remaining(zone, pos, capacity, character.hours_elapsed))
+}
+
+// Delete a character and save, but only for the right password. Returns
+// whether the character was deleted.
+fn delete_with_password(database: &mut CharacterDatabase, db_path: &std::path::Path, name: &str, password: &str) -> anyhow::Result<bool> {
+    if database.authenticate(name, password).is_err() {
+        return Ok(false);
+    }
+    database.delete_character(name)?;
+    database.save(db_path)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge::{sample_character, Difficulty};
+
+    fn database_with(character: &crate::forge::ForgeCharacter) -> CharacterDatabase {
+        let mut database = CharacterDatabase::new();
+        database.characters.insert(character.name.clone(), crate::database::CharacterRecord {
+            character: character.clone(),
+            password_hash: String::new(),
+            salt: String::new(),
+        });
+        database
+    }
+
+    #[test]
+    fn permadeath_removes_the_character() {
+        let mut character = sample_character("Fallen");
+        character.difficulty = Difficulty::Hard;
+        let mut database = database_with(&character);
+        settle_fallen_character(&mut database, &character).unwrap();
+        assert!(!database.characters.contains_key("Fallen"));
+    }
+
+    #[test]
+    fn other_difficulties_keep_the_revived_character() {
+        for difficulty in [Difficulty::Easy, Difficulty::Normal] {
+            let mut character = sample_character("Fallen");
+            character.difficulty = difficulty;
+            let mut database = database_with(&character);
+            character.combat_stats.hit_points.current = 0;
+            character.revive();
+            settle_fallen_character(&mut database, &character).unwrap();
+            let stored = &database.characters["Fallen"].character;
+            assert_eq!(stored.combat_stats.hit_points.current, character.combat_stats.hit_points.current);
+            assert!(stored.combat_stats.hit_points.current > 0);
+        }
+    }
+
+    #[test]
+    fn only_fights_the_player_starts_can_be_assessed() {
+        assert!(CombatOpening::Engaged.allows_assessment());
+        assert!(!CombatOpening::Ambushed.allows_assessment());
+        assert!(!CombatOpening::RangedShot.allows_assessment());
+    }
+
+    fn skirmish() -> Vec<CombatParticipant> {
+        let mut hero = CombatParticipant::from_character(&sample_character("Hero"));
+        hero.is_player = true;
+        vec![hero, crate::forge::create_goblin(), crate::forge::create_wolf()]
+    }
+
+    #[test]
+    fn every_opening_starts_from_the_same_kind_of_state() {
+        for opening in [CombatOpening::Engaged, CombatOpening::Ambushed, CombatOpening::RangedShot] {
+            let state = new_combat_state(open_encounter(skirmish(), opening), vec!["Melee Combat".to_string()], CombatReturn::World(None));
+            assert!(matches!(state.combat_phase, CombatPhase::InitiativeRoll));
+            assert_eq!(state.encounter.participants.len(), 3);
+            assert_eq!(state.encounter.current_turn, 0);
+            assert_eq!(state.available_skills, vec!["Melee Combat".to_string()]);
+            assert!(state.return_to_world.is_none() && state.return_to_dungeon.is_none());
+            assert!(state.summary.is_none());
+            assert!(state.encounter.participants.windows(2).all(|pair| pair[0].initiative >= pair[1].initiative));
+        }
+    }
+
+    #[test]
+    fn a_ranged_opener_hands_the_player_the_first_turn() {
+        let encounter = open_encounter(skirmish(), CombatOpening::RangedShot);
+        assert!(encounter.participants[0].is_player);
+        assert!(encounter.participants[1..].iter().all(|p| p.initiative < encounter.participants[0].initiative));
+    }
+
+    #[test]
+    fn a_wrong_password_leaves_the_character_in_the_saved_database() {
+        let directory = std::env::temp_dir().join(format!("warlords-delete-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("characters.json");
+        let mut database = CharacterDatabase::new();
+        database.create_character("Aria".to_string(), "secret".to_string(), sample_character("Aria")).unwrap();
+        database.save(&path).unwrap();
+
+        assert!(!delete_with_password(&mut database, &path, "Aria", "wrong").unwrap());
+        assert!(CharacterDatabase::load_or_create(&path).unwrap().characters.contains_key("Aria"));
+
+        assert!(delete_with_password(&mut database, &path, "Aria", "secret").unwrap());
+        assert!(!CharacterDatabase::load_or_create(&path).unwrap().characters.contains_key("Aria"));
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn gathering_the_same_tile_twice_in_a_row_is_refused() {
+        let mut character = sample_character("Gatherer");
+        let zone = crate::world::ZoneCoord::new(2, 3);
+        let here = crate::world::LocalCoord::new(10, 10);
+        assert_eq!(take_gathering(&mut character, zone, here, 5), Ok(4));
+        assert_eq!(take_gathering(&mut character, zone, here, 5), Err("🍂 You've already stripped this area recently."));
+
+        // A different tile is fine, and so is this one once the cooldown is over
+        assert!(take_gathering(&mut character, zone, crate::world::LocalCoord::new(11, 10), 5).is_ok());
+        character.advance_time(crate::world::GATHER_COOLDOWN_HOURS);
+        assert!(take_gathering(&mut character, zone, here, 5).is_ok());
+
+        // The cooldown is saved with the character
+        let saved: crate::world::ResourceNodes = serde_json::from_str(&serde_json::to_string(&character.resource_nodes).unwrap()).unwrap();
+        assert!(saved.gathered_recently(zone, here, character.hours_elapsed));
+    }
+}
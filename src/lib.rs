@@ -4,6 +4,7 @@ pub mod network;
 pub mod ui;
 pub mod database;
 pub mod world;
+pub mod rng;
 
 pub use forge::*;
 pub use game::*;
@@ -1,6 +1,7 @@
 use warlords::game::Game;
-use warlords::forge::ForgeCharacterCreation;
-use clap::Command;
+use warlords::forge::{Difficulty, ForgeCharacterCreation, SpellPointModel};
+use warlords::database::SaveProfile;
+use clap::{Arg, ArgAction, Command};
 use crossterm::{terminal, execute, cursor};
 use anyhow::Result;
 
@@ -23,17 +24,112 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let matches = Command::new("warlords")
         .about("A terminal-based Forge: Out of Chaos RPG")
         .version("0.1.0")
+        .arg(
+            Arg::new("ascii")
+                .long("ascii")
+                .global(true)
+                .help("Draw with plain ASCII instead of emoji and box drawing characters")
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("difficulty")
+                .long("difficulty")
+                .global(true)
+                .help("Difficulty for newly created characters")
+                .value_parser(["easy", "normal", "hard"])
+        )
+        .arg(
+            Arg::new("world-size")
+                .long("world-size")
+                .global(true)
+                .help("Width and height of a newly generated world, in 64-tile zones")
+                .value_parser(clap::value_parser!(u16).range(1..=256))
+        )
+        .arg(
+            Arg::new("wrap-world")
+                .long("wrap-world")
+                .global(true)
+                .help("Let a newly generated world wrap around at its edges")
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("shared-spell-points")
+                .long("shared-spell-points")
+                .global(true)
+                .help("Have every school draw on one spell point pool instead of giving each trained school a reserve")
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("message-history")
+                .long("message-history")
+                .global(true)
+                .value_name("LINES")
+                .help("How many messages the exploration log keeps for scrolling back (default 200)")
+                .value_parser(clap::value_parser!(u16).range(10..))
+        )
+        .arg(
+            Arg::new("rerolls")
+                .long("rerolls")
+                .global(true)
+                .value_name("COUNT")
+                .help("How many times a new character may re-roll their characteristics after the first roll (default 3)")
+                .value_parser(clap::value_parser!(u8))
+        )
+        .arg(
+            Arg::new("record")
+                .long("record")
+                .global(true)
+                .value_name("FILE")
+                .help("Log every key and major game event to FILE, one JSON object per line. Characters typed into password fields are left out")
+        )
+        .arg(
+            Arg::new("replay")
+                .long("replay")
+                .global(true)
+                .value_name("FILE")
+                .help("Play back the keys logged in FILE, then hand control back. Dice are rolled from the seed in the log, so starting from the same saves plays out the same way. It stops early at a key press or where a password was typed")
+        )
+        .arg(
+            Arg::new("server")
+                .long("server")
+                .global(true)
+                .value_name("HOST:PORT")
+                .help("Join a warlords-server once in the world to chat with the players there")
+        )
         .subcommand(
             Command::new("test")
                 .about("Test character creation system")
         )
+        .subcommand(
+            Command::new("play")
+                .about("Play the game, optionally with a separate save profile")
+                .arg(
+                    Arg::new("profile")
+                        .long("profile")
+                        .value_name("NAME")
+                        .help("Keep characters and world under profiles/NAME instead of the default saves")
+                )
+        )
+        .subcommand(
+            Command::new("profiles")
+                .about("List the save profiles that have been played")
+        )
         .get_matches();
 
     let result = match matches.subcommand() {
         Some(("test", _)) => {
             run_character_test()
         }
-        _ => {
+        Some(("profiles", _)) => {
+            list_profiles()
+        }
+        subcommand => {
+            let profile_name = match subcommand {
+                Some(("play", play)) => play.get_one::<String>("profile").map(String::as_str),
+                _ => None,
+            };
+            let profile = SaveProfile::new(profile_name)?;
+
             // Check if we're in a proper terminal for the full game
             if !is_proper_terminal() {
                 println!("🎮 Welcome to Warlords!");
@@ -53,7 +149,38 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             
             // Run full game
-            let mut game = Game::new()?;
+            let mut game = Game::new(&profile)?;
+            if matches.get_flag("ascii") {
+                game.enable_ascii_mode();
+            }
+            if let Some(difficulty) = matches.get_one::<String>("difficulty").and_then(|name| Difficulty::from_name(name)) {
+                game.set_default_difficulty(difficulty);
+            }
+            if let Some(&zones) = matches.get_one::<u16>("world-size") {
+                game.set_world_size(zones as i32, zones as i32);
+            }
+            if matches.get_flag("wrap-world") {
+                game.set_world_wrap(true);
+            }
+            if matches.get_flag("shared-spell-points") {
+                game.set_spell_point_model(SpellPointModel::Shared);
+            }
+            if let Some(&lines) = matches.get_one::<u16>("message-history") {
+                game.set_message_history(lines as usize);
+            }
+            if let Some(&rerolls) = matches.get_one::<u8>("rerolls") {
+                game.set_reroll_budget(rerolls);
+            }
+            // A replay sets the seed first, so recording it again logs the same one
+            if let Some(path) = matches.get_one::<String>("replay") {
+                game.load_replay(std::path::Path::new(path))?;
+            }
+            if let Some(path) = matches.get_one::<String>("record") {
+                game.enable_event_log(std::path::Path::new(path))?;
+            }
+            if let Some(address) = matches.get_one::<String>("server") {
+                game.set_server(address);
+            }
             match game.run() {
                 Ok(()) => Ok(()),
                 Err(e) => Err(e.to_string().into())
@@ -69,11 +196,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 fn is_proper_terminal() -> bool {
-    // Check if stdin is a TTY
-    use std::os::unix::io::AsRawFd;
-    unsafe {
-        libc::isatty(std::io::stdin().as_raw_fd()) == 1
+    // Check if stdin and stdout are TTYs (works on Unix and Windows)
+    use std::io::IsTerminal;
+    std::io::stdin().is_terminal() && std::io::stdout().is_terminal()
+}
+
+fn list_profiles() -> Result<(), Box<dyn std::error::Error>> {
+    let profiles = SaveProfile::list()?;
+    if profiles.is_empty() {
+        println!("📁 No save profiles yet. Start one with: cargo run -- play --profile <name>");
+        return Ok(());
+    }
+    println!("📁 Save profiles:");
+    for name in profiles {
+        println!("• {}", name);
     }
+    Ok(())
 }
 
 fn run_character_test() -> Result<(), Box<dyn std::error::Error>> {
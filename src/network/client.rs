@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+use anyhow::{anyhow, Result};
+use crate::world::WorldCoord;
+use super::sanitize_chat;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Something the server told the game about other players
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkEvent {
+    Chat { from: String, message: String },
+    Joined(String),
+    Left(String),
+    PlayerAt { name: String, position: WorldCoord }, // Someone in the player's zone moved or came into it
+    LeftZone(String),
+    Error(String),
+    Disconnected,
+}
+
+impl NetworkEvent {
+    // How the event reads in the message log
+    pub fn describe(&self) -> String {
+        match self {
+            NetworkEvent::Chat { from, message } => format!("💬 [{}] {}", from, message),
+            NetworkEvent::Joined(name) => format!("📡 [{}] joined", name),
+            NetworkEvent::Left(name) => format!("📡 [{}] left", name),
+            NetworkEvent::PlayerAt { name, position } => format!("📡 [{}] is at ({}, {})", name, position.x, position.y),
+            NetworkEvent::LeftZone(name) => format!("📡 [{}] left the area", name),
+            NetworkEvent::Error(message) => format!("📡 Server: {}", message),
+            NetworkEvent::Disconnected => "📡 Lost the connection to the server.".to_string(),
+        }
+    }
+
+    // Positions are drawn on the map rather than written to the message log
+    pub fn is_position(&self) -> bool {
+        matches!(self, NetworkEvent::PlayerAt { .. } | NetworkEvent::LeftZone(_))
+    }
+}
+
+// The game's connection to a multiplayer server. Lines from the server are read
+// on a background thread so the game loop never waits on the network.
+pub struct ChatClient {
+    stream: TcpStream,
+    events: Receiver<NetworkEvent>,
+    last_position: Option<WorldCoord>,
+    players: HashMap<String, WorldCoord>, // Where the other players in the zone were last seen
+}
+
+impl ChatClient {
+    // Connect and join as the given character, creating it on the server if needed
+    pub fn connect(address: &str, name: &str, password: &str) -> Result<Self> {
+        let socket_address = address.to_socket_addrs()?
+            .next()
+            .ok_or_else(|| anyhow!("No address found for '{}'", address))?;
+        let mut stream = TcpStream::connect_timeout(&socket_address, CONNECT_TIMEOUT)?;
+        writeln!(stream, "join {} {}", name, password)?;
+
+        let (tx, events) = mpsc::channel();
+        let reader = BufReader::new(stream.try_clone()?);
+        std::thread::spawn(move || {
+            for line in reader.lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if let Some(event) = parse_server_line(&line) {
+                    if tx.send(event).is_err() {
+                        return; // The game has hung up
+                    }
+                }
+            }
+            let _ = tx.send(NetworkEvent::Disconnected);
+        });
+
+        Ok(Self { stream, events, last_position: None, players: HashMap::new() })
+    }
+
+    pub fn send_chat(&mut self, text: &str) -> Result<()> {
+        let message = sanitize_chat(text);
+        if !message.is_empty() {
+            writeln!(self.stream, "say {}", message)?;
+        }
+        Ok(())
+    }
+
+    // Tell the server where the player is, if they've moved since last time
+    pub fn update_position(&mut self, position: WorldCoord) -> Result<()> {
+        if self.last_position != Some(position) {
+            // The server only reports the zone the player is in, so anyone in the old one is out of sight
+            if self.last_position.is_some_and(|last| last.to_zone() != position.to_zone()) {
+                self.players.retain(|_, other| other.to_zone() == position.to_zone());
+            }
+            writeln!(self.stream, "pos {} {}", position.x, position.y)?;
+            self.last_position = Some(position);
+        }
+        Ok(())
+    }
+
+    // Everything that has arrived since the last poll
+    pub fn poll(&mut self) -> Vec<NetworkEvent> {
+        let events: Vec<NetworkEvent> = self.events.try_iter().collect();
+        for event in &events {
+            track_player(&mut self.players, event);
+        }
+        events
+    }
+
+    // The other players in the zone and where they stand
+    pub fn players(&self) -> &HashMap<String, WorldCoord> {
+        &self.players
+    }
+}
+
+impl Drop for ChatClient {
+    fn drop(&mut self) {
+        let _ = writeln!(self.stream, "quit");
+        let _ = self.stream.shutdown(std::net::Shutdown::Both);
+    }
+}
+
+// Keep the other players' positions up to date with an event from the server
+fn track_player(players: &mut HashMap<String, WorldCoord>, event: &NetworkEvent) {
+    match event {
+        NetworkEvent::PlayerAt { name, position } => {
+            players.insert(name.clone(), *position);
+        }
+        NetworkEvent::LeftZone(name) | NetworkEvent::Left(name) => {
+            players.remove(name);
+        }
+        NetworkEvent::Disconnected => players.clear(),
+        _ => {}
+    }
+}
+
+// Pick the updates the game cares about out of the server's output. The server
+// talks to telnet users too, so anything else is prompts and text for them.
+fn parse_server_line(line: &str) -> Option<NetworkEvent> {
+    let line = strip_ansi(line);
+    let line = line.trim_start_matches("> ").trim();
+    let (tag, rest) = line.split_once(' ').unwrap_or((line, ""));
+    match tag {
+        "CHAT" => {
+            let (from, message) = rest.split_once(' ')?;
+            Some(NetworkEvent::Chat { from: sanitize_chat(from), message: sanitize_chat(message) })
+        }
+        "JOINED" => Some(NetworkEvent::Joined(sanitize_chat(rest))),
+        "QUIT" => Some(NetworkEvent::Left(sanitize_chat(rest))),
+        "PLAYER" => {
+            let mut parts = rest.split_whitespace();
+            let name = sanitize_chat(parts.next()?);
+            let x = parts.next()?.parse().ok()?;
+            let y = parts.next()?.parse().ok()?;
+            Some(NetworkEvent::PlayerAt { name, position: WorldCoord::new(x, y) })
+        }
+        "LEFT" => Some(NetworkEvent::LeftZone(sanitize_chat(rest))),
+        _ => line.strip_prefix("Error: ").map(|message| NetworkEvent::Error(sanitize_chat(message))),
+    }
+}
+
+// Drop the colour codes the server wraps its text in
+fn strip_ansi(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip to the letter that ends the escape sequence
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn player_positions_are_parsed_and_tracked() {
+        let mut players = HashMap::new();
+        for line in ["PLAYER Aria 10 -4", "> PLAYER Bran 3 7", "PLAYER Aria 11 -4", "PLAYER Cole x 2", "LEFT Bran"] {
+            if let Some(event) = parse_server_line(line) {
+                assert!(event.is_position());
+                track_player(&mut players, &event);
+            }
+        }
+        assert_eq!(players.len(), 1);
+        assert_eq!(players["Aria"], WorldCoord::new(11, -4));
+
+        track_player(&mut players, &parse_server_line("QUIT Aria").unwrap());
+        assert!(players.is_empty());
+    }
+}
@@ -1,12 +1,18 @@
+pub mod client;
+
+pub use client::*;
+
 use tokio::net::{TcpListener, TcpStream};
 use tokio::io::{AsyncWriteExt, BufReader, AsyncBufReadExt};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::{Mutex, mpsc};
 use uuid::Uuid;
 use serde::{Serialize, Deserialize};
 use crate::forge::ForgeCharacter;
 use crate::database::CharacterDatabase;
+use crate::world::{LocalCoord, WorldBounds, WorldCoord, ZONE_SIZE};
 use anyhow::Result;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,27 +32,64 @@ pub enum ServerMessage {
     CreationFailed { reason: String },
     GameUpdate { data: String },
     ChatMessage { from: String, message: String },
+    PlayerMoved { name: String, position: WorldCoord }, // Someone in the same zone is here now
+    PlayerLeft { name: String },                        // Someone left the zone or the game
+    PlayerJoined { name: String },                      // Someone connected, wherever they are
+    PlayerQuit { name: String },                        // Someone disconnected
     SystemMessage { message: String },
     Error { message: String },
 }
 
+pub const MAX_CHAT_LENGTH: usize = 200; // Characters kept from a chat line
+
+// A chat line made safe to show anywhere: control characters that could break
+// a terminal or the game's display are dropped, and it's cut to MAX_CHAT_LENGTH
+pub fn sanitize_chat(text: &str) -> String {
+    let message: String = text.chars().filter(|c| !c.is_control()).take(MAX_CHAT_LENGTH).collect();
+    message.trim().to_string()
+}
+
 pub struct GameSession {
     pub id: Uuid,
     pub character: Option<ForgeCharacter>,
     pub authenticated: bool,
+    pub position: Option<WorldCoord>, // Where the character stands once logged in
     pub tx: mpsc::UnboundedSender<ServerMessage>,
 }
 
+impl GameSession {
+    // Logged in and standing in the world
+    fn player(&self) -> Option<(&str, WorldCoord)> {
+        match (&self.character, self.position) {
+            (Some(character), Some(position)) if self.authenticated => Some((character.name.as_str(), position)),
+            _ => None,
+        }
+    }
+}
+
+// Where a character stands in the world, from the zone and spot they last saved in
+fn character_position(character: &ForgeCharacter, bounds: WorldBounds) -> WorldCoord {
+    match (character.current_zone, character.current_position) {
+        (Some(zone), Some(local)) => WorldCoord::from_zone_local(zone, local),
+        (Some(zone), None) => WorldCoord::from_zone_local(zone, LocalCoord::new(ZONE_SIZE / 2, ZONE_SIZE / 2)),
+        _ => bounds.center(),
+    }
+}
+
 pub struct MultiplayerServer {
     sessions: Arc<Mutex<HashMap<Uuid, GameSession>>>,
     database: Arc<Mutex<CharacterDatabase>>,
+    database_path: PathBuf,
+    bounds: WorldBounds, // Size of the world being played, positions outside it are refused
 }
 
 impl MultiplayerServer {
-    pub fn new(database: CharacterDatabase) -> Self {
+    pub fn new(database: CharacterDatabase, database_path: PathBuf, bounds: WorldBounds) -> Self {
         Self {
             sessions: Arc::new(Mutex::new(HashMap::new())),
             database: Arc::new(Mutex::new(database)),
+            database_path,
+            bounds,
         }
     }
 
@@ -61,9 +104,11 @@ impl MultiplayerServer {
             
             let sessions = Arc::clone(&self.sessions);
             let database = Arc::clone(&self.database);
+            let database_path = self.database_path.clone();
+            let bounds = self.bounds;
             
             tokio::spawn(async move {
-                if let Err(e) = Self::handle_client(stream, sessions, database).await {
+                if let Err(e) = Self::handle_client(stream, sessions, database, database_path, bounds).await {
                     eprintln!("Error handling client {}: {}", addr, e);
                 }
             });
@@ -74,6 +119,8 @@ impl MultiplayerServer {
         mut stream: TcpStream,
         sessions: Arc<Mutex<HashMap<Uuid, GameSession>>>,
         database: Arc<Mutex<CharacterDatabase>>,
+        database_path: PathBuf,
+        bounds: WorldBounds,
     ) -> Result<()> {
         let session_id = Uuid::new_v4();
         let (tx, mut rx) = mpsc::unbounded_channel();
@@ -85,6 +132,7 @@ impl MultiplayerServer {
                 id: session_id,
                 character: None,
                 authenticated: false,
+                position: None,
                 tx: tx.clone(),
             });
         }
@@ -95,8 +143,9 @@ impl MultiplayerServer {
         let (read_half, write_half) = stream.into_split();
         let mut reader = BufReader::new(read_half);
 
-        // Spawn task to handle outgoing messages
-        let sessions_for_writer = Arc::clone(&sessions);
+        // Spawn task to handle outgoing messages. It ends once the session is
+        // gone and every sender has been dropped, closing the connection.
+        drop(tx);
         let mut write_half = write_half;
         tokio::spawn(async move {
             while let Some(message) = rx.recv().await {
@@ -105,10 +154,6 @@ impl MultiplayerServer {
                     break;
                 }
             }
-            
-            // Clean up session when writer closes
-            let mut sessions_lock = sessions_for_writer.lock().await;
-            sessions_lock.remove(&session_id);
         });
 
         // Handle incoming messages
@@ -128,21 +173,248 @@ impl MultiplayerServer {
                         session_id,
                         &sessions,
                         &database,
+                        &database_path,
+                        bounds,
                     ).await {
                         eprintln!("Error handling input: {}", e);
                     }
+                    if matches!(input.to_lowercase().as_str(), "quit" | "exit") {
+                        break;
+                    }
                 }
                 Err(_) => break,
             }
         }
 
-        // Clean up session
-        let mut sessions_lock = sessions.lock().await;
-        sessions_lock.remove(&session_id);
-        
+        Self::disconnect(session_id, &sessions, &database, &database_path).await;
         Ok(())
     }
 
+    // Drop a session whichever way the client went: tell the zone they left and
+    // keep where they were standing for next time
+    async fn disconnect(
+        session_id: Uuid,
+        sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
+        database: &Arc<Mutex<CharacterDatabase>>,
+        database_path: &Path,
+    ) {
+        let session = sessions.lock().await.remove(&session_id);
+        let Some(session) = session else {
+            return;
+        };
+        let Some((name, position)) = session.player() else {
+            return;
+        };
+        println!("👋 {} disconnected", name);
+        Self::broadcast_to_zone(session_id, position, ServerMessage::PlayerLeft { name: name.to_string() }, sessions).await;
+        Self::announce(session_id, ServerMessage::PlayerQuit { name: name.to_string() }, sessions).await;
+
+        if let Some(mut character) = session.character.clone() {
+            character.current_zone = Some(position.to_zone());
+            character.current_position = Some(position.to_local());
+            let mut db_lock = database.lock().await;
+            if db_lock.update_character(name, character).is_ok() {
+                if let Err(e) = db_lock.save(database_path) {
+                    eprintln!("Failed to save {}: {}", name, e);
+                }
+            }
+        }
+    }
+
+    // Send a message to everyone else standing in the same zone as `position`
+    async fn broadcast_to_zone(
+        session_id: Uuid,
+        position: WorldCoord,
+        message: ServerMessage,
+        sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
+    ) {
+        let sessions_lock = sessions.lock().await;
+        for (id, session) in sessions_lock.iter() {
+            if *id == session_id {
+                continue;
+            }
+            if let Some((_, other)) = session.player() {
+                if other.to_zone() == position.to_zone() {
+                    let _ = session.tx.send(message.clone());
+                }
+            }
+        }
+    }
+
+    // Put a newly logged in character into the world: everyone in their zone
+    // sees them arrive and they see everyone already there
+    async fn enter_world(
+        session_id: Uuid,
+        bounds: WorldBounds,
+        sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
+    ) {
+        let arrival = {
+            let mut sessions_lock = sessions.lock().await;
+            let Some(session) = sessions_lock.get_mut(&session_id) else {
+                return;
+            };
+            session.position = session.character.as_ref().map(|character| character_position(character, bounds));
+            session.player().map(|(name, position)| (name.to_string(), position))
+        };
+        let Some((name, position)) = arrival else {
+            return;
+        };
+        println!("🎮 {} joined", name);
+        Self::announce(session_id, ServerMessage::PlayerJoined { name: name.clone() }, sessions).await;
+
+        Self::broadcast_to_zone(session_id, position, ServerMessage::PlayerMoved { name, position }, sessions).await;
+        let sessions_lock = sessions.lock().await;
+        if let Some(session) = sessions_lock.get(&session_id) {
+            for (_, other) in Self::players_in_zone(session_id, position, &sessions_lock) {
+                let _ = session.tx.send(other);
+            }
+        }
+    }
+
+    // Everyone else in the zone, as the positions a client needs to draw them
+    fn players_in_zone(
+        session_id: Uuid,
+        position: WorldCoord,
+        sessions: &HashMap<Uuid, GameSession>,
+    ) -> Vec<(WorldCoord, ServerMessage)> {
+        sessions.iter()
+            .filter(|(id, _)| **id != session_id)
+            .filter_map(|(_, session)| session.player())
+            .filter(|(_, other)| other.to_zone() == position.to_zone())
+            .map(|(name, other)| (other, ServerMessage::PlayerMoved { name: name.to_string(), position: other }))
+            .collect()
+    }
+
+    // Move the player, letting the old zone know if they cross into another.
+    // Returns where they ended up, or None if the spot is off the world.
+    async fn move_player(
+        session_id: Uuid,
+        destination: impl FnOnce(WorldCoord) -> WorldCoord,
+        bounds: WorldBounds,
+        sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
+    ) -> Result<Option<WorldCoord>> {
+        let step = {
+            let mut sessions_lock = sessions.lock().await;
+            let Some(session) = sessions_lock.get_mut(&session_id) else {
+                return Ok(None);
+            };
+            let Some((name, from)) = session.player().map(|(name, position)| (name.to_string(), position)) else {
+                return Ok(None);
+            };
+            let to = destination(from);
+            if !bounds.contains(to.to_zone()) {
+                None
+            } else {
+                session.position = Some(to);
+                Some((name, from, to))
+            }
+        };
+        let Some((name, from, to)) = step else {
+            return Ok(None);
+        };
+
+        if from.to_zone() != to.to_zone() {
+            Self::broadcast_to_zone(session_id, from, ServerMessage::PlayerLeft { name: name.clone() }, sessions).await;
+            let sessions_lock = sessions.lock().await;
+            if let Some(session) = sessions_lock.get(&session_id) {
+                for (_, other) in Self::players_in_zone(session_id, to, &sessions_lock) {
+                    let _ = session.tx.send(other);
+                }
+            }
+        }
+        Self::broadcast_to_zone(session_id, to, ServerMessage::PlayerMoved { name, position: to }, sessions).await;
+        Ok(Some(to))
+    }
+
+    // Take a step and tell the player where it got them
+    async fn step_player(
+        session_id: Uuid,
+        dx: i32,
+        dy: i32,
+        bounds: WorldBounds,
+        sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
+    ) -> Result<()> {
+        match Self::move_player(session_id, |from| WorldCoord::new(from.x + dx, from.y + dy), bounds, sessions).await? {
+            Some(to) => Self::send_system_message(session_id, &format!("You are at ({}, {}).", to.x, to.y), sessions).await,
+            None => Self::send_error(session_id, "You can't go any further that way.", sessions).await,
+        }
+    }
+
+    // Pass a chat line to everyone else in the speaker's zone
+    async fn say(
+        session_id: Uuid,
+        text: &str,
+        sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
+    ) -> Result<()> {
+        let message = sanitize_chat(text);
+        if message.is_empty() {
+            return Self::send_error(session_id, "Usage: say <message>", sessions).await;
+        }
+        let speaker = {
+            let sessions_lock = sessions.lock().await;
+            sessions_lock.get(&session_id).and_then(|session| session.player()).map(|(name, position)| (name.to_string(), position))
+        };
+        let Some((from, position)) = speaker else {
+            return Ok(());
+        };
+
+        Self::broadcast_to_zone(session_id, position, ServerMessage::ChatMessage { from, message: message.clone() }, sessions).await;
+        Self::send_system_message(session_id, &format!("You say: {}", message), sessions).await
+    }
+
+    // Tell every player, wherever they are, that someone came or went
+    async fn announce(
+        session_id: Uuid,
+        message: ServerMessage,
+        sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
+    ) {
+        let sessions_lock = sessions.lock().await;
+        for (id, session) in sessions_lock.iter() {
+            if *id != session_id && session.player().is_some() {
+                let _ = session.tx.send(message.clone());
+            }
+        }
+    }
+
+    // The tiles around the player, with everyone in sight drawn as @
+    async fn send_surroundings(
+        session_id: Uuid,
+        sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
+    ) -> Result<()> {
+        const VIEW_RADIUS_X: i32 = 10;
+        const VIEW_RADIUS_Y: i32 = 5;
+
+        let view = {
+            let sessions_lock = sessions.lock().await;
+            let Some((_, position)) = sessions_lock.get(&session_id).and_then(|session| session.player()) else {
+                return Ok(());
+            };
+            let others = Self::players_in_zone(session_id, position, &sessions_lock);
+
+            let mut view = format!("Zone ({}, {}), position ({}, {})\r\n", position.to_zone().x, position.to_zone().y, position.x, position.y);
+            for y in (position.y - VIEW_RADIUS_Y)..=(position.y + VIEW_RADIUS_Y) {
+                for x in (position.x - VIEW_RADIUS_X)..=(position.x + VIEW_RADIUS_X) {
+                    if x == position.x && y == position.y {
+                        view.push_str("\x1b[93m@\x1b[0m");
+                    } else if others.iter().any(|(other, _)| other.x == x && other.y == y) {
+                        view.push_str("\x1b[96m@\x1b[0m");
+                    } else {
+                        view.push('.');
+                    }
+                }
+                view.push_str("\r\n");
+            }
+            for (_, other) in &others {
+                if let ServerMessage::PlayerMoved { name, position } = other {
+                    view.push_str(&format!("{} is at ({}, {})\r\n", name, position.x, position.y));
+                }
+            }
+            view
+        };
+
+        Self::send_system_message(session_id, view.trim_end(), sessions).await
+    }
+
     async fn send_welcome(stream: &mut TcpStream) -> Result<()> {
         let welcome = format!("{}{}{}",
             "\x1b[2J\x1b[H", // Clear screen and home cursor
@@ -172,6 +444,8 @@ impl MultiplayerServer {
         session_id: Uuid,
         sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
         database: &Arc<Mutex<CharacterDatabase>>,
+        database_path: &Path,
+        bounds: WorldBounds,
     ) -> Result<()> {
         let parts: Vec<&str> = input.split_whitespace().collect();
         if parts.is_empty() {
@@ -188,16 +462,31 @@ impl MultiplayerServer {
                 if parts.len() >= 3 {
                     let name = parts[1];
                     let password = parts[2];
-                    Self::handle_login(session_id, name, password, sessions, database).await?;
+                    Self::handle_login(session_id, name, password, sessions, database, database_path, bounds).await?;
                 } else {
                     Self::send_error(session_id, "Usage: login <name> <password>", sessions).await?;
                 }
             }
+            "join" => {
+                // Log in, creating the character first if the server hasn't seen it
+                if parts.len() >= 3 {
+                    let name = parts[1];
+                    let password = parts[2];
+                    let known = database.lock().await.list_characters().iter().any(|character| character.0 == name);
+                    if known {
+                        Self::handle_login(session_id, name, password, sessions, database, database_path, bounds).await?;
+                    } else {
+                        Self::handle_create_character(session_id, name, password, sessions, database, database_path, bounds).await?;
+                    }
+                } else {
+                    Self::send_error(session_id, "Usage: join <name> <password>", sessions).await?;
+                }
+            }
             "create" => {
                 if parts.len() >= 3 {
                     let name = parts[1];
                     let password = parts[2];
-                    Self::handle_create_character(session_id, name, password, sessions, database).await?;
+                    Self::handle_create_character(session_id, name, password, sessions, database, database_path, bounds).await?;
                 } else {
                     Self::send_error(session_id, "Usage: create <name> <password>", sessions).await?;
                 }
@@ -213,7 +502,7 @@ impl MultiplayerServer {
                 };
 
                 if is_authenticated {
-                    Self::handle_game_command(session_id, input, sessions).await?;
+                    Self::handle_game_command(session_id, input, bounds, sessions).await?;
                 } else {
                     Self::send_error(session_id, "Please login first. Type 'help' for commands.", sessions).await?;
                 }
@@ -229,10 +518,28 @@ impl MultiplayerServer {
         password: &str,
         sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
         database: &Arc<Mutex<CharacterDatabase>>,
+        database_path: &Path,
+        bounds: WorldBounds,
     ) -> Result<()> {
+        // One connection per character, or the two would fight over its position
+        let already_playing = {
+            let sessions_lock = sessions.lock().await;
+            sessions_lock.values().any(|session| session.player().is_some_and(|(playing, _)| playing == name))
+        };
+        if already_playing {
+            return Self::send_error(session_id, &format!("{} is already playing.", name), sessions).await;
+        }
+
         let result = {
-            let db_lock = database.lock().await;
-            db_lock.authenticate(name, password)
+            let mut db_lock = database.lock().await;
+            let result = db_lock.authenticate(name, password);
+            // Keep any upgrade of an old password hash
+            if result.is_ok() {
+                if let Err(e) = db_lock.save(database_path) {
+                    eprintln!("Failed to save {}: {}", name, e);
+                }
+            }
+            result
         };
 
         match result {
@@ -247,6 +554,7 @@ impl MultiplayerServer {
                     }
                 }
                 Self::send_system_message(session_id, &format!("Welcome back, {}!", name), sessions).await?;
+                Self::enter_world(session_id, bounds, sessions).await;
             }
             Err(_) => {
                 Self::send_error(session_id, "Invalid credentials", sessions).await?;
@@ -262,6 +570,8 @@ impl MultiplayerServer {
         password: &str,
         sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
         database: &Arc<Mutex<CharacterDatabase>>,
+        database_path: &Path,
+        bounds: WorldBounds,
     ) -> Result<()> {
         // For simplicity, create a basic character
         // In a full implementation, this would be a multi-step process
@@ -288,7 +598,9 @@ impl MultiplayerServer {
                 // Save database
                 {
                     let db_lock = database.lock().await;
-                    let _ = db_lock.save(&std::path::PathBuf::from("characters.json"));
+                    if let Err(e) = db_lock.save(database_path) {
+                        eprintln!("Failed to save {}: {}", name, e);
+                    }
                 }
 
                 // Update session
@@ -301,6 +613,7 @@ impl MultiplayerServer {
                     }
                 }
                 Self::send_system_message(session_id, &format!("Character {} created successfully!", name), sessions).await?;
+                Self::enter_world(session_id, bounds, sessions).await;
             }
             Err(e) => {
                 Self::send_error(session_id, &format!("Failed to create character: {}", e), sessions).await?;
@@ -313,8 +626,15 @@ impl MultiplayerServer {
     async fn handle_game_command(
         session_id: Uuid,
         input: &str,
+        bounds: WorldBounds,
         sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
     ) -> Result<()> {
+        // Chat keeps its case, so it's picked out before the rest
+        let (command, rest) = input.split_once(' ').unwrap_or((input, ""));
+        if command.eq_ignore_ascii_case("say") {
+            return Self::say(session_id, rest, sessions).await;
+        }
+
         match input.to_lowercase().as_str() {
             "stats" | "character" => {
                 let character_info = {
@@ -330,11 +650,27 @@ impl MultiplayerServer {
                     Self::send_character_sheet(session_id, &info, sessions).await?;
                 }
             }
-            "look" => {
-                Self::send_system_message(session_id, "You are in a simple starting area. More features coming soon!", sessions).await?;
+            "look" | "who" => {
+                Self::send_surroundings(session_id, sessions).await?;
+            }
+            "n" | "north" => Self::step_player(session_id, 0, -1, bounds, sessions).await?,
+            "s" | "south" => Self::step_player(session_id, 0, 1, bounds, sessions).await?,
+            "e" | "east" => Self::step_player(session_id, 1, 0, bounds, sessions).await?,
+            "w" | "west" => Self::step_player(session_id, -1, 0, bounds, sessions).await?,
+            // Clients keep the server up to date on where their player is
+            position if position.starts_with("pos ") => {
+                let coords: Vec<i32> = position.split_whitespace().skip(1).filter_map(|part| part.parse().ok()).collect();
+                match coords[..] {
+                    [x, y] => {
+                        if Self::move_player(session_id, |_| WorldCoord::new(x, y), bounds, sessions).await?.is_none() {
+                            Self::send_error(session_id, "That position is off the world.", sessions).await?;
+                        }
+                    }
+                    _ => Self::send_error(session_id, "Usage: pos <x> <y>", sessions).await?,
+                }
             }
             _ => {
-                Self::send_error(session_id, "Unknown command. Try 'stats', 'look', or 'help'", sessions).await?;
+                Self::send_error(session_id, "Unknown command. Try 'stats', 'look', 'n/s/e/w', or 'help'", sessions).await?;
             }
         }
 
@@ -345,14 +681,17 @@ impl MultiplayerServer {
         session_id: Uuid,
         sessions: &Arc<Mutex<HashMap<Uuid, GameSession>>>,
     ) -> Result<()> {
-        let help_text = format!("{}{}{}{}{}{}{}{}{}",
+        let help_text = format!("{}{}{}{}{}{}{}{}{}{}{}{}",
             "\x1b[96m", // Bright cyan
             "=== WARLORDS COMMANDS ===\r\n",
             "\x1b[93m", // Bright yellow
             "login <name> <password>  - Login to existing character\r\n",
             "create <name> <password> - Create new character\r\n",
+            "join <name> <password>   - Login, creating the character if it's new\r\n",
             "stats                    - Show character stats\r\n",
-            "look                     - Look around\r\n",
+            "look                     - Look around and see who's nearby\r\n",
+            "n / s / e / w            - Take a step\r\n",
+            "say <message>            - Talk to everyone in your zone\r\n",
             "quit                     - Exit the game\r\n",
             "\x1b[0m" // Reset
         );
@@ -430,6 +769,14 @@ impl MultiplayerServer {
             ServerMessage::CharacterCreated { .. } => {
                 format!("\x1b[92mCharacter created!\x1b[0m\r\n> ")
             }
+            // One line per update so clients can parse them as they arrive
+            ServerMessage::PlayerMoved { name, position } => {
+                format!("PLAYER {} {} {}\r\n", name, position.x, position.y)
+            }
+            ServerMessage::PlayerLeft { name } => format!("LEFT {}\r\n", name),
+            ServerMessage::PlayerJoined { name } => format!("JOINED {}\r\n", name),
+            ServerMessage::PlayerQuit { name } => format!("QUIT {}\r\n", name),
+            ServerMessage::ChatMessage { from, message } => format!("CHAT {} {}\r\n", from, message),
             _ => format!("{}\r\n> ", serde_json::to_string(message).unwrap_or_default()),
         }
     }
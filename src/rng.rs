@@ -0,0 +1,65 @@
+use std::cell::RefCell;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Every roll made during play comes from one seeded generator. The event log
+// stores the seed, so a replay started from the same saves rolls the same dice.
+thread_local! {
+    static SESSION: RefCell<(u64, ChaCha8Rng)> = RefCell::new(seeded(rand::thread_rng().next_u64()));
+}
+
+fn seeded(seed: u64) -> (u64, ChaCha8Rng) {
+    (seed, ChaCha8Rng::seed_from_u64(seed))
+}
+
+// The seed the session's rolls started from
+pub fn seed() -> u64 {
+    SESSION.with(|session| session.borrow().0)
+}
+
+// Start the rolls over from the beginning of the given seed
+pub fn restart(seed: u64) {
+    SESSION.with(|session| *session.borrow_mut() = seeded(seed));
+}
+
+// Stands in for `rand::thread_rng()` wherever the game rolls dice
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SessionRng;
+
+pub fn session_rng() -> SessionRng {
+    SessionRng
+}
+
+impl RngCore for SessionRng {
+    fn next_u32(&mut self) -> u32 {
+        SESSION.with(|session| session.borrow_mut().1.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        SESSION.with(|session| session.borrow_mut().1.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        SESSION.with(|session| session.borrow_mut().1.fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        SESSION.with(|session| session.borrow_mut().1.try_fill_bytes(dest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn restarting_a_seed_rolls_the_same_dice() {
+        let roll = || (0..20).map(|_| session_rng().gen_range(1..=20)).collect::<Vec<u32>>();
+        restart(77);
+        let first = roll();
+        restart(77);
+        assert_eq!(roll(), first);
+        assert_eq!(seed(), 77);
+    }
+}
@@ -1,5 +1,6 @@
 use warlords::network::MultiplayerServer;
 use warlords::database::CharacterDatabase;
+use warlords::world::{WorldManager, DEFAULT_WORLD_NAME};
 use std::path::PathBuf;
 use clap::{Arg, Command};
 
@@ -20,6 +21,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .value_name("FILE")
             .help("Database file path")
             .default_value("characters.json"))
+        .arg(Arg::new("world-dir")
+            .short('w')
+            .long("world-dir")
+            .value_name("DIR")
+            .help("Directory holding the world the players share")
+            .default_value("world_data"))
         .get_matches();
 
     let port: u16 = matches.get_one::<String>("port").unwrap().parse()?;
@@ -27,8 +34,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     println!("🎮 Loading character database from: {:?}", db_path);
     let database = CharacterDatabase::load_or_create(&db_path)?;
+
+    // Positions are checked against the size of the world the game made
+    let world_dir = PathBuf::from(matches.get_one::<String>("world-dir").unwrap());
+    let bounds = WorldManager::saved_bounds(DEFAULT_WORLD_NAME, &world_dir)?;
+    println!("🗺️  World is {} by {} zones", bounds.width_zones, bounds.height_zones);
     
-    let server = MultiplayerServer::new(database);
+    let server = MultiplayerServer::new(database, db_path, bounds);
     
     println!("🚀 Starting Warlords Multiplayer Server...");
     println!("🌐 Connect with: telnet localhost {}", port);
@@ -2,10 +2,10 @@ use tokio::net::{TcpListener, TcpStream};
 use tokio::io::{AsyncWriteExt, AsyncBufReadExt, BufReader};
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::sync::Mutex;
 use warlords::forge::ForgeCharacterCreation;
-use warlords::database::CharacterDatabase;
+use warlords::database::{CharacterDatabase, SaveProfile};
 use anyhow::Result;
 use clap::{Arg, Command};
 
@@ -18,14 +18,19 @@ struct SimpleSession {
 struct SimpleServer {
     sessions: Arc<Mutex<HashMap<String, SimpleSession>>>,
     database: Arc<Mutex<CharacterDatabase>>,
+    database_path: Arc<PathBuf>,
 }
 
 impl SimpleServer {
-    fn new() -> Result<Self> {
-        let database = CharacterDatabase::load_or_create(&PathBuf::from("characters.json"))?;
+    // Characters are kept in the same place the local game keeps them for this profile
+    fn new(profile: &SaveProfile) -> Result<Self> {
+        profile.prepare()?;
+        let database_path = profile.database_path();
+        let database = CharacterDatabase::load_or_create(&database_path)?;
         Ok(Self {
             sessions: Arc::new(Mutex::new(HashMap::new())),
             database: Arc::new(Mutex::new(database)),
+            database_path: Arc::new(database_path),
         })
     }
 
@@ -41,9 +46,10 @@ impl SimpleServer {
             
             let sessions = Arc::clone(&self.sessions);
             let database = Arc::clone(&self.database);
+            let database_path = Arc::clone(&self.database_path);
             
             tokio::spawn(async move {
-                if let Err(e) = Self::handle_client(stream, sessions, database).await {
+                if let Err(e) = Self::handle_client(stream, sessions, database, &database_path).await {
                     eprintln!("Error handling client {}: {}", addr, e);
                 }
             });
@@ -54,6 +60,7 @@ impl SimpleServer {
         mut stream: TcpStream,
         sessions: Arc<Mutex<HashMap<String, SimpleSession>>>,
         database: Arc<Mutex<CharacterDatabase>>,
+        database_path: &Path,
     ) -> Result<()> {
         let addr = stream.peer_addr()?.to_string();
         
@@ -91,7 +98,7 @@ impl SimpleServer {
                         continue;
                     }
 
-                    let response = Self::handle_command(input, &addr, &sessions, &database).await;
+                    let response = Self::handle_command(input, &addr, &sessions, &database, database_path).await;
                     
                     match response {
                         Ok(Some(msg)) => {
@@ -122,6 +129,7 @@ impl SimpleServer {
         addr: &str,
         sessions: &Arc<Mutex<HashMap<String, SimpleSession>>>,
         database: &Arc<Mutex<CharacterDatabase>>,
+        database_path: &Path,
     ) -> Result<Option<String>> {
         let parts: Vec<&str> = input.split_whitespace().collect();
         if parts.is_empty() {
@@ -166,7 +174,9 @@ impl SimpleServer {
                     let mut db_lock = database.lock().await;
                     match db_lock.create_character(name.to_string(), password.to_string(), character) {
                         Ok(()) => {
-                            let _ = db_lock.save(&PathBuf::from("characters.json"));
+                            if let Err(e) = db_lock.save(database_path) {
+                                eprintln!("Failed to save {}: {}", name, e);
+                            }
                             
                             // Update session
                             let mut sessions_lock = sessions.lock().await;
@@ -190,8 +200,15 @@ impl SimpleServer {
                 let password = parts[2];
                 
                 let result = {
-                    let db_lock = database.lock().await;
-                    db_lock.authenticate(name, password)
+                    let mut db_lock = database.lock().await;
+                    let result = db_lock.authenticate(name, password);
+                    // Keep any upgrade of an old password hash
+                    if result.is_ok() {
+                        if let Err(e) = db_lock.save(database_path) {
+                            eprintln!("Failed to save {}: {}", name, e);
+                        }
+                    }
+                    result
                 };
 
                 match result {
@@ -216,7 +233,7 @@ impl SimpleServer {
                     if session.authenticated {
                         if let Some(char_name) = &session.character_name {
                             let character_info = {
-                                let db_lock = database.lock().await;
+                                let mut db_lock = database.lock().await;
                                 db_lock.authenticate(char_name, "").ok() // We'll improve auth later
                             };
 
@@ -264,8 +281,8 @@ impl SimpleServer {
                 if characters.is_empty() {
                     result.push_str("No characters found.");
                 } else {
-                    for (name, last_played) in characters {
-                        result.push_str(&format!("• {} (last played: {})\r\n", name, last_played.format("%Y-%m-%d %H:%M")));
+                    for (name, last_played, stats) in characters {
+                        result.push_str(&format!("• {} (last played: {}, played {})\r\n", name, last_played.format("%Y-%m-%d %H:%M"), stats.describe_playtime()));
                     }
                 }
                 Ok(Some(result))
@@ -291,11 +308,16 @@ async fn main() -> Result<()> {
             .value_name("PORT")
             .help("Port to listen on")
             .default_value("2323"))
+        .arg(Arg::new("profile")
+            .long("profile")
+            .value_name("NAME")
+            .help("Serve the characters under profiles/NAME instead of the default saves"))
         .get_matches();
 
     let port: u16 = matches.get_one::<String>("port").unwrap().parse()?;
+    let profile = SaveProfile::new(matches.get_one::<String>("profile").map(String::as_str))?;
     
-    let server = SimpleServer::new()?;
+    let server = SimpleServer::new(&profile)?;
     server.start(port).await?;
     
     Ok(())
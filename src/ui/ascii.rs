@@ -0,0 +1,88 @@
+use ratatui::buffer::Buffer;
+
+pub const ASCII_TITLE_ART: [&str; 6] = [
+    "__        __             _               _     ",
+    "\\ \\      / /_ _ _ __ ___| |    ___  _ __| |___ ",
+    " \\ \\ /\\ / / _` | '__/ __| |   / _ \\| '__| / __|",
+    "  \\ V  V / (_| | |  \\__ \\ |__| (_) | |  | \\__ \\",
+    "   \\_/\\_/ \\__,_|_|  |___/_____\\___/|_|  |_|___/",
+    "                                               ",
+];
+
+// Guess whether the terminal can show emoji and box drawing. The Linux console,
+// dumb terminals, non UTF-8 locales and the legacy Windows console all struggle.
+pub fn terminal_prefers_ascii() -> bool {
+    let term = std::env::var("TERM").unwrap_or_default();
+    if matches!(term.as_str(), "linux" | "dumb" | "vt100" | "vt102" | "vt220" | "ansi") {
+        return true;
+    }
+
+    if cfg!(windows) {
+        // Windows Terminal and most third party terminals announce themselves
+        return std::env::var_os("WT_SESSION").is_none() && std::env::var_os("TERM_PROGRAM").is_none();
+    }
+
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"].iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default()
+        .to_lowercase();
+    !(locale.contains("utf-8") || locale.contains("utf8"))
+}
+
+// Swap every non-ASCII symbol in a drawn frame for a plain ASCII stand-in
+pub fn asciify_buffer(buffer: &mut Buffer) {
+    let area = buffer.area;
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let cell = buffer.get_mut(x, y);
+            if !cell.symbol.is_ascii() {
+                let replacement = cell.symbol.chars()
+                    .find(|&c| c != '\u{fe0f}')
+                    .map(ascii_for)
+                    .unwrap_or(' ');
+                cell.set_char(replacement);
+            }
+        }
+    }
+}
+
+fn ascii_for(c: char) -> char {
+    match c {
+        // Box drawing
+        '─' | '━' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' => '-',
+        '═' => '=',
+        '│' | '┃' | '║' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' => '|',
+        '\u{2500}'..='\u{257f}' => '+',
+        // Blocks and shades
+        '\u{2580}'..='\u{259f}' => '#',
+        // Bullets, arrows and shapes
+        '·' | '•' | '◦' => '.',
+        '●' | '○' | '◉' | '◘' => 'o',
+        '▶' | '►' => '>',
+        '▲' | '↑' | '⬆' => '^',
+        '▼' | '↓' | '⬇' => 'v',
+        '≈' => '~',
+        '≡' => '=',
+        '×' => 'x',
+        '°' => 'o',
+        '∩' => 'n',
+        '⌂' => 'n',
+        '◊' | '♦' | '⬟' => '*',
+        '✓' | '✅' => 'v',
+        '❌' => 'x',
+        '❓' | '❔' => '?',
+        '⚠' | '🚨' => '!',
+        '⚔' | '💥' => 'X',
+        '🛡' => ']',
+        '💰' | '💎' => '$',
+        '❤' | '💚' => '<',
+        '🔍' => '?',
+        '🎲' => '#',
+        '🎯' | '🏹' => '+',
+        '🌊' | '💧' => '~',
+        '🔥' => '^',
+        '💀' | '☠' | '🪦' => '%',
+        _ => '*',
+    }
+}
@@ -9,16 +9,27 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Style, Modifier},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, List, ListItem},
+    widgets::{Block, Borders, Gauge, Paragraph, List, ListItem},
     Frame, Terminal,
 };
 use std::io::{self, Stdout};
-use crate::forge::{RolledCharacteristics, ForgeRace};
+use crate::forge::{RolledCharacteristics, ForgeRace, ForgeCharacterCreation};
+
+pub mod theme;
+pub mod tileset;
+pub mod ascii;
+
+pub use theme::*;
+pub use tileset::*;
+pub use ascii::*;
 
 pub type TerminalType = Terminal<CrosstermBackend<Stdout>>;
 
 pub struct GameUI {
     terminal: TerminalType,
+    theme: Theme,
+    tileset: Tileset,
+    ascii: bool, // Replace emoji and box drawing with plain ASCII when drawing
 }
 
 #[derive(Debug, Clone)]
@@ -27,12 +38,43 @@ pub enum UIState {
     MainMenu,
     CharacterLogin,
     CharacterCreation(CharacterCreationState),
-    CharacterList(Vec<(String, chrono::DateTime<chrono::Utc>)>, Option<usize>), // characters, selected_index
+    CharacterList(Vec<(String, chrono::DateTime<chrono::Utc>, crate::forge::PlayStats)>, Option<usize>), // characters, selected_index
+    CharacterDelete(String, usize, bool), // character name, list index to return to, last password was wrong
+    CharacterUnlock(String, usize, bool), // Logging in from the list: character name, list index to return to, last password was wrong
     Playing,
     CharacterMenu,
     WorldExploration(WorldExplorationState),
     DungeonExploration(DungeonExplorationState),
     Combat(CombatState),
+    Death(DeathState),
+    Bestiary,
+    Quests,
+    Inventory(InventoryState),
+    WorldMap(WorldMapState),
+}
+
+impl UIState {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            UIState::Welcome => "Welcome",
+            UIState::MainMenu => "Main Menu",
+            UIState::CharacterLogin => "Character Login",
+            UIState::CharacterCreation(_) => "Character Creation",
+            UIState::CharacterList(..) => "Character List",
+            UIState::CharacterDelete(..) => "Delete Character",
+            UIState::CharacterUnlock(..) => "Character Password",
+            UIState::Playing => "Playing",
+            UIState::CharacterMenu => "Character Menu",
+            UIState::WorldExploration(_) => "World Exploration",
+            UIState::DungeonExploration(_) => "Dungeon Exploration",
+            UIState::Combat(_) => "Combat",
+            UIState::Death(_) => "Death",
+            UIState::Bestiary => "Bestiary",
+            UIState::Quests => "Quests",
+            UIState::Inventory(_) => "Inventory",
+            UIState::WorldMap(_) => "World Map",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -40,15 +82,30 @@ pub struct WorldExplorationState {
     pub current_zone: crate::world::ZoneCoord,
     pub player_local_pos: crate::world::LocalCoord,
     pub zone_data: Option<crate::world::WorldZone>,
+    pub region: Option<crate::world::Region>,
     pub messages: Vec<String>,
+    pub message_scroll: usize, // Messages scrolled back from the newest
+    pub look_prompt: bool, // Waiting for a direction to look in
+    pub conversation: Option<crate::world::Conversation>, // Talking to an NPC of this zone
+    pub chat_prompt: bool, // Typing a chat line to other players
+    pub other_players: Vec<crate::world::WorldCoord>, // Where the other players online are, drawn as @
 }
 
 #[derive(Debug, Clone)]
 pub struct DungeonExplorationState {
     pub dungeon: crate::world::DungeonLayout,
     pub player_pos: crate::world::LocalCoord,
+    pub zone: crate::world::ZoneCoord, // Overworld zone holding the entrance
+    pub poi_position: crate::world::LocalCoord,
     pub messages: Vec<String>,
+    pub message_scroll: usize, // Messages scrolled back from the newest
     pub turn_count: u32,
+    pub stair_prompt: bool, // Waiting for up or down on a spiral staircase
+    pub retreat_prompt: bool, // Waiting for a direction to run after fleeing a fight
+    pub ranged_targets: Vec<crate::world::LocalCoord>, // Creatures to pick from for a ranged shot, nearest first
+    pub safe_turns: u32, // Turns left before creatures will start another fight
+    pub look_prompt: bool, // Waiting for a direction to look in
+    pub corpse_menu: Option<crate::world::LocalCoord>, // Corpse whose actions are listed, waiting for a pick
 }
 
 #[derive(Debug, Clone)]
@@ -59,16 +116,71 @@ pub struct CombatState {
     pub selected_skill: Option<String>,
     pub combat_phase: CombatPhase,
     pub return_to_dungeon: Option<DungeonExplorationState>,
+    pub return_to_world: Option<WorldExplorationState>,
     pub current_skill_index: usize,
     pub skill_list_offset: usize, // For scrolling through long lists
+    pub log_scroll: usize, // Log lines scrolled back from the newest
+    pub summary: Option<CombatSummary>, // Results shown once the fight is over
+}
+
+impl CombatState {
+    // Healing and buffing spells are aimed at the player's own side
+    pub fn targets_allies(&self) -> bool {
+        self.selected_skill.as_deref()
+            .and_then(|skill| skill.strip_prefix("Cast "))
+            .and_then(|spell_name| crate::forge::spell_book().get(spell_name))
+            .map(|spell| spell.targets_allies())
+            .unwrap_or(false)
+    }
+
+    // Participant indices the player can pick from with the number keys
+    pub fn target_candidates(&self) -> Vec<usize> {
+        self.encounter.target_candidates(self.targets_allies())
+    }
+}
+
+// What the player got out of a finished fight
+#[derive(Debug, Clone, Default)]
+pub struct CombatSummary {
+    pub victory: bool,
+    pub experience: u32,
+    pub new_levels: Vec<u8>,
+    pub gold: u32,
+    pub items: Vec<String>,
+    pub notes: Vec<String>, // Ammo recovered, corpses left behind and so on
+}
+
+// A zoomed-out look at the zones the character has been through
+#[derive(Debug, Clone)]
+pub struct WorldMapState {
+    pub world_state: WorldExplorationState, // Where to go back to once the map is closed
+    pub cells: Vec<Vec<Option<crate::world::TerrainType>>>, // Sampled terrain by row, None for unvisited zones
+    pub player_cell: (usize, usize), // Column and row of the @
+}
+
+// Browsing the pack from the character menu
+#[derive(Debug, Clone, Default)]
+pub struct InventoryState {
+    pub selected: usize, // Index into the character's inventory summary
+    pub message: Option<String>, // What the last action did
+}
+
+// Shown once the player has fallen in battle
+#[derive(Debug, Clone)]
+pub struct DeathState {
+    pub character_name: String,
+    pub permadeath: bool,        // The character has been deleted for good
+    pub messages: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
 pub enum CombatPhase {
+    Assessment,            // Sizing up the enemy before committing to the fight
     InitiativeRoll,        // Rolling initiative for all participants
     DeclaringActions,      // All participants declare their actions
     SelectingSkill,        // Player selecting skill/spell/action
     SelectingTarget,       // Player selecting target for action
+    ConfirmingAction { target_index: usize, preview: Vec<String> }, // Last look at the odds before committing
     ResolvingActions,      // Executing all declared actions
     RoundComplete,         // Round finished, preparing for next
     CombatComplete(bool),  // Combat over, true if player won
@@ -80,6 +192,7 @@ pub struct CharacterCreationState {
     pub rolled_data: Option<RolledCharacteristics>,
     pub selected_race: Option<ForgeRace>,
     pub character_name: Option<String>,
+    pub password: Option<String>, // Chosen after the name, needed to log back in
     pub selected_skills: Vec<String>,
     pub available_skill_points: u8,
     pub selected_spells: Vec<(String, crate::forge::magic::MagicSchool)>,
@@ -92,6 +205,66 @@ pub struct CharacterCreationState {
     pub available_gear_list: Vec<(String, u32)>, // (item name, cost in gold)
     pub starting_gold: u32,
     pub spent_gold: u32,
+    pub difficulty: crate::forge::Difficulty,
+    pub list_filter: String, // Typed text narrowing the skill and gear lists
+    pub point_buy: Option<[u8; 9]>, // Points put into each characteristic when buying instead of rolling
+    pub rerolls_left: u8,
+}
+
+impl CharacterCreationState {
+    fn matches_filter(&self, name: &str) -> bool {
+        name.to_lowercase().contains(&self.list_filter.to_lowercase())
+    }
+
+    pub fn filtered_skills(&self) -> Vec<String> {
+        self.available_skills_list.iter()
+            .filter(|skill| self.matches_filter(skill))
+            .cloned()
+            .collect()
+    }
+
+    pub fn filtered_gear(&self) -> Vec<(String, u32)> {
+        self.available_gear_list.iter()
+            .filter(|(gear_name, _)| self.matches_filter(gear_name))
+            .cloned()
+            .collect()
+    }
+
+    pub fn remaining_gold(&self) -> u32 {
+        self.starting_gold.saturating_sub(self.spent_gold)
+    }
+
+    pub fn can_afford(&self, cost: u32) -> bool {
+        self.spent_gold.saturating_add(cost) <= self.starting_gold
+    }
+
+    // Buy a piece of gear, or hand it back for a refund if it was already picked.
+    // Returns false when it can't be afforded.
+    pub fn toggle_gear(&mut self, gear_name: &str, cost: u32) -> bool {
+        let bought = if self.selected_gear.iter().any(|g| g == gear_name) {
+            self.selected_gear.retain(|g| g != gear_name);
+            self.spent_gold = self.spent_gold.saturating_sub(cost);
+            true
+        } else if self.can_afford(cost) {
+            self.selected_gear.push(gear_name.to_string());
+            self.spent_gold = self.spent_gold.saturating_add(cost);
+            true
+        } else {
+            false
+        };
+        self.reconcile_gold();
+        bought
+    }
+
+    // Recompute spent gold from the selected gear so the two can never drift apart
+    pub fn reconcile_gold(&mut self) {
+        let total: u32 = self.selected_gear.iter()
+            .filter_map(|gear_name| self.available_gear_list.iter().find(|(name, _)| name == gear_name))
+            .map(|(_, cost)| *cost)
+            .sum();
+        debug_assert_eq!(total, self.spent_gold, "spent gold out of sync with selected gear");
+        self.spent_gold = total.min(self.starting_gold);
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -99,6 +272,7 @@ pub enum CreationStep {
     Rolling,
     RaceSelection,
     NameEntry,
+    PasswordEntry,
     SkillSelection,
     SpellSelection,
     GearSelection,
@@ -107,6 +281,23 @@ pub enum CreationStep {
 
 impl GameUI {
     pub fn new() -> anyhow::Result<Self> {
+        // Load the color theme before the terminal is taken over so problems can be reported
+        let theme = Theme::load(std::path::Path::new(THEME_CONFIG_PATH)).unwrap_or_else(|e| {
+            eprintln!("⚠️  {} - using the default theme", e);
+            Theme::default()
+        });
+        let tileset_path = std::path::Path::new(TILESET_CONFIG_PATH);
+        let mut tileset = Tileset::load(tileset_path).unwrap_or_else(|e| {
+            eprintln!("⚠️  {} - using the default tileset", e);
+            Tileset::default()
+        });
+        
+        // Without an explicit tileset, fall back to ASCII on terminals that can't show unicode
+        if !tileset_path.exists() && terminal_prefers_ascii() {
+            tileset = Tileset::preset("ascii").unwrap_or_default();
+        }
+        let ascii = tileset.name == "ascii";
+        
         // Try to enable raw mode with better error handling
         terminal::enable_raw_mode()
             .map_err(|e| {
@@ -133,7 +324,18 @@ impl GameUI {
         terminal.clear()
             .map_err(|e| anyhow::anyhow!("Failed to clear terminal: {}", e))?;
         
-        Ok(GameUI { terminal })
+        Ok(GameUI { terminal, theme, tileset, ascii })
+    }
+
+    pub fn tileset(&self) -> &Tileset {
+        &self.tileset
+    }
+
+    pub fn enable_ascii_mode(&mut self) {
+        self.ascii = true;
+        if self.tileset.name != "ascii" {
+            self.tileset = Tileset::preset("ascii").unwrap_or_default();
+        }
     }
 
     pub fn cleanup(&mut self) -> anyhow::Result<()> {
@@ -146,35 +348,51 @@ impl GameUI {
         let state_clone = state.clone();
         let input_clone = input_buffer.to_string();
         let character_clone = current_character.cloned();
+        let theme = self.theme.clone();
+        let tileset = self.tileset.clone();
+        let ascii = self.ascii;
         self.terminal.draw(move |f| {
+            let theme = &theme;
+            let tileset = &tileset;
             match &state_clone {
-                UIState::Welcome => Self::draw_welcome_static(f),
-                UIState::MainMenu => Self::draw_main_menu_static(f, character_clone.as_ref()),
-                UIState::CharacterLogin => Self::draw_character_login_static(f, &input_clone),
-                UIState::CharacterCreation(creation_state) => Self::draw_character_creation_static(f, creation_state, &input_clone),
-                UIState::CharacterList(character_list, selected_index) => Self::draw_character_list_static(f, Some(character_list), *selected_index),
-                UIState::Playing => Self::draw_game_static(f, character_clone.as_ref()),
-                UIState::CharacterMenu => Self::draw_character_menu_static(f, character_clone.as_ref()),
-                UIState::WorldExploration(world_state) => Self::draw_world_exploration_static(f, world_state, character_clone.as_ref()),
-                UIState::DungeonExploration(dungeon_state) => Self::draw_dungeon_exploration_static(f, dungeon_state, character_clone.as_ref()),
-                UIState::Combat(combat_state) => Self::draw_combat_static(f, combat_state),
+                UIState::Welcome => Self::draw_welcome_static(f, theme, ascii),
+                UIState::MainMenu => Self::draw_main_menu_static(f, theme, character_clone.as_ref()),
+                UIState::CharacterLogin => Self::draw_character_login_static(f, theme, &input_clone),
+                UIState::CharacterCreation(creation_state) => Self::draw_character_creation_static(f, theme, creation_state, &input_clone),
+                UIState::CharacterList(character_list, selected_index) => Self::draw_character_list_static(f, theme, Some(character_list), *selected_index),
+                UIState::CharacterDelete(name, _, wrong_password) => Self::draw_character_delete_static(f, theme, name, *wrong_password, &input_clone),
+                UIState::CharacterUnlock(name, _, wrong_password) => Self::draw_character_unlock_static(f, theme, name, *wrong_password, &input_clone),
+                UIState::Playing => Self::draw_game_static(f, theme, character_clone.as_ref()),
+                UIState::CharacterMenu => Self::draw_character_menu_static(f, theme, character_clone.as_ref()),
+                UIState::WorldExploration(world_state) => Self::draw_world_exploration_static(f, theme, tileset, world_state, &input_clone, character_clone.as_ref()),
+                UIState::DungeonExploration(dungeon_state) => Self::draw_dungeon_exploration_static(f, theme, tileset, dungeon_state, character_clone.as_ref()),
+                UIState::Combat(combat_state) => Self::draw_combat_static(f, theme, combat_state, character_clone.as_ref()),
+                UIState::Death(death_state) => Self::draw_death_static(f, theme, death_state),
+                UIState::Bestiary => Self::draw_bestiary_static(f, theme, character_clone.as_ref()),
+                UIState::Quests => Self::draw_quests_static(f, theme, character_clone.as_ref()),
+                UIState::Inventory(inventory_state) => Self::draw_inventory_static(f, theme, inventory_state, character_clone.as_ref()),
+                UIState::WorldMap(map_state) => Self::draw_world_map_static(f, theme, map_state),
+            }
+            
+            if ascii {
+                asciify_buffer(f.buffer_mut());
             }
         })?;
         Ok(())
     }
 
-    fn draw_welcome_static(f: &mut Frame) {
+    fn draw_welcome_static(f: &mut Frame, theme: &Theme, ascii: bool) {
         let area = f.size();
         
         // Create beautiful ASCII art title
-        let title_art = vec![
+        let title_art = if ascii { ASCII_TITLE_ART.to_vec() } else { vec![
             "██╗    ██╗ █████╗ ██████╗ ██╗      ██████╗ ██████╗ ██████╗ ███████╗",
             "██║    ██║██╔══██╗██╔══██╗██║     ██╔═══██╗██╔══██╗██╔══██╗██╔════╝",
             "██║ █╗ ██║███████║██████╔╝██║     ██║   ██║██████╔╝██║  ██║███████╗",
             "██║███╗██║██╔══██║██╔══██╗██║     ██║   ██║██╔══██╗██║  ██║╚════██║",
             "╚███╔███╔╝██║  ██║██║  ██║███████╗╚██████╔╝██║  ██║██████╔╝███████║",
             " ╚══╝╚══╝ ╚═╝  ╚═╝╚═╝  ╚═╝╚══════╝ ╚═════╝ ╚═╝  ╚═╝╚═════╝ ╚══════╝",
-        ];
+        ] };
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -189,19 +407,19 @@ impl GameUI {
 
         // Title
         let title_lines: Vec<Line> = title_art.iter()
-            .map(|line| Line::from(Span::styled(*line, Style::default().fg(Color::Yellow))))
+            .map(|line| Line::from(Span::styled(*line, Style::default().fg(theme.title))))
             .collect();
         
         let title = Paragraph::new(title_lines)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)))
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)))
             .alignment(Alignment::Center);
         f.render_widget(title, chunks[1]);
 
         // Subtitle
         let subtitle = Paragraph::new("A Forge: Out of Chaos Adventure")
-            .style(Style::default().fg(Color::Cyan))
+            .style(Style::default().fg(theme.accent))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.info)));
         f.render_widget(subtitle, chunks[2]);
 
         // Story intro
@@ -209,14 +427,14 @@ impl GameUI {
             Line::from("From humble farm worker to mighty warlord,"),
             Line::from("your destiny awaits in the realm of chaos!"),
             Line::from(""),
-            Line::from(Span::styled("Press any key to continue...", Style::default().fg(Color::Green))),
+            Line::from(Span::styled("Press any key to continue...", Style::default().fg(theme.success))),
         ])
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Green)));
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.success)));
         f.render_widget(story, chunks[4]);
     }
 
-    fn draw_main_menu_static(f: &mut Frame, current_character: Option<&crate::forge::ForgeCharacter>) {
+    fn draw_main_menu_static(f: &mut Frame, theme: &Theme, current_character: Option<&crate::forge::ForgeCharacter>) {
         let area = f.size();
         
         let chunks = Layout::default()
@@ -235,9 +453,9 @@ impl GameUI {
             "WARLORDS MAIN MENU".to_string()
         };
         let title = Paragraph::new(title_text)
-            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
         f.render_widget(title, chunks[0]);
 
         // Menu options - different based on whether character is logged in
@@ -249,7 +467,7 @@ impl GameUI {
                 ListItem::new("4. Logout & Switch Character"),
                 ListItem::new("5. Quit"),
                 ListItem::new(""),
-                ListItem::new(Span::styled("Select an option (1-5):", Style::default().fg(Color::Green))),
+                ListItem::new(Span::styled("Select an option (1-5):", Style::default().fg(theme.success))),
             ]
         } else {
             vec![
@@ -258,13 +476,13 @@ impl GameUI {
                 ListItem::new("3. List Characters"),
                 ListItem::new("4. Quit"),
                 ListItem::new(""),
-                ListItem::new(Span::styled("Select an option (1-4):", Style::default().fg(Color::Green))),
+                ListItem::new(Span::styled("Select an option (1-4):", Style::default().fg(theme.success))),
             ]
         };
 
         let menu = List::new(menu_items)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::White)))
-            .style(Style::default().fg(Color::White));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.text)))
+            .style(Style::default().fg(theme.text));
         f.render_widget(menu, chunks[1]);
 
         // Instructions
@@ -274,13 +492,13 @@ impl GameUI {
             Paragraph::new("Enter your choice and press ENTER | Q/Ctrl+C: Quit")
         };
         let instructions = instructions
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.muted)));
         f.render_widget(instructions, chunks[2]);
     }
 
-    fn draw_character_login_static(f: &mut Frame, input_buffer: &str) {
+    fn draw_character_login_static(f: &mut Frame, theme: &Theme, input_buffer: &str) {
         let area = f.size();
         
         let chunks = Layout::default()
@@ -292,54 +510,55 @@ impl GameUI {
             .split(area);
 
         let title = Paragraph::new("CHARACTER LOGIN")
-            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.accent)));
         f.render_widget(title, chunks[0]);
 
         let mut content_lines = vec![
             Line::from(""),
             Line::from("Enter character name and password"),
-            Line::from(Span::styled("Format: name:password", Style::default().fg(Color::Yellow))),
-            Line::from(Span::styled("Example: Aldric:mypassword", Style::default().fg(Color::DarkGray))),
+            Line::from(Span::styled("Format: name:password", Style::default().fg(theme.title))),
+            Line::from(Span::styled("Example: Aldric:mypassword", Style::default().fg(theme.muted))),
             Line::from(""),
-            Line::from(Span::styled("Type 'back' to return to main menu", Style::default().fg(Color::Green))),
+            Line::from(Span::styled("Type 'back' to return to main menu", Style::default().fg(theme.success))),
             Line::from(""),
             Line::from("Character login: "),
         ];
 
         // Add input line
         let input_line = if input_buffer.is_empty() {
-            Line::from(Span::styled("▶ _", Style::default().fg(Color::Yellow)))
+            Line::from(Span::styled("▶ _", Style::default().fg(theme.title)))
         } else {
             Line::from(vec![
-                Span::styled("▶ ", Style::default().fg(Color::Yellow)),
-                Span::styled(input_buffer, Style::default().fg(Color::White)),
-                Span::styled("_", Style::default().fg(Color::Yellow)),
+                Span::styled("▶ ", Style::default().fg(theme.title)),
+                Span::styled(input_buffer, Style::default().fg(theme.text)),
+                Span::styled("_", Style::default().fg(theme.title)),
             ])
         };
         content_lines.push(input_line);
 
         let content = Paragraph::new(content_lines)
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::White)));
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.text)));
         f.render_widget(content, chunks[1]);
     }
 
-    fn draw_character_creation_static(f: &mut Frame, creation_state: &CharacterCreationState, input_buffer: &str) {
+    fn draw_character_creation_static(f: &mut Frame, theme: &Theme, creation_state: &CharacterCreationState, input_buffer: &str) {
         
         match creation_state.step {
-            CreationStep::Rolling => Self::draw_characteristic_rolling_static(f, creation_state),
-            CreationStep::RaceSelection => Self::draw_race_selection_static(f),
-            CreationStep::NameEntry => Self::draw_name_entry_static(f, creation_state, input_buffer),
-            CreationStep::SkillSelection => Self::draw_skill_selection_static(f, creation_state),
-            CreationStep::SpellSelection => Self::draw_spell_selection_static(f, creation_state),
-            CreationStep::GearSelection => Self::draw_gear_selection_static(f, creation_state),
-            CreationStep::Confirmation => Self::draw_character_confirmation_static(f, creation_state),
+            CreationStep::Rolling => Self::draw_characteristic_rolling_static(f, theme, creation_state),
+            CreationStep::RaceSelection => Self::draw_race_selection_static(f, theme),
+            CreationStep::NameEntry => Self::draw_name_entry_static(f, theme, creation_state, input_buffer),
+            CreationStep::PasswordEntry => Self::draw_password_entry_static(f, theme, creation_state, input_buffer),
+            CreationStep::SkillSelection => Self::draw_skill_selection_static(f, theme, creation_state),
+            CreationStep::SpellSelection => Self::draw_spell_selection_static(f, theme, creation_state),
+            CreationStep::GearSelection => Self::draw_gear_selection_static(f, theme, creation_state),
+            CreationStep::Confirmation => Self::draw_character_confirmation_static(f, theme, creation_state),
         }
     }
 
-    fn draw_characteristic_rolling_static(f: &mut Frame, creation_state: &CharacterCreationState) {
+    fn draw_characteristic_rolling_static(f: &mut Frame, theme: &Theme, creation_state: &CharacterCreationState) {
         let area = f.size();
         
         let chunks = Layout::default()
@@ -358,13 +577,33 @@ impl GameUI {
 
         // Title
         let title = Paragraph::new("Forge: Out of Chaos - Character Creation")
-            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
         f.render_widget(title, left_chunks[0]);
 
         // Main content
-        let content = if let Some(rolled_data) = &creation_state.rolled_data {
+        let content = if let Some(allocation) = &creation_state.point_buy {
+            let spent: u8 = allocation.iter().sum();
+            let mut lines = vec![
+                Line::from(Span::styled("Buy Your Characteristics:", Style::default().add_modifier(Modifier::BOLD))),
+                Line::from(format!("Points left: {} of {}", crate::forge::POINT_BUY_POOL - spent, crate::forge::POINT_BUY_POOL)),
+                Line::from(""),
+            ];
+            for (index, (name, min, max)) in crate::forge::CHARACTERISTIC_RANGES.iter().enumerate() {
+                let text = format!("{:<12} {:>2}  ({}-{})", format!("{}:", name), min + allocation[index], min, max);
+                if index == creation_state.current_selection_index {
+                    lines.push(Line::from(Span::styled(format!("▶ {}", text), Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD))));
+                } else {
+                    lines.push(Line::from(format!("  {}", text)));
+                }
+            }
+            lines.extend([
+                Line::from(""),
+                Line::from(Span::styled("Racial modifiers are applied after you pick a race.", Style::default().fg(theme.muted))),
+            ]);
+            lines
+        } else if let Some(rolled_data) = &creation_state.rolled_data {
             vec![
                 Line::from(Span::styled("Your Rolled Characteristics:", Style::default().add_modifier(Modifier::BOLD))),
                 Line::from(""),
@@ -378,11 +617,15 @@ impl GameUI {
                 Line::from(format!("Power:       {} ({})", rolled_data.power.total, rolled_data.power.formula)),
                 Line::from(format!("Luck:        {} ({})", rolled_data.luck.total, rolled_data.luck.formula)),
                 Line::from(""),
-                Line::from(Span::styled("Press C to continue or R to re-roll", Style::default().fg(Color::Green))),
+                if creation_state.rerolls_left > 0 {
+                    Line::from(Span::styled(format!("Press C to continue or R to re-roll ({} left)", creation_state.rerolls_left), Style::default().fg(theme.success)))
+                } else {
+                    Line::from(Span::styled("No re-rolls left. Press C to continue or P to buy points", Style::default().fg(theme.warning)))
+                },
             ]
         } else {
             vec![
-                Line::from(Span::styled("Welcome to Forge: Out of Chaos Character Creation!", Style::default().fg(Color::Yellow))),
+                Line::from(Span::styled("Welcome to Forge: Out of Chaos Character Creation!", Style::default().fg(theme.title))),
                 Line::from(""),
                 Line::from("In this step, you will roll dice to determine your character's"),
                 Line::from("nine basic characteristics. These define your character's"),
@@ -396,12 +639,12 @@ impl GameUI {
                 Line::from("• Power: 2d10"),
                 Line::from("• Luck: 2d6 + 4"),
                 Line::from(""),
-                Line::from(Span::styled("Note: If you roll 0 on d10, it counts as 1.0", Style::default().fg(Color::Yellow))),
+                Line::from(Span::styled("Note: If you roll 0 on d10, it counts as 1.0", Style::default().fg(theme.title))),
             ]
         };
 
         let main_content = Paragraph::new(content)
-            .block(Block::default().borders(Borders::ALL).title("Step 1: Roll Characteristics").border_style(Style::default().fg(Color::Green)))
+            .block(Block::default().borders(Borders::ALL).title("Step 1: Roll Characteristics").border_style(Style::default().fg(theme.success)))
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(main_content, left_chunks[1]);
 
@@ -420,19 +663,25 @@ impl GameUI {
             Line::from("Roll 2d10 for Power"),
             Line::from("Roll 2d6+4 for Luck"),
         ])
-        .block(Block::default().borders(Borders::ALL).title("Instructions").border_style(Style::default().fg(Color::Cyan)))
+        .block(Block::default().borders(Borders::ALL).title("Instructions").border_style(Style::default().fg(theme.accent)))
         .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(instructions, chunks[1]);
 
         // Navigation
-        let navigation = Paragraph::new("ENTER: Roll Characteristics | ESC: Cancel")
-            .style(Style::default().fg(Color::Magenta))
+        let navigation = Paragraph::new(if creation_state.point_buy.is_some() {
+            "↑/↓: Select | ←/→: Remove/Add Point | C: Continue | P: Roll Instead | ESC: Cancel"
+        } else if creation_state.rolled_data.is_some() && creation_state.rerolls_left == 0 {
+            "C: Continue | P: Point-Buy | ESC: Cancel"
+        } else {
+            "ENTER: Roll Characteristics | P: Point-Buy | ESC: Cancel"
+        })
+            .style(Style::default().fg(theme.magic))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).title("Navigation").border_style(Style::default().fg(Color::Magenta)));
+            .block(Block::default().borders(Borders::ALL).title("Navigation").border_style(Style::default().fg(theme.magic)));
         f.render_widget(navigation, left_chunks[2]);
     }
 
-    fn draw_race_selection_static(f: &mut Frame) {
+    fn draw_race_selection_static(f: &mut Frame, theme: &Theme) {
         let area = f.size();
         
         let chunks = Layout::default()
@@ -451,41 +700,41 @@ impl GameUI {
         
         // Title
         let title = Paragraph::new("Forge: Out of Chaos - Race Selection")
-            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
         f.render_widget(title, left_chunks[0]);
         
         // Race list
         let races = vec![
             Line::from(Span::styled("Select Your Race:", Style::default().add_modifier(Modifier::BOLD))),
             Line::from(""),
-            Line::from(Span::styled("1. Berserker", Style::default().fg(Color::Red))),
+            Line::from(Span::styled("1. Berserker", Style::default().fg(theme.enemy))),
             Line::from("   Large warriors who fear magic"),
-            Line::from(Span::styled("2. Dunnar", Style::default().fg(Color::Magenta))),
+            Line::from(Span::styled("2. Dunnar", Style::default().fg(theme.magic))),
             Line::from("   Pale beings with mind protection"),
-            Line::from(Span::styled("3. Dwarf", Style::default().fg(Color::Yellow))),
+            Line::from(Span::styled("3. Dwarf", Style::default().fg(theme.title))),
             Line::from("   Stout warriors with heat vision"),
-            Line::from(Span::styled("4. Elf", Style::default().fg(Color::Green))),
+            Line::from(Span::styled("4. Elf", Style::default().fg(theme.success))),
             Line::from("   Graceful beings with magical affinity"),
-            Line::from(Span::styled("5. Ghantu", Style::default().fg(Color::Red))),
+            Line::from(Span::styled("5. Ghantu", Style::default().fg(theme.enemy))),
             Line::from("   Massive one-eyed humanoids"),
-            Line::from(Span::styled("6. Higmoni", Style::default().fg(Color::Yellow))),
+            Line::from(Span::styled("6. Higmoni", Style::default().fg(theme.title))),
             Line::from("   Boar-like with fast healing"),
-            Line::from(Span::styled("7. Human", Style::default().fg(Color::White))),
+            Line::from(Span::styled("7. Human", Style::default().fg(theme.text))),
             Line::from("   Versatile with no penalties"),
-            Line::from(Span::styled("8. Jher-em", Style::default().fg(Color::Cyan))),
+            Line::from(Span::styled("8. Jher-em", Style::default().fg(theme.accent))),
             Line::from("   Small telepathic beings"),
-            Line::from(Span::styled("9. Kithsara", Style::default().fg(Color::Green))),
+            Line::from(Span::styled("9. Kithsara", Style::default().fg(theme.success))),
             Line::from("   Lizard-like with natural armor"),
-            Line::from(Span::styled("0. Merikii", Style::default().fg(Color::Yellow))),
+            Line::from(Span::styled("0. Merikii", Style::default().fg(theme.title))),
             Line::from("   Bird-like dual wielders"),
-            Line::from(Span::styled("#. Sprite", Style::default().fg(Color::Magenta))),
+            Line::from(Span::styled("#. Sprite", Style::default().fg(theme.magic))),
             Line::from("   Tiny empathic beings"),
         ];
         
         let race_list = Paragraph::new(races)
-            .block(Block::default().borders(Borders::ALL).title("Step 2: Choose Race").border_style(Style::default().fg(Color::Green)))
+            .block(Block::default().borders(Borders::ALL).title("Step 2: Choose Race").border_style(Style::default().fg(theme.success)))
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(race_list, left_chunks[1]);
         
@@ -510,19 +759,63 @@ impl GameUI {
         ];
         
         let details_panel = Paragraph::new(details)
-            .block(Block::default().borders(Borders::ALL).title("Race Information").border_style(Style::default().fg(Color::Cyan)))
+            .block(Block::default().borders(Borders::ALL).title("Race Information").border_style(Style::default().fg(theme.accent)))
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(details_panel, chunks[1]);
         
         // Navigation
         let navigation = Paragraph::new("1-9, 0, #: Select Race | ESC: Go Back")
-            .style(Style::default().fg(Color::Magenta))
+            .style(Style::default().fg(theme.magic))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).title("Navigation").border_style(Style::default().fg(Color::Magenta)));
+            .block(Block::default().borders(Borders::ALL).title("Navigation").border_style(Style::default().fg(theme.magic)));
         f.render_widget(navigation, left_chunks[2]);
     }
 
-    fn draw_name_entry_static(f: &mut Frame, _creation_state: &CharacterCreationState, input_buffer: &str) {
+    fn draw_password_entry_static(f: &mut Frame, theme: &Theme, creation_state: &CharacterCreationState, input_buffer: &str) {
+        let area = f.size();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3),
+            ])
+            .split(area);
+
+        let title = Paragraph::new("Forge: Out of Chaos - Character Password")
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
+        f.render_widget(title, chunks[0]);
+
+        let name = creation_state.character_name.as_deref().unwrap_or("your character");
+        let content = vec![
+            Line::from(Span::styled(format!("Choose a password for {}", name), Style::default().add_modifier(Modifier::BOLD))),
+            Line::from(""),
+            Line::from("You'll need it to log in, to join a server and to delete the character."),
+            Line::from(""),
+            // Mask the password as it's typed
+            Line::from(vec![
+                Span::styled("▶ ", Style::default().fg(theme.title)),
+                Span::styled("*".repeat(input_buffer.chars().count()), Style::default().fg(theme.text)),
+                Span::styled("_", Style::default().fg(theme.title)),
+            ]),
+        ];
+
+        let password_entry = Paragraph::new(content)
+            .block(Block::default().borders(Borders::ALL).title("Password").border_style(Style::default().fg(theme.success)))
+            .alignment(Alignment::Left);
+        f.render_widget(password_entry, chunks[1]);
+
+        let navigation = Paragraph::new("Type a password and press ENTER | ESC: Go Back")
+            .style(Style::default().fg(theme.magic))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Navigation").border_style(Style::default().fg(theme.magic)));
+        f.render_widget(navigation, chunks[2]);
+    }
+
+    fn draw_name_entry_static(f: &mut Frame, theme: &Theme, _creation_state: &CharacterCreationState, input_buffer: &str) {
         let area = f.size();
         
         let chunks = Layout::default()
@@ -536,9 +829,9 @@ impl GameUI {
 
         // Title
         let title = Paragraph::new("Forge: Out of Chaos - Character Name")
-            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
         f.render_widget(title, chunks[0]);
 
         // Name entry content
@@ -548,27 +841,27 @@ impl GameUI {
             Line::from("Your character needs a name to be known by in the world."),
             Line::from("This will be used for login and display throughout the game."),
             Line::from(""),
-            Line::from(Span::styled("Requirements:", Style::default().fg(Color::Cyan))),
+            Line::from(Span::styled("Requirements:", Style::default().fg(theme.accent))),
             Line::from("• Must be at least 2 characters long"),
             Line::from("• Can contain letters, numbers, and basic symbols"),
             Line::from("• Should be unique and memorable"),
             Line::from(""),
-            Line::from(Span::styled("Enter your character's name:", Style::default().fg(Color::Green))),
+            Line::from(Span::styled("Enter your character's name:", Style::default().fg(theme.success))),
             Line::from(""),
         ];
 
         // Add the input line with current buffer
         let input_line = if input_buffer.is_empty() {
             Line::from(vec![
-                Span::styled("▶ ", Style::default().fg(Color::Yellow)),
-                Span::styled("_", Style::default().fg(Color::DarkGray)),
+                Span::styled("▶ ", Style::default().fg(theme.title)),
+                Span::styled("_", Style::default().fg(theme.muted)),
             ])
         } else {
-            let color = if input_buffer.len() >= 2 { Color::Green } else { Color::Red };
+            let color = if input_buffer.len() >= 2 { theme.success } else { theme.warning };
             Line::from(vec![
-                Span::styled("▶ ", Style::default().fg(Color::Yellow)),
+                Span::styled("▶ ", Style::default().fg(theme.title)),
                 Span::styled(input_buffer, Style::default().fg(color)),
-                Span::styled("_", Style::default().fg(Color::Yellow)),
+                Span::styled("_", Style::default().fg(theme.title)),
             ])
         };
         content.push(input_line);
@@ -581,24 +874,24 @@ impl GameUI {
         } else {
             "Press ENTER to continue"
         };
-        let status_color = if input_buffer.len() >= 2 { Color::Green } else { Color::Red };
+        let status_color = if input_buffer.len() >= 2 { theme.success } else { theme.warning };
         content.push(Line::from(""));
         content.push(Line::from(Span::styled(status_text, Style::default().fg(status_color))));
 
         let name_entry = Paragraph::new(content)
-            .block(Block::default().borders(Borders::ALL).title("Character Naming").border_style(Style::default().fg(Color::Green)))
+            .block(Block::default().borders(Borders::ALL).title("Character Naming").border_style(Style::default().fg(theme.success)))
             .alignment(Alignment::Left);
         f.render_widget(name_entry, chunks[1]);
 
         // Navigation
         let navigation = Paragraph::new("Type name and press ENTER (min 2 chars) | ESC: Go Back")
-            .style(Style::default().fg(Color::Magenta))
+            .style(Style::default().fg(theme.magic))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).title("Navigation").border_style(Style::default().fg(Color::Magenta)));
+            .block(Block::default().borders(Borders::ALL).title("Navigation").border_style(Style::default().fg(theme.magic)));
         f.render_widget(navigation, chunks[2]);
     }
 
-    fn draw_skill_selection_static(f: &mut Frame, creation_state: &CharacterCreationState) {
+    fn draw_skill_selection_static(f: &mut Frame, theme: &Theme, creation_state: &CharacterCreationState) {
         let area = f.size();
         
         let chunks = Layout::default()
@@ -608,19 +901,21 @@ impl GameUI {
 
         // Title
         let title = Paragraph::new("🎯 Skill Selection")
-            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::ALL));
         f.render_widget(title, chunks[0]);
 
-        let skill_items: Vec<ListItem> = creation_state.available_skills_list.iter().enumerate().map(|(i, skill)| {
+        let skills = creation_state.filtered_skills();
+        let offset = Self::list_scroll_offset(creation_state.current_selection_index, chunks[1].height);
+        let mut skill_items: Vec<ListItem> = skills.iter().enumerate().skip(offset).map(|(i, skill)| {
             let selected = creation_state.selected_skills.contains(skill);
             let is_current = i == creation_state.current_selection_index;
             
             let style = if is_current {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.title).add_modifier(Modifier::BOLD)
             } else if selected {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.success)
             } else {
                 Style::default()
             };
@@ -629,21 +924,26 @@ impl GameUI {
             ListItem::new(format!("{}{}", prefix, skill)).style(style)
         }).collect();
 
+        if skill_items.is_empty() {
+            skill_items.push(ListItem::new("  No skills match the filter").style(Style::default().fg(theme.muted)));
+        }
+
         let skills_list = List::new(skill_items)
             .block(Block::default().borders(Borders::ALL).title(format!(
-                "Available Skills (Points remaining: {})", 
-                creation_state.available_skill_points
+                "Available Skills (Points remaining: {}){}", 
+                creation_state.available_skill_points,
+                Self::filter_label(&creation_state.list_filter)
             )));
         f.render_widget(skills_list, chunks[1]);
 
         // Navigation
-        let navigation = Paragraph::new("↑/↓: Navigate | Enter: Select/Deselect | C: Continue | Esc: Back")
+        let navigation = Paragraph::new("↑/↓: Navigate | Enter: Select/Deselect | Type: Filter | Tab: Continue | Esc: Clear/Back")
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::ALL));
         f.render_widget(navigation, chunks[2]);
     }
 
-    fn draw_spell_selection_static(f: &mut Frame, creation_state: &CharacterCreationState) {
+    fn draw_spell_selection_static(f: &mut Frame, theme: &Theme, creation_state: &CharacterCreationState) {
         let area = f.size();
         
         let chunks = Layout::default()
@@ -653,25 +953,44 @@ impl GameUI {
 
         // Title
         let title = Paragraph::new("🔮 Spell Selection")
-            .style(Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.magic).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::ALL));
         f.render_widget(title, chunks[0]);
 
+        // Spell points the character will start with, to flag spells they can't afford to cast
+        let starting_spell_points = Self::creation_characteristics(creation_state)
+            .map(|characteristics| ForgeCharacterCreation::derive_secondary_stats(&characteristics).spell_points);
+        let spell_book = crate::forge::magic::spell_book();
+
         let spell_items: Vec<ListItem> = creation_state.available_spells_list.iter().enumerate().map(|(i, (spell, school))| {
             let selected = creation_state.selected_spells.iter().any(|(s, _)| s == spell);
             let is_current = i == creation_state.current_selection_index;
+            let details = spell_book.get(spell);
+            let too_costly = match (details, starting_spell_points) {
+                (Some(details), Some(points)) => details.cost as u32 > points,
+                _ => false,
+            };
             
             let style = if is_current {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.title).add_modifier(Modifier::BOLD)
             } else if selected {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.success)
+            } else if too_costly {
+                Style::default().fg(theme.muted)
             } else {
                 Style::default()
             };
             
             let prefix = if selected { "✓ " } else { "  " };
-            ListItem::new(format!("{}{} ({})", prefix, spell, school)).style(style)
+            let mut hint = format!("needs {} ✓", school);
+            if let Some(details) = details {
+                hint = format!("level {}, {} SP, {}", details.level, details.cost, hint);
+            }
+            if too_costly {
+                hint.push_str(" | ⚠ costs more SP than you have");
+            }
+            ListItem::new(format!("{}{} ({}) - {}", prefix, spell, school, hint)).style(style)
         }).collect();
 
         let spells_list = List::new(spell_items)
@@ -688,57 +1007,132 @@ impl GameUI {
         f.render_widget(navigation, chunks[2]);
     }
 
-    fn draw_gear_selection_static(f: &mut Frame, creation_state: &CharacterCreationState) {
+    fn draw_gear_selection_static(f: &mut Frame, theme: &Theme, creation_state: &CharacterCreationState) {
         let area = f.size();
         
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(3), Constraint::Min(5), Constraint::Length(3)])
+            .constraints([Constraint::Length(3), Constraint::Length(3), Constraint::Min(5), Constraint::Length(3)])
             .split(area);
 
         // Title
         let title = Paragraph::new("⚔️ Gear Selection")
-            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::ALL));
         f.render_widget(title, chunks[0]);
 
-        let gear_items: Vec<ListItem> = creation_state.available_gear_list.iter().enumerate().map(|(i, (gear_name, cost))| {
+        // Gold remaining, with a warning when the highlighted item is out of reach
+        let gear = creation_state.filtered_gear();
+        let mut gold_spans = vec![Span::styled(
+            format!("💰 Gold remaining: {} / {}", creation_state.remaining_gold(), creation_state.starting_gold),
+            Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD),
+        )];
+        if let Some((gear_name, cost)) = gear.get(creation_state.current_selection_index) {
+            if !creation_state.selected_gear.contains(gear_name) && !creation_state.can_afford(*cost) {
+                gold_spans.push(Span::styled(
+                    format!("   ⚠ Can't afford {} ({} gp, short by {})", gear_name, cost, cost - creation_state.remaining_gold()),
+                    Style::default().fg(theme.warning),
+                ));
+            }
+        }
+        let gold = Paragraph::new(Line::from(gold_spans))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.highlight)));
+        f.render_widget(gold, chunks[1]);
+
+        let offset = Self::list_scroll_offset(creation_state.current_selection_index, chunks[2].height);
+        let characteristics = Self::creation_characteristics(creation_state);
+        let mut gear_items: Vec<ListItem> = gear.iter().enumerate().skip(offset).map(|(i, (gear_name, cost))| {
             let selected = creation_state.selected_gear.contains(gear_name);
             let is_current = i == creation_state.current_selection_index;
-            let can_afford = creation_state.spent_gold + cost <= creation_state.starting_gold;
+            let can_afford = creation_state.can_afford(*cost);
+            let requirements = ForgeCharacterCreation::gear_requirements(gear_name);
+            let unmet = characteristics.as_ref()
+                .map(|characteristics| ForgeCharacterCreation::unmet_gear_requirements(gear_name, characteristics))
+                .unwrap_or_default();
             
             let style = if is_current {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.title).add_modifier(Modifier::BOLD)
             } else if selected {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.success)
             } else if !can_afford {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme.muted)
+            } else if !unmet.is_empty() {
+                Style::default().fg(theme.warning)
             } else {
                 Style::default()
             };
             
             let prefix = if selected { "✓ " } else { "  " };
-            ListItem::new(format!("{}{:<30} {} gp", prefix, gear_name, cost)).style(style)
+            let hint = if requirements.is_empty() {
+                String::new()
+            } else {
+                let needs: Vec<String> = requirements.iter().map(|(stat, minimum)| format!("{} {}", stat, minimum)).collect();
+                if unmet.is_empty() {
+                    format!("  needs {} ✓", needs.join(", "))
+                } else {
+                    format!("  ⚠ needs {} (too weak to use well)", needs.join(", "))
+                }
+            };
+            ListItem::new(format!("{}{:<30} {:>3} gp{}", prefix, gear_name, cost, hint)).style(style)
         }).collect();
 
+        if gear_items.is_empty() {
+            gear_items.push(ListItem::new("  No gear matches the filter").style(Style::default().fg(theme.muted)));
+        }
+
         let gear_list = List::new(gear_items)
             .block(Block::default().borders(Borders::ALL).title(format!(
-                "Available Gear (Gold: {}/{} | Spent: {})", 
-                creation_state.starting_gold - creation_state.spent_gold,
-                creation_state.starting_gold,
-                creation_state.spent_gold
+                "Available Gear (Spent: {} gp){}", 
+                creation_state.spent_gold,
+                Self::filter_label(&creation_state.list_filter)
             )));
-        f.render_widget(gear_list, chunks[1]);
+        f.render_widget(gear_list, chunks[2]);
 
         // Navigation
-        let navigation = Paragraph::new("↑/↓: Navigate | Enter: Select/Deselect | C: Continue | Esc: Back")
+        let navigation = Paragraph::new("↑/↓: Navigate | Enter: Select/Deselect | Type: Filter | Tab: Continue | Esc: Clear/Back")
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::ALL));
-        f.render_widget(navigation, chunks[2]);
+        f.render_widget(navigation, chunks[3]);
     }
 
-    fn draw_character_confirmation_static(f: &mut Frame, creation_state: &CharacterCreationState) {
+    fn secondary_stats_lines(theme: &Theme, stats: &crate::forge::SecondaryStats) -> Vec<Line<'static>> {
+        vec![
+            Line::from(Span::styled("Derived Stats:", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))),
+            Line::from(format!("Hit Points:  {}", stats.hit_points)),
+            Line::from(format!("Attack Val:  {}", stats.attack_value)),
+            Line::from(format!("Defense Val: {}", stats.defensive_value)),
+            Line::from(format!("Dmg Bonus:   {:+}", stats.damage_bonus)),
+            Line::from(format!("Spell Pts:   {}", stats.spell_points)),
+            Line::from(format!("Carry:       {} lbs", stats.carry_capacity)),
+            Line::from(format!("Movement:    {} ft/round", stats.movement)),
+        ]
+    }
+
+    // The rolled characteristics with racial modifiers, once both have been chosen
+    fn creation_characteristics(creation_state: &CharacterCreationState) -> Option<crate::forge::ForgeCharacteristics> {
+        match (&creation_state.rolled_data, &creation_state.selected_race) {
+            (Some(rolled_data), Some(race)) => Some(ForgeCharacterCreation::apply_racial_modifiers(rolled_data, race)),
+            _ => None,
+        }
+    }
+
+    // First row to draw so the highlighted entry stays inside a bordered list
+    fn list_scroll_offset(selected: usize, area_height: u16) -> usize {
+        let max_visible = (area_height.saturating_sub(2) as usize).max(1);
+        selected.saturating_sub(max_visible - 1)
+    }
+
+    fn filter_label(filter: &str) -> String {
+        if filter.is_empty() {
+            String::new()
+        } else {
+            format!(" | Filter: {}_", filter)
+        }
+    }
+
+    fn draw_character_confirmation_static(f: &mut Frame, theme: &Theme, creation_state: &CharacterCreationState) {
         let area = f.size();
         
         let chunks = Layout::default()
@@ -757,9 +1151,9 @@ impl GameUI {
 
         // Title
         let title = Paragraph::new("Forge: Out of Chaos - Character Confirmation")
-            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
         f.render_widget(title, left_chunks[0]);
 
         // Character summary
@@ -774,12 +1168,11 @@ impl GameUI {
             &creation_state.character_name,
         ) {
             // Apply racial modifiers for display
-            use crate::forge::ForgeCharacterCreation;
             let final_characteristics = ForgeCharacterCreation::apply_racial_modifiers(rolled_data, race);
 
             content.extend(vec![
-                Line::from(Span::styled(format!("Name: {}", name), Style::default().fg(Color::Cyan))),
-                Line::from(Span::styled(format!("Race: {}", race.name), Style::default().fg(Color::Cyan))),
+                Line::from(Span::styled(format!("Name: {}", name), Style::default().fg(theme.accent))),
+                Line::from(Span::styled(format!("Race: {}", race.name), Style::default().fg(theme.accent))),
                 Line::from(""),
                 Line::from(Span::styled("Final Characteristics:", Style::default().add_modifier(Modifier::BOLD))),
                 Line::from(format!("Strength:    {:.1}", final_characteristics.strength)),
@@ -792,7 +1185,11 @@ impl GameUI {
                 Line::from(format!("Power:       {}", final_characteristics.power)),
                 Line::from(format!("Luck:        {}", final_characteristics.luck)),
                 Line::from(""),
-                Line::from(Span::styled("Special Abilities:", Style::default().fg(Color::Green))),
+            ]);
+            content.extend(Self::secondary_stats_lines(theme, &ForgeCharacterCreation::derive_secondary_stats(&final_characteristics)));
+            content.extend(vec![
+                Line::from(""),
+                Line::from(Span::styled("Special Abilities:", Style::default().fg(theme.success))),
             ]);
 
             for ability in &race.special_abilities {
@@ -801,15 +1198,19 @@ impl GameUI {
 
             content.extend(vec![
                 Line::from(""),
-                Line::from(Span::styled("Press ENTER to create character", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))),
-                Line::from(Span::styled("Press ESC to go back and change name", Style::default().fg(Color::Yellow))),
+                Line::from(Span::styled(format!("Difficulty: {}", creation_state.difficulty.get_name()), Style::default().fg(theme.warning).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled(creation_state.difficulty.get_description(), Style::default().fg(theme.muted))),
+                Line::from(""),
+                Line::from(Span::styled("Press ENTER to create character", Style::default().fg(theme.success).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("Press D to change difficulty", Style::default().fg(theme.title))),
+                Line::from(Span::styled("Press ESC to go back and change name", Style::default().fg(theme.title))),
             ]);
         } else {
             content.push(Line::from("Error: Missing character data"));
         }
 
         let confirmation = Paragraph::new(content)
-            .block(Block::default().borders(Borders::ALL).title("Character Summary").border_style(Style::default().fg(Color::Green)))
+            .block(Block::default().borders(Borders::ALL).title("Character Summary").border_style(Style::default().fg(theme.success)))
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(confirmation, left_chunks[1]);
 
@@ -820,7 +1221,7 @@ impl GameUI {
                 Line::from(""),
                 Line::from(race.description.as_str()),
                 Line::from(""),
-                Line::from(Span::styled("Starting Skills:", Style::default().fg(Color::Cyan))),
+                Line::from(Span::styled("Starting Skills:", Style::default().fg(theme.accent))),
             ];
 
             let mut race_content = race_info;
@@ -829,20 +1230,20 @@ impl GameUI {
             }
 
             let race_panel = Paragraph::new(race_content)
-                .block(Block::default().borders(Borders::ALL).title("Race Details").border_style(Style::default().fg(Color::Cyan)))
+                .block(Block::default().borders(Borders::ALL).title("Race Details").border_style(Style::default().fg(theme.accent)))
                 .wrap(ratatui::widgets::Wrap { trim: true });
             f.render_widget(race_panel, chunks[1]);
         }
 
         // Navigation
-        let navigation = Paragraph::new("ENTER: Create Character | ESC: Go Back")
-            .style(Style::default().fg(Color::Magenta))
+        let navigation = Paragraph::new("ENTER: Create Character | D: Difficulty | ESC: Go Back")
+            .style(Style::default().fg(theme.magic))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).title("Navigation").border_style(Style::default().fg(Color::Magenta)));
+            .block(Block::default().borders(Borders::ALL).title("Navigation").border_style(Style::default().fg(theme.magic)));
         f.render_widget(navigation, left_chunks[2]);
     }
 
-    fn draw_character_list_static(f: &mut Frame, character_list: Option<&Vec<(String, chrono::DateTime<chrono::Utc>)>>, selected_index: Option<usize>) {
+    fn draw_character_list_static(f: &mut Frame, theme: &Theme, character_list: Option<&Vec<(String, chrono::DateTime<chrono::Utc>, crate::forge::PlayStats)>>, selected_index: Option<usize>) {
         let area = f.size();
         
         let chunks = Layout::default()
@@ -856,9 +1257,9 @@ impl GameUI {
 
         // Title
         let title = Paragraph::new("SAVED CHARACTERS")
-            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
         f.render_widget(title, chunks[0]);
 
         // Character list content
@@ -866,12 +1267,12 @@ impl GameUI {
             if characters.is_empty() {
                 vec![
                     Line::from(""),
-                    Line::from(Span::styled("No characters found", Style::default().fg(Color::DarkGray))),
+                    Line::from(Span::styled("No characters found", Style::default().fg(theme.muted))),
                     Line::from(""),
                     Line::from("Create your first character by selecting"),
                     Line::from("'Create New Character' from the main menu."),
                     Line::from(""),
-                    Line::from(Span::styled("Press any key to return to main menu", Style::default().fg(Color::Green))),
+                    Line::from(Span::styled("Press any key to return to main menu", Style::default().fg(theme.success))),
                 ]
             } else {
                 // Sort characters by last played (most recent first)
@@ -883,25 +1284,26 @@ impl GameUI {
                     Line::from(""),
                 ];
 
-                for (index, (name, last_played)) in sorted_chars.into_iter().enumerate() {
+                for (index, (name, last_played, stats)) in sorted_chars.into_iter().enumerate() {
                     let time_str = last_played.format("%Y-%m-%d %H:%M UTC").to_string();
                     let is_selected = selected_index == Some(index);
                     let is_most_recent = index == 0;
                     
                     let (color, modifier, prefix) = if is_selected {
-                        (Color::Black, Modifier::BOLD, "► ")
+                        (theme.background, Modifier::BOLD, "► ")
                     } else if is_most_recent {
-                        (Color::Green, Modifier::BOLD, "  ")
+                        (theme.success, Modifier::BOLD, "  ")
                     } else {
-                        (Color::White, Modifier::empty(), "  ")
+                        (theme.text, Modifier::empty(), "  ")
                     };
                     
                     let index_str = format!("{}. ", index + 1);
                     let char_line = format!("{}{}{}", prefix, index_str, name);
-                    let time_line = format!("     Last played: {}", time_str);
+                    let time_line = format!("     Last played: {} | Played {} | {} foes slain",
+                        time_str, stats.describe_playtime(), stats.enemies_defeated);
                     
                     let char_style = if is_selected {
-                        Style::default().fg(color).bg(Color::Yellow).add_modifier(modifier)
+                        Style::default().fg(color).bg(theme.title).add_modifier(modifier)
                     } else {
                         Style::default().fg(color).add_modifier(modifier)
                     };
@@ -912,12 +1314,13 @@ impl GameUI {
                 }
 
                 lines.extend(vec![
-                    Line::from(Span::styled("Navigation:".to_string(), Style::default().fg(Color::Cyan))),
+                    Line::from(Span::styled("Navigation:".to_string(), Style::default().fg(theme.accent))),
                     Line::from("↑/↓ or W/S: Select character"),
                     Line::from("ENTER: Play selected character"),
+                    Line::from("D: Delete selected character"),
                     Line::from("ESC: Return to main menu"),
                     Line::from(""),
-                    Line::from(Span::styled("Select a character and press ENTER to play!".to_string(), Style::default().fg(Color::Green))),
+                    Line::from(Span::styled("Select a character and press ENTER to play!".to_string(), Style::default().fg(theme.success))),
                 ]);
 
                 lines
@@ -925,32 +1328,115 @@ impl GameUI {
         } else {
             vec![
                 Line::from(""),
-                Line::from(Span::styled("Loading character list...", Style::default().fg(Color::DarkGray))),
+                Line::from(Span::styled("Loading character list...", Style::default().fg(theme.muted))),
                 Line::from(""),
                 Line::from("Please wait while we retrieve your characters."),
             ]
         };
 
         let character_list_widget = Paragraph::new(content)
-            .block(Block::default().borders(Borders::ALL).title("Character Information").border_style(Style::default().fg(Color::Green)))
+            .block(Block::default().borders(Borders::ALL).title("Character Information").border_style(Style::default().fg(theme.success)))
             .alignment(Alignment::Left);
         f.render_widget(character_list_widget, chunks[1]);
 
         // Instructions
         let instructions = if character_list.is_some() && !character_list.unwrap().is_empty() {
-            Paragraph::new("↑/↓ W/S: Navigate | ENTER: Play Character | ESC: Main Menu | Q/Ctrl+C: Quit")
+            Paragraph::new("↑/↓ W/S: Navigate | ENTER: Play Character | D: Delete | ESC: Main Menu | Q/Ctrl+C: Quit")
         } else {
             Paragraph::new("Any Key: Return to Main Menu | Q/Ctrl+C: Quit Game")
         };
         
         let instructions = instructions
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(Color::DarkGray)));
+            .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(theme.muted)));
         f.render_widget(instructions, chunks[2]);
     }
 
-    fn draw_game_static(f: &mut Frame, current_character: Option<&crate::forge::ForgeCharacter>) {
+    fn draw_character_delete_static(f: &mut Frame, theme: &Theme, name: &str, wrong_password: bool, input_buffer: &str) {
+        let area = f.size();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        let title = Paragraph::new("DELETE CHARACTER")
+            .style(Style::default().fg(theme.warning).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.warning)));
+        f.render_widget(title, chunks[0]);
+
+        let mut content_lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(format!("⚠️ {} will be gone for good.", name), Style::default().fg(theme.warning).add_modifier(Modifier::BOLD))),
+            Line::from(""),
+            Line::from(format!("Enter {}'s password to confirm", name)),
+            Line::from(Span::styled("ENTER: Delete | ESC: Keep the character", Style::default().fg(theme.muted))),
+            Line::from(""),
+        ];
+        if wrong_password {
+            content_lines.push(Line::from(Span::styled("❌ Wrong password. Nothing was deleted.", Style::default().fg(theme.warning))));
+            content_lines.push(Line::from(""));
+        }
+
+        // Mask the password as it's typed
+        content_lines.push(Line::from(vec![
+            Span::styled("▶ ", Style::default().fg(theme.title)),
+            Span::styled("*".repeat(input_buffer.chars().count()), Style::default().fg(theme.text)),
+            Span::styled("_", Style::default().fg(theme.title)),
+        ]));
+
+        let content = Paragraph::new(content_lines)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.text)));
+        f.render_widget(content, chunks[1]);
+    }
+
+    fn draw_character_unlock_static(f: &mut Frame, theme: &Theme, name: &str, wrong_password: bool, input_buffer: &str) {
+        let area = f.size();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        let title = Paragraph::new("CHARACTER LOGIN")
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
+        f.render_widget(title, chunks[0]);
+
+        let mut content_lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(format!("Enter {}'s password", name), Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled("ENTER: Log in | ESC: Back to the list", Style::default().fg(theme.muted))),
+            Line::from(""),
+        ];
+        if wrong_password {
+            content_lines.push(Line::from(Span::styled("❌ Wrong password.", Style::default().fg(theme.warning))));
+            content_lines.push(Line::from(""));
+        }
+
+        content_lines.push(Line::from(vec![
+            Span::styled("▶ ", Style::default().fg(theme.title)),
+            Span::styled("*".repeat(input_buffer.chars().count()), Style::default().fg(theme.text)),
+            Span::styled("_", Style::default().fg(theme.title)),
+        ]));
+
+        let content = Paragraph::new(content_lines)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.text)));
+        f.render_widget(content, chunks[1]);
+    }
+
+    fn draw_game_static(f: &mut Frame, theme: &Theme, current_character: Option<&crate::forge::ForgeCharacter>) {
         let area = f.size();
         
         let chunks = Layout::default()
@@ -967,12 +1453,11 @@ impl GameUI {
             ])
             .split(chunks[0]);
 
-        // Status bar - show actual character info if available
+        // Status bar - show actual character info if available, with gauges for
+        // hit points and, for casters, spell points
         let status_text = if let Some(character) = current_character {
-            format!("{} | HP: {}/{} | STR: {:.1} | Level: {} | Gold: {}", 
+            format!("{} | STR: {:.1} | Level: {} | Gold: {}", 
                 character.name,
-                character.combat_stats.hit_points.current,
-                character.combat_stats.hit_points.max,
                 character.characteristics.strength,
                 character.level,
                 character.gold
@@ -980,21 +1465,44 @@ impl GameUI {
         } else {
             "No Character Loaded".to_string()
         };
+        let pools: Vec<(&str, u32, u32)> = match current_character {
+            Some(character) => {
+                let hit_points = &character.combat_stats.hit_points;
+                let spell_points = &character.magic.spell_points;
+                let mut pools = vec![("HP", hit_points.current, hit_points.max)];
+                if spell_points.max > 0 {
+                    pools.push(("SP", spell_points.current, spell_points.max));
+                }
+                pools
+            }
+            None => Vec::new(),
+        };
+        let mut status_constraints = vec![Constraint::Min(0)];
+        status_constraints.extend(pools.iter().map(|_| Constraint::Length(20)));
+        let status_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(status_constraints)
+            .split(left_chunks[0]);
 
         let status = Paragraph::new(status_text)
-            .style(Style::default().fg(Color::Cyan))
+            .style(Style::default().fg(theme.accent))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).title("Character Status").border_style(Style::default().fg(Color::Cyan)));
-        f.render_widget(status, left_chunks[0]);
+            .block(Block::default().borders(Borders::ALL).title("Character Status").border_style(Style::default().fg(theme.accent)));
+        f.render_widget(status, status_chunks[0]);
+        for (index, (label, current, max)) in pools.into_iter().enumerate() {
+            let gauge = Self::pool_gauge(theme, current, max)
+                .block(Block::default().borders(Borders::ALL).title(label).border_style(Style::default().fg(theme.accent)));
+            f.render_widget(gauge, status_chunks[index + 1]);
+        }
 
         // Game world overview
         let world_content = if current_character.is_some() {
             vec![
-                Line::from(Span::styled("🏰 WARLORDS REALM 🏰", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("🏰 WARLORDS REALM 🏰", Style::default().fg(theme.title).add_modifier(Modifier::BOLD))),
                 Line::from(""),
                 Line::from("Your journey from farm worker to mighty warlord begins!"),
                 Line::from(""),
-                Line::from(Span::styled("Available Actions:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled("Available Actions:", Style::default().fg(theme.success).add_modifier(Modifier::BOLD))),
                 Line::from(""),
                 Line::from("🗺️  Explore World - Venture into the unknown lands"),
                 Line::from("   Discover new territories, find settlements, and encounter"),
@@ -1008,13 +1516,13 @@ impl GameUI {
                 Line::from("   Check your skills, inventory, and character progression."),
                 Line::from("   Access comprehensive character details and statistics."),
                 Line::from(""),
-                Line::from(Span::styled("World Status:", Style::default().fg(Color::Cyan))),
+                Line::from(Span::styled("World Status:", Style::default().fg(theme.accent))),
                 Line::from("• World Generation: Ready"),
                 Line::from("• Current Location: Central Lands"),
                 Line::from("• Time of Day: Morning"),
                 Line::from("• Weather: Clear"),
                 Line::from(""),
-                Line::from(Span::styled("Choose your path wisely, adventurer!", Style::default().fg(Color::Yellow))),
+                Line::from(Span::styled("Choose your path wisely, adventurer!", Style::default().fg(theme.title))),
             ]
         } else {
             vec![
@@ -1024,8 +1532,8 @@ impl GameUI {
         };
 
         let world = Paragraph::new(world_content)
-            .style(Style::default().fg(Color::White))
-            .block(Block::default().borders(Borders::ALL).title("Game World").border_style(Style::default().fg(Color::Green)))
+            .style(Style::default().fg(theme.text))
+            .block(Block::default().borders(Borders::ALL).title("Game World").border_style(Style::default().fg(theme.success)))
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(world, left_chunks[1]);
 
@@ -1039,7 +1547,7 @@ impl GameUI {
                 Line::from(format!("Level: {}", character.level)),
                 Line::from(format!("Experience: {}", character.experience)),
                 Line::from(""),
-                Line::from(Span::styled("Characteristics:", Style::default().fg(Color::Cyan))),
+                Line::from(Span::styled("Characteristics:", Style::default().fg(theme.accent))),
                 Line::from(format!("STR: {:.1}", character.characteristics.strength)),
                 Line::from(format!("STA: {:.1}", character.characteristics.stamina)),
                 Line::from(format!("INT: {:.1}", character.characteristics.intellect)),
@@ -1050,12 +1558,12 @@ impl GameUI {
                 Line::from(format!("POW: {}", character.characteristics.power)),
                 Line::from(format!("LUC: {}", character.characteristics.luck)),
                 Line::from(""),
-                Line::from(Span::styled("Combat Stats:", Style::default().fg(Color::Red))),
+                Line::from(Span::styled("Combat Stats:", Style::default().fg(theme.enemy))),
                 Line::from(format!("Attack: {}", character.combat_stats.attack_value)),
                 Line::from(format!("Defense: {}", character.combat_stats.defensive_value)),
                 Line::from(format!("Damage: {:+}", character.combat_stats.damage_bonus)),
                 Line::from(""),
-                Line::from(Span::styled("Skills:", Style::default().fg(Color::Green))),
+                Line::from(Span::styled("Skills:", Style::default().fg(theme.success))),
             ];
 
             for (skill, level) in &character.skills {
@@ -1063,26 +1571,26 @@ impl GameUI {
             }
 
             let character_panel = Paragraph::new(character_info)
-                .block(Block::default().borders(Borders::ALL).title("Character Sheet").border_style(Style::default().fg(Color::Magenta)))
+                .block(Block::default().borders(Borders::ALL).title("Character Sheet").border_style(Style::default().fg(theme.magic)))
                 .wrap(ratatui::widgets::Wrap { trim: true });
             f.render_widget(character_panel, chunks[1]);
         } else {
             let no_char = Paragraph::new("No character loaded.\nCreate or log in to\na character to view\ndetailed information.")
-                .style(Style::default().fg(Color::DarkGray))
+                .style(Style::default().fg(theme.muted))
                 .alignment(Alignment::Center)
-                .block(Block::default().borders(Borders::ALL).title("Character Sheet").border_style(Style::default().fg(Color::DarkGray)));
+                .block(Block::default().borders(Borders::ALL).title("Character Sheet").border_style(Style::default().fg(theme.muted)));
             f.render_widget(no_char, chunks[1]);
         }
 
         // Controls
         let controls = Paragraph::new("E: Explore World | F: Practice Combat | C: Character Menu | M: Main Menu | Q/Ctrl+C: Quit")
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(Color::DarkGray)));
+            .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(theme.muted)));
         f.render_widget(controls, left_chunks[2]);
     }
 
-    fn draw_character_menu_static(f: &mut Frame, current_character: Option<&crate::forge::ForgeCharacter>) {
+    fn draw_character_menu_static(f: &mut Frame, theme: &Theme, current_character: Option<&crate::forge::ForgeCharacter>) {
         let area = f.size();
         
         if let Some(character) = current_character {
@@ -1110,9 +1618,9 @@ impl GameUI {
 
             // Title
             let title = Paragraph::new(format!("Character Menu - {}", character.name))
-                .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
                 .alignment(Alignment::Center)
-                .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
+                .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
             f.render_widget(title, left_chunks[0]);
 
             // Detailed character information
@@ -1125,10 +1633,10 @@ impl GameUI {
                 Line::from(format!("Experience: {}", character.experience)),
                 Line::from(format!("Gold: {}", character.gold)),
                 Line::from(""),
-                Line::from(Span::styled("Race Description:", Style::default().fg(Color::Cyan))),
+                Line::from(Span::styled("Race Description:", Style::default().fg(theme.accent))),
                 Line::from(character.race.description.as_str()),
                 Line::from(""),
-                Line::from(Span::styled("Special Abilities:", Style::default().fg(Color::Green))),
+                Line::from(Span::styled("Special Abilities:", Style::default().fg(theme.success))),
             ];
 
             let mut details = character_details;
@@ -1138,7 +1646,10 @@ impl GameUI {
 
             details.extend(vec![
                 Line::from(""),
-                Line::from(Span::styled("Inventory:", Style::default().fg(Color::Magenta))),
+                Line::from(Span::styled("Equipped:", Style::default().fg(theme.magic))),
+                Line::from(character.describe_equipment()),
+                Line::from(""),
+                Line::from(Span::styled("Inventory:", Style::default().fg(theme.magic))),
             ]);
 
             for item in &character.inventory {
@@ -1149,10 +1660,13 @@ impl GameUI {
                 Line::from(""),
                 Line::from(format!("Created: {}", character.created_at.format("%Y-%m-%d %H:%M"))),
                 Line::from(format!("Last Played: {}", character.last_played.format("%Y-%m-%d %H:%M"))),
+                Line::from(""),
+                Line::from(Span::styled("Campaign:", Style::default().fg(theme.accent))),
             ]);
+            details.extend(character.stats.describe().into_iter().map(Line::from));
 
             let character_info = Paragraph::new(details)
-                .block(Block::default().borders(Borders::ALL).title("Character Details").border_style(Style::default().fg(Color::Green)))
+                .block(Block::default().borders(Borders::ALL).title("Character Details").border_style(Style::default().fg(theme.success)))
                 .wrap(ratatui::widgets::Wrap { trim: true });
             f.render_widget(character_info, left_chunks[1]);
 
@@ -1172,7 +1686,7 @@ impl GameUI {
             ];
 
             let char_panel = Paragraph::new(characteristics)
-                .block(Block::default().borders(Borders::ALL).title("Characteristics").border_style(Style::default().fg(Color::Cyan)))
+                .block(Block::default().borders(Borders::ALL).title("Characteristics").border_style(Style::default().fg(theme.accent)))
                 .wrap(ratatui::widgets::Wrap { trim: true });
             f.render_widget(char_panel, right_chunks[0]);
 
@@ -1184,36 +1698,273 @@ impl GameUI {
                 Line::from(format!("Attack Value:  {}", character.combat_stats.attack_value)),
                 Line::from(format!("Defense Value: {}", character.combat_stats.defensive_value)),
                 Line::from(format!("Damage Bonus:  {:+}", character.combat_stats.damage_bonus)),
+                Line::from(format!("Spell Points:  {}/{}", character.magic.spell_points.current, character.magic.spell_points.max)),
+            ];
+            combat_skills.extend(character.magic.describe_reserves().into_iter().map(|reserve| Line::from(format!("  {}", reserve))));
+            combat_skills.extend([
+                Line::from(Span::styled(format!("Carry:         {:.0}/{} lbs", character.current_weight(), character.secondary_stats().carry_capacity),
+                    if character.is_overloaded() { Style::default().fg(theme.warning) } else { Style::default() })),
+                Line::from(format!("Movement:      {} ft/round", character.secondary_stats().movement)),
                 Line::from(""),
                 Line::from(Span::styled("Skills", Style::default().add_modifier(Modifier::BOLD))),
                 Line::from(""),
-            ];
+            ]);
 
             for (skill, level) in &character.skills {
                 combat_skills.push(Line::from(format!("{}: {}", skill, level)));
             }
 
             let combat_panel = Paragraph::new(combat_skills)
-                .block(Block::default().borders(Borders::ALL).title("Combat & Skills").border_style(Style::default().fg(Color::Red)))
+                .block(Block::default().borders(Borders::ALL).title("Combat & Skills").border_style(Style::default().fg(theme.enemy)))
                 .wrap(ratatui::widgets::Wrap { trim: true });
             f.render_widget(combat_panel, right_chunks[1]);
 
             // Controls
-            let controls = Paragraph::new("ESC/M: Return to Game | Q/Ctrl+C: Quit")
-                .style(Style::default().fg(Color::DarkGray))
+            let controls = Paragraph::new("I: Inventory | B: Bestiary | J: Quests | ESC/M: Return to Game | Q/Ctrl+C: Quit")
+                .style(Style::default().fg(theme.muted))
                 .alignment(Alignment::Center)
-                .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(Color::DarkGray)));
+                .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(theme.muted)));
             f.render_widget(controls, left_chunks[2]);
         } else {
             let no_char = Paragraph::new("No character loaded.")
-                .style(Style::default().fg(Color::Red))
+                .style(Style::default().fg(theme.enemy))
                 .alignment(Alignment::Center)
-                .block(Block::default().borders(Borders::ALL).title("Character Menu").border_style(Style::default().fg(Color::Red)));
+                .block(Block::default().borders(Borders::ALL).title("Character Menu").border_style(Style::default().fg(theme.enemy)));
             f.render_widget(no_char, area);
         }
     }
 
-    fn draw_world_exploration_static(f: &mut Frame, world_state: &WorldExplorationState, current_character: Option<&crate::forge::ForgeCharacter>) {
+    fn draw_world_map_static(f: &mut Frame, theme: &Theme, map_state: &WorldMapState) {
+        let area = f.size();
+        
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(5),
+                Constraint::Length(3),
+            ])
+            .split(area);
+        
+        let zone = map_state.world_state.current_zone;
+        let title = Paragraph::new(format!("🗺️ World Map - zone ({}, {})", zone.x, zone.y))
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
+        f.render_widget(title, chunks[0]);
+        
+        let lines: Vec<Line> = map_state.cells.iter().enumerate()
+            .map(|(row, cells)| Line::from(cells.iter().enumerate()
+                .map(|(column, cell)| match cell {
+                    _ if (column, row) == map_state.player_cell => Span::styled("@", Style::default().fg(theme.player).add_modifier(Modifier::BOLD)),
+                    Some(terrain) => Span::styled("█", Style::default().fg(theme.terrain_color(terrain))),
+                    None => Span::styled("░", Style::default().fg(theme.subtle)),
+                })
+                .collect::<Vec<_>>()))
+            .collect();
+        let map = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Explored Lands").border_style(Style::default().fg(theme.accent)));
+        f.render_widget(map, chunks[1]);
+        
+        let controls = Paragraph::new("@: You | ░: Unexplored | M/ESC: Close map")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(theme.muted)));
+        f.render_widget(controls, chunks[2]);
+    }
+
+    fn draw_inventory_static(f: &mut Frame, theme: &Theme, inventory_state: &InventoryState, current_character: Option<&crate::forge::ForgeCharacter>) {
+        let area = f.size();
+        
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(5),
+                Constraint::Length(3),
+                Constraint::Length(3),
+            ])
+            .split(area);
+        
+        let title = Paragraph::new("🎒 Inventory")
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
+        f.render_widget(title, chunks[0]);
+        
+        let Some(character) = current_character else {
+            return;
+        };
+        
+        let purse = Paragraph::new(format!("💰 Gold: {}   |   Load: {:.0}/{:.0} lbs   |   Equipped: {}",
+                character.gold, character.current_weight(), character.carry_capacity(), character.describe_equipment()))
+            .style(Style::default().fg(if character.is_overloaded() { theme.warning } else { theme.text }))
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.accent)));
+        f.render_widget(purse, chunks[1]);
+        
+        let summary = character.inventory_summary();
+        let mut lines = Vec::new();
+        if summary.is_empty() {
+            lines.push(Line::from(Span::styled("Your pack is empty.", Style::default().fg(theme.muted))));
+        }
+        for (index, (name, count)) in summary.iter().enumerate() {
+            let label = if *count > 1 { format!("{} x{}", name, count) } else { name.clone() };
+            let tag = if crate::forge::Weapon::from_item_name(name).is_some() || crate::forge::Armor::from_item_name(name).is_some() {
+                " (equip)"
+            } else if crate::forge::ForgeCharacter::is_consumable(name) {
+                " (use)"
+            } else {
+                ""
+            };
+            let (prefix, style) = if index == inventory_state.selected {
+                ("▶ ", Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD))
+            } else {
+                ("  ", Style::default().fg(theme.text))
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}{}", prefix, label), style),
+                Span::styled(tag, Style::default().fg(theme.muted)),
+            ]));
+        }
+        
+        // Keep the selected item in view
+        let visible = chunks[2].height.saturating_sub(2) as usize;
+        let scroll = inventory_state.selected.saturating_sub(visible.saturating_sub(1));
+        let list = Paragraph::new(lines)
+            .scroll((scroll as u16, 0))
+            .block(Block::default().borders(Borders::ALL).title("Pack").border_style(Style::default().fg(theme.magic)));
+        f.render_widget(list, chunks[2]);
+        
+        let message = Paragraph::new(inventory_state.message.clone().unwrap_or_default())
+            .style(Style::default().fg(theme.success))
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.muted)));
+        f.render_widget(message, chunks[3]);
+        
+        let controls = Paragraph::new("↑/↓: Select | Enter: Use or Equip | D: Drop one | ESC/I: Back")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(theme.muted)));
+        f.render_widget(controls, chunks[4]);
+    }
+
+    fn draw_bestiary_static(f: &mut Frame, theme: &Theme, current_character: Option<&crate::forge::ForgeCharacter>) {
+        let area = f.size();
+        
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(5),
+                Constraint::Length(3),
+            ])
+            .split(area);
+        
+        let title = Paragraph::new("📖 Bestiary")
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
+        f.render_widget(title, chunks[0]);
+        
+        let mut lines = Vec::new();
+        match current_character {
+            Some(character) if !character.bestiary.is_empty() => {
+                for entry in character.bestiary.entries() {
+                    lines.push(Line::from(Span::styled(
+                        format!("{} - met {} time(s), {} slain (first seen on day {})",
+                            entry.creature_type.get_name(), entry.encountered, entry.defeated, entry.first_seen_day),
+                        Style::default().fg(theme.creature_color(&entry.creature_type)).add_modifier(Modifier::BOLD),
+                    )));
+                    if entry.stats_known() {
+                        lines.push(Line::from(format!("   HP up to {} | Attack {} | Defense {}",
+                            entry.max_hit_points, entry.attack_value, entry.defensive_value)));
+                    } else {
+                        lines.push(Line::from(Span::styled(
+                            format!("   Slay {} to learn its strength", crate::world::BESTIARY_STATS_KILLS),
+                            Style::default().fg(theme.muted),
+                        )));
+                    }
+                    if entry.weakness_known() {
+                        lines.push(Line::from(Span::styled(format!("   Weakness: {}", entry.creature_type.get_weakness()), Style::default().fg(theme.success))));
+                    } else if entry.stats_known() {
+                        lines.push(Line::from(Span::styled(
+                            format!("   Slay {} to learn its weakness", crate::world::BESTIARY_WEAKNESS_KILLS),
+                            Style::default().fg(theme.muted),
+                        )));
+                    }
+                    lines.push(Line::from(""));
+                }
+            }
+            _ => {
+                lines.push(Line::from(Span::styled("You haven't fought anything worth writing down yet.", Style::default().fg(theme.muted))));
+            }
+        }
+        
+        let entries = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Creatures Encountered").border_style(Style::default().fg(theme.accent)))
+            .wrap(ratatui::widgets::Wrap { trim: true });
+        f.render_widget(entries, chunks[1]);
+        
+        let controls = Paragraph::new("ESC/B: Back to Character Menu | Q/Ctrl+C: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(theme.muted)));
+        f.render_widget(controls, chunks[2]);
+    }
+
+    fn draw_quests_static(f: &mut Frame, theme: &Theme, current_character: Option<&crate::forge::ForgeCharacter>) {
+        let area = f.size();
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(5),
+                Constraint::Length(3),
+            ])
+            .split(area);
+
+        let title = Paragraph::new("📜 Quests")
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
+        f.render_widget(title, chunks[0]);
+
+        let mut lines = Vec::new();
+        match current_character {
+            Some(character) if !character.active_quests.is_empty() => {
+                for quest in &character.active_quests {
+                    lines.push(Line::from(Span::styled(
+                        format!("{} (for {})", quest.objective.describe(), quest.giver),
+                        Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+                    )));
+                    lines.push(Line::from(format!("   {}", quest.describe_progress())));
+                    lines.push(Line::from(Span::styled(format!("   Reward: {}", quest.reward.describe()), Style::default().fg(theme.success))));
+                    lines.push(Line::from(""));
+                }
+            }
+            _ => {
+                lines.push(Line::from(Span::styled("No quests yet. Townsfolk who trust you may have work.", Style::default().fg(theme.muted))));
+            }
+        }
+        if let Some(character) = current_character.filter(|character| !character.completed_quests.is_empty()) {
+            lines.push(Line::from(Span::styled(format!("Quests completed: {}", character.completed_quests.len()), Style::default().fg(theme.muted))));
+        }
+
+        let entries = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Active Quests").border_style(Style::default().fg(theme.accent)))
+            .wrap(ratatui::widgets::Wrap { trim: true });
+        f.render_widget(entries, chunks[1]);
+
+        let controls = Paragraph::new("ESC/J: Back to Character Menu | Q/Ctrl+C: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(theme.muted)));
+        f.render_widget(controls, chunks[2]);
+    }
+
+    fn draw_world_exploration_static(f: &mut Frame, theme: &Theme, tileset: &Tileset, world_state: &WorldExplorationState, input_buffer: &str, current_character: Option<&crate::forge::ForgeCharacter>) {
         let area = f.size();
         
         // Main layout: 2/3 for world/status, 1/3 for messages
@@ -1251,20 +2002,20 @@ impl GameUI {
         let title_text = format!("World Exploration - Zone ({}, {})", 
             world_state.current_zone.x, world_state.current_zone.y);
         let title = Paragraph::new(title_text)
-            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.title).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
         f.render_widget(title, left_chunks[0]);
 
         // Generate world view from actual zone data - calculate available space
         let available_height = left_chunks[1].height.saturating_sub(3); // Subtract borders and title
         let available_width = left_chunks[1].width.saturating_sub(2); // Subtract borders
-        let world_content = Self::generate_world_view(world_state, available_width as i32, available_height as i32);
+        let world_content = Self::generate_world_view(world_state, current_character.map(|c| &c.map_memory), available_width as i32, available_height as i32, theme, tileset);
         
         let world = Paragraph::new(world_content)
-            .style(Style::default().fg(Color::White))
+            .style(Style::default().fg(theme.text))
             .alignment(Alignment::Left)
-            .block(Block::default().borders(Borders::ALL).title("World View").border_style(Style::default().fg(Color::Green)));
+            .block(Block::default().borders(Borders::ALL).title("World View").border_style(Style::default().fg(theme.success)));
         f.render_widget(world, left_chunks[1]);
 
         // Status panel
@@ -1273,15 +2024,18 @@ impl GameUI {
             Line::from(""),
             Line::from(format!("Zone: ({}, {})", world_state.current_zone.x, world_state.current_zone.y)),
             Line::from(format!("Position: ({}, {})", world_state.player_local_pos.x, world_state.player_local_pos.y)),
-            Line::from(""),
         ];
+        if let Some(character) = current_character {
+            status_lines.push(Line::from(format!("Day {}, {:02}:00 ({})", character.current_day(), character.hour_of_day(), character.time_of_day().get_name())));
+        }
+        status_lines.push(Line::from(""));
 
         if let Some(zone_data) = &world_state.zone_data {
             let settlement_count = zone_data.settlements.len();
             let road_count = zone_data.roads.roads.len();
             
             status_lines.extend(vec![
-                Line::from(Span::styled("Zone Contents:", Style::default().fg(Color::Cyan))),
+                Line::from(Span::styled("Zone Contents:", Style::default().fg(theme.accent))),
                 Line::from(format!("Settlements: {}", settlement_count)),
                 Line::from(format!("Roads: {}", road_count)),
                 Line::from(""),
@@ -1289,7 +2043,7 @@ impl GameUI {
 
             // Show nearby settlements
             if !zone_data.settlements.is_empty() {
-                status_lines.push(Line::from(Span::styled("Settlements:", Style::default().fg(Color::Green))));
+                status_lines.push(Line::from(Span::styled("Settlements:", Style::default().fg(theme.success))));
                 for settlement in &zone_data.settlements {
                     let distance = ((settlement.position.x - world_state.player_local_pos.x).pow(2) + 
                                   (settlement.position.y - world_state.player_local_pos.y).pow(2)) as f32;
@@ -1328,42 +2082,58 @@ impl GameUI {
                         crate::world::TerrainType::Tundra => "Tundra",
                     };
                     status_lines.extend(vec![
-                        Line::from(Span::styled("Current Location:", Style::default().fg(Color::Yellow))),
+                        Line::from(Span::styled("Current Location:", Style::default().fg(theme.title))),
                         Line::from(format!("Terrain: {}", terrain_name)),
                         Line::from(format!("Elevation: {}", tile.elevation)),
                     ]);
                 }
             }
         } else {
-            status_lines.push(Line::from(Span::styled("World data loading...", Style::default().fg(Color::DarkGray))));
+            status_lines.push(Line::from(Span::styled("World data loading...", Style::default().fg(theme.muted))));
+        }
+
+        if let Some(region) = &world_state.region {
+            status_lines.extend(vec![
+                Line::from(""),
+                Line::from(Span::styled("Region:", Style::default().fg(theme.magic))),
+                Line::from(region.name.clone()),
+                Line::from(format!("Held by {} ({})", region.faction, region.ruler)),
+            ]);
+            if let Some(character) = current_character {
+                status_lines.push(Line::from(format!("Standing: {} ({})",
+                    character.get_standing(&region.faction).get_name(),
+                    character.get_reputation(&region.faction))));
+            }
         }
 
         if let Some(character) = current_character {
             status_lines.extend(vec![
                 Line::from(""),
-                Line::from(Span::styled("Character Status:", Style::default().fg(Color::Cyan))),
+                Line::from(Span::styled("Character Status:", Style::default().fg(theme.accent))),
                 Line::from(format!("HP: {}/{}", character.combat_stats.hit_points.current, character.combat_stats.hit_points.max)),
                 Line::from(format!("Gold: {}", character.gold)),
+                Line::from(format!("Zones mapped: {}", character.map_memory.visited_zone_count())),
             ]);
         }
 
         let status_panel = Paragraph::new(status_lines)
-            .block(Block::default().borders(Borders::ALL).title("Status").border_style(Style::default().fg(Color::Cyan)))
+            .block(Block::default().borders(Borders::ALL).title("Status").border_style(Style::default().fg(theme.accent)))
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(status_panel, right_chunks[0]);
 
         // Legend panel
         let legend_lines = vec![
             Line::from(Span::styled("Legend:", Style::default().add_modifier(Modifier::BOLD))),
-            Line::from("@ = You"),
-            Line::from("█●○◦· = Settlements"),
-            Line::from("MGTHR! = NPCs"),
-            Line::from("⌂◊♜♠♦ = POIs"),
-            Line::from("♣^▲.,~ = Terrain"),
-            Line::from("═ = Roads"),
+            Line::from(format!("{} = You", tileset.glyph("player"))),
+            Line::from(format!("{} = Settlements", tileset.glyphs(&["settlement.capital", "settlement.city", "settlement.town", "settlement.village", "settlement.outpost"]))),
+            Line::from(format!("{} = NPCs", tileset.glyphs(&["npc.merchant", "npc.guard", "npc.traveler", "npc.hermit", "npc.ranger", "npc.bandit"]))),
+            Line::from(format!("{} = POIs", tileset.glyphs(&["poi.ancientruins", "poi.cave", "poi.abandonedtower", "poi.mysticshrine", "poi.dragonlair"]))),
+            Line::from(format!("{} = Terrain", tileset.glyphs(&["terrain.forest", "terrain.hill", "terrain.mountain", "terrain.plains", "terrain.grassland", "terrain.ocean"]))),
+            Line::from(format!("{} = Roads  {} = Bridges", tileset.glyph("road"), tileset.glyph("bridge"))),
+            Line::from("Blank = Unexplored"),
         ];
         let legend_panel = Paragraph::new(legend_lines)
-            .block(Block::default().borders(Borders::ALL).title("Legend").border_style(Style::default().fg(Color::Yellow)))
+            .block(Block::default().borders(Borders::ALL).title("Legend").border_style(Style::default().fg(theme.title)))
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(legend_panel, right_chunks[1]);
 
@@ -1378,31 +2148,58 @@ impl GameUI {
             
             world_state.messages.iter()
                 .rev()
+                .skip(world_state.message_scroll)
                 .take(max_messages)
                 .rev()
                 .cloned()
                 .collect::<Vec<String>>()
                 .join("\n")
         };
+        let dialog_text = if world_state.chat_prompt {
+            format!("{}\n💬 Say: {}_", dialog_text, input_buffer)
+        } else {
+            dialog_text
+        };
+        
+        // While talking, the responses sit beside the message log
+        let conversation = world_state.conversation.and_then(|conversation| {
+            world_state.zone_data.as_ref()
+                .and_then(|zone_data| zone_data.npcs.get(conversation.npc_index as usize))
+                .map(|npc| (npc, conversation))
+        });
+        let message_area = match conversation {
+            Some((npc, conversation)) => {
+                let bottom_chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                    .split(main_chunks[1]);
+                match conversation.trade {
+                    Some(cursor) => Self::draw_trade(f, theme, bottom_chunks[1], world_state, npc, cursor, current_character),
+                    None => Self::draw_conversation(f, theme, bottom_chunks[1], world_state, npc, conversation.node as usize, current_character),
+                }
+                bottom_chunks[0]
+            }
+            None => main_chunks[1],
+        };
         
         let dialog_panel = Paragraph::new(dialog_text)
-            .block(Block::default().borders(Borders::ALL).title("Messages").border_style(Style::default().fg(Color::Green)))
+            .block(Block::default().borders(Borders::ALL).title(Self::message_log_title(world_state.message_scroll)).border_style(Style::default().fg(theme.success)))
             .wrap(ratatui::widgets::Wrap { trim: true });
-        f.render_widget(dialog_panel, main_chunks[1]);
+        f.render_widget(dialog_panel, message_area);
         
         // Controls
         let controls_text = vec![
-            Line::from("WASD/Arrow Keys: Move | M: Menu | F: Fight | Q: Quit | H: Help"),
-            Line::from("L: Look | E: Enter/Examine | P: POIs | T: Talk | R: Search | I: Interact | C: Camp | G: Gather"),
+            Line::from("WASD/Arrow Keys: Move | M: Menu | F: Fight | Q: Quit | H: Help | PgUp/PgDn: Message log | Shift+T: Chat"),
+            Line::from("L: Look | E: Enter/Examine | P: POIs | T: Talk | R: Search | I: Interact | C: Camp | G: Gather | U: Hunt | N: Fish"),
         ];
         let controls = Paragraph::new(controls_text)
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme.muted))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(Color::DarkGray)));
+            .block(Block::default().borders(Borders::ALL).title("Controls").border_style(Style::default().fg(theme.muted)));
         f.render_widget(controls, left_chunks[2]);
     }
 
-    fn generate_world_view(world_state: &WorldExplorationState, view_width: i32, view_height: i32) -> Vec<Line<'static>> {
+    fn generate_world_view(world_state: &WorldExplorationState, map_memory: Option<&crate::world::MapMemory>, view_width: i32, view_height: i32, theme: &Theme, tileset: &Tileset) -> Vec<Line<'static>> {
         let mut world_content = vec![];
         
         if let Some(zone_data) = &world_state.zone_data {
@@ -1431,7 +2228,10 @@ impl GameUI {
                     
                     if screen_x == center_x && screen_y == center_y {
                         // Player always at center - bright yellow
-                        line_spans.push(Span::styled("@", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+                        line_spans.push(Span::styled(tileset.glyph("player").to_string(), Style::default().fg(tileset.color("player", theme.player)).add_modifier(Modifier::BOLD)));
+                    } else if world_state.other_players.contains(&crate::world::WorldCoord::from_zone_local(world_state.current_zone, crate::world::LocalCoord::new(x, y))) {
+                        // Other players online, in a different color from the player's own @
+                        line_spans.push(Span::styled(tileset.glyph("player").to_string(), Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)));
                     } else {
                         // Handle coordinates that might be outside current zone
                         let (zone_coord, local_x, local_y) = if x < 0 || x >= crate::world::ZONE_SIZE || y < 0 || y >= crate::world::ZONE_SIZE {
@@ -1467,25 +2267,35 @@ impl GameUI {
                         
                         // For now, show void for adjacent zones (we'd need to load them for seamless transitions)
                         if zone_coord.is_some() {
-                            line_spans.push(Span::styled("·", Style::default().fg(Color::DarkGray))); // Show faded terrain for adjacent zones
+                            line_spans.push(Span::styled(tileset.glyph("offmap").to_string(), Style::default().fg(theme.muted))); // Show faded terrain for adjacent zones
                             continue;
                         }
                         // Use the calculated local coordinates for lookups
                         let lookup_x = local_x;
                         let lookup_y = local_y;
                         
+                        // Tiles the character has never seen stay unknown
+                        if let Some(memory) = map_memory {
+                            if !memory.is_seen(world_state.current_zone, crate::world::LocalCoord::new(lookup_x, lookup_y)) {
+                                line_spans.push(Span::raw(tileset.glyph("unexplored").to_string()));
+                                continue;
+                            }
+                        }
+                        
                         // Check for settlements first
                         let mut found_settlement = false;
                         for settlement in &zone_data.settlements {
                             if settlement.position.x == lookup_x && settlement.position.y == lookup_y {
                                 found_settlement = true;
-                                match settlement.settlement_type {
-                                    crate::world::SettlementType::Capital => line_spans.push(Span::styled("█", Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD))),
-                                    crate::world::SettlementType::City => line_spans.push(Span::styled("●", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
-                                    crate::world::SettlementType::Town => line_spans.push(Span::styled("○", Style::default().fg(Color::White))),
-                                    crate::world::SettlementType::Village => line_spans.push(Span::styled("◦", Style::default().fg(Color::LightYellow))),
-                                    crate::world::SettlementType::Outpost => line_spans.push(Span::styled("·", Style::default().fg(Color::Gray))),
-                                }
+                                let key = Tileset::type_key("settlement", &settlement.settlement_type);
+                                let (color, modifier) = match settlement.settlement_type {
+                                    crate::world::SettlementType::Capital => (theme.magic, Modifier::BOLD),
+                                    crate::world::SettlementType::City => (theme.accent, Modifier::BOLD),
+                                    crate::world::SettlementType::Town => (theme.text, Modifier::empty()),
+                                    crate::world::SettlementType::Village => (theme.highlight, Modifier::empty()),
+                                    crate::world::SettlementType::Outpost => (theme.subtle, Modifier::empty()),
+                                };
+                                line_spans.push(Span::styled(tileset.glyph(&key).to_string(), Style::default().fg(tileset.color(&key, color)).add_modifier(modifier)));
                                 break;
                             }
                         }
@@ -1496,24 +2306,9 @@ impl GameUI {
                             for npc in &zone_data.npcs {
                                 if npc.position.x == lookup_x && npc.position.y == lookup_y {
                                     found_npc = true;
-                                    let npc_color = match npc.npc_type {
-                                        crate::world::NPCType::Merchant => Color::Yellow,
-                                        crate::world::NPCType::Guard => Color::Blue,
-                                        crate::world::NPCType::Traveler => Color::Green,
-                                        crate::world::NPCType::Hermit => Color::Gray,
-                                        crate::world::NPCType::Scholar => Color::Cyan,
-                                        crate::world::NPCType::Warrior => Color::Red,
-                                        crate::world::NPCType::Thief => Color::DarkGray,
-                                        crate::world::NPCType::Farmer => Color::LightGreen,
-                                        crate::world::NPCType::Noble => Color::Magenta,
-                                        crate::world::NPCType::Blacksmith => Color::Gray,
-                                        crate::world::NPCType::Innkeeper => Color::LightYellow,
-                                        crate::world::NPCType::Priest => Color::White,
-                                        crate::world::NPCType::Ranger => Color::Green,
-                                        crate::world::NPCType::Bandit => Color::Red,
-                                        crate::world::NPCType::Explorer => Color::Cyan,
-                                    };
-                                    line_spans.push(Span::styled(npc.npc_type.get_ascii_char().to_string(), Style::default().fg(npc_color)));
+                                    let key = Tileset::type_key("npc", &npc.npc_type);
+                                    let npc_color = tileset.color(&key, theme.npc_color(&npc.npc_type));
+                                    line_spans.push(Span::styled(tileset.glyph(&key).to_string(), Style::default().fg(npc_color)));
                                     break;
                                 }
                             }
@@ -1524,20 +2319,21 @@ impl GameUI {
                                 for poi in &zone_data.points_of_interest {
                                     if poi.position.x == lookup_x && poi.position.y == lookup_y {
                                         found_poi = true;
-                                        let (symbol, color) = match poi.poi_type {
-                                            crate::world::PoiType::AncientRuins => ('⌂', Color::LightYellow),
-                                            crate::world::PoiType::Cave => ('◊', Color::Gray),
-                                            crate::world::PoiType::AbandonedTower => ('♜', Color::DarkGray),
-                                            crate::world::PoiType::MysticShrine => ('♠', Color::Magenta),
-                                            crate::world::PoiType::DragonLair => ('♦', Color::Red),
-                                            crate::world::PoiType::BanditCamp => ('▲', Color::Red),
-                                            crate::world::PoiType::WizardTower => ('♨', Color::Blue),
-                                            crate::world::PoiType::Temple => ('⌘', Color::White),
-                                            crate::world::PoiType::Crypt => ('◘', Color::DarkGray),
-                                            crate::world::PoiType::TreasureVault => ('♛', Color::Yellow),
-                                            _ => ('?', Color::White),
+                                        let key = Tileset::type_key("poi", &poi.poi_type);
+                                        let color = match poi.poi_type {
+                                            crate::world::PoiType::AncientRuins => theme.highlight,
+                                            crate::world::PoiType::Cave => theme.subtle,
+                                            crate::world::PoiType::AbandonedTower => theme.muted,
+                                            crate::world::PoiType::MysticShrine => theme.magic,
+                                            crate::world::PoiType::DragonLair => theme.enemy,
+                                            crate::world::PoiType::BanditCamp => theme.enemy,
+                                            crate::world::PoiType::WizardTower => theme.info,
+                                            crate::world::PoiType::Temple => theme.text,
+                                            crate::world::PoiType::Crypt => theme.muted,
+                                            crate::world::PoiType::TreasureVault => theme.title,
+                                            _ => theme.text,
                                         };
-                                        line_spans.push(Span::styled(symbol.to_string(), Style::default().fg(color)));
+                                        line_spans.push(Span::styled(tileset.glyph(&key).to_string(), Style::default().fg(tileset.color(&key, color))));
                                         break;
                                     }
                                 }
@@ -1550,13 +2346,14 @@ impl GameUI {
                                             if point.x == lookup_x && point.y == lookup_y {
                                                 found_road = true;
                                                 let road_color = match road.road_type {
-                                                    crate::world::RoadType::Trail => Color::DarkGray,
-                                                    crate::world::RoadType::Path => Color::Gray,
-                                                    crate::world::RoadType::Road => Color::LightYellow,
-                                                    crate::world::RoadType::Highway => Color::Yellow,
-                                                    crate::world::RoadType::Imperial => Color::White,
+                                                    crate::world::RoadType::Trail => theme.muted,
+                                                    crate::world::RoadType::Path => theme.subtle,
+                                                    crate::world::RoadType::Road => theme.highlight,
+                                                    crate::world::RoadType::Highway => theme.title,
+                                                    crate::world::RoadType::Imperial => theme.text,
                                                 };
-                                                line_spans.push(Span::styled("═", Style::default().fg(road_color)));
+                                                let key = if road.bridges.contains(point) { "bridge" } else { "road" };
+                                                line_spans.push(Span::styled(tileset.glyph(key).to_string(), Style::default().fg(tileset.color(key, road_color))));
                                                 break;
                                             }
                                         }
@@ -1567,20 +2364,9 @@ impl GameUI {
                                         // Show terrain with subtle colors
                                         if let Some(row) = zone_data.terrain.tiles.get(lookup_y as usize) {
                                             if let Some(tile) = row.get(lookup_x as usize) {
-                                                let (symbol, base_color) = match tile.terrain_type {
-                                                    crate::world::TerrainType::Ocean => ('~', Color::Blue),
-                                                    crate::world::TerrainType::Lake => ('~', Color::Cyan),
-                                                    crate::world::TerrainType::River => ('~', Color::LightBlue),
-                                                    crate::world::TerrainType::Plains => ('.', Color::Yellow),
-                                                    crate::world::TerrainType::Grassland => (',', Color::LightGreen),
-                                                    crate::world::TerrainType::Forest => ('♣', Color::Green),
-                                                    crate::world::TerrainType::Hill => ('^', Color::LightGreen),
-                                                    crate::world::TerrainType::Mountain => ('▲', Color::White),
-                                                    crate::world::TerrainType::Desert => ('·', Color::LightYellow),
-                                                    crate::world::TerrainType::Swamp => ('≈', Color::DarkGray),
-                                                    crate::world::TerrainType::Snow => ('*', Color::White),
-                                                    crate::world::TerrainType::Tundra => (':', Color::Gray),
-                                                };
+                                                let key = Tileset::type_key("terrain", &tile.terrain_type);
+                                                let symbol = tileset.glyph(&key);
+                                                let base_color = tileset.color(&key, theme.terrain_color(&tile.terrain_type));
                                                 
                                                 // Add subtle variation based on elevation and fertility
                                                 let mut style = Style::default().fg(base_color);
@@ -1591,10 +2377,8 @@ impl GameUI {
                                                 } else if tile.elevation < 25.0 {
                                                     // Lower elevation areas are slightly darker
                                                     style = match base_color {
-                                                        Color::Green => style.fg(Color::DarkGray),
-                                                        Color::LightGreen => style.fg(Color::Green),
-                                                        Color::Yellow => style.fg(Color::DarkGray),
-                                                        Color::LightYellow => style.fg(Color::Yellow),
+                                                        Color::Green | Color::Yellow => style.fg(theme.muted),
+                                                        Color::LightGreen | Color::LightYellow => style.fg(Theme::dimmed(base_color)),
                                                         _ => style,
                                                     };
                                                 }
@@ -1605,7 +2389,7 @@ impl GameUI {
                                                     crate::world::TerrainType::Grassland |
                                                     crate::world::TerrainType::Hill |
                                                     crate::world::TerrainType::Forest) {
-                                                    style = style.fg(Color::LightGreen);
+                                                    style = style.fg(theme.terrain_color(&crate::world::TerrainType::Grassland));
                                                 } else if tile.fertility < 0.3 && !matches!(tile.terrain_type,
                                                     crate::world::TerrainType::Ocean | 
                                                     crate::world::TerrainType::Lake | 
@@ -1613,15 +2397,15 @@ impl GameUI {
                                                     crate::world::TerrainType::Desert |
                                                     crate::world::TerrainType::Snow) {
                                                     // Poor fertility areas are more brown/gray
-                                                    style = style.fg(Color::DarkGray);
+                                                    style = style.fg(theme.muted);
                                                 }
                                                 
                                                 line_spans.push(Span::styled(symbol.to_string(), style));
                                             } else {
-                                                line_spans.push(Span::styled("?", Style::default().fg(Color::Red)));
+                                                line_spans.push(Span::styled("?", Style::default().fg(theme.enemy)));
                                             }
                                         } else {
-                                            line_spans.push(Span::styled("?", Style::default().fg(Color::Red)));
+                                            line_spans.push(Span::styled("?", Style::default().fg(theme.enemy)));
                                         }
                                     }
                                 }
@@ -1643,7 +2427,7 @@ impl GameUI {
         world_content
     }
 
-    fn draw_dungeon_exploration_static(f: &mut Frame, dungeon_state: &DungeonExplorationState, current_character: Option<&crate::forge::ForgeCharacter>) {
+    fn draw_dungeon_exploration_static(f: &mut Frame, theme: &Theme, tileset: &Tileset, dungeon_state: &DungeonExplorationState, current_character: Option<&crate::forge::ForgeCharacter>) {
         let area = f.size();
         
         // Main layout: 2/3 for dungeon view/status, 1/3 for messages
@@ -1682,26 +2466,26 @@ impl GameUI {
             dungeon_state.dungeon.name, 
             dungeon_state.dungeon.current_floor + 1);
         let title = Paragraph::new(title_text)
-            .style(Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.magic).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Magenta)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.magic)));
         f.render_widget(title, left_chunks[0]);
 
         // Generate dungeon view
         let available_height = left_chunks[1].height.saturating_sub(2); // Subtract borders
         let available_width = left_chunks[1].width.saturating_sub(2); // Subtract borders
-        let dungeon_content = Self::generate_dungeon_view(dungeon_state, available_width as i32, available_height as i32);
+        let dungeon_content = Self::generate_dungeon_view(dungeon_state, available_width as i32, available_height as i32, theme, tileset);
         
         let dungeon = Paragraph::new(dungeon_content)
-            .style(Style::default().fg(Color::White))
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Gray)));
+            .style(Style::default().fg(theme.text))
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.subtle)));
         f.render_widget(dungeon, left_chunks[1]);
 
         // Controls at bottom
-        let controls = Paragraph::new("WASD/Arrows: Move | (E)xamine | (I)nteract | (F)ight | (U)se stairs | (L)ook | (X)it dungeon | Ctrl+Q: Quit")
-            .style(Style::default().fg(Color::Green))
+        let controls = Paragraph::new("WASD/Arrows: Move | (E)xamine | (I)nteract | (F)ight | (U)se stairs | (L)ook | (X)it dungeon | PgUp/PgDn: Log | Ctrl+Q: Quit")
+            .style(Style::default().fg(theme.success))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Green)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.success)));
         f.render_widget(controls, left_chunks[2]);
 
         // Character status (right top)
@@ -1712,7 +2496,11 @@ impl GameUI {
                 Line::from(format!("HP: {}/{}", character.combat_stats.hit_points.current, character.combat_stats.hit_points.max)),
                 Line::from(format!("Gold: {}", character.gold)),
                 Line::from(format!("Position: ({}, {})", dungeon_state.player_pos.x, dungeon_state.player_pos.y)),
-                Line::from(format!("Turn: {}", dungeon_state.turn_count)),
+                Line::from(if dungeon_state.safe_turns > 0 {
+                    format!("Turn: {} (unnoticed for {})", dungeon_state.turn_count, dungeon_state.safe_turns)
+                } else {
+                    format!("Turn: {}", dungeon_state.turn_count)
+                }),
                 Line::from(""),
                 Line::from("Equipment:"),
                 Line::from("• Simple tools"),
@@ -1723,8 +2511,8 @@ impl GameUI {
         };
 
         let status = Paragraph::new(status_content)
-            .style(Style::default().fg(Color::Cyan))
-            .block(Block::default().title("Status").borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)));
+            .style(Style::default().fg(theme.accent))
+            .block(Block::default().title("Status").borders(Borders::ALL).border_style(Style::default().fg(theme.accent)));
         f.render_widget(status, right_chunks[0]);
 
         // Floor info (right bottom)
@@ -1742,26 +2530,146 @@ impl GameUI {
         };
 
         let floor_panel = Paragraph::new(floor_info)
-            .style(Style::default().fg(Color::Yellow))
-            .block(Block::default().title("Floor Info").borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
+            .style(Style::default().fg(theme.title))
+            .block(Block::default().title("Floor Info").borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
         f.render_widget(floor_panel, right_chunks[1]);
 
         // Messages area (bottom)
         let message_content: Vec<Line> = dungeon_state.messages.iter()
             .rev()
+            .skip(dungeon_state.message_scroll)
             .take(10)
             .rev()
             .map(|msg| Line::from(msg.clone()))
             .collect();
 
         let messages = Paragraph::new(message_content)
-            .style(Style::default().fg(Color::White))
-            .block(Block::default().title("Messages").borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)))
+            .style(Style::default().fg(theme.text))
+            .block(Block::default().title(Self::message_log_title(dungeon_state.message_scroll)).borders(Borders::ALL).border_style(Style::default().fg(theme.info)))
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(messages, main_chunks[1]);
     }
 
-    fn generate_dungeon_view(dungeon_state: &DungeonExplorationState, view_width: i32, view_height: i32) -> Vec<Line<'static>> {
+    fn draw_conversation(f: &mut Frame, theme: &Theme, area: ratatui::layout::Rect, world_state: &WorldExplorationState, npc: &crate::world::NPC, node: usize, current_character: Option<&crate::forge::ForgeCharacter>) {
+        let reputation = match (&world_state.region, current_character) {
+            (Some(region), Some(character)) => character.get_reputation(&region.faction),
+            _ => 0,
+        };
+        let telepathy = current_character.is_some_and(|character| character.has_telepathy());
+        
+        let tree = npc.conversation();
+        let mut lines = Vec::new();
+        if let Some(node) = tree.node(node) {
+            lines.push(Line::from(Span::styled(format!("\"{}\"", node.speech), Style::default().fg(theme.text))));
+            lines.push(Line::from(""));
+            for (index, option) in node.available_options(reputation, telepathy).iter().enumerate() {
+                lines.push(Line::from(Span::styled(format!("{}. {}", index + 1, option.text), Style::default().fg(theme.highlight))));
+            }
+        }
+        lines.push(Line::from(""));
+        let trades = npc.services.iter().any(|service| matches!(service, crate::world::NPCService::Trade));
+        let hint = if trades { "Number keys to answer, B to trade, Esc to leave" } else { "Number keys to answer, Esc to leave" };
+        lines.push(Line::from(Span::styled(hint, Style::default().fg(theme.muted))));
+        
+        let panel = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(format!("Talking to {}", npc.name)).border_style(Style::default().fg(theme.accent)))
+            .wrap(ratatui::widgets::Wrap { trim: true });
+        f.render_widget(panel, area);
+    }
+
+    // The merchant's goods or the player's pack, priced for this merchant
+    fn draw_trade(f: &mut Frame, theme: &Theme, area: ratatui::layout::Rect, world_state: &WorldExplorationState, npc: &crate::world::NPC, cursor: crate::world::TradeCursor, current_character: Option<&crate::forge::ForgeCharacter>) {
+        let standing = match (&world_state.region, current_character) {
+            (Some(region), Some(character)) => character.get_standing(&region.faction),
+            _ => crate::world::Standing::Neutral,
+        };
+        let modifier = crate::world::trade_modifier(standing, &npc.disposition);
+        let gold = current_character.map(|character| character.gold).unwrap_or(0);
+        
+        let tab = |label: &str, active: bool| {
+            let style = if active {
+                Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default().fg(theme.muted)
+            };
+            Span::styled(format!(" {} ", label), style)
+        };
+        let mut lines = vec![
+            Line::from(vec![tab("Buy", !cursor.selling), Span::raw(" "), tab("Sell", cursor.selling), Span::styled(format!("   💰 {} gold", gold), Style::default().fg(theme.warning))]),
+            Line::from(""),
+        ];
+        
+        let (items, empty): (&[String], &str) = if cursor.selling {
+            (current_character.map(|character| character.inventory.as_slice()).unwrap_or(&[]), "Your pack is empty.")
+        } else {
+            (&npc.inventory, "They have nothing left to sell.")
+        };
+        // Keep the selection in view on long lists
+        let rows = (area.height as usize).saturating_sub(6).max(1);
+        let selected = cursor.selected as usize;
+        let first = selected.saturating_sub(rows - 1);
+        for (index, item) in items.iter().enumerate().skip(first).take(rows) {
+            let price = if cursor.selling { crate::world::sell_price(item, modifier) } else { crate::world::buy_price(item, modifier) };
+            let style = if index == selected {
+                Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            let marker = if index == selected { "▶ " } else { "  " };
+            lines.push(Line::from(Span::styled(format!("{}{} - {}g", marker, item, price), style)));
+        }
+        if items.is_empty() {
+            lines.push(Line::from(Span::styled(empty, Style::default().fg(theme.muted))));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("↑↓ select, Enter buy/sell, Tab switch, Esc back", Style::default().fg(theme.muted))));
+        
+        let panel = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(format!("Trading with {}", npc.name)).border_style(Style::default().fg(theme.accent)));
+        f.render_widget(panel, area);
+    }
+
+    // How full a pool is, from 0 to 1. An empty maximum counts as empty.
+    fn pool_ratio(current: u32, max: u32) -> f64 {
+        if max == 0 {
+            0.0
+        } else {
+            (current as f64 / max as f64).min(1.0)
+        }
+    }
+
+    // Green while healthy, yellow below half and red below a quarter
+    fn pool_color(theme: &Theme, ratio: f64) -> Color {
+        if ratio < 0.25 {
+            theme.enemy
+        } else if ratio < 0.5 {
+            theme.warning
+        } else {
+            theme.success
+        }
+    }
+
+    fn pool_gauge<'a>(theme: &Theme, current: u32, max: u32) -> Gauge<'a> {
+        let ratio = Self::pool_ratio(current, max);
+        Gauge::default()
+            .gauge_style(Style::default().fg(Self::pool_color(theme, ratio)).bg(theme.background))
+            .ratio(ratio)
+            .label(format!("{}/{}", current, max))
+    }
+
+    fn message_log_title(scroll: usize) -> String {
+        Self::log_title("Messages", scroll)
+    }
+
+    fn log_title(name: &str, scroll: usize) -> String {
+        if scroll == 0 {
+            format!("{} (PgUp: older)", name)
+        } else {
+            format!("{} ({} newer below, PgDn: newer)", name, scroll)
+        }
+    }
+
+    fn generate_dungeon_view(dungeon_state: &DungeonExplorationState, view_width: i32, view_height: i32, theme: &Theme, tileset: &Tileset) -> Vec<Line<'static>> {
         let mut dungeon_content = Vec::new();
         
         if let Some(floor) = dungeon_state.dungeon.get_current_floor() {
@@ -1776,169 +2684,86 @@ impl GameUI {
             let start_y = (player_y - half_height).max(0);
             let end_y = (player_y + half_height).min(crate::world::DUNGEON_HEIGHT - 1);
             
+            let unexplored = Span::styled(tileset.glyph("unexplored").to_string(), Style::default().fg(theme.background));
+            
             for y in start_y..=end_y {
                 let mut line_spans = Vec::new();
                 
                 for x in start_x..=end_x {
+                    let tile = floor.tiles.get(y as usize).and_then(|row| row.get(x as usize));
+                    let visible = tile.map(|t| t.visible).unwrap_or(false);
+                    
                     if x == player_x && y == player_y {
                         // Player position
-                        line_spans.push(Span::styled("@", Style::default().fg(Color::LightYellow).add_modifier(Modifier::BOLD)));
-                    } else if let Some(creature) = floor.creatures.iter().find(|c| c.position.x == x && c.position.y == y) {
-                        // Creature position - only show if tile is visible
-                        if let Some(tile) = floor.tiles.get(y as usize).and_then(|row| row.get(x as usize)) {
-                            if tile.visible {
-                                let (symbol, color) = match creature.creature_type {
-                                    crate::world::CreatureType::Skeleton => ('S', Color::White),
-                                    crate::world::CreatureType::Zombie => ('Z', Color::Green),
-                                    crate::world::CreatureType::Ghost => ('G', Color::Cyan),
-                                    crate::world::CreatureType::Rat => ('r', Color::Red),
-                                    crate::world::CreatureType::Bat => ('b', Color::Gray),
-                                    crate::world::CreatureType::Spider => ('s', Color::Red),
-                                    crate::world::CreatureType::Goblin => ('g', Color::LightGreen),
-                                    crate::world::CreatureType::Orc => ('O', Color::Red),
-                                    crate::world::CreatureType::Bandit => ('B', Color::Red),
-                                    crate::world::CreatureType::GuardianSpirit => ('*', Color::LightBlue),
-                                    crate::world::CreatureType::WildAnimal => ('a', Color::Yellow),
-                                    crate::world::CreatureType::Construct => ('C', Color::Gray),
-                                };
-                                line_spans.push(Span::styled(symbol.to_string(), Style::default().fg(color)));
-                            } else {
-                                // Creature not visible - fall through to tile rendering
-                                if tile.explored {
-                                    let (symbol, color) = match &tile.tile_type {
-                                        crate::world::DungeonTileType::Wall => ('#', Color::DarkGray),
-                                        crate::world::DungeonTileType::Floor => ('.', Color::Gray),
-                                        crate::world::DungeonTileType::Door(state) => {
-                                            match state {
-                                                crate::world::DoorState::Open => ('+', Color::Gray),
-                                                crate::world::DoorState::Closed => ('D', Color::Gray),
-                                                crate::world::DoorState::Locked => ('L', Color::Gray),
-                                                crate::world::DoorState::Secret => ('#', Color::Gray), // Secret doors look like walls when not visible
-                                            }
-                                        }
-                                        crate::world::DungeonTileType::Stairs(_) => ('<', Color::Gray),
-                                        crate::world::DungeonTileType::Chest => ('$', Color::Gray),
-                                        crate::world::DungeonTileType::Altar => ('A', Color::Gray),
-                                        crate::world::DungeonTileType::Water => ('~', Color::Gray),
-                                        crate::world::DungeonTileType::Pit => ('O', Color::Gray),
-                                        crate::world::DungeonTileType::Rubble => ('&', Color::Gray),
-                                        crate::world::DungeonTileType::Pillar => ('|', Color::Gray),
-                                        crate::world::DungeonTileType::Window => ('=', Color::Gray),
-                                        crate::world::DungeonTileType::Torch => ('*', Color::Gray),
-                                    };
-                                    line_spans.push(Span::styled(symbol.to_string(), Style::default().fg(color)));
-                                } else {
-                                    line_spans.push(Span::styled(" ", Style::default().fg(Color::Black)));
-                                }
-                            }
-                        } else {
-                            // No tile data - render as empty
-                            line_spans.push(Span::styled(" ", Style::default().fg(Color::Black)));
-                        }
-                    } else if let Some(loot_pile) = floor.loot_piles.iter().find(|lp| lp.position.x == x && lp.position.y == y) {
-                        // Loot pile - show if tile is visible
-                        if let Some(tile) = floor.tiles.get(y as usize).and_then(|row| row.get(x as usize)) {
-                            if tile.visible {
-                                let symbol = if loot_pile.discovered { '$' } else { '?' };
-                                line_spans.push(Span::styled(symbol.to_string(), Style::default().fg(Color::LightYellow).add_modifier(Modifier::BOLD)));
-                            } else {
-                                // Fall through to normal tile rendering
-                                if tile.explored {
-                                    let (symbol, color) = match &tile.tile_type {
-                                        crate::world::DungeonTileType::Wall => ('#', Color::DarkGray),
-                                        crate::world::DungeonTileType::Floor => ('.', Color::Gray),
-                                        _ => ('.', Color::Gray),
-                                    };
-                                    line_spans.push(Span::styled(symbol.to_string(), Style::default().fg(color)));
-                                } else {
-                                    line_spans.push(Span::styled(" ", Style::default().fg(Color::Black)));
-                                }
-                            }
-                        } else {
-                            line_spans.push(Span::styled(" ", Style::default().fg(Color::Black)));
+                        line_spans.push(Span::styled(tileset.glyph("player").to_string(), Style::default().fg(tileset.color("player", theme.player)).add_modifier(Modifier::BOLD)));
+                    } else if let (true, Some(creature)) = (visible, floor.creatures.iter().find(|c| c.position.x == x && c.position.y == y)) {
+                        // Creatures are only shown while their tile is visible
+                        let key = Tileset::type_key("creature", &creature.creature_type);
+                        let color = tileset.color(&key, theme.creature_color(&creature.creature_type));
+                        let mut style = Style::default().fg(color);
+                        if dungeon_state.ranged_targets.contains(&creature.position) {
+                            // Highlight what the player can shoot at
+                            style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
                         }
-                    } else if let Some(corpse) = floor.corpses.iter().find(|c| c.position.x == x && c.position.y == y) {
-                        // Corpse - show if tile is visible
-                        if let Some(tile) = floor.tiles.get(y as usize).and_then(|row| row.get(x as usize)) {
-                            if tile.visible {
-                                let (symbol, color) = match corpse.decay_level {
-                                    0..=2 => ('%', Color::Red),        // Fresh corpse - red
-                                    3..=6 => ('%', Color::Yellow),     // Decaying corpse - yellow
-                                    7..=9 => ('%', Color::White),      // Old corpse - white
-                                    _ => ('☠', Color::Gray),           // Skeleton remains - gray
-                                };
-                                line_spans.push(Span::styled(symbol.to_string(), Style::default().fg(color)));
-                            } else {
-                                // Fall through to normal tile rendering
-                                if tile.explored {
-                                    let (symbol, color) = match &tile.tile_type {
-                                        crate::world::DungeonTileType::Wall => ('#', Color::DarkGray),
-                                        crate::world::DungeonTileType::Floor => ('.', Color::Gray),
-                                        _ => ('.', Color::Gray),
-                                    };
-                                    line_spans.push(Span::styled(symbol.to_string(), Style::default().fg(color)));
-                                } else {
-                                    line_spans.push(Span::styled(" ", Style::default().fg(Color::Black)));
-                                }
-                            }
-                        } else {
-                            line_spans.push(Span::styled(" ", Style::default().fg(Color::Black)));
-                        }
-                    } else if let Some(tile) = floor.tiles.get(y as usize).and_then(|row| row.get(x as usize)) {
+                        line_spans.push(Span::styled(tileset.glyph(&key).to_string(), style));
+                    } else if let (true, Some(loot_pile)) = (visible, floor.loot_piles.iter().find(|lp| lp.position.x == x && lp.position.y == y)) {
+                        let key = if loot_pile.discovered { "feature.loot" } else { "feature.hidden_loot" };
+                        line_spans.push(Span::styled(tileset.glyph(key).to_string(), Style::default().fg(tileset.color(key, theme.highlight)).add_modifier(Modifier::BOLD)));
+                    } else if let (true, Some(corpse)) = (visible, floor.corpses.iter().find(|c| c.position.x == x && c.position.y == y)) {
+                        let (key, color) = match corpse.decay_level {
+                            0..=2 => ("feature.corpse", theme.enemy),     // Fresh corpse
+                            3..=6 => ("feature.corpse", theme.title),     // Decaying corpse
+                            7..=9 => ("feature.corpse", theme.text),      // Old corpse
+                            _ => ("feature.remains", theme.subtle),       // Skeleton remains
+                        };
+                        line_spans.push(Span::styled(tileset.glyph(key).to_string(), Style::default().fg(tileset.color(key, color))));
+                    } else if let (true, Some(trap)) = (tile.is_some_and(|t| t.visible || t.explored), floor.features.iter().find(|f| f.position.x == x && f.position.y == y && f.detected && matches!(f.feature_type, crate::world::FeatureType::Trap(_)))) {
+                        // Traps the player knows about stay marked, dimmed once sprung
+                        let color = if trap.sprung { theme.subtle } else { theme.warning };
+                        line_spans.push(Span::styled(tileset.glyph("feature.trap").to_string(), Style::default().fg(tileset.color("feature.trap", color)).add_modifier(Modifier::BOLD)));
+                    } else if let Some(tile) = tile {
                         // Tile rendering
                         if tile.visible || tile.explored {
-                            let (symbol, color) = match &tile.tile_type {
-                                crate::world::DungeonTileType::Wall => ('#', Color::Gray),
-                                crate::world::DungeonTileType::Floor => ('.', Color::White),
+                            let key = Self::dungeon_tile_key(&tile.tile_type);
+                            let color = match &tile.tile_type {
+                                crate::world::DungeonTileType::Wall => theme.subtle,
+                                crate::world::DungeonTileType::Floor => theme.text,
                                 crate::world::DungeonTileType::Door(state) => {
                                     match state {
-                                        crate::world::DoorState::Open => ('+', Color::Yellow),
-                                        crate::world::DoorState::Closed => ('|', Color::Yellow),
-                                        crate::world::DoorState::Locked => ('X', Color::Red),
-                                        crate::world::DoorState::Secret => ('#', Color::Gray), // Hidden
+                                        crate::world::DoorState::Open => theme.title,
+                                        crate::world::DoorState::Closed => theme.title,
+                                        crate::world::DoorState::Locked => theme.enemy,
+                                        crate::world::DoorState::Secret => theme.subtle, // Hidden
                                     }
                                 },
-                                crate::world::DungeonTileType::Stairs(stair_type) => {
-                                    match stair_type {
-                                        crate::world::StairType::Up => ('<', Color::LightBlue),
-                                        crate::world::StairType::Down => ('>', Color::LightBlue),
-                                        crate::world::StairType::UpDown => ('=', Color::LightBlue),
-                                    }
-                                },
-                                crate::world::DungeonTileType::Water => ('~', Color::Blue),
-                                crate::world::DungeonTileType::Pit => ('O', Color::Red),
-                                crate::world::DungeonTileType::Rubble => ('*', Color::Gray),
-                                crate::world::DungeonTileType::Altar => ('A', Color::LightMagenta),
-                                crate::world::DungeonTileType::Chest => ('C', Color::Yellow),
-                                crate::world::DungeonTileType::Pillar => ('I', Color::White),
-                                crate::world::DungeonTileType::Window => ('W', Color::LightBlue),
-                                crate::world::DungeonTileType::Torch => ('T', Color::LightRed),
+                                crate::world::DungeonTileType::Stairs(_) => theme.info,
+                                crate::world::DungeonTileType::Water => theme.info,
+                                crate::world::DungeonTileType::Pit => theme.enemy,
+                                crate::world::DungeonTileType::Rubble => theme.subtle,
+                                crate::world::DungeonTileType::Altar => theme.magic,
+                                crate::world::DungeonTileType::Chest => theme.title,
+                                crate::world::DungeonTileType::Pillar => theme.text,
+                                crate::world::DungeonTileType::Window => theme.info,
+                                crate::world::DungeonTileType::Torch => theme.warning,
                             };
+                            let color = tileset.color(key, color);
                             
                             // Adjust brightness based on light level and visibility
                             let adjusted_color = if tile.visible {
                                 color
                             } else {
                                 // Dimmed for explored but not currently visible
-                                match color {
-                                    Color::White => Color::Gray,
-                                    Color::LightBlue => Color::Blue,
-                                    Color::LightYellow => Color::Yellow,
-                                    Color::LightGreen => Color::Green,
-                                    Color::LightRed => Color::Red,
-                                    Color::LightMagenta => Color::Magenta,
-                                    c => c,
-                                }
+                                Theme::dimmed(color)
                             };
                             
-                            line_spans.push(Span::styled(symbol.to_string(), Style::default().fg(adjusted_color)));
+                            line_spans.push(Span::styled(tileset.glyph(key).to_string(), Style::default().fg(adjusted_color)));
                         } else {
                             // Unexplored area
-                            line_spans.push(Span::styled(" ".to_string(), Style::default().fg(Color::Black)));
+                            line_spans.push(unexplored.clone());
                         }
                     } else {
                         // Out of bounds
-                        line_spans.push(Span::styled(" ".to_string(), Style::default().fg(Color::Black)));
+                        line_spans.push(unexplored.clone());
                     }
                 }
                 
@@ -1955,7 +2780,38 @@ impl GameUI {
         dungeon_content
     }
 
-    fn draw_combat_static(f: &mut Frame, combat_state: &CombatState) {
+    fn dungeon_tile_key(tile_type: &crate::world::DungeonTileType) -> &'static str {
+        match tile_type {
+            crate::world::DungeonTileType::Wall => "dungeon.wall",
+            crate::world::DungeonTileType::Floor => "dungeon.floor",
+            crate::world::DungeonTileType::Door(state) => match state {
+                crate::world::DoorState::Open => "dungeon.door_open",
+                crate::world::DoorState::Closed => "dungeon.door_closed",
+                crate::world::DoorState::Locked => "dungeon.door_locked",
+                crate::world::DoorState::Secret => "dungeon.door_secret",
+            },
+            crate::world::DungeonTileType::Stairs(stair_type) => match stair_type {
+                crate::world::StairType::Up => "dungeon.stairs_up",
+                crate::world::StairType::Down => "dungeon.stairs_down",
+                crate::world::StairType::UpDown => "dungeon.stairs_updown",
+            },
+            crate::world::DungeonTileType::Water => "dungeon.water",
+            crate::world::DungeonTileType::Pit => "dungeon.pit",
+            crate::world::DungeonTileType::Rubble => "dungeon.rubble",
+            crate::world::DungeonTileType::Altar => "dungeon.altar",
+            crate::world::DungeonTileType::Chest => "dungeon.chest",
+            crate::world::DungeonTileType::Pillar => "dungeon.pillar",
+            crate::world::DungeonTileType::Window => "dungeon.window",
+            crate::world::DungeonTileType::Torch => "dungeon.torch",
+        }
+    }
+
+    fn draw_combat_static(f: &mut Frame, theme: &Theme, combat_state: &CombatState, character: Option<&crate::forge::ForgeCharacter>) {
+        if let Some(summary) = &combat_state.summary {
+            Self::draw_combat_summary_static(f, theme, summary);
+            return;
+        }
+        
         let area = f.size();
         
         let chunks = Layout::default()
@@ -1971,16 +2827,16 @@ impl GameUI {
 
         // Combat title
         let title = Paragraph::new(format!("⚔️  COMBAT - Round {} ⚔️", combat_state.encounter.round))
-            .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(theme.enemy).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Red)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.enemy)));
         f.render_widget(title, chunks[0]);
 
         // Combatants status
         let mut status_lines = vec![Line::from(Span::styled("Combatants:", Style::default().add_modifier(Modifier::BOLD)))];
         for (i, participant) in combat_state.encounter.participants.iter().enumerate() {
-            let hp_ratio = participant.combat_stats.hit_points.current as f32 / participant.combat_stats.hit_points.max as f32;
-            let hp_color = if hp_ratio > 0.5 { Color::Green } else if hp_ratio > 0.25 { Color::Yellow } else { Color::Red };
+            let hit_points = &participant.combat_stats.hit_points;
+            let hp_color = Self::pool_color(theme, Self::pool_ratio(hit_points.current, hit_points.max));
             
             let is_current = i == combat_state.encounter.current_turn;
             let turn_indicator = if is_current { "► " } else { "  " };
@@ -1994,20 +2850,51 @@ impl GameUI {
                 String::new()
             };
             
-            let line = format!("{}{} - HP: {}/{} | AV: {} | DV: {}{}",
+            let ammo_info = match participant.ranged_weapon.as_ref().and_then(|w| w.ammo_type()) {
+                Some(ammo_type) => format!(" | {}: {}", ammo_type.get_name(), participant.ammo),
+                None => String::new(),
+            };
+            
+            // Only the player's side tires
+            let stamina_info = if let Some(filled) = (participant.stamina * 10).checked_div(participant.max_stamina) {
+                let filled = (filled as usize).min(10);
+                format!(" | STA: [{}{}] {}/{}{}",
+                    "#".repeat(filled),
+                    "-".repeat(10 - filled),
+                    participant.stamina,
+                    participant.max_stamina,
+                    if participant.is_exhausted() { " EXHAUSTED" } else { "" })
+            } else {
+                String::new()
+            };
+            
+            let effects_info = if participant.active_effects.is_empty() {
+                String::new()
+            } else {
+                format!(" | {}", participant.active_effects.iter()
+                    .map(|effect| format!("{} {}r", effect.source, effect.rounds_remaining))
+                    .collect::<Vec<_>>()
+                    .join(", "))
+            };
+            
+            let name = if participant.fled { format!("{} (fled)", participant.name) } else { participant.name.clone() };
+            let line = format!("{}{} - HP: {}/{} | AV: {} | DV: {}{}{}{}{}",
                 turn_indicator,
-                participant.name,
+                name,
                 participant.combat_stats.hit_points.current,
                 participant.combat_stats.hit_points.max,
                 participant.get_total_attack_value(),
                 participant.get_total_defense_value(),
-                armor_info
+                stamina_info,
+                armor_info,
+                ammo_info,
+                effects_info
             );
             
             let style = if is_current {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.title).add_modifier(Modifier::BOLD)
             } else if !participant.is_alive() {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme.muted)
             } else {
                 Style::default().fg(hp_color)
             };
@@ -2015,50 +2902,85 @@ impl GameUI {
             status_lines.push(Line::from(Span::styled(line, style)));
         }
         
+        // Hit point gauges in a column beside the combatants, one per line
+        const GAUGE_WIDTH: u16 = 16;
+        let status_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(GAUGE_WIDTH + 2)])
+            .split(chunks[1]);
         let status = Paragraph::new(status_lines)
-            .block(Block::default().borders(Borders::ALL).title("Status").border_style(Style::default().fg(Color::Cyan)));
-        f.render_widget(status, chunks[1]);
+            .block(Block::default().borders(Borders::ALL).title("Status").border_style(Style::default().fg(theme.accent)));
+        f.render_widget(status, status_chunks[0]);
 
-        // Combat log
-        let log_start = combat_state.encounter.combat_log.len().saturating_sub(10);
-        let recent_logs: Vec<Line> = combat_state.encounter.combat_log[log_start..]
+        let gauge_block = Block::default().borders(Borders::ALL).title("HP").border_style(Style::default().fg(theme.accent));
+        let gauge_area = gauge_block.inner(status_chunks[1]);
+        f.render_widget(gauge_block, status_chunks[1]);
+        for (i, participant) in combat_state.encounter.participants.iter().enumerate() {
+            // The first line of the status panel is its heading
+            let row = i as u16 + 1;
+            if row >= gauge_area.height {
+                break;
+            }
+            let hit_points = &participant.combat_stats.hit_points;
+            let row_area = ratatui::layout::Rect { y: gauge_area.y + row, height: 1, ..gauge_area };
+            f.render_widget(Self::pool_gauge(theme, hit_points.current, hit_points.max), row_area);
+        }
+
+        // Combat log, as much of it as fits, ending where the player has scrolled to
+        let log = &combat_state.encounter.combat_log;
+        let visible = (chunks[2].height.saturating_sub(2) as usize).max(1);
+        let log_end = log.len().saturating_sub(combat_state.log_scroll);
+        let recent_logs: Vec<Line> = log[log_end.saturating_sub(visible)..log_end]
             .iter()
             .map(|log| Line::from(log.as_str()))
             .collect();
         
         let combat_log = Paragraph::new(recent_logs)
-            .block(Block::default().borders(Borders::ALL).title("Combat Log").border_style(Style::default().fg(Color::White)))
+            .block(Block::default().borders(Borders::ALL).title(Self::log_title("Combat Log", combat_state.log_scroll)).border_style(Style::default().fg(theme.text)))
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(combat_log, chunks[2]);
 
         // Actions based on combat phase
-        if let Some(current) = combat_state.encounter.get_current_participant() {
+        if let CombatPhase::Assessment = combat_state.combat_phase {
+            let assess_items = vec![
+                ListItem::new("You haven't been noticed yet.").style(Style::default().fg(theme.title)),
+                ListItem::new("Press ENTER to attack").style(Style::default().fg(theme.enemy)),
+                ListItem::new("Press ESC to back away").style(Style::default().fg(theme.success)),
+            ];
+            
+            let actions = List::new(assess_items)
+                .block(Block::default().borders(Borders::ALL)
+                    .title("Size Up the Enemy")
+                    .border_style(Style::default().fg(theme.warning)));
+            f.render_widget(actions, chunks[3]);
+        } else if let Some(current) = combat_state.encounter.get_current_participant() {
             if current.is_player && current.is_alive() {
-                match combat_state.combat_phase {
+                match &combat_state.combat_phase {
+                    CombatPhase::Assessment => {}
                     CombatPhase::InitiativeRoll => {
                         let init_items = vec![
-                            ListItem::new("Combat is about to begin!").style(Style::default().fg(Color::Yellow)),
-                            ListItem::new("Initiative will be rolled for all participants").style(Style::default().fg(Color::White)),
-                            ListItem::new("Press ENTER to roll initiative").style(Style::default().fg(Color::Green)),
+                            ListItem::new("Combat is about to begin!").style(Style::default().fg(theme.title)),
+                            ListItem::new("Initiative will be rolled for all participants").style(Style::default().fg(theme.text)),
+                            ListItem::new("Press ENTER to roll initiative").style(Style::default().fg(theme.success)),
                         ];
                         
                         let actions = List::new(init_items)
                             .block(Block::default().borders(Borders::ALL)
                                 .title("Rolling Initiative")
-                                .border_style(Style::default().fg(Color::Yellow)));
+                                .border_style(Style::default().fg(theme.title)));
                         f.render_widget(actions, chunks[3]);
                     }
                     CombatPhase::DeclaringActions => {
                         let declare_items = vec![
-                            ListItem::new("All participants declare their actions").style(Style::default().fg(Color::Yellow)),
-                            ListItem::new("Actions will be resolved in initiative order").style(Style::default().fg(Color::White)),
-                            ListItem::new("Press ENTER to continue").style(Style::default().fg(Color::Green)),
+                            ListItem::new("All participants declare their actions").style(Style::default().fg(theme.title)),
+                            ListItem::new("Actions will be resolved in initiative order").style(Style::default().fg(theme.text)),
+                            ListItem::new("Press ENTER to continue").style(Style::default().fg(theme.success)),
                         ];
                         
                         let actions = List::new(declare_items)
                             .block(Block::default().borders(Borders::ALL)
                                 .title("Declaring Actions")
-                                .border_style(Style::default().fg(Color::Blue)));
+                                .border_style(Style::default().fg(theme.info)));
                         f.render_widget(actions, chunks[3]);
                     }
                     CombatPhase::SelectingSkill => {
@@ -2083,9 +3005,9 @@ impl GameUI {
                             let is_selected = i == combat_state.current_skill_index;
                             
                             let style = if is_selected {
-                                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                                Style::default().fg(theme.title).add_modifier(Modifier::BOLD)
                             } else {
-                                Style::default().fg(Color::Green)
+                                Style::default().fg(theme.success)
                             };
                             
                             let prefix = if is_selected { "► " } else { "  " };
@@ -2104,66 +3026,88 @@ impl GameUI {
                         let actions = List::new(skill_items)
                             .block(Block::default().borders(Borders::ALL)
                                 .title(format!("{}'s Turn - Select Skill/Spell/Action{}", current.name, scroll_info))
-                                .border_style(Style::default().fg(Color::Green)));
+                                .border_style(Style::default().fg(theme.success)));
                         f.render_widget(actions, chunks[3]);
                     }
                     CombatPhase::SelectingTarget => {
-                        let mut target_items = Vec::new();
-                        let mut enemy_counter = 1;
+                        let (side, side_color) = if combat_state.targets_allies() {
+                            ("Ally", theme.player)
+                        } else {
+                            ("Target", theme.enemy)
+                        };
+                        // Weapon attacks show their odds against each target
+                        let skill = combat_state.selected_skill.as_deref().unwrap_or("Melee Combat");
+                        let weapon = if skill.starts_with("Cast ") { None } else { current.weapon_for_skill(skill) };
                         
-                        for participant in &combat_state.encounter.participants {
-                            if !participant.is_player && participant.is_alive() {
-                                let target_text = format!("{}. {} (HP: {}/{})", 
-                                    enemy_counter, 
+                        let target_items: Vec<ListItem> = combat_state.target_candidates().iter()
+                            .enumerate()
+                            .map(|(number, &index)| {
+                                let participant = &combat_state.encounter.participants[index];
+                                let you = if index == combat_state.encounter.current_turn { " (you)" } else { "" };
+                                let odds = weapon.as_ref()
+                                    .map(|weapon| format!(" | {}", crate::forge::AttackOdds::for_skill(current, weapon, skill, character, participant).describe()))
+                                    .unwrap_or_default();
+                                ListItem::new(format!("{}. {}{} (HP: {}/{}){}",
+                                    number + 1,
                                     participant.name,
+                                    you,
                                     participant.combat_stats.hit_points.current,
-                                    participant.combat_stats.hit_points.max);
-                                target_items.push(ListItem::new(target_text).style(Style::default().fg(Color::Red)));
-                                enemy_counter += 1;
-                            }
-                        }
+                                    participant.combat_stats.hit_points.max,
+                                    odds)).style(Style::default().fg(side_color))
+                            })
+                            .collect();
                         
                         let default_skill = "Unknown".to_string();
                         let skill_name = combat_state.selected_skill.as_ref().unwrap_or(&default_skill);
                         let actions = List::new(target_items)
                             .block(Block::default().borders(Borders::ALL)
-                                .title(format!("Using {} - Select Target", skill_name))
-                                .border_style(Style::default().fg(Color::Red)));
+                                .title(format!("Using {} - Select {}", skill_name, side))
+                                .border_style(Style::default().fg(side_color)));
+                        f.render_widget(actions, chunks[3]);
+                    }
+                    CombatPhase::ConfirmingAction { preview, .. } => {
+                        let preview_items: Vec<ListItem> = preview.iter()
+                            .map(|line| ListItem::new(line.as_str()).style(Style::default().fg(theme.text)))
+                            .collect();
+                        let actions = List::new(preview_items)
+                            .block(Block::default().borders(Borders::ALL)
+                                .title("Confirm Action")
+                                .border_style(Style::default().fg(theme.warning)));
                         f.render_widget(actions, chunks[3]);
                     }
                     CombatPhase::ResolvingActions => {
                         let resolving = Paragraph::new("Resolving actions...")
-                            .style(Style::default().fg(Color::Yellow))
+                            .style(Style::default().fg(theme.title))
                             .alignment(Alignment::Center)
-                            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
+                            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.title)));
                         f.render_widget(resolving, chunks[3]);
                     }
                     CombatPhase::RoundComplete => {
                         let round_items = vec![
-                            ListItem::new("Round completed!").style(Style::default().fg(Color::Cyan)),
-                            ListItem::new("Preparing for next round").style(Style::default().fg(Color::White)),
-                            ListItem::new("Press ENTER to continue").style(Style::default().fg(Color::Green)),
+                            ListItem::new("Round completed!").style(Style::default().fg(theme.accent)),
+                            ListItem::new("Preparing for next round").style(Style::default().fg(theme.text)),
+                            ListItem::new("Press ENTER to continue").style(Style::default().fg(theme.success)),
                         ];
                         
                         let actions = List::new(round_items)
                             .block(Block::default().borders(Borders::ALL)
                                 .title("Round Complete")
-                                .border_style(Style::default().fg(Color::Cyan)));
+                                .border_style(Style::default().fg(theme.accent)));
                         f.render_widget(actions, chunks[3]);
                     }
                     CombatPhase::CombatComplete(_) => {
                         let complete = Paragraph::new("Combat Complete! Press ENTER to continue.")
-                            .style(Style::default().fg(Color::Green))
+                            .style(Style::default().fg(theme.success))
                             .alignment(Alignment::Center)
-                            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Green)));
+                            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.success)));
                         f.render_widget(complete, chunks[3]);
                     }
                 }
             } else {
                 let waiting = Paragraph::new("Waiting for enemy turn...")
-                    .style(Style::default().fg(Color::DarkGray))
+                    .style(Style::default().fg(theme.muted))
                     .alignment(Alignment::Center)
-                    .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)));
+                    .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.muted)));
                 f.render_widget(waiting, chunks[3]);
             }
         }
@@ -2171,46 +3115,164 @@ impl GameUI {
         // Controls
         let controls = if combat_state.encounter.is_combat_over() {
             Paragraph::new("Combat Over! Press ENTER to continue | Q/Ctrl+C: Quit")
-                .style(Style::default().fg(Color::Green))
+                .style(Style::default().fg(theme.success))
         } else {
             match combat_state.combat_phase {
+                CombatPhase::Assessment => {
+                    Paragraph::new("ENTER: Fight | ESC: Back Away | Q/Ctrl+C: Quit")
+                        .style(Style::default().fg(theme.warning))
+                }
                 CombatPhase::InitiativeRoll => {
                     Paragraph::new("ENTER: Roll Initiative | Q/Ctrl+C: Quit")
-                        .style(Style::default().fg(Color::Yellow))
+                        .style(Style::default().fg(theme.title))
                 }
                 CombatPhase::DeclaringActions => {
                     Paragraph::new("ENTER: Declare Actions | Q/Ctrl+C: Quit")
-                        .style(Style::default().fg(Color::Blue))
+                        .style(Style::default().fg(theme.info))
                 }
                 CombatPhase::SelectingSkill => {
                     Paragraph::new("↑/↓: Navigate | ENTER: Select | ESC: Cancel | Q/Ctrl+C: Quit")
-                        .style(Style::default().fg(Color::Green))
+                        .style(Style::default().fg(theme.success))
                 }
                 CombatPhase::SelectingTarget => {
                     Paragraph::new("1-9: Select Target | ESC: Go Back | Q/Ctrl+C: Quit")
-                        .style(Style::default().fg(Color::Red))
+                        .style(Style::default().fg(theme.enemy))
+                }
+                CombatPhase::ConfirmingAction { .. } => {
+                    Paragraph::new("ENTER/Y: Confirm | ESC: Cancel | Q/Ctrl+C: Quit")
+                        .style(Style::default().fg(theme.warning))
                 }
                 CombatPhase::ResolvingActions => {
                     Paragraph::new("Resolving all declared actions...")
-                        .style(Style::default().fg(Color::Yellow))
+                        .style(Style::default().fg(theme.title))
                 }
                 CombatPhase::RoundComplete => {
                     Paragraph::new("Round complete! ENTER: Start next round")
-                        .style(Style::default().fg(Color::Cyan))
+                        .style(Style::default().fg(theme.accent))
                 }
                 CombatPhase::CombatComplete(_) => {
                     Paragraph::new("Combat Over! Press ENTER to continue")
-                        .style(Style::default().fg(Color::Green))
+                        .style(Style::default().fg(theme.success))
                 }
             }
         };
         
         let controls = controls
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)));
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.muted)));
         f.render_widget(controls, chunks[4]);
     }
 
+    fn draw_combat_summary_static(f: &mut Frame, theme: &Theme, summary: &CombatSummary) {
+        let area = f.size();
+        
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(8),
+                Constraint::Length(3),
+            ])
+            .split(area);
+        
+        let (heading, heading_color) = if summary.victory {
+            ("🏆 VICTORY 🏆", theme.success)
+        } else {
+            ("💀 DEFEAT 💀", theme.enemy)
+        };
+        let title = Paragraph::new(heading)
+            .style(Style::default().fg(heading_color).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(heading_color)));
+        f.render_widget(title, chunks[0]);
+        
+        let mut lines = Vec::new();
+        if summary.victory {
+            lines.push(Line::from(Span::styled(format!("⭐ Experience gained: {}", summary.experience), Style::default().fg(theme.title))));
+            for level in &summary.new_levels {
+                lines.push(Line::from(Span::styled(format!("🎉 You reached level {}! Your wounds are healed.", level), Style::default().fg(theme.success).add_modifier(Modifier::BOLD))));
+            }
+            lines.push(Line::from(Span::styled(format!("💰 Gold looted: {}", summary.gold), Style::default().fg(theme.highlight))));
+            
+            lines.push(Line::from(""));
+            if summary.items.is_empty() {
+                lines.push(Line::from(Span::styled("🎒 No items found", Style::default().fg(theme.muted))));
+            } else {
+                lines.push(Line::from(Span::styled("🎒 Items obtained:", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD))));
+                for item in &summary.items {
+                    lines.push(Line::from(Span::styled(format!("  - {}", item), Style::default().fg(theme.text))));
+                }
+            }
+        } else {
+            lines.push(Line::from(Span::styled("You were beaten and gained nothing from this fight.", Style::default().fg(theme.enemy))));
+        }
+        
+        if !summary.notes.is_empty() {
+            lines.push(Line::from(""));
+            for note in &summary.notes {
+                lines.push(Line::from(Span::styled(note.as_str(), Style::default().fg(theme.subtle))));
+            }
+        }
+        
+        let results = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Combat Results").border_style(Style::default().fg(theme.accent)))
+            .wrap(ratatui::widgets::Wrap { trim: true });
+        f.render_widget(results, chunks[1]);
+        
+        let controls = Paragraph::new("ENTER: Continue | Q/Ctrl+C: Quit")
+            .style(Style::default().fg(theme.success))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.muted)));
+        f.render_widget(controls, chunks[2]);
+    }
+
+    fn draw_death_static(f: &mut Frame, theme: &Theme, death_state: &DeathState) {
+        let area = f.size();
+        
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(8),
+                Constraint::Length(3),
+            ])
+            .split(area);
+        
+        let heading = if death_state.permadeath {
+            format!("☠️ {} HAS DIED ☠️", death_state.character_name.to_uppercase())
+        } else {
+            format!("💀 {} HAS FALLEN 💀", death_state.character_name.to_uppercase())
+        };
+        let title = Paragraph::new(heading)
+            .style(Style::default().fg(theme.enemy).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.enemy)));
+        f.render_widget(title, chunks[0]);
+        
+        let mut lines = vec![Line::from("")];
+        for message in &death_state.messages {
+            lines.push(Line::from(Span::styled(message.as_str(), Style::default().fg(theme.text))));
+        }
+        lines.push(Line::from(""));
+        if death_state.permadeath {
+            lines.push(Line::from(Span::styled("Their story ends here. Create a new character to play again.", Style::default().fg(theme.muted))));
+        } else {
+            lines.push(Line::from(Span::styled("Log in again from the main menu to continue your journey.", Style::default().fg(theme.muted))));
+        }
+        
+        let body = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Death").border_style(Style::default().fg(theme.enemy)))
+            .wrap(ratatui::widgets::Wrap { trim: true });
+        f.render_widget(body, chunks[1]);
+        
+        let controls = Paragraph::new("ENTER: Return to Main Menu | Q/Ctrl+C: Quit")
+            .style(Style::default().fg(theme.muted))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(theme.muted)));
+        f.render_widget(controls, chunks[2]);
+    }
+
     pub fn handle_input(&self) -> anyhow::Result<Option<KeyEvent>> {
         if event::poll(std::time::Duration::from_millis(100))? {
             match event::read()? {
@@ -2226,4 +3288,60 @@ impl GameUI {
         }
         Ok(None)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gear_shop() -> CharacterCreationState {
+        CharacterCreationState {
+            step: CreationStep::GearSelection,
+            rolled_data: None,
+            selected_race: None,
+            character_name: None,
+            password: None,
+            selected_skills: Vec::new(),
+            available_skill_points: 0,
+            selected_spells: Vec::new(),
+            available_spell_picks: 0,
+            selected_gear: Vec::new(),
+            current_selection_index: 0,
+            available_skills_list: Vec::new(),
+            available_spells_list: Vec::new(),
+            available_gear_list: vec![("Sword".to_string(), 40), ("Shield".to_string(), 35), ("Rope".to_string(), 5)],
+            starting_gold: 100,
+            spent_gold: 0,
+            difficulty: crate::forge::Difficulty::Normal,
+            list_filter: String::new(),
+            point_buy: None,
+            rerolls_left: 0,
+        }
+    }
+
+    #[test]
+    fn rapid_select_and_deselect_keeps_spent_gold_in_step() {
+        let mut shop = gear_shop();
+        for _ in 0..10 {
+            assert!(shop.toggle_gear("Sword", 40));
+            assert!(shop.toggle_gear("Shield", 35));
+            assert!(shop.toggle_gear("Sword", 40));
+            assert!(shop.toggle_gear("Rope", 5));
+            assert!(shop.toggle_gear("Rope", 5));
+            assert!(shop.toggle_gear("Shield", 35));
+        }
+        assert!(shop.selected_gear.is_empty());
+        assert_eq!(shop.spent_gold, 0);
+        assert_eq!(shop.remaining_gold(), 100);
+    }
+
+    #[test]
+    fn gear_that_costs_too_much_is_refused() {
+        let mut shop = gear_shop();
+        shop.starting_gold = 70;
+        assert!(shop.toggle_gear("Sword", 40));
+        assert!(!shop.toggle_gear("Shield", 35));
+        assert_eq!(shop.selected_gear, vec!["Sword".to_string()]);
+        assert_eq!(shop.spent_gold, 40);
+        assert_eq!(shop.remaining_gold(), 30);
+    }
+}
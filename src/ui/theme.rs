@@ -0,0 +1,277 @@
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use anyhow::{Context, Result};
+use crate::world::{CreatureType, NPCType, TerrainType};
+
+pub const THEME_CONFIG_PATH: &str = "theme.json";
+
+// Named color roles used by every screen, plus map colors keyed as
+// "terrain.<type>", "npc.<type>" and "creature.<type>" (lowercase)
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub name: String,
+    pub title: Color,      // Screen titles and headline borders
+    pub accent: Color,     // Section headings and secondary panels
+    pub text: Color,
+    pub muted: Color,      // Controls, hints and unavailable options
+    pub subtle: Color,     // Remembered or less important map details
+    pub warning: Color,
+    pub success: Color,
+    pub enemy: Color,
+    pub player: Color,
+    pub magic: Color,
+    pub info: Color,
+    pub highlight: Color,  // Treasure and other things worth noticing
+    pub background: Color,
+    palette: HashMap<String, Color>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ThemeConfig {
+    #[serde(default)]
+    preset: Option<String>,
+    #[serde(default)]
+    colors: HashMap<String, String>, // Role or palette key -> color name or #rrggbb
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::default_preset()
+    }
+}
+
+impl Theme {
+    pub fn preset_names() -> Vec<&'static str> {
+        vec!["default", "high-contrast", "colorblind"]
+    }
+
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default_preset()),
+            "high-contrast" => Some(Self::high_contrast_preset()),
+            "colorblind" => Some(Self::colorblind_preset()),
+            _ => None,
+        }
+    }
+
+    // Load the theme config, falling back to the default theme when there is none
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read theme config from {}", path.display()))?;
+        let config: ThemeConfig = serde_json::from_str(&content)
+            .with_context(|| "Failed to parse theme config JSON")?;
+
+        let preset = config.preset.as_deref().unwrap_or("default");
+        let mut theme = Self::preset(preset)
+            .ok_or_else(|| anyhow::anyhow!("Unknown theme preset '{}' (expected one of: {})", preset, Self::preset_names().join(", ")))?;
+
+        for (key, value) in &config.colors {
+            let color = Color::from_str(value)
+                .map_err(|_| anyhow::anyhow!("Invalid color '{}' for '{}'", value, key))?;
+            theme.set_color(key, color)?;
+        }
+
+        Ok(theme)
+    }
+
+    pub fn set_color(&mut self, key: &str, color: Color) -> Result<()> {
+        let role = match key {
+            "title" => &mut self.title,
+            "accent" => &mut self.accent,
+            "text" => &mut self.text,
+            "muted" => &mut self.muted,
+            "subtle" => &mut self.subtle,
+            "warning" => &mut self.warning,
+            "success" => &mut self.success,
+            "enemy" => &mut self.enemy,
+            "player" => &mut self.player,
+            "magic" => &mut self.magic,
+            "info" => &mut self.info,
+            "highlight" => &mut self.highlight,
+            "background" => &mut self.background,
+            _ => match self.palette.get_mut(key) {
+                Some(entry) => entry,
+                None => return Err(anyhow::anyhow!("Unknown theme color '{}'", key)),
+            },
+        };
+        *role = color;
+        Ok(())
+    }
+
+    pub fn terrain_color(&self, terrain: &TerrainType) -> Color {
+        self.palette_color("terrain", format!("{:?}", terrain))
+    }
+
+    pub fn npc_color(&self, npc_type: &NPCType) -> Color {
+        self.palette_color("npc", format!("{:?}", npc_type))
+    }
+
+    pub fn creature_color(&self, creature_type: &CreatureType) -> Color {
+        self.palette_color("creature", format!("{:?}", creature_type))
+    }
+
+    // Darker shade for things that are remembered but not currently seen
+    pub fn dimmed(color: Color) -> Color {
+        match color {
+            Color::White => Color::Gray,
+            Color::LightBlue => Color::Blue,
+            Color::LightYellow => Color::Yellow,
+            Color::LightGreen => Color::Green,
+            Color::LightRed => Color::Red,
+            Color::LightMagenta => Color::Magenta,
+            Color::LightCyan => Color::Cyan,
+            Color::Rgb(r, g, b) => Color::Rgb(r / 2, g / 2, b / 2),
+            c => c,
+        }
+    }
+
+    fn palette_color(&self, group: &str, name: String) -> Color {
+        self.palette
+            .get(&format!("{}.{}", group, name.to_lowercase()))
+            .copied()
+            .unwrap_or(self.text)
+    }
+
+    fn build_palette(terrain: [Color; 12], npcs: [Color; 15], creatures: [Color; 12]) -> HashMap<String, Color> {
+        let terrain_names = [
+            "ocean", "lake", "river", "plains", "grassland", "forest",
+            "hill", "mountain", "desert", "swamp", "snow", "tundra",
+        ];
+        let npc_names = [
+            "merchant", "guard", "traveler", "hermit", "scholar", "warrior", "thief", "farmer",
+            "noble", "blacksmith", "innkeeper", "priest", "ranger", "bandit", "explorer",
+        ];
+        let creature_names = [
+            "skeleton", "zombie", "ghost", "rat", "bat", "spider",
+            "goblin", "orc", "bandit", "guardianspirit", "wildanimal", "construct",
+        ];
+
+        let mut palette = HashMap::new();
+        for (name, color) in terrain_names.iter().zip(terrain) {
+            palette.insert(format!("terrain.{}", name), color);
+        }
+        for (name, color) in npc_names.iter().zip(npcs) {
+            palette.insert(format!("npc.{}", name), color);
+        }
+        for (name, color) in creature_names.iter().zip(creatures) {
+            palette.insert(format!("creature.{}", name), color);
+        }
+        palette
+    }
+
+    fn default_preset() -> Self {
+        Self {
+            name: "default".to_string(),
+            title: Color::Yellow,
+            accent: Color::Cyan,
+            text: Color::White,
+            muted: Color::DarkGray,
+            subtle: Color::Gray,
+            warning: Color::Yellow,
+            success: Color::Green,
+            enemy: Color::Red,
+            player: Color::LightYellow,
+            magic: Color::Magenta,
+            info: Color::Blue,
+            highlight: Color::LightYellow,
+            background: Color::Black,
+            palette: Self::build_palette(
+                [
+                    Color::Blue, Color::Cyan, Color::LightBlue, Color::Yellow, Color::LightGreen, Color::Green,
+                    Color::LightGreen, Color::White, Color::LightYellow, Color::DarkGray, Color::White, Color::Gray,
+                ],
+                [
+                    Color::Yellow, Color::Blue, Color::Green, Color::Gray, Color::Cyan, Color::Red, Color::DarkGray, Color::LightGreen,
+                    Color::Magenta, Color::Gray, Color::LightYellow, Color::White, Color::Green, Color::Red, Color::Cyan,
+                ],
+                [
+                    Color::White, Color::Green, Color::Cyan, Color::Red, Color::Gray, Color::Red,
+                    Color::LightGreen, Color::Red, Color::Red, Color::LightBlue, Color::Yellow, Color::Gray,
+                ],
+            ),
+        }
+    }
+
+    fn high_contrast_preset() -> Self {
+        Self {
+            name: "high-contrast".to_string(),
+            title: Color::LightYellow,
+            accent: Color::LightCyan,
+            text: Color::White,
+            muted: Color::Gray,
+            subtle: Color::White,
+            warning: Color::LightYellow,
+            success: Color::LightGreen,
+            enemy: Color::LightRed,
+            player: Color::LightYellow,
+            magic: Color::LightMagenta,
+            info: Color::LightCyan,
+            highlight: Color::LightYellow,
+            background: Color::Black,
+            palette: Self::build_palette(
+                [
+                    Color::LightBlue, Color::LightCyan, Color::LightCyan, Color::LightYellow, Color::LightGreen, Color::Green,
+                    Color::LightGreen, Color::White, Color::LightYellow, Color::LightMagenta, Color::White, Color::Gray,
+                ],
+                [
+                    Color::LightYellow, Color::LightBlue, Color::LightGreen, Color::White, Color::LightCyan, Color::LightRed, Color::Gray, Color::LightGreen,
+                    Color::LightMagenta, Color::White, Color::LightYellow, Color::White, Color::LightGreen, Color::LightRed, Color::LightCyan,
+                ],
+                [
+                    Color::White, Color::LightGreen, Color::LightCyan, Color::LightRed, Color::White, Color::LightRed,
+                    Color::LightGreen, Color::LightRed, Color::LightRed, Color::LightBlue, Color::LightYellow, Color::White,
+                ],
+            ),
+        }
+    }
+
+    // Okabe-Ito colors, which stay distinguishable with the common forms of color blindness
+    fn colorblind_preset() -> Self {
+        let orange = Color::Rgb(230, 159, 0);
+        let sky_blue = Color::Rgb(86, 180, 233);
+        let bluish_green = Color::Rgb(0, 158, 115);
+        let yellow = Color::Rgb(240, 228, 66);
+        let blue = Color::Rgb(0, 114, 178);
+        let vermillion = Color::Rgb(213, 94, 0);
+        let reddish_purple = Color::Rgb(204, 121, 167);
+
+        Self {
+            name: "colorblind".to_string(),
+            title: yellow,
+            accent: sky_blue,
+            text: Color::White,
+            muted: Color::DarkGray,
+            subtle: Color::Gray,
+            warning: orange,
+            success: blue,
+            enemy: vermillion,
+            player: yellow,
+            magic: reddish_purple,
+            info: sky_blue,
+            highlight: yellow,
+            background: Color::Black,
+            palette: Self::build_palette(
+                [
+                    blue, sky_blue, sky_blue, yellow, bluish_green, bluish_green,
+                    orange, Color::White, yellow, Color::DarkGray, Color::White, Color::Gray,
+                ],
+                [
+                    yellow, blue, bluish_green, Color::Gray, sky_blue, vermillion, Color::DarkGray, bluish_green,
+                    reddish_purple, Color::Gray, yellow, Color::White, bluish_green, vermillion, sky_blue,
+                ],
+                [
+                    Color::White, bluish_green, sky_blue, vermillion, Color::Gray, vermillion,
+                    bluish_green, vermillion, vermillion, sky_blue, orange, Color::Gray,
+                ],
+            ),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use anyhow::{Context, Result};
+
+pub const TILESET_CONFIG_PATH: &str = "tileset.json";
+
+// Every drawable thing as (key, unicode glyph, ascii glyph). Type keys are the
+// lowercase enum variant names, e.g. "terrain.forest" or "creature.guardianspirit"
+const GLYPHS: &[(&str, char, char)] = &[
+    ("player", '@', '@'),
+    ("unexplored", ' ', ' '),
+    ("offmap", '·', '`'),
+    ("road", '═', '='),
+    ("bridge", '╪', '#'),
+    ("settlement.capital", '█', '&'),
+    ("settlement.city", '●', 'O'),
+    ("settlement.town", '○', 'o'),
+    ("settlement.village", '◦', 'v'),
+    ("settlement.outpost", '·', '\''),
+    ("npc.merchant", 'M', 'M'),
+    ("npc.guard", 'G', 'G'),
+    ("npc.traveler", 'T', 'T'),
+    ("npc.hermit", 'H', 'H'),
+    ("npc.scholar", 'S', 'S'),
+    ("npc.warrior", 'W', 'W'),
+    ("npc.thief", 't', 't'),
+    ("npc.farmer", 'F', 'F'),
+    ("npc.noble", 'N', 'N'),
+    ("npc.blacksmith", 'B', 'B'),
+    ("npc.innkeeper", 'I', 'I'),
+    ("npc.priest", 'P', 'P'),
+    ("npc.ranger", 'R', 'R'),
+    ("npc.bandit", '!', '!'),
+    ("npc.explorer", 'E', 'E'),
+    ("poi.ancientruins", '⌂', 'n'),
+    ("poi.cave", '◊', 'c'),
+    ("poi.abandonedtower", '♜', 'l'),
+    ("poi.mysticshrine", '♠', 'j'),
+    ("poi.dragonlair", '♦', 'd'),
+    ("poi.banditcamp", '▲', 'x'),
+    ("poi.wizardtower", '♨', 'w'),
+    ("poi.temple", '⌘', 'm'),
+    ("poi.crypt", '◘', 'u'),
+    ("poi.treasurevault", '♛', '$'),
+    ("poi.other", '?', '?'),
+    ("terrain.ocean", '~', '~'),
+    ("terrain.lake", '~', '~'),
+    ("terrain.river", '~', '~'),
+    ("terrain.plains", '.', '.'),
+    ("terrain.grassland", ',', ','),
+    ("terrain.forest", '♣', 'f'),
+    ("terrain.hill", '^', '^'),
+    ("terrain.mountain", '▲', 'A'),
+    ("terrain.desert", '·', '_'),
+    ("terrain.swamp", '≈', '"'),
+    ("terrain.snow", '*', '*'),
+    ("terrain.tundra", ':', ':'),
+    ("dungeon.wall", '#', '#'),
+    ("dungeon.floor", '.', '.'),
+    ("dungeon.door_open", '+', '+'),
+    ("dungeon.door_closed", '|', '|'),
+    ("dungeon.door_locked", 'X', 'X'),
+    ("dungeon.door_secret", '#', '#'),
+    ("dungeon.stairs_up", '<', '<'),
+    ("dungeon.stairs_down", '>', '>'),
+    ("dungeon.stairs_updown", '=', '='),
+    ("dungeon.water", '~', '~'),
+    ("dungeon.pit", 'O', 'O'),
+    ("dungeon.rubble", '*', '*'),
+    ("dungeon.altar", 'A', 'A'),
+    ("dungeon.chest", 'C', 'C'),
+    ("dungeon.pillar", 'I', 'I'),
+    ("dungeon.window", 'W', 'W'),
+    ("dungeon.torch", 'T', 'T'),
+    ("creature.skeleton", 'S', 'S'),
+    ("creature.zombie", 'Z', 'Z'),
+    ("creature.ghost", 'G', 'G'),
+    ("creature.rat", 'r', 'r'),
+    ("creature.bat", 'b', 'b'),
+    ("creature.spider", 's', 's'),
+    ("creature.goblin", 'g', 'g'),
+    ("creature.orc", 'O', 'O'),
+    ("creature.bandit", 'B', 'B'),
+    ("creature.guardianspirit", '*', '*'),
+    ("creature.wildanimal", 'a', 'a'),
+    ("creature.construct", 'C', 'C'),
+    ("feature.corpse", '%', '%'),
+    ("feature.remains", '☠', '&'),
+    ("feature.loot", '$', '$'),
+    ("feature.hidden_loot", '?', '?'),
+    ("feature.trap", '^', '^'),
+];
+
+#[derive(Debug, Clone)]
+pub struct Tileset {
+    pub name: String,
+    glyphs: HashMap<String, char>,
+    colors: HashMap<String, Color>, // Overrides for the theme's color of a tile
+}
+
+#[derive(Debug, Deserialize)]
+struct TilesetConfig {
+    #[serde(default)]
+    preset: Option<String>,
+    #[serde(default)]
+    glyphs: HashMap<String, String>,
+    #[serde(default)]
+    colors: HashMap<String, String>,
+}
+
+impl Default for Tileset {
+    fn default() -> Self {
+        Self::preset("unicode").unwrap()
+    }
+}
+
+impl Tileset {
+    pub fn preset_names() -> Vec<&'static str> {
+        vec!["unicode", "ascii"]
+    }
+
+    pub fn preset(name: &str) -> Option<Self> {
+        let ascii = match name {
+            "unicode" => false,
+            "ascii" => true,
+            _ => return None,
+        };
+
+        Some(Self {
+            name: name.to_string(),
+            glyphs: GLYPHS.iter()
+                .map(|(key, unicode, ascii_glyph)| (key.to_string(), if ascii { *ascii_glyph } else { *unicode }))
+                .collect(),
+            colors: HashMap::new(),
+        })
+    }
+
+    // Load the tileset config, falling back to the unicode tileset when there is none
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read tileset config from {}", path.display()))?;
+        let config: TilesetConfig = serde_json::from_str(&content)
+            .with_context(|| "Failed to parse tileset config JSON")?;
+
+        let preset = config.preset.as_deref().unwrap_or("unicode");
+        let mut tileset = Self::preset(preset)
+            .ok_or_else(|| anyhow::anyhow!("Unknown tileset preset '{}' (expected one of: {})", preset, Self::preset_names().join(", ")))?;
+
+        for (key, value) in &config.glyphs {
+            let glyph = value.chars().next()
+                .ok_or_else(|| anyhow::anyhow!("Empty glyph for '{}'", key))?;
+            match tileset.glyphs.get_mut(key) {
+                Some(entry) => *entry = glyph,
+                None => return Err(anyhow::anyhow!("Unknown tile '{}'", key)),
+            }
+        }
+
+        for (key, value) in &config.colors {
+            if !tileset.glyphs.contains_key(key) {
+                return Err(anyhow::anyhow!("Unknown tile '{}'", key));
+            }
+            let color = Color::from_str(value)
+                .map_err(|_| anyhow::anyhow!("Invalid color '{}' for '{}'", value, key))?;
+            tileset.colors.insert(key.clone(), color);
+        }
+
+        Ok(tileset)
+    }
+
+    // Unlisted types fall back to their group's "other" glyph, e.g. "poi.other"
+    pub fn glyph(&self, key: &str) -> char {
+        self.glyphs.get(key)
+            .or_else(|| key.split_once('.').and_then(|(group, _)| self.glyphs.get(&format!("{}.other", group))))
+            .copied()
+            .unwrap_or('?')
+    }
+
+    // Several glyphs run together, for legends
+    pub fn glyphs(&self, keys: &[&str]) -> String {
+        keys.iter().map(|key| self.glyph(key)).collect()
+    }
+
+    // Tileset color if one was configured, otherwise the theme's color
+    pub fn color(&self, key: &str, theme_color: Color) -> Color {
+        self.colors.get(key).copied().unwrap_or(theme_color)
+    }
+
+    pub fn type_key<T: std::fmt::Debug>(group: &str, value: &T) -> String {
+        format!("{}.{}", group, format!("{:?}", value).to_lowercase())
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use super::CreatureType;
+
+pub const BESTIARY_STATS_KILLS: u32 = 3;    // Kills before a creature's stats are known
+pub const BESTIARY_WEAKNESS_KILLS: u32 = 5; // Kills before its weakness is known
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BestiaryEntry {
+    pub creature_type: CreatureType,
+    pub encountered: u32,
+    pub defeated: u32,
+    pub first_seen_day: u32,
+    pub max_hit_points: u32, // Toughest specimen seen so far
+    pub attack_value: u8,
+    pub defensive_value: u8,
+}
+
+impl BestiaryEntry {
+    pub fn stats_known(&self) -> bool {
+        self.defeated >= BESTIARY_STATS_KILLS
+    }
+
+    pub fn weakness_known(&self) -> bool {
+        self.defeated >= BESTIARY_WEAKNESS_KILLS
+    }
+}
+
+// Every kind of creature a character has fought, keyed by creature name
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Bestiary {
+    entries: HashMap<String, BestiaryEntry>,
+}
+
+impl Bestiary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns true the first time this kind of creature is met
+    pub fn record_encounter(&mut self, creature_type: &CreatureType, day: u32) -> bool {
+        let mut first = false;
+        let entry = self.entries.entry(creature_type.get_name().to_string()).or_insert_with(|| {
+            first = true;
+            BestiaryEntry {
+                creature_type: creature_type.clone(),
+                encountered: 0,
+                defeated: 0,
+                first_seen_day: day,
+                max_hit_points: 0,
+                attack_value: 0,
+                defensive_value: 0,
+            }
+        });
+        entry.encountered += 1;
+        first
+    }
+
+    pub fn record_kill(&mut self, creature_type: &CreatureType, stats: &crate::forge::CombatStats, day: u32) {
+        if !self.entries.contains_key(creature_type.get_name()) {
+            self.record_encounter(creature_type, day);
+        }
+        if let Some(entry) = self.entries.get_mut(creature_type.get_name()) {
+            entry.defeated += 1;
+            entry.max_hit_points = entry.max_hit_points.max(stats.hit_points.max);
+            entry.attack_value = entry.attack_value.max(stats.attack_value);
+            entry.defensive_value = entry.defensive_value.max(stats.defensive_value);
+        }
+    }
+
+    // Entries with the most fought creatures first
+    pub fn entries(&self) -> Vec<&BestiaryEntry> {
+        let mut entries: Vec<&BestiaryEntry> = self.entries.values().collect();
+        entries.sort_by(|a, b| b.encountered.cmp(&a.encountered).then(a.creature_type.get_name().cmp(b.creature_type.get_name())));
+        entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
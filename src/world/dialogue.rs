@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use super::{NPCDisposition, NPCService, NPCType};
+
+pub const LIKED_REPUTATION: i32 = 15; // Reputation where townsfolk start trusting the player with work
+
+// What the player needs for a response to be offered at all
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DialogueRequirement {
+    Reputation(i32), // At least this much with the local faction
+    Telepathy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DialogueAction {
+    Goto(usize), // Move to another node of the tree
+    ShowWares,   // Services offered and items for trade
+    Trade,       // Open the buy and sell screen
+    Directions,  // Point the player at something worth visiting
+    QuestDetails, // Hear what the NPC's job involves and pays
+    AcceptQuest,
+    Leave,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialogueOption {
+    pub text: String,
+    #[serde(default)]
+    pub requires: Option<DialogueRequirement>,
+    pub action: DialogueAction,
+}
+
+impl DialogueOption {
+    fn new(text: &str, action: DialogueAction) -> Self {
+        Self { text: text.to_string(), requires: None, action }
+    }
+
+    fn requiring(mut self, requirement: DialogueRequirement) -> Self {
+        self.requires = Some(requirement);
+        self
+    }
+
+    pub fn is_available(&self, reputation: i32, telepathy: bool) -> bool {
+        match self.requires {
+            None => true,
+            Some(DialogueRequirement::Reputation(minimum)) => reputation >= minimum,
+            Some(DialogueRequirement::Telepathy) => telepathy,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialogueNode {
+    pub speech: String,
+    pub options: Vec<DialogueOption>,
+}
+
+impl DialogueNode {
+    // The responses this player can pick, numbered from 1 in the UI
+    pub fn available_options(&self, reputation: i32, telepathy: bool) -> Vec<&DialogueOption> {
+        self.options.iter().filter(|option| option.is_available(reputation, telepathy)).collect()
+    }
+}
+
+// A branching conversation. The first node opens it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DialogueTree {
+    pub nodes: Vec<DialogueNode>,
+}
+
+// Where the player is in a conversation with an NPC of the current zone. Kept
+// small since it lives in the UI state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conversation {
+    pub npc_index: u16,
+    pub node: u16,
+    pub trade: Option<super::TradeCursor>, // Browsing the NPC's wares
+}
+
+impl Conversation {
+    pub fn new(npc_index: usize, node: usize) -> Self {
+        Self { npc_index: npc_index as u16, node: node as u16, trade: None }
+    }
+}
+
+impl DialogueTree {
+    // Build a conversation out of an NPC's lines: the first greets, the rest are
+    // what they say about themselves
+    pub fn generate(npc_type: &NPCType, disposition: &NPCDisposition, lines: &[String], services: &[NPCService], has_wares: bool) -> Self {
+        let greeting = lines.first().cloned().unwrap_or_else(|| "...".to_string());
+        let about = if lines.len() > 1 { lines[1..].join(" ") } else { "There's not much to tell.".to_string() };
+
+        let mut nodes = vec![DialogueNode { speech: greeting, options: Vec::new() }];
+        let mut opening = Vec::new();
+
+        nodes.push(Self::branch(about));
+        opening.push(DialogueOption::new("Tell me about yourself.", DialogueAction::Goto(nodes.len() - 1)));
+
+        if services.iter().any(|service| matches!(service, NPCService::Trade)) {
+            opening.push(DialogueOption::new("Let's trade.", DialogueAction::Trade));
+        } else if has_wares {
+            opening.push(DialogueOption::new("What do you have to offer?", DialogueAction::ShowWares));
+        }
+
+        let knows_the_land = services.iter().any(|service| matches!(service, NPCService::Information))
+            || matches!(npc_type, NPCType::Ranger | NPCType::Explorer | NPCType::Scholar | NPCType::Hermit | NPCType::Traveler);
+        if knows_the_land {
+            opening.push(DialogueOption::new("Anything worth seeing around here?", DialogueAction::Directions));
+        }
+
+        if services.iter().any(|service| matches!(service, NPCService::Quests)) {
+            let mut work = Self::branch("There's trouble in the wilds that needs a steady hand. Clear out a den of it and the realm will remember your name.".to_string());
+            work.options.splice(0..0, [
+                DialogueOption::new("What's the job?", DialogueAction::QuestDetails),
+                DialogueOption::new("I'll do it.", DialogueAction::AcceptQuest),
+                DialogueOption::new("Where should I start?", DialogueAction::Directions),
+            ]);
+            nodes.push(work);
+            opening.push(DialogueOption::new("Do you have any work for me?", DialogueAction::Goto(nodes.len() - 1))
+                .requiring(DialogueRequirement::Reputation(LIKED_REPUTATION)));
+        }
+
+        let thoughts = match disposition {
+            NPCDisposition::Friendly | NPCDisposition::Helpful => "(Their thoughts are warm. They hope you'll stay a while.)",
+            NPCDisposition::Neutral => "(They're thinking about supper more than about you.)",
+            NPCDisposition::Wary => "(They're weighing whether you're trouble, and keep glancing at your weapon.)",
+            NPCDisposition::Hostile => "(They're working out how to be rid of you, one way or another.)",
+            NPCDisposition::Fearful => "(Fear floods their mind. They're sure you've come to rob them.)",
+            NPCDisposition::Greedy => "(They're adding up what your purse might hold.)",
+        };
+        nodes.push(Self::branch(thoughts.to_string()));
+        opening.push(DialogueOption::new("[Telepathy] Listen to their thoughts.", DialogueAction::Goto(nodes.len() - 1))
+            .requiring(DialogueRequirement::Telepathy));
+
+        opening.push(DialogueOption::new("Farewell.", DialogueAction::Leave));
+        nodes[0].options = opening;
+        Self { nodes }
+    }
+
+    // A follow-up that leads back to the opening
+    fn branch(speech: String) -> DialogueNode {
+        DialogueNode {
+            speech,
+            options: vec![
+                DialogueOption::new("Let's talk about something else.", DialogueAction::Goto(0)),
+                DialogueOption::new("Farewell.", DialogueAction::Leave),
+            ],
+        }
+    }
+
+    pub fn node(&self, index: usize) -> Option<&DialogueNode> {
+        self.nodes.get(index)
+    }
+}
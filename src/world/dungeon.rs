@@ -2,10 +2,14 @@ use serde::{Deserialize, Serialize};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 use std::collections::HashMap;
-use crate::world::{LocalCoord, PoiType};
+use crate::world::{LocalCoord, PoiType, ZoneCoord};
 
 pub const DUNGEON_WIDTH: i32 = 40;
 pub const DUNGEON_HEIGHT: i32 = 30;
+pub const WELL_LIT: u8 = 6; // Light level at which nobody needs a torch to see
+pub const WALL_TORCH_LIGHT: u8 = 8; // Light right next to a wall torch, fading 2 per tile
+pub const LIT_SIGHT_RANGE: i32 = 8; // How far away well-lit tiles can be made out
+const TRAP_CHANCE: f64 = 0.25; // Of a room hiding a trap
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DungeonLayout {
@@ -17,6 +21,17 @@ pub struct DungeonLayout {
     pub seed: u64,
 }
 
+// A dungeon visit in progress, saved with the character so the next session
+// picks up on the same floor and tile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedDungeon {
+    pub dungeon: DungeonLayout,
+    pub player_pos: LocalCoord,
+    pub zone: ZoneCoord, // Overworld zone holding the entrance
+    pub poi_position: LocalCoord,
+    pub turn_count: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DungeonFloor {
     pub floor_number: i32,
@@ -28,6 +43,8 @@ pub struct DungeonFloor {
     pub features: Vec<DungeonFeature>,
     pub corpses: Vec<DungeonCorpse>,
     pub loot_piles: Vec<LootPile>,
+    #[serde(default)]
+    pub ambient_light: u8, // 0-10 like tile light, from windows, wall torches or open sky
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,6 +71,38 @@ pub enum DungeonTileType {
     Torch,
 }
 
+impl DungeonTileType {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            DungeonTileType::Wall => "a solid wall",
+            DungeonTileType::Floor => "bare floor",
+            DungeonTileType::Door(DoorState::Open) => "an open door",
+            DungeonTileType::Door(DoorState::Closed) => "a closed door",
+            DungeonTileType::Door(DoorState::Locked) => "a locked door",
+            DungeonTileType::Door(DoorState::Secret) => "a solid wall", // Secret doors look like walls
+            DungeonTileType::Stairs(StairType::Up) => "stairs leading up",
+            DungeonTileType::Stairs(StairType::Down) => "stairs leading down",
+            DungeonTileType::Stairs(StairType::UpDown) => "a spiral staircase",
+            DungeonTileType::Water => "shallow water",
+            DungeonTileType::Pit => "a gaping pit",
+            DungeonTileType::Rubble => "a heap of rubble",
+            DungeonTileType::Altar => "an altar",
+            DungeonTileType::Chest => "a chest",
+            DungeonTileType::Pillar => "a stone pillar",
+            DungeonTileType::Window => "a narrow window",
+            DungeonTileType::Torch => "a wall torch",
+        }
+    }
+
+    // Whether the player can step onto this tile
+    pub fn is_passable(&self) -> bool {
+        matches!(self,
+            DungeonTileType::Floor | DungeonTileType::Stairs(_) | DungeonTileType::Chest |
+            DungeonTileType::Altar | DungeonTileType::Torch | DungeonTileType::Water |
+            DungeonTileType::Door(DoorState::Open))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DoorState {
     Open,
@@ -144,12 +193,203 @@ pub enum CreatureType {
     Construct,
 }
 
+impl CreatureType {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            CreatureType::Skeleton => "Skeleton",
+            CreatureType::Zombie => "Zombie",
+            CreatureType::Ghost => "Ghost",
+            CreatureType::Rat => "Rat",
+            CreatureType::Bat => "Bat",
+            CreatureType::Spider => "Spider",
+            CreatureType::Goblin => "Goblin",
+            CreatureType::Orc => "Orc",
+            CreatureType::Bandit => "Bandit",
+            CreatureType::GuardianSpirit => "Guardian Spirit",
+            CreatureType::WildAnimal => "Wild Animal",
+            CreatureType::Construct => "Construct",
+        }
+    }
+
+    // What a seasoned hunter knows about fighting this kind of creature
+    pub fn get_weakness(&self) -> &'static str {
+        match self {
+            CreatureType::Skeleton => "Brittle bones shatter under heavy blows; Turn Undead sends them reeling.",
+            CreatureType::Zombie => "Slow and clumsy. Keep your distance and strike first.",
+            CreatureType::Ghost => "Ordinary steel passes through it. Magic works far better.",
+            CreatureType::Rat => "Weak alone. Dangerous only in swarms.",
+            CreatureType::Bat => "Fragile, but hard to hit. Patience wins.",
+            CreatureType::Spider => "Soft bodied under the legs. Beware the venom.",
+            CreatureType::Goblin => "Cowardly. Drop one and the rest lose heart.",
+            CreatureType::Orc => "Strong but poorly defended. Armor blunts their blows.",
+            CreatureType::Bandit => "Fights for coin. Wounded bandits often flee.",
+            CreatureType::GuardianSpirit => "Bound to its post. It will not follow you far.",
+            CreatureType::WildAnimal => "Fire and noise scare most beasts off.",
+            CreatureType::Construct => "Feels no pain, but its joints are weak.",
+        }
+    }
+
+    // Loot table shared by dungeon corpses and overworld fights
+    pub fn generate_loot(&self) -> Vec<LootItem> {
+        use rand::Rng;
+        let mut rng = crate::rng::session_rng();
+        let mut loot = Vec::new();
+        
+        // Base gold drop
+        let gold_amount = match self {
+            CreatureType::Bandit => rng.gen_range(5..15),
+            CreatureType::Orc => rng.gen_range(3..8),
+            CreatureType::Goblin => rng.gen_range(1..5),
+            CreatureType::WildAnimal => 0, // Beasts don't carry coins
+            _ => rng.gen_range(0..3),
+        };
+        
+        if gold_amount > 0 {
+            loot.push(LootItem {
+                name: "Gold Coins".to_string(),
+                item_type: LootItemType::Gold,
+                quantity: gold_amount,
+                value: 1, // Per coin
+                description: "Shiny gold coins".to_string(),
+            });
+        }
+        
+        // Creature-specific loot
+        match self {
+            CreatureType::Rat => {
+                if rng.gen_bool(0.1) { // 10% chance
+                    loot.push(LootItem {
+                        name: "Rat Tail".to_string(),
+                        item_type: LootItemType::SpellComponent,
+                        quantity: 1,
+                        value: 2,
+                        description: "A rat's tail, useful for certain potions".to_string(),
+                    });
+                }
+            }
+            CreatureType::Spider => {
+                if rng.gen_bool(0.3) { // 30% chance
+                    loot.push(LootItem {
+                        name: "Spider Silk".to_string(),
+                        item_type: LootItemType::SpellComponent,
+                        quantity: rng.gen_range(1..4),
+                        value: 5,
+                        description: "Strong spider silk for crafting".to_string(),
+                    });
+                }
+                if rng.gen_bool(0.2) { // 20% chance
+                    loot.push(LootItem {
+                        name: "Venom Sac".to_string(),
+                        item_type: LootItemType::SpellComponent,
+                        quantity: 1,
+                        value: 10,
+                        description: "Spider venom for poison crafting".to_string(),
+                    });
+                }
+            }
+            CreatureType::Skeleton => {
+                if rng.gen_bool(0.4) { // 40% chance
+                    loot.push(LootItem {
+                        name: "Ancient Bone".to_string(),
+                        item_type: LootItemType::Bone,
+                        quantity: rng.gen_range(1..3),
+                        value: 3,
+                        description: "Well-preserved bone suitable for necromancy".to_string(),
+                    });
+                }
+                if rng.gen_bool(0.15) { // 15% chance, the crypt's keeper
+                    loot.push(LootItem {
+                        name: super::DUNGEON_KEY.to_string(),
+                        item_type: LootItemType::Key,
+                        quantity: 1,
+                        value: 5,
+                        description: "A heavy iron key, still on its rotted cord".to_string(),
+                    });
+                }
+            }
+            CreatureType::WildAnimal => {
+                if rng.gen_bool(0.5) { // 50% chance
+                    loot.push(LootItem {
+                        name: "Animal Hide".to_string(),
+                        item_type: LootItemType::Hide,
+                        quantity: 1,
+                        value: 4,
+                        description: "A rough hide that a tanner would buy".to_string(),
+                    });
+                }
+                if rng.gen_bool(0.6) { // 60% chance
+                    loot.push(LootItem {
+                        name: "Raw Meat".to_string(),
+                        item_type: LootItemType::Meat,
+                        quantity: rng.gen_range(1..3),
+                        value: 1,
+                        description: "Fresh meat, good for a meal or two".to_string(),
+                    });
+                }
+            }
+            CreatureType::Bandit => {
+                if rng.gen_bool(0.6) { // 60% chance for weapon
+                    let weapons = vec!["Rusty Sword", "Wooden Club", "Iron Dagger"];
+                    let weapon = weapons[rng.gen_range(0..weapons.len())];
+                    loot.push(LootItem {
+                        name: weapon.to_string(),
+                        item_type: LootItemType::Weapon,
+                        quantity: 1,
+                        value: rng.gen_range(10..25),
+                        description: format!("A {}", weapon.to_lowercase()),
+                    });
+                }
+                if rng.gen_bool(0.3) { // 30% chance for armor
+                    loot.push(LootItem {
+                        name: "Leather Armor".to_string(),
+                        item_type: LootItemType::Armor,
+                        quantity: 1,
+                        value: rng.gen_range(15..30),
+                        description: "Worn leather armor".to_string(),
+                    });
+                }
+                if rng.gen_bool(0.25) { // 25% chance for a quiver
+                    loot.push(LootItem {
+                        name: "Arrows".to_string(),
+                        item_type: LootItemType::Ammo,
+                        quantity: rng.gen_range(3..10),
+                        value: 0,
+                        description: "A handful of arrows from a bandit's quiver".to_string(),
+                    });
+                }
+            }
+            _ => {}
+        }
+        
+        loot
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DungeonFeature {
     pub position: LocalCoord,
     pub feature_type: FeatureType,
     pub interactable: bool,
     pub description: String,
+    #[serde(default)]
+    pub detected: bool, // The player knows it's there, only matters for traps
+    #[serde(default)]
+    pub sprung: bool,   // A trap that has gone off and is harmless now
+}
+
+impl DungeonFeature {
+    // A trap that can still go off
+    pub fn armed_trap(&self) -> Option<&TrapType> {
+        match &self.feature_type {
+            FeatureType::Trap(trap) if !self.sprung => Some(trap),
+            _ => None,
+        }
+    }
+
+    // Undetected traps stay out of descriptions and off the map
+    pub fn is_noticed(&self) -> bool {
+        !matches!(self.feature_type, FeatureType::Trap(_)) || self.detected
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -229,6 +469,8 @@ pub enum LootItemType {
     SpellComponent, // Magical reagents
     Tool,
     Trinket,
+    Ammo,           // Arrows and bolts
+    Key,            // Opens a locked door
 }
 
 pub struct DungeonGenerator;
@@ -293,6 +535,7 @@ impl DungeonGenerator {
             }
         };
         
+        Self::place_wall_torches(&rooms, &mut tiles);
         let creatures = self.generate_creatures(poi_type, &rooms, &tiles, rng);
         let features = self.generate_features(poi_type, &rooms, &tiles, rng);
         
@@ -306,6 +549,39 @@ impl DungeonGenerator {
             features,
             corpses: Vec::new(), // Initially no corpses
             loot_piles: Vec::new(), // Initially no loot
+            ambient_light: Self::ambient_light(poi_type, floor_number),
+        }
+    }
+    
+    // Halls and guard posts keep torches burning in their far corners;
+    // crypts, caves and private rooms are left dark
+    fn place_wall_torches(rooms: &[DungeonRoom], tiles: &mut [Vec<DungeonTile>]) {
+        for room in rooms {
+            if !matches!(room.room_type, RoomType::EntryHall | RoomType::GreatHall | RoomType::Throne |
+                RoomType::Chapel | RoomType::GuardRoom | RoomType::Armory | RoomType::Kitchen) {
+                continue;
+            }
+            for x in [room.top_left.x, room.top_left.x + room.width - 1] {
+                if let Some(tile) = tiles.get_mut(room.top_left.y as usize).and_then(|row| row.get_mut(x as usize)) {
+                    if matches!(tile.tile_type, DungeonTileType::Floor) {
+                        tile.tile_type = DungeonTileType::Torch;
+                    }
+                }
+            }
+        }
+    }
+    
+    // Towers have windows and temples keep their torches burning. Ground floors
+    // catch some daylight, anything underground is dark.
+    fn ambient_light(poi_type: &PoiType, floor_number: i32) -> u8 {
+        match poi_type {
+            PoiType::AbandonedTower | PoiType::WizardTower => 6,
+            PoiType::Temple if floor_number == 0 => 7,
+            PoiType::AncientRuins if floor_number == 0 => 6,
+            PoiType::Cave | PoiType::AbandonedMine => 0,
+            PoiType::Crypt | PoiType::TreasureVault => 1,
+            _ if floor_number == 0 => 3,
+            _ => 1,
         }
     }
     
@@ -521,7 +797,9 @@ impl DungeonGenerator {
             // Connect to main chamber
             let connect_x = if i % 2 == 0 { main_x } else { main_x + main_width - 1 };
             let connect_y = alcove_y + alcove_height / 2;
-            tiles[connect_y as usize][connect_x as usize].tile_type = DungeonTileType::Door(DoorState::Closed);
+            // Some alcoves were sealed for good
+            let door = if rng.gen_bool(0.3) { DoorState::Locked } else { DoorState::Closed };
+            tiles[connect_y as usize][connect_x as usize].tile_type = DungeonTileType::Door(door);
             
             rooms.push(DungeonRoom {
                 room_type: RoomType::Crypt,
@@ -658,7 +936,8 @@ impl DungeonGenerator {
                 
                 if tiles.get(y as usize).and_then(|row| row.get(x as usize))
                     .map(|tile| matches!(tile.tile_type, DungeonTileType::Floor))
-                    .unwrap_or(false) {
+                    .unwrap_or(false)
+                    && !creatures.iter().any(|creature: &DungeonCreature| creature.position == LocalCoord::new(x, y)) {
                     
                     let creature_type = self.select_creature_type(poi_type, rng);
                     let name = self.generate_creature_name(&creature_type, rng);
@@ -762,6 +1041,29 @@ impl DungeonGenerator {
                         feature_type,
                         interactable: true,
                         description,
+                        detected: false,
+                        sprung: false,
+                    });
+                }
+            }
+
+            // Some rooms hide a trap on an open stretch of floor
+            if rng.gen_bool(TRAP_CHANCE) {
+                let x = rng.gen_range(room.top_left.x..(room.top_left.x + room.width));
+                let y = rng.gen_range(room.top_left.y..(room.top_left.y + room.height));
+                let position = LocalCoord::new(x, y);
+                let open_floor = tiles.get(y as usize).and_then(|row| row.get(x as usize))
+                    .is_some_and(|tile| matches!(tile.tile_type, DungeonTileType::Floor));
+                if open_floor && !features.iter().any(|feature| feature.position == position) {
+                    let options = [TrapType::Dart, TrapType::Pit, TrapType::Fire, TrapType::Poison, TrapType::Magic];
+                    let feature_type = FeatureType::Trap(options[rng.gen_range(0..options.len())].clone());
+                    features.push(DungeonFeature {
+                        position,
+                        description: self.generate_feature_description(&feature_type),
+                        feature_type,
+                        interactable: false,
+                        detected: false,
+                        sprung: false,
                     });
                 }
             }
@@ -812,6 +1114,84 @@ impl DungeonGenerator {
     }
 }
 
+// What a creature does on its turn to get closer to where it wants to be
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CreatureMove {
+    Step(LocalCoord),
+    OpenDoor(LocalCoord), // Opening the door takes the creature's turn
+    Wait,                 // Someone is standing in the way, try again next turn
+    Blocked,
+}
+
+impl DungeonFloor {
+    pub fn is_well_lit(&self) -> bool {
+        self.ambient_light >= WELL_LIT
+    }
+
+    pub fn torch_positions(&self) -> Vec<LocalCoord> {
+        let mut torches = Vec::new();
+        for (y, row) in self.tiles.iter().enumerate() {
+            for (x, tile) in row.iter().enumerate() {
+                if matches!(tile.tile_type, DungeonTileType::Torch) {
+                    torches.push(LocalCoord::new(x as i32, y as i32));
+                }
+            }
+        }
+        torches
+    }
+
+    // Light on a tile without the player's own torch: its own light or the floor's,
+    // plus whatever the nearest wall torch throws on it
+    pub fn light_at(&self, pos: LocalCoord, torches: &[LocalCoord]) -> u8 {
+        let base = self.tile_at(pos).map(|tile| tile.light_level).unwrap_or(0).max(self.ambient_light);
+        let torch_light = torches.iter()
+            .map(|torch| {
+                let distance = (torch.x - pos.x).abs().max((torch.y - pos.y).abs());
+                (WALL_TORCH_LIGHT as i32 - distance * 2).max(0) as u8
+            })
+            .max()
+            .unwrap_or(0);
+        (base + torch_light).min(10)
+    }
+
+    pub fn tile_at(&self, pos: LocalCoord) -> Option<&DungeonTile> {
+        self.tiles.get(pos.y as usize)?.get(pos.x as usize)
+    }
+
+    // Take one step toward the target along the longer axis first, sidestepping
+    // along the other when that way is blocked. Creatures only get through a
+    // closed door if they're clever enough to open it.
+    pub fn creature_step(&self, creature_index: usize, target: LocalCoord, opens_doors: bool, player_pos: LocalCoord) -> CreatureMove {
+        let from = self.creatures[creature_index].position;
+        let (dx, dy) = ((target.x - from.x).signum(), (target.y - from.y).signum());
+        let mut steps = vec![(dx, 0), (0, dy)];
+        if (target.y - from.y).abs() > (target.x - from.x).abs() {
+            steps.reverse();
+        }
+
+        let mut crowded = false;
+        for (step_x, step_y) in steps.into_iter().filter(|&step| step != (0, 0)) {
+            let next = LocalCoord::new(from.x + step_x, from.y + step_y);
+            match self.tile_at(next).map(|tile| &tile.tile_type) {
+                Some(DungeonTileType::Door(DoorState::Closed)) if opens_doors => return CreatureMove::OpenDoor(next),
+                Some(tile_type) if tile_type.is_passable() => {
+                    if !self.is_occupied(next, player_pos) {
+                        return CreatureMove::Step(next);
+                    }
+                    crowded = true;
+                }
+                _ => {}
+            }
+        }
+        if crowded { CreatureMove::Wait } else { CreatureMove::Blocked }
+    }
+
+    // Only one creature, or the player, fits on a tile
+    pub fn is_occupied(&self, pos: LocalCoord, player_pos: LocalCoord) -> bool {
+        pos == player_pos || self.creatures.iter().any(|creature| creature.position == pos)
+    }
+}
+
 impl DungeonLayout {
     pub fn get_current_floor(&self) -> Option<&DungeonFloor> {
         self.floors.get(&self.current_floor)
@@ -830,6 +1210,30 @@ impl DungeonLayout {
         }
     }
     
+    // Per-floor record of which tiles the player has mapped
+    pub fn explored_map(&self) -> HashMap<i32, Vec<Vec<bool>>> {
+        self.floors.iter()
+            .map(|(&number, floor)| {
+                let rows = floor.tiles.iter()
+                    .map(|row| row.iter().map(|tile| tile.explored).collect())
+                    .collect();
+                (number, rows)
+            })
+            .collect()
+    }
+    
+    pub fn restore_explored_map(&mut self, map: &HashMap<i32, Vec<Vec<bool>>>) {
+        for (number, rows) in map {
+            if let Some(floor) = self.floors.get_mut(number) {
+                for (tile_row, explored_row) in floor.tiles.iter_mut().zip(rows) {
+                    for (tile, &explored) in tile_row.iter_mut().zip(explored_row) {
+                        tile.explored = explored;
+                    }
+                }
+            }
+        }
+    }
+    
     pub fn get_tile_at(&self, pos: LocalCoord) -> Option<&DungeonTile> {
         self.get_current_floor()?
             .tiles
@@ -844,12 +1248,31 @@ impl DungeonLayout {
             .get_mut(pos.x as usize)
     }
     
+    // Swing a closed or secret door open, returning what it was. Locked doors
+    // need unlocking first and anything else isn't a door to open.
+    pub fn open_door(&mut self, pos: LocalCoord) -> Option<DoorState> {
+        let tile = self.get_tile_at_mut(pos)?;
+        let previous = match &tile.tile_type {
+            DungeonTileType::Door(state @ (DoorState::Closed | DoorState::Secret)) => state.clone(),
+            _ => return None,
+        };
+        tile.tile_type = DungeonTileType::Door(DoorState::Open);
+        Some(previous)
+    }
+    
     pub fn add_corpse(&mut self, corpse: DungeonCorpse) {
         if let Some(floor) = self.get_current_floor_mut() {
             floor.corpses.push(corpse);
         }
     }
     
+    // Pick up the pile lying at a spot on this floor, leaving nothing behind
+    pub fn take_loot_pile(&mut self, pos: LocalCoord) -> Option<LootPile> {
+        let floor = self.get_current_floor_mut()?;
+        let index = floor.loot_piles.iter().position(|pile| pile.position == pos)?;
+        Some(floor.loot_piles.remove(index))
+    }
+    
     pub fn add_loot_pile(&mut self, loot_pile: LootPile) {
         if let Some(floor) = self.get_current_floor_mut() {
             floor.loot_piles.push(loot_pile);
@@ -902,102 +1325,104 @@ impl DungeonCorpse {
             _ => {}
         }
         
-        interactions.push(CorpseInteraction::Burn); // Can always burn corpses
+        // Can always burn corpses
+        if !interactions.contains(&CorpseInteraction::Burn) {
+            interactions.push(CorpseInteraction::Burn);
+        }
         interactions
     }
     
     pub fn generate_loot(&self) -> Vec<LootItem> {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
-        let mut loot = Vec::new();
-        
-        // Base gold drop
-        let gold_amount = match self.creature_type {
-            CreatureType::Bandit => rng.gen_range(5..15),
-            CreatureType::Orc => rng.gen_range(3..8),
-            CreatureType::Goblin => rng.gen_range(1..5),
-            _ => rng.gen_range(0..3),
-        };
-        
-        if gold_amount > 0 {
-            loot.push(LootItem {
-                name: "Gold Coins".to_string(),
-                item_type: LootItemType::Gold,
-                quantity: gold_amount,
-                value: gold_amount,
-                description: "Shiny gold coins".to_string(),
-            });
+        self.creature_type.generate_loot()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{CharacterDatabase, CharacterRecord};
+
+    #[test]
+    fn dungeon_in_progress_survives_a_character_save() {
+        let dungeon = DungeonGenerator::new().generate_dungeon(PoiType::Cave, "Echoing Cave".to_string(), 7);
+        let floor_count = dungeon.floors.len();
+        let mut character = crate::forge::sample_character("Delver");
+        character.active_dungeon = Some(SavedDungeon {
+            dungeon,
+            player_pos: LocalCoord::new(4, 5),
+            zone: ZoneCoord::new(2, 3),
+            poi_position: LocalCoord::new(10, 11),
+            turn_count: 17,
+        });
+
+        let mut database = CharacterDatabase::new();
+        database.characters.insert(character.name.clone(), CharacterRecord {
+            character,
+            password_hash: String::new(),
+            salt: String::new(),
+        });
+        let path = std::env::temp_dir().join(format!("warlords-dungeon-{}.json", uuid::Uuid::new_v4()));
+        database.save(&path).unwrap();
+        let reloaded = CharacterDatabase::load_or_create(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(path.with_extension("json.bak"));
+
+        let saved = reloaded.characters["Delver"].character.active_dungeon.clone().expect("dungeon was not saved");
+        assert_eq!(saved.dungeon.name, "Echoing Cave");
+        assert_eq!(saved.dungeon.floors.len(), floor_count);
+        assert_eq!(saved.player_pos, LocalCoord::new(4, 5));
+        assert_eq!(saved.zone, ZoneCoord::new(2, 3));
+        assert_eq!(saved.poi_position, LocalCoord::new(10, 11));
+        assert_eq!(saved.turn_count, 17);
+    }
+
+    #[test]
+    fn an_opened_door_lets_the_player_through() {
+        let mut dungeon = DungeonGenerator::new().generate_dungeon(PoiType::Cave, "Shut Cave".to_string(), 3);
+        let door = dungeon.entrance_pos;
+        for state in [DoorState::Closed, DoorState::Secret] {
+            dungeon.get_tile_at_mut(door).unwrap().tile_type = DungeonTileType::Door(state.clone());
+            assert!(!dungeon.get_tile_at(door).unwrap().tile_type.is_passable());
+
+            assert_eq!(dungeon.open_door(door), Some(state));
+            assert!(dungeon.get_tile_at(door).unwrap().tile_type.is_passable());
+            // Already open, so there's nothing more to do
+            assert_eq!(dungeon.open_door(door), None);
         }
-        
-        // Creature-specific loot
-        match self.creature_type {
-            CreatureType::Rat => {
-                if rng.gen_bool(0.1) { // 10% chance
-                    loot.push(LootItem {
-                        name: "Rat Tail".to_string(),
-                        item_type: LootItemType::SpellComponent,
-                        quantity: 1,
-                        value: 2,
-                        description: "A rat's tail, useful for certain potions".to_string(),
-                    });
-                }
-            }
-            CreatureType::Spider => {
-                if rng.gen_bool(0.3) { // 30% chance
-                    loot.push(LootItem {
-                        name: "Spider Silk".to_string(),
-                        item_type: LootItemType::SpellComponent,
-                        quantity: rng.gen_range(1..4),
-                        value: 5,
-                        description: "Strong spider silk for crafting".to_string(),
-                    });
-                }
-                if rng.gen_bool(0.2) { // 20% chance
-                    loot.push(LootItem {
-                        name: "Venom Sac".to_string(),
-                        item_type: LootItemType::SpellComponent,
-                        quantity: 1,
-                        value: 10,
-                        description: "Spider venom for poison crafting".to_string(),
-                    });
-                }
-            }
-            CreatureType::Skeleton => {
-                if rng.gen_bool(0.4) { // 40% chance
-                    loot.push(LootItem {
-                        name: "Ancient Bone".to_string(),
-                        item_type: LootItemType::Bone,
-                        quantity: rng.gen_range(1..3),
-                        value: 3,
-                        description: "Well-preserved bone suitable for necromancy".to_string(),
-                    });
-                }
-            }
-            CreatureType::Bandit => {
-                if rng.gen_bool(0.6) { // 60% chance for weapon
-                    let weapons = vec!["Rusty Sword", "Wooden Club", "Iron Dagger"];
-                    let weapon = weapons[rng.gen_range(0..weapons.len())];
-                    loot.push(LootItem {
-                        name: weapon.to_string(),
-                        item_type: LootItemType::Weapon,
-                        quantity: 1,
-                        value: rng.gen_range(10..25),
-                        description: format!("A {}", weapon.to_lowercase()),
-                    });
-                }
-                if rng.gen_bool(0.3) { // 30% chance for armor
-                    loot.push(LootItem {
-                        name: "Leather Armor".to_string(),
-                        item_type: LootItemType::Armor,
-                        quantity: 1,
-                        value: rng.gen_range(15..30),
-                        description: "Worn leather armor".to_string(),
-                    });
-                }
-            }
-            _ => {}
+
+        dungeon.get_tile_at_mut(door).unwrap().tile_type = DungeonTileType::Door(DoorState::Locked);
+        assert_eq!(dungeon.open_door(door), None);
+        assert!(!dungeon.get_tile_at(door).unwrap().tile_type.is_passable());
+    }
+
+    #[test]
+    fn a_loot_pile_can_only_be_taken_once() {
+        let mut dungeon = DungeonGenerator::new().generate_dungeon(PoiType::Cave, "Hoard Cave".to_string(), 5);
+        let spot = dungeon.entrance_pos;
+        dungeon.add_loot_pile(LootPile {
+            position: spot,
+            items: CreatureType::Goblin.generate_loot(),
+            source: "Goblin".to_string(),
+            discovered: true,
+        });
+        let piles_before = dungeon.get_current_floor().unwrap().loot_piles.len();
+
+        let taken = dungeon.take_loot_pile(spot).expect("the pile was not there");
+        assert_eq!(taken.source, "Goblin");
+        assert_eq!(dungeon.get_current_floor().unwrap().loot_piles.len(), piles_before - 1);
+        assert!(dungeon.take_loot_pile(spot).is_none());
+    }
+
+    #[test]
+    fn creature_gold_is_counted_once_per_coin() {
+        for _ in 0..200 {
+            let gold: u32 = CreatureType::Bandit.generate_loot().iter()
+                .filter(|item| matches!(item.item_type, LootItemType::Gold))
+                .map(|item| {
+                    assert_eq!(item.value, 1);
+                    item.quantity * item.value
+                })
+                .sum();
+            assert!((5..15).contains(&gold));
         }
-        
-        loot
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,60 @@
+use rand::Rng;
+
+pub const LOCKPICKS: &str = "Lockpicks";
+pub const DUNGEON_KEY: &str = "Iron Key"; // Fits any lock in a dungeon, and is used up doing it
+pub const LOCKPICK_SKILLS: [&str; 2] = ["Lockpicking", "Stealth"];
+
+const BASE_LOCK_DIFFICULTY: u32 = 12;
+const LOCK_DIFFICULTY_PER_FLOOR: u32 = 2;
+const BREAK_CHANCE: f64 = 0.3; // Of snapping the picks on a failed attempt
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockpickResult {
+    Opened,
+    Failed,
+    ToolsBroken, // Failed, and the picks are gone too
+}
+
+// Locks get better the further a dungeon goes from the surface
+pub fn lock_difficulty(floor_number: i32) -> u32 {
+    BASE_LOCK_DIFFICULTY + floor_number.unsigned_abs() * LOCK_DIFFICULTY_PER_FLOOR
+}
+
+// d20 plus twice the skill against the lock, like other skill checks
+pub fn attempt_lockpick(skill_level: u8, roll: u32, difficulty: u32) -> LockpickResult {
+    if roll + skill_level as u32 * 2 >= difficulty {
+        LockpickResult::Opened
+    } else if crate::rng::session_rng().gen_bool(BREAK_CHANCE) {
+        LockpickResult::ToolsBroken
+    } else {
+        LockpickResult::Failed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_good_enough_roll_opens_the_lock() {
+        let difficulty = lock_difficulty(1);
+        assert_eq!(attempt_lockpick(3, difficulty - 6, difficulty), LockpickResult::Opened);
+        assert_eq!(attempt_lockpick(0, 20, 20), LockpickResult::Opened);
+    }
+
+    #[test]
+    fn a_roll_just_short_fails_and_may_cost_the_picks() {
+        let difficulty = lock_difficulty(1);
+        for _ in 0..50 {
+            let result = attempt_lockpick(3, difficulty - 7, difficulty);
+            assert!(matches!(result, LockpickResult::Failed | LockpickResult::ToolsBroken));
+        }
+    }
+
+    #[test]
+    fn locks_get_harder_deeper_down() {
+        assert_eq!(lock_difficulty(0), BASE_LOCK_DIFFICULTY);
+        assert!(lock_difficulty(-3) > lock_difficulty(-1));
+        assert_eq!(lock_difficulty(-2), lock_difficulty(2));
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use super::{LocalCoord, ZoneCoord, ZONE_SIZE};
+
+pub const OVERWORLD_SIGHT_RADIUS: i32 = 8; // Tiles revealed around the player while travelling
+
+const WORDS_PER_ZONE: usize = (ZONE_SIZE * ZONE_SIZE / 64) as usize;
+
+// Which overworld tiles a character has seen, stored as one bitset per zone
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MapMemory {
+    zones: HashMap<String, Vec<u64>>, // "x,y" keys keep the JSON map valid
+}
+
+impl MapMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reveal_around(&mut self, zone: ZoneCoord, center: LocalCoord, radius: i32) {
+        let bits = self.zones
+            .entry(Self::zone_key(zone))
+            .or_insert_with(|| vec![0; WORDS_PER_ZONE]);
+
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                if dx * dx + dy * dy > radius * radius {
+                    continue;
+                }
+                let x = center.x + dx;
+                let y = center.y + dy;
+                if (0..ZONE_SIZE).contains(&x) && (0..ZONE_SIZE).contains(&y) {
+                    let index = (y * ZONE_SIZE + x) as usize;
+                    bits[index / 64] |= 1 << (index % 64);
+                }
+            }
+        }
+    }
+
+    pub fn is_seen(&self, zone: ZoneCoord, pos: LocalCoord) -> bool {
+        if !(0..ZONE_SIZE).contains(&pos.x) || !(0..ZONE_SIZE).contains(&pos.y) {
+            return false;
+        }
+        let index = (pos.y * ZONE_SIZE + pos.x) as usize;
+        self.zones
+            .get(&Self::zone_key(zone))
+            .map(|bits| bits[index / 64] & (1 << (index % 64)) != 0)
+            .unwrap_or(false)
+    }
+
+    pub fn has_visited_zone(&self, zone: ZoneCoord) -> bool {
+        self.zones.contains_key(&Self::zone_key(zone))
+    }
+
+    pub fn visited_zone_count(&self) -> usize {
+        self.zones.len()
+    }
+
+    fn zone_key(zone: ZoneCoord) -> String {
+        format!("{},{}", zone.x, zone.y)
+    }
+}
@@ -12,6 +12,16 @@ pub mod npc;
 pub mod persistence;
 pub mod display;
 pub mod dungeon;
+pub mod region;
+pub mod map_memory;
+pub mod bestiary;
+pub mod resources;
+pub mod dialogue;
+pub mod trade;
+pub mod locks;
+pub mod traps;
+pub mod quests;
+pub mod time;
 
 pub use terrain::*;
 pub use settlement::*;
@@ -21,6 +31,16 @@ pub use npc::*;
 pub use persistence::*;
 pub use display::*;
 pub use dungeon::*;
+pub use region::*;
+pub use map_memory::*;
+pub use bestiary::*;
+pub use resources::*;
+pub use dialogue::*;
+pub use trade::*;
+pub use locks::*;
+pub use traps::*;
+pub use quests::*;
+pub use time::*;
 
 /// World coordinates - each zone is ZONE_SIZE x ZONE_SIZE tiles
 pub const ZONE_SIZE: i32 = 64;  // Reduced from 512 for better performance
@@ -44,6 +64,61 @@ pub struct LocalCoord {
     pub y: i32,
 }
 
+pub const DEFAULT_WORLD_ZONES: i32 = 8; // 8 x 64 = 512 tiles across
+
+// Size of the world in zones. Zones run from 0 to width-1 and 0 to height-1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorldBounds {
+    pub width_zones: i32,
+    pub height_zones: i32,
+    #[serde(default)]
+    pub wrap: bool, // Walking off one edge brings you back on the opposite one
+}
+
+impl Default for WorldBounds {
+    fn default() -> Self {
+        Self::new(DEFAULT_WORLD_ZONES, DEFAULT_WORLD_ZONES)
+    }
+}
+
+impl WorldBounds {
+    pub fn new(width_zones: i32, height_zones: i32) -> Self {
+        Self {
+            width_zones: width_zones.max(1),
+            height_zones: height_zones.max(1),
+            wrap: false,
+        }
+    }
+
+    pub fn contains(&self, zone: ZoneCoord) -> bool {
+        (0..self.width_zones).contains(&zone.x) && (0..self.height_zones).contains(&zone.y)
+    }
+
+    // Where a step into `zone` actually lands: the zone itself, the zone on the far
+    // side of the world when wrapping, or None when the edge can't be crossed
+    pub fn resolve(&self, zone: ZoneCoord) -> Option<ZoneCoord> {
+        if self.contains(zone) {
+            Some(zone)
+        } else if self.wrap {
+            Some(ZoneCoord::new(zone.x.rem_euclid(self.width_zones), zone.y.rem_euclid(self.height_zones)))
+        } else {
+            None
+        }
+    }
+
+    pub fn center(&self) -> WorldCoord {
+        WorldCoord::new(self.width_zones * ZONE_SIZE / 2, self.height_zones * ZONE_SIZE / 2)
+    }
+
+    pub fn width_tiles(&self) -> i32 {
+        self.width_zones * ZONE_SIZE
+    }
+
+    pub fn height_tiles(&self) -> i32 {
+        self.height_zones * ZONE_SIZE
+    }
+}
+
 impl ZoneCoord {
     pub fn new(x: i32, y: i32) -> Self {
         Self { x, y }
@@ -104,6 +179,22 @@ impl LocalCoord {
     pub fn new(x: i32, y: i32) -> Self {
         Self { x, y }
     }
+
+    // Rough compass direction towards another tile, e.g. "north-east"
+    pub fn direction_to(&self, other: LocalCoord) -> &'static str {
+        let (dx, dy) = (other.x - self.x, other.y - self.y);
+        let vertical = if dy * 2 < -dx.abs() { "north" } else if dy * 2 > dx.abs() { "south" } else { "" };
+        let horizontal = if dx * 2 < -dy.abs() { "west" } else if dx * 2 > dy.abs() { "east" } else { "" };
+        match (vertical, horizontal) {
+            ("", "") => "right here",
+            ("", horizontal) => horizontal,
+            (vertical, "") => vertical,
+            ("north", "east") => "north-east",
+            ("north", _) => "north-west",
+            (_, "east") => "south-east",
+            _ => "south-west",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -130,6 +221,12 @@ pub struct PointOfInterest {
     pub treasure: Option<Treasure>,
     pub encounter: Option<Encounter>,
     pub difficulty: u8, // 1-10 difficulty rating
+    #[serde(default)]
+    pub treasure_taken: bool,
+    #[serde(default)]
+    pub encounter_resolved: bool,
+    #[serde(default)]
+    pub used_altars: Vec<(i32, LocalCoord)>, // Floor and tile of dungeon altars already called upon
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -315,6 +412,9 @@ impl PoiGenerator {
                 treasure,
                 encounter,
                 difficulty,
+                treasure_taken: false,
+                encounter_resolved: false,
+                used_altars: Vec::new(),
             });
         }
         
@@ -569,6 +669,29 @@ impl PoiGenerator {
     }
 }
 
+impl PointOfInterest {
+    // Hand over the treasure the first time it's looted. The POI is marked explored
+    // and emptied so it stays that way once the zone is saved.
+    pub fn take_treasure(&mut self) -> Option<Treasure> {
+        if self.treasure_taken {
+            return None;
+        }
+        let treasure = self.treasure.clone()?;
+        self.explored = true;
+        self.treasure_taken = true;
+        Some(treasure)
+    }
+
+    // An altar in this POI's dungeon answers only once. Returns false if it already has.
+    pub fn use_altar(&mut self, floor: i32, position: LocalCoord) -> bool {
+        if self.used_altars.contains(&(floor, position)) {
+            return false;
+        }
+        self.used_altars.push((floor, position));
+        true
+    }
+}
+
 impl WorldZone {
     pub fn mark_visited(&mut self) {
         self.last_visited = Some(chrono::Utc::now());
@@ -589,4 +712,61 @@ impl WorldZone {
             dx < 3 && dy < 3 // POIs have small interaction radius
         })
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_contain_only_zones_inside_the_world() {
+        let bounds = WorldBounds::new(4, 3);
+        assert!(bounds.contains(ZoneCoord::new(0, 0)));
+        assert!(bounds.contains(ZoneCoord::new(3, 2)));
+        assert!(!bounds.contains(ZoneCoord::new(4, 0)));
+        assert!(!bounds.contains(ZoneCoord::new(0, 3)));
+        assert!(!bounds.contains(ZoneCoord::new(-1, 1)));
+        assert!(bounds.contains(ZoneCoord::from_world(bounds.center())));
+    }
+
+    #[test]
+    fn bounds_never_shrink_below_one_zone() {
+        let bounds = WorldBounds::new(0, -5);
+        assert_eq!((bounds.width_zones, bounds.height_zones), (1, 1));
+        assert!(bounds.contains(ZoneCoord::new(0, 0)));
+    }
+
+    #[test]
+    fn stepping_off_the_edge_stops_or_wraps() {
+        let mut bounds = WorldBounds::new(4, 3);
+        assert_eq!(bounds.resolve(ZoneCoord::new(2, 1)), Some(ZoneCoord::new(2, 1)));
+        assert_eq!(bounds.resolve(ZoneCoord::new(-1, 1)), None);
+        assert_eq!(bounds.resolve(ZoneCoord::new(4, 3)), None);
+
+        bounds.wrap = true;
+        assert_eq!(bounds.resolve(ZoneCoord::new(-1, 1)), Some(ZoneCoord::new(3, 1)));
+        assert_eq!(bounds.resolve(ZoneCoord::new(4, 3)), Some(ZoneCoord::new(0, 0)));
+    }
+
+    #[test]
+    fn an_altar_answers_only_once_even_after_saving() {
+        let mut poi = PointOfInterest {
+            position: LocalCoord::new(3, 3),
+            poi_type: PoiType::AncientRuins,
+            name: "Old Temple".to_string(),
+            description: String::new(),
+            explored: false,
+            treasure: None,
+            encounter: None,
+            difficulty: 2,
+            treasure_taken: false,
+            encounter_resolved: false,
+            used_altars: Vec::new(),
+        };
+        assert!(poi.use_altar(-1, LocalCoord::new(10, 4)));
+        assert!(!poi.use_altar(-1, LocalCoord::new(10, 4)));
+        assert!(poi.use_altar(-2, LocalCoord::new(10, 4)));
+
+        let mut reloaded: PointOfInterest = serde_json::from_str(&serde_json::to_string(&poi).unwrap()).unwrap();
+        assert!(!reloaded.use_altar(-1, LocalCoord::new(10, 4)));
+    }
+}
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use rand::Rng;
 use rand_chacha::ChaCha8Rng;
-use super::{LocalCoord, TerrainMap};
+use super::{DialogueTree, LocalCoord, LootItem, LootItemType, TerrainMap};
+
+pub const GRUDGE_HOURS: u32 = 24; // How long an assault keeps someone after the player
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NPC {
@@ -14,6 +16,10 @@ pub struct NPC {
     pub services: Vec<NPCService>,
     pub level: u8,
     pub faction: String,
+    #[serde(default)]
+    pub dialogue_tree: DialogueTree, // Empty for NPCs saved before conversations branched
+    #[serde(default)]
+    pub grudge_until: Option<u32>, // Hour on the player's clock when anger over an assault fades
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -35,7 +41,7 @@ pub enum NPCType {
     Explorer,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum NPCDisposition {
     Friendly,
     Neutral,
@@ -58,6 +64,97 @@ pub enum NPCService {
     Storage,
 }
 
+impl NPC {
+    // Nudge how the NPC feels about the player. Returns true if their disposition changed.
+    pub fn adjust_disposition(&mut self, delta: i32) -> bool {
+        if delta == 0 {
+            return false;
+        }
+        let shifted = NPCDisposition::from_warmth(self.disposition.warmth() + delta);
+        let changed = shifted != self.disposition;
+        self.disposition = shifted;
+        changed
+    }
+
+    // Stay angry about an assault for a day from the given hour
+    pub fn hold_grudge(&mut self, now: u32) {
+        self.grudge_until = Some(now.saturating_add(GRUDGE_HOURS));
+    }
+
+    // Once a grudge has run its course a hostile NPC calms down to wary.
+    // Returns true if they did.
+    pub fn settle_grudge(&mut self, now: u32) -> bool {
+        match self.grudge_until {
+            Some(until) if now >= until => {
+                self.grudge_until = None;
+                if self.disposition == NPCDisposition::Hostile {
+                    self.disposition = NPCDisposition::Wary;
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn conversation(&self) -> DialogueTree {
+        if self.dialogue_tree.nodes.is_empty() {
+            DialogueTree::generate(&self.npc_type, &self.disposition, &self.dialogue, &self.services, !self.inventory.is_empty())
+        } else {
+            self.dialogue_tree.clone()
+        }
+    }
+
+    // What a defeated NPC leaves behind: a purse and everything they carried
+    pub fn generate_loot(&self) -> Vec<LootItem> {
+        let mut rng = crate::rng::session_rng();
+        let mut loot = Vec::new();
+        
+        let purse_bonus = match self.npc_type {
+            NPCType::Noble => 20,
+            NPCType::Merchant => 10,
+            NPCType::Bandit | NPCType::Thief => 5,
+            _ => 0,
+        };
+        let mut gold = rng.gen_range(0..=self.level as u32 * 3) + purse_bonus;
+        
+        for item in &self.inventory {
+            let (item_type, quantity) = match item.as_str() {
+                "Coin Purse" => {
+                    gold += rng.gen_range(3..12);
+                    continue;
+                }
+                "Arrows" => (LootItemType::Ammo, rng.gen_range(5..15)),
+                name if ["Sword", "Bow", "Hammer", "Dagger"].iter().any(|w| name.contains(w)) => (LootItemType::Weapon, 1),
+                name if name.contains("Armor") || name.contains("Mail") => (LootItemType::Armor, 1),
+                name if name.contains("Potion") || name.contains("Remedy") || name.contains("Holy Water") => (LootItemType::Potion, 1),
+                name if name.contains("Scroll") => (LootItemType::Scroll, 1),
+                "Rations" => (LootItemType::Food, 1),
+                "Lockpicks" | "Iron Ingot" => (LootItemType::Tool, 1),
+                _ => (LootItemType::Trinket, 1),
+            };
+            loot.push(LootItem {
+                name: item.clone(),
+                item_type,
+                quantity,
+                value: 0,
+                description: format!("Taken from {}", self.name),
+            });
+        }
+        
+        if gold > 0 {
+            loot.push(LootItem {
+                name: "Gold Coins".to_string(),
+                item_type: LootItemType::Gold,
+                quantity: gold,
+                value: 1, // Per coin
+                description: format!("{}'s purse", self.name),
+            });
+        }
+        
+        loot
+    }
+}
+
 pub struct NPCGenerator {
     names: Vec<&'static str>,
     surnames: Vec<&'static str>,
@@ -129,6 +226,7 @@ impl NPCGenerator {
             let services = self.generate_services(&npc_type);
             let level = rng.gen_range(1..=10);
             let faction = self.determine_faction(&npc_type, rng);
+            let dialogue_tree = DialogueTree::generate(&npc_type, &disposition, &dialogue, &services, !inventory.is_empty());
 
             return Some(NPC {
                 name,
@@ -140,6 +238,8 @@ impl NPCGenerator {
                 services,
                 level,
                 faction,
+                dialogue_tree,
+                grudge_until: None,
             });
         }
         None
@@ -321,6 +421,7 @@ impl NPCGenerator {
                     "Blessing Scroll".to_string(),
                     "Holy Water".to_string(),
                     "Health Potion".to_string(),
+                    "Spell Point Potion".to_string(),
                 ]);
             }
             NPCType::Ranger => {
@@ -378,6 +479,52 @@ impl NPCGenerator {
     }
 }
 
+impl NPCDisposition {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            NPCDisposition::Friendly => "Friendly",
+            NPCDisposition::Neutral => "Neutral",
+            NPCDisposition::Wary => "Wary",
+            NPCDisposition::Hostile => "Hostile",
+            NPCDisposition::Fearful => "Fearful",
+            NPCDisposition::Greedy => "Greedy",
+            NPCDisposition::Helpful => "Helpful",
+        }
+    }
+
+    // How warmly they feel about the player, from -2 (hostile) to 2 (helpful)
+    pub fn warmth(&self) -> i32 {
+        match self {
+            NPCDisposition::Hostile => -2,
+            NPCDisposition::Wary | NPCDisposition::Fearful => -1,
+            NPCDisposition::Neutral | NPCDisposition::Greedy => 0,
+            NPCDisposition::Friendly => 1,
+            NPCDisposition::Helpful => 2,
+        }
+    }
+
+    pub fn from_warmth(warmth: i32) -> Self {
+        match warmth {
+            i32::MIN..=-2 => NPCDisposition::Hostile,
+            -1 => NPCDisposition::Wary,
+            0 => NPCDisposition::Neutral,
+            1 => NPCDisposition::Friendly,
+            _ => NPCDisposition::Helpful,
+        }
+    }
+
+    // Percentage added to a merchant's prices
+    pub fn price_modifier(&self) -> i32 {
+        match self {
+            NPCDisposition::Friendly | NPCDisposition::Helpful => -10,
+            NPCDisposition::Neutral | NPCDisposition::Fearful => 0,
+            NPCDisposition::Wary => 10,
+            NPCDisposition::Greedy => 25,
+            NPCDisposition::Hostile => 50,
+        }
+    }
+}
+
 impl NPCType {
     pub fn get_ascii_char(&self) -> char {
         match self {
@@ -418,4 +565,47 @@ impl NPCType {
             NPCType::Explorer => "cyan",
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard() -> NPC {
+        NPC {
+            name: "Edric Stone".to_string(),
+            npc_type: NPCType::Guard,
+            position: LocalCoord::new(4, 4),
+            dialogue: Vec::new(),
+            disposition: NPCDisposition::Neutral,
+            inventory: Vec::new(),
+            services: Vec::new(),
+            level: 3,
+            faction: "Kingdom".to_string(),
+            dialogue_tree: DialogueTree::default(),
+            grudge_until: None,
+        }
+    }
+
+    #[test]
+    fn an_assaulted_guard_calms_down_after_a_day() {
+        let mut guard = guard();
+        guard.adjust_disposition(-4);
+        guard.hold_grudge(10);
+        assert_eq!(guard.disposition, NPCDisposition::Hostile);
+
+        assert!(!guard.settle_grudge(10 + GRUDGE_HOURS - 1));
+        assert_eq!(guard.disposition, NPCDisposition::Hostile);
+        assert!(guard.settle_grudge(10 + GRUDGE_HOURS));
+        assert_eq!(guard.disposition, NPCDisposition::Wary);
+        assert!(guard.grudge_until.is_none());
+    }
+
+    #[test]
+    fn hostility_without_a_grudge_is_left_alone() {
+        let mut guard = guard();
+        guard.disposition = NPCDisposition::Hostile;
+        assert!(!guard.settle_grudge(1000));
+        assert_eq!(guard.disposition, NPCDisposition::Hostile);
+    }
+}
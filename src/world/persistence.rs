@@ -3,13 +3,103 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::fs;
 use anyhow::{Result, Context};
-use super::{WorldZone, ZoneCoord, WorldGenerator};
+use super::{WorldZone, ZoneCoord, LocalCoord, WorldGenerator, Region, RegionCoord, RegionGenerator, WorldBounds};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WorldDatabase {
     pub master_seed: u64,
+    #[serde(with = "map_as_list")]
     pub zones: HashMap<ZoneCoord, WorldZone>,
     pub metadata: WorldMetadata,
+    #[serde(default)]
+    pub dungeon_maps: HashMap<String, HashMap<i32, Vec<Vec<bool>>>>, // Explored tiles per dungeon floor
+    #[serde(default = "first_format_version")]
+    pub format_version: u32, // Layout of the saved zones, see WORLD_FORMAT_VERSION
+}
+
+// Databases saved before they recorded a format version
+fn first_format_version() -> u32 {
+    1
+}
+
+// JSON object keys have to be strings, so maps keyed by coordinates are saved
+// as a list of (key, value) pairs instead
+pub(crate) mod map_as_list {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    pub fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(map.iter())
+    }
+
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let entries: Vec<(K, V)> = Vec::deserialize(deserializer)?;
+        Ok(entries.into_iter().collect())
+    }
+}
+
+// Bump when saved worlds stop being readable by older code.
+//   1: terrain tiles stored column-major, tiles[x][y]
+//   2: terrain tiles stored row-major, tiles[y][x]. Format 1 zones are turned
+//      around on load. The terrain smoothing pass now walks the tiles in a
+//      different order too, so zones generated from here on can come out a
+//      little different from what an older version made from the same seed.
+pub const WORLD_FORMAT_VERSION: u32 = 2;
+
+pub const DEFAULT_WORLD_NAME: &str = "default_world"; // The world the game and the server play in
+
+// Small file next to the world database describing how the world was made.
+// Reopening a world takes the seed from here, not from the caller.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldManifest {
+    pub world_name: String,
+    pub master_seed: u64,
+    pub format_version: u32,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub bounds: WorldBounds,
+}
+
+impl WorldManifest {
+    fn path(save_directory: &Path, world_name: &str) -> PathBuf {
+        save_directory.join(format!("{}_manifest.json", world_name))
+    }
+
+    fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read world manifest from {}", path.display()))?;
+        let manifest: WorldManifest = serde_json::from_str(&content)
+            .with_context(|| "Failed to parse world manifest JSON")?;
+
+        if manifest.format_version > WORLD_FORMAT_VERSION {
+            return Err(anyhow::anyhow!(
+                "World '{}' was saved in format {}, but this version only reads up to {}",
+                manifest.world_name, manifest.format_version, WORLD_FORMAT_VERSION
+            ));
+        }
+        Ok(manifest)
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .with_context(|| "Failed to serialize world manifest")?;
+        crate::database::write_atomically(path, &content, None)
+            .with_context(|| format!("Failed to write world manifest to {}", path.display()))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,6 +109,8 @@ pub struct WorldMetadata {
     pub total_zones_generated: usize,
     pub world_name: String,
     pub version: String,
+    #[serde(default)]
+    pub bounds: WorldBounds, // Worlds saved before this was stored use the default size
 }
 
 pub struct WorldManager {
@@ -26,14 +118,31 @@ pub struct WorldManager {
     save_path: PathBuf,
     generator: WorldGenerator,
     dirty_zones: std::collections::HashSet<ZoneCoord>,
+    manifest: WorldManifest,
 }
 
 impl WorldManager {
-    pub fn new(world_name: &str, master_seed: u64, save_directory: &Path) -> Result<Self> {
+    // The seed and bounds only apply to a brand new world; a saved world keeps the ones in its manifest
+    pub fn new(world_name: &str, master_seed: u64, save_directory: &Path, bounds: WorldBounds) -> Result<Self> {
         let save_path = save_directory.join(format!("{}_world.json", world_name));
+        let manifest_path = WorldManifest::path(save_directory, world_name);
+        let manifest = if manifest_path.exists() {
+            Some(WorldManifest::load(&manifest_path)?)
+        } else {
+            None
+        };
+        let master_seed = manifest.as_ref().map(|manifest| manifest.master_seed).unwrap_or(master_seed);
+        let bounds = manifest.as_ref().map(|manifest| manifest.bounds).unwrap_or(bounds);
         
         let database = if save_path.exists() {
-            Self::load_database(&save_path)?
+            let database = Self::load_database(&save_path)?;
+            if database.master_seed != master_seed {
+                return Err(anyhow::anyhow!(
+                    "World '{}' was generated with seed {}, but its manifest says {}",
+                    world_name, database.master_seed, master_seed
+                ));
+            }
+            database
         } else {
             WorldDatabase {
                 master_seed,
@@ -44,7 +153,32 @@ impl WorldManager {
                     total_zones_generated: 0,
                     world_name: world_name.to_string(),
                     version: env!("CARGO_PKG_VERSION").to_string(),
+                    bounds,
                 },
+                dungeon_maps: HashMap::new(),
+                format_version: WORLD_FORMAT_VERSION,
+            }
+        };
+        
+        // Worlds saved before manifests existed get one describing them as they are
+        let manifest = match manifest {
+            Some(mut manifest) if manifest.format_version < WORLD_FORMAT_VERSION => {
+                println!("🔄 Updating world '{}' from format {} to {}", world_name, manifest.format_version, WORLD_FORMAT_VERSION);
+                manifest.format_version = WORLD_FORMAT_VERSION;
+                manifest.save(&manifest_path)?;
+                manifest
+            }
+            Some(manifest) => manifest,
+            None => {
+                let manifest = WorldManifest {
+                    world_name: world_name.to_string(),
+                    master_seed: database.master_seed,
+                    format_version: WORLD_FORMAT_VERSION,
+                    created_at: database.metadata.created_at,
+                    bounds: database.metadata.bounds,
+                };
+                manifest.save(&manifest_path)?;
+                manifest
             }
         };
         
@@ -55,10 +189,27 @@ impl WorldManager {
             save_path,
             generator,
             dirty_zones: std::collections::HashSet::new(),
+            manifest,
         })
     }
     
+    // The size of a saved world without loading its zones, from the manifest or,
+    // for worlds older than the manifest, the world file. A world that hasn't
+    // been made yet will get the default size.
+    pub fn saved_bounds(world_name: &str, save_directory: &Path) -> Result<WorldBounds> {
+        let manifest_path = WorldManifest::path(save_directory, world_name);
+        if manifest_path.exists() {
+            return Ok(WorldManifest::load(&manifest_path)?.bounds);
+        }
+        let save_path = save_directory.join(format!("{}_world.json", world_name));
+        if save_path.exists() {
+            return Ok(Self::load_database(&save_path)?.metadata.bounds);
+        }
+        Ok(WorldBounds::default())
+    }
+
     pub fn get_zone(&mut self, coord: ZoneCoord) -> Result<&WorldZone> {
+        self.check_in_bounds(coord)?;
         if !self.database.zones.contains_key(&coord) {
             self.generate_zone(coord)?;
         }
@@ -72,14 +223,52 @@ impl WorldManager {
         Ok(self.database.zones.get(&coord).unwrap())
     }
     
+    pub fn bounds(&self) -> WorldBounds {
+        self.database.metadata.bounds
+    }
+    
+    fn check_in_bounds(&self, coord: ZoneCoord) -> Result<()> {
+        let bounds = self.bounds();
+        if bounds.contains(coord) {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Zone ({}, {}) is outside the {}x{} world", coord.x, coord.y, bounds.width_zones, bounds.height_zones))
+        }
+    }
+    
+    pub fn get_region(&self, zone: ZoneCoord) -> Region {
+        RegionGenerator::new().generate(RegionCoord::from_zone(zone), self.database.master_seed)
+    }
+    
     pub fn get_zone_if_exists(&self, coord: ZoneCoord) -> Option<&WorldZone> {
         self.database.zones.get(&coord)
     }
     
+    // Write a modified copy of a zone back into the cache so it is saved
+    pub fn update_zone(&mut self, zone: WorldZone) {
+        let coord = zone.coord;
+        self.database.zones.insert(coord, zone);
+        self.dirty_zones.insert(coord);
+    }
+    
+    pub fn get_dungeon_map(&self, zone: ZoneCoord, entrance: LocalCoord) -> Option<&HashMap<i32, Vec<Vec<bool>>>> {
+        self.database.dungeon_maps.get(&Self::dungeon_key(zone, entrance))
+    }
+    
+    pub fn store_dungeon_map(&mut self, zone: ZoneCoord, entrance: LocalCoord, map: HashMap<i32, Vec<Vec<bool>>>) {
+        self.database.dungeon_maps.insert(Self::dungeon_key(zone, entrance), map);
+        self.dirty_zones.insert(zone);
+    }
+    
+    fn dungeon_key(zone: ZoneCoord, entrance: LocalCoord) -> String {
+        format!("{},{}:{},{}", zone.x, zone.y, entrance.x, entrance.y)
+    }
+    
     pub fn generate_zone(&mut self, coord: ZoneCoord) -> Result<()> {
         if self.database.zones.contains_key(&coord) {
             return Ok(()); // Already exists
         }
+        self.check_in_bounds(coord)?;
         
         // Get adjacent zones for context
         let adjacent_zones: HashMap<ZoneCoord, WorldZone> = coord.adjacent_zones()
@@ -122,13 +311,17 @@ impl WorldManager {
         &self.database.metadata
     }
     
+    pub fn manifest(&self) -> &WorldManifest {
+        &self.manifest
+    }
+    
     pub fn pregenerate_area(&mut self, center: ZoneCoord, radius: i32) -> Result<Vec<ZoneCoord>> {
         let mut generated = Vec::new();
         
         for x in (center.x - radius)..=(center.x + radius) {
             for y in (center.y - radius)..=(center.y + radius) {
                 let coord = ZoneCoord::new(x, y);
-                if !self.database.zones.contains_key(&coord) {
+                if self.bounds().contains(coord) && !self.database.zones.contains_key(&coord) {
                     self.generate_zone(coord)?;
                     generated.push(coord);
                 }
@@ -182,11 +375,25 @@ impl WorldManager {
     }
     
     fn load_database(path: &Path) -> Result<WorldDatabase> {
-        let content = fs::read_to_string(path)
-            .with_context(|| format!("Failed to read world database from {}", path.display()))?;
+        let mut database = match Self::read_database(path) {
+            Ok(database) => database,
+            // A damaged save falls back to the copy kept by the previous save
+            Err(error) => {
+                let backup_path = Self::backup_path(path);
+                Self::read_database(&backup_path).map_err(|_| error.context(format!(
+                    "{} is damaged and there is no usable backup at {}. Move it aside to generate the world again from its seed",
+                    path.display(), backup_path.display()
+                )))?
+            }
+        };
         
-        let mut database: WorldDatabase = serde_json::from_str(&content)
-            .with_context(|| "Failed to parse world database JSON")?;
+        // Format 1 kept terrain column by column
+        if database.format_version < 2 {
+            for zone in database.zones.values_mut() {
+                zone.terrain.transpose();
+            }
+        }
+        database.format_version = WORLD_FORMAT_VERSION;
         
         // Update last accessed time
         database.metadata.last_accessed = chrono::Utc::now();
@@ -194,6 +401,18 @@ impl WorldManager {
         Ok(database)
     }
     
+    fn read_database(path: &Path) -> Result<WorldDatabase> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read world database from {}", path.display()))?;
+        
+        serde_json::from_str(&content)
+            .with_context(|| "Failed to parse world database JSON")
+    }
+    
+    fn backup_path(path: &Path) -> PathBuf {
+        path.with_extension("json.bak")
+    }
+    
     fn save_database(&self) -> Result<()> {
         // Create directory if it doesn't exist
         if let Some(parent) = self.save_path.parent() {
@@ -205,15 +424,9 @@ impl WorldManager {
         let content = serde_json::to_string_pretty(&self.database)
             .with_context(|| "Failed to serialize world database")?;
         
-        // Write to temporary file first, then rename (atomic operation)
-        let temp_path = self.save_path.with_extension("tmp");
-        fs::write(&temp_path, content)
-            .with_context(|| format!("Failed to write world database to {}", temp_path.display()))?;
-        
-        fs::rename(&temp_path, &self.save_path)
-            .with_context(|| format!("Failed to rename {} to {}", temp_path.display(), self.save_path.display()))?;
-        
-        Ok(())
+        // Written atomically, and the previous save becomes the backup
+        crate::database::write_atomically(&self.save_path, &content, Some(&Self::backup_path(&self.save_path)))
+            .with_context(|| format!("Failed to save world database to {}", self.save_path.display()))
     }
     
     pub fn export_zone_map(&self, center: ZoneCoord, radius: i32) -> Result<String> {
@@ -347,4 +560,194 @@ impl Drop for WorldManager {
         // Auto-save when the manager is dropped
         let _ = self.save_if_dirty();
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::{PoiType, PointOfInterest, Treasure};
+
+    fn scratch_directory() -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("warlords-world-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn zones_survive_a_save_and_reload() {
+        let directory = scratch_directory();
+        let coord = ZoneCoord::new(3, 4);
+        let mut zone = {
+            let mut manager = WorldManager::new("roundtrip", 42, &directory, WorldBounds::default()).unwrap();
+            manager.get_zone(coord).unwrap().clone()
+        };
+        zone.roads.connections.insert(LocalCoord::new(1, 2), vec![LocalCoord::new(3, 4)]);
+        {
+            let mut manager = WorldManager::new("roundtrip", 42, &directory, WorldBounds::default()).unwrap();
+            manager.update_zone(zone.clone());
+            manager.save().unwrap();
+        }
+
+        let mut manager = WorldManager::new("roundtrip", 42, &directory, WorldBounds::default()).unwrap();
+        let reloaded = manager.get_zone_if_exists(coord).expect("zone was not saved").clone();
+        assert_eq!(reloaded.settlements.len(), zone.settlements.len());
+        assert_eq!(reloaded.points_of_interest.len(), zone.points_of_interest.len());
+        assert_eq!(reloaded.roads.connections.get(&LocalCoord::new(1, 2)), Some(&vec![LocalCoord::new(3, 4)]));
+        assert!(manager.get_zone(coord).is_ok());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn looted_treasure_stays_looted_after_a_reload() {
+        let directory = scratch_directory();
+        let coord = ZoneCoord::new(1, 1);
+        let position = LocalCoord::new(5, 5);
+        {
+            let mut manager = WorldManager::new("looted", 7, &directory, WorldBounds::default()).unwrap();
+            let mut zone = manager.get_zone(coord).unwrap().clone();
+            zone.points_of_interest.push(PointOfInterest {
+                position,
+                poi_type: PoiType::AncientRuins,
+                name: "Sunken Vault".to_string(),
+                description: String::new(),
+                explored: false,
+                treasure: Some(Treasure { items: vec!["Silver ring".to_string()], gold: 30, experience: 10, hidden: true }),
+                encounter: None,
+                difficulty: 1,
+                treasure_taken: false,
+                encounter_resolved: false,
+                used_altars: Vec::new(),
+            });
+            let vault = zone.points_of_interest.last_mut().unwrap();
+            assert_eq!(vault.take_treasure().map(|treasure| treasure.gold), Some(30));
+            manager.update_zone(zone);
+            manager.save().unwrap();
+        }
+
+        let mut manager = WorldManager::new("looted", 7, &directory, WorldBounds::default()).unwrap();
+        let mut zone = manager.get_zone(coord).unwrap().clone();
+        let vault = zone.points_of_interest.iter_mut().find(|poi| poi.position == position).expect("vault was not saved");
+        assert!(vault.explored);
+        assert!(vault.treasure_taken);
+        assert!(vault.take_treasure().is_none());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn explored_dungeon_maps_survive_a_reload() {
+        let directory = scratch_directory();
+        let zone = ZoneCoord::new(2, 2);
+        let entrance = LocalCoord::new(8, 9);
+        let map: HashMap<i32, Vec<Vec<bool>>> = [(-1, vec![vec![true, false], vec![false, true]])].into_iter().collect();
+        {
+            let mut manager = WorldManager::new("mapped", 9, &directory, WorldBounds::default()).unwrap();
+            manager.store_dungeon_map(zone, entrance, map.clone());
+            manager.save().unwrap();
+        }
+
+        let manager = WorldManager::new("mapped", 9, &directory, WorldBounds::default()).unwrap();
+        assert_eq!(manager.get_dungeon_map(zone, entrance), Some(&map));
+        assert!(manager.get_dungeon_map(zone, LocalCoord::new(0, 0)).is_none());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn zones_outside_the_world_are_refused_without_generating() {
+        let directory = scratch_directory();
+        let bounds = WorldBounds::new(3, 3);
+        let mut manager = WorldManager::new("edge", 5, &directory, bounds).unwrap();
+        // Walk east along the top row, then keep going past the edge
+        for x in 0..6 {
+            let result = manager.get_zone(ZoneCoord::new(x, 0)).map(|_| ());
+            assert_eq!(result.is_ok(), x < 3);
+        }
+        assert!(manager.get_zone(ZoneCoord::new(-1, 0)).is_err());
+        assert!(manager.generate_zone(ZoneCoord::new(0, 7)).is_err());
+        assert_eq!(manager.get_statistics().zones_generated, 3);
+        drop(manager);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn saved_bounds_come_from_the_manifest() {
+        let directory = scratch_directory();
+        assert_eq!(WorldManager::saved_bounds("small", &directory).unwrap(), WorldBounds::default());
+        drop(WorldManager::new("small", 3, &directory, WorldBounds::new(4, 2)).unwrap());
+        assert_eq!(WorldManager::saved_bounds("small", &directory).unwrap(), WorldBounds::new(4, 2));
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn a_truncated_world_save_loads_from_its_backup() {
+        let directory = scratch_directory();
+        let coord = ZoneCoord::new(2, 2);
+        {
+            let mut manager = WorldManager::new("cut", 11, &directory, WorldBounds::default()).unwrap();
+            manager.get_zone(coord).unwrap();
+            manager.save().unwrap();
+            // The second save keeps the first one as the backup
+            manager.save().unwrap();
+        }
+        let save_path = directory.join("cut_world.json");
+        let content = fs::read_to_string(&save_path).unwrap();
+        fs::write(&save_path, &content[..content.len() / 2]).unwrap();
+
+        let manager = WorldManager::new("cut", 11, &directory, WorldBounds::default()).unwrap();
+        assert!(manager.get_zone_if_exists(coord).is_some());
+        drop(manager);
+
+        // Without a backup the damage is reported instead of starting a fresh world
+        fs::write(&save_path, &content[..content.len() / 2]).unwrap();
+        fs::remove_file(directory.join("cut_world.json.bak")).unwrap();
+        assert!(WorldManager::new("cut", 11, &directory, WorldBounds::default()).is_err());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn format_one_terrain_is_turned_the_right_way_on_load() {
+        let directory = scratch_directory();
+        let coord = ZoneCoord::new(1, 2);
+        let zone = WorldManager::new("old", 13, &directory, WorldBounds::default()).unwrap()
+            .get_zone(coord).unwrap().clone();
+
+        // Write the zone the way format 1 did, with no version in the database
+        let mut legacy_zone = zone.clone();
+        legacy_zone.terrain.transpose();
+        let mut database = serde_json::to_value(WorldDatabase {
+            master_seed: 13,
+            zones: [(coord, legacy_zone)].into_iter().collect(),
+            metadata: WorldMetadata {
+                created_at: chrono::Utc::now(),
+                last_accessed: chrono::Utc::now(),
+                total_zones_generated: 1,
+                world_name: "old".to_string(),
+                version: "0.1.0".to_string(),
+                bounds: WorldBounds::default(),
+            },
+            dungeon_maps: HashMap::new(),
+            format_version: 1,
+        }).unwrap();
+        database.as_object_mut().unwrap().remove("format_version");
+        fs::write(directory.join("old_world.json"), database.to_string()).unwrap();
+        let manifest_path = WorldManifest::path(&directory, "old");
+        let mut manifest = WorldManifest::load(&manifest_path).unwrap();
+        manifest.format_version = 1;
+        manifest.save(&manifest_path).unwrap();
+
+        let mut manager = WorldManager::new("old", 13, &directory, WorldBounds::default()).unwrap();
+        let reloaded = manager.get_zone_if_exists(coord).unwrap().clone();
+        for (x, y) in [(0, 5), (5, 0), (3, 17), (17, 3)] {
+            let pos = LocalCoord::new(x, y);
+            assert_eq!(reloaded.terrain.get_tile(pos).terrain_type, zone.terrain.get_tile(pos).terrain_type);
+            assert_eq!(reloaded.terrain.get_tile(pos).elevation, zone.terrain.get_tile(pos).elevation);
+        }
+        assert_eq!(WorldManifest::load(&manifest_path).unwrap().format_version, WORLD_FORMAT_VERSION);
+
+        // Saved again it's format 2 and isn't turned a second time
+        manager.save().unwrap();
+        drop(manager);
+        let manager = WorldManager::new("old", 13, &directory, WorldBounds::default()).unwrap();
+        let pos = LocalCoord::new(3, 17);
+        assert_eq!(manager.get_zone_if_exists(coord).unwrap().terrain.get_tile(pos).elevation, zone.terrain.get_tile(pos).elevation);
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
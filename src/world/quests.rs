@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use super::{NPCService, NPC};
+
+// Creatures townsfolk want thinned out, all of them met in the wilds or below ground
+const BOUNTY_CREATURES: [&str; 6] = ["Wolf", "Goblin", "Bandit", "Skeleton", "Spider", "Orc"];
+const GOLD_PER_KILL: u32 = 15;
+const XP_PER_KILL: u32 = 25;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum QuestObjective {
+    Slay { creature: String, count: u32 }, // Defeat this many of a creature, matched by name
+}
+
+impl QuestObjective {
+    pub fn describe(&self) -> String {
+        match self {
+            QuestObjective::Slay { creature, count } => format!("Defeat {} {}", count, plural(creature, *count)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestReward {
+    pub gold: u32,
+    pub experience: u32,
+    #[serde(default)]
+    pub item: Option<String>,
+}
+
+impl QuestReward {
+    pub fn describe(&self) -> String {
+        let mut parts = vec![format!("{} gold", self.gold), format!("{} XP", self.experience)];
+        if let Some(item) = &self.item {
+            parts.push(item.clone());
+        }
+        parts.join(", ")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Quest {
+    pub id: String,
+    pub giver: String, // Name of the NPC who handed it out
+    pub objective: QuestObjective,
+    pub reward: QuestReward,
+    pub progress: u32,
+}
+
+impl Quest {
+    // The job an NPC who hands out work has going. Always the same one for the
+    // same NPC, so it can't be rerolled by asking again.
+    pub fn offered_by(npc: &NPC) -> Option<Self> {
+        if !npc.services.iter().any(|service| matches!(service, NPCService::Quests)) {
+            return None;
+        }
+        let seed = npc.name.bytes().map(|byte| byte as usize).sum::<usize>();
+        let creature = BOUNTY_CREATURES[seed % BOUNTY_CREATURES.len()];
+        let count = 3 + (seed / BOUNTY_CREATURES.len()) as u32 % 3;
+        Some(Self {
+            id: format!("{}:slay:{}", npc.name, creature.to_lowercase()),
+            giver: npc.name.clone(),
+            objective: QuestObjective::Slay { creature: creature.to_string(), count },
+            reward: QuestReward {
+                gold: count * GOLD_PER_KILL,
+                experience: count * XP_PER_KILL,
+                item: (count >= 5).then(|| "Health Potion".to_string()),
+            },
+            progress: 0,
+        })
+    }
+
+    // Count a defeated foe towards the objective. Returns true when it counted.
+    pub fn record_kill(&mut self, foe_name: &str) -> bool {
+        match &self.objective {
+            QuestObjective::Slay { creature, count } if self.progress < *count && foe_name.contains(creature.as_str()) => {
+                self.progress += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        match &self.objective {
+            QuestObjective::Slay { count, .. } => self.progress >= *count,
+        }
+    }
+
+    pub fn describe_progress(&self) -> String {
+        match &self.objective {
+            QuestObjective::Slay { creature, count } => format!("{} slain: {}/{}", plural(creature, 2), self.progress, count),
+        }
+    }
+}
+
+fn plural(creature: &str, count: u32) -> String {
+    match (creature, count) {
+        (_, 1) => creature.to_string(),
+        ("Wolf", _) => "Wolves".to_string(),
+        _ => format!("{}s", creature),
+    }
+}
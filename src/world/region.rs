@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use super::ZoneCoord;
+
+pub const REGION_SIZE: i32 = 4; // Regions span REGION_SIZE x REGION_SIZE zones
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RegionCoord {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl RegionCoord {
+    pub fn from_zone(zone: ZoneCoord) -> Self {
+        Self {
+            x: zone.x.div_euclid(REGION_SIZE),
+            y: zone.y.div_euclid(REGION_SIZE),
+        }
+    }
+
+    pub fn contains_zone(&self, zone: ZoneCoord) -> bool {
+        Self::from_zone(zone) == *self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Region {
+    pub coord: RegionCoord,
+    pub name: String,
+    pub region_type: RegionType,
+    pub faction: String, // Owning faction, used as the reputation key
+    pub ruler: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RegionType {
+    Kingdom,
+    Duchy,
+    Barony,
+    FreeMarch,
+    Wildlands,
+}
+
+impl RegionType {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            RegionType::Kingdom => "Kingdom",
+            RegionType::Duchy => "Duchy",
+            RegionType::Barony => "Barony",
+            RegionType::FreeMarch => "Free March",
+            RegionType::Wildlands => "Wildlands",
+        }
+    }
+
+    pub fn ruler_title(&self) -> &'static str {
+        match self {
+            RegionType::Kingdom => "King",
+            RegionType::Duchy => "Duke",
+            RegionType::Barony => "Baron",
+            RegionType::FreeMarch => "Marshal",
+            RegionType::Wildlands => "Warlord",
+        }
+    }
+}
+
+// How a faction regards the player, derived from reputation (-100..=100)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Standing {
+    Hated,
+    Disliked,
+    Neutral,
+    Liked,
+    Honored,
+}
+
+impl Standing {
+    pub fn from_reputation(reputation: i32) -> Self {
+        match reputation {
+            i32::MIN..=-50 => Standing::Hated,
+            -49..=-15 => Standing::Disliked,
+            -14..=14 => Standing::Neutral,
+            15..=49 => Standing::Liked,
+            _ => Standing::Honored,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Standing::Hated => "Hated",
+            Standing::Disliked => "Disliked",
+            Standing::Neutral => "Neutral",
+            Standing::Liked => "Liked",
+            Standing::Honored => "Honored",
+        }
+    }
+
+    // Percentage applied to settlement prices
+    pub fn price_modifier(&self) -> i32 {
+        match self {
+            Standing::Hated => 50,
+            Standing::Disliked => 20,
+            Standing::Neutral => 0,
+            Standing::Liked => -10,
+            Standing::Honored => -20,
+        }
+    }
+}
+
+pub struct RegionGenerator {
+    name_roots: Vec<&'static str>,
+    house_names: Vec<&'static str>,
+    ruler_names: Vec<&'static str>,
+}
+
+impl Default for RegionGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegionGenerator {
+    pub fn new() -> Self {
+        Self {
+            name_roots: vec![
+                "Aldmere", "Brackenfell", "Carrowdun", "Dunmarrow", "Eldhollow", "Fenwyck",
+                "Greymoor", "Harrowvale", "Ironreach", "Kestrel", "Lowmarch", "Morrowind",
+                "Northwatch", "Oakenshield", "Ravensgate", "Stormhold", "Thornwood", "Westerfold",
+            ],
+            house_names: vec![
+                "Ashford", "Blackwood", "Crane", "Dawnbreaker", "Everhart", "Falkner",
+                "Greaves", "Hollis", "Ironside", "Lockwood", "Marsh", "Vance",
+            ],
+            ruler_names: vec![
+                "Aldric", "Bertrand", "Cedric", "Edmund", "Gareth", "Isolde",
+                "Leofric", "Matilda", "Osric", "Rowena", "Sigrid", "Wulfric",
+            ],
+        }
+    }
+
+    // Regions are derived from the master seed so they never need saving
+    pub fn generate(&self, coord: RegionCoord, master_seed: u64) -> Region {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        use std::hash::{Hash, Hasher};
+        master_seed.hash(&mut hasher);
+        "region".hash(&mut hasher);
+        coord.hash(&mut hasher);
+        let mut rng = ChaCha8Rng::seed_from_u64(hasher.finish());
+
+        let region_type = match rng.gen_range(0..10) {
+            0 => RegionType::Kingdom,
+            1..=3 => RegionType::Duchy,
+            4..=6 => RegionType::Barony,
+            7..=8 => RegionType::FreeMarch,
+            _ => RegionType::Wildlands,
+        };
+
+        let root = self.name_roots[rng.gen_range(0..self.name_roots.len())];
+        let house = self.house_names[rng.gen_range(0..self.house_names.len())];
+        let ruler_name = self.ruler_names[rng.gen_range(0..self.ruler_names.len())];
+
+        let name = match region_type {
+            RegionType::Wildlands => format!("The {} Wilds", root),
+            _ => format!("{} of {}", region_type.get_name(), root),
+        };
+
+        let faction = match region_type {
+            RegionType::Wildlands => format!("{}'s Horde", ruler_name),
+            RegionType::FreeMarch => format!("Free Company of {}", root),
+            _ => format!("House {}", house),
+        };
+
+        Region {
+            coord,
+            name,
+            region_type: region_type.clone(),
+            faction,
+            ruler: format!("{} {}", region_type.ruler_title(), ruler_name),
+        }
+    }
+}
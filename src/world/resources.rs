@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use super::{LocalCoord, TerrainType, ZoneCoord};
+
+pub const RESOURCE_REGROW_HOURS: u32 = 24; // One gathering's worth grows back per day
+pub const GATHER_HOURS: u32 = 1;
+pub const GATHER_COOLDOWN_HOURS: u32 = 6; // A spot just gathered from has nothing new to give for a while
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ResourceNode {
+    harvests: u32,    // Gatherings not yet grown back
+    last_update: u32, // Hour the regrowth was last counted up to
+}
+
+// Tiles the character has gathered from, so a spot runs dry and recovers over time.
+// Untouched tiles aren't stored at all.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResourceNodes {
+    nodes: HashMap<String, ResourceNode>, // "zx,zy:x,y" keys keep the JSON map valid
+    #[serde(default, with = "super::persistence::map_as_list")]
+    last_gathered_turn: HashMap<(ZoneCoord, LocalCoord), u32>, // Hour each tile was last gathered from
+}
+
+impl ResourceNodes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // How many times a tile can be gathered from when untouched. Fertile land
+    // holds more, barren terrain less.
+    pub fn capacity(terrain_type: &TerrainType, fertility: f32) -> u32 {
+        let terrain_bonus: i32 = match terrain_type {
+            TerrainType::Forest | TerrainType::Grassland | TerrainType::Lake | TerrainType::River => 1,
+            TerrainType::Desert | TerrainType::Snow | TerrainType::Tundra | TerrainType::Ocean => -1,
+            _ => 0,
+        };
+        (1 + (fertility * 4.0).round() as i32 + terrain_bonus).max(1) as u32
+    }
+
+    pub fn remaining(&self, zone: ZoneCoord, pos: LocalCoord, capacity: u32, now: u32) -> u32 {
+        let harvests = self.nodes.get(&Self::node_key(zone, pos))
+            .map(|node| node.harvests.saturating_sub(now.saturating_sub(node.last_update) / RESOURCE_REGROW_HOURS))
+            .unwrap_or(0);
+        capacity.saturating_sub(harvests)
+    }
+
+    // Take one gathering from a tile. Returns false when it has been stripped bare.
+    pub fn harvest(&mut self, zone: ZoneCoord, pos: LocalCoord, capacity: u32, now: u32) -> bool {
+        if self.remaining(zone, pos, capacity, now) == 0 {
+            return false;
+        }
+
+        let node = self.nodes.entry(Self::node_key(zone, pos))
+            .or_insert(ResourceNode { harvests: 0, last_update: now });
+        let regrown = now.saturating_sub(node.last_update) / RESOURCE_REGROW_HOURS;
+        node.harvests = node.harvests.saturating_sub(regrown) + 1;
+        node.last_update += regrown * RESOURCE_REGROW_HOURS;
+        true
+    }
+
+    // Gathering the same tile again straight away is refused, however much it still holds
+    pub fn gathered_recently(&self, zone: ZoneCoord, pos: LocalCoord, now: u32) -> bool {
+        self.last_gathered_turn.get(&(zone, pos))
+            .is_some_and(|&turn| now.saturating_sub(turn) < GATHER_COOLDOWN_HOURS)
+    }
+
+    pub fn mark_gathered(&mut self, zone: ZoneCoord, pos: LocalCoord, now: u32) {
+        self.last_gathered_turn.insert((zone, pos), now);
+    }
+
+    // Forget tiles that have fully grown back
+    pub fn prune(&mut self, now: u32) {
+        self.nodes.retain(|_, node| node.harvests > now.saturating_sub(node.last_update) / RESOURCE_REGROW_HOURS);
+        self.last_gathered_turn.retain(|_, turn| now.saturating_sub(*turn) < GATHER_COOLDOWN_HOURS);
+    }
+
+    fn node_key(zone: ZoneCoord, pos: LocalCoord) -> String {
+        format!("{},{}:{},{}", zone.x, zone.y, pos.x, pos.y)
+    }
+}
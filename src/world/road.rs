@@ -10,6 +10,7 @@ use super::{ZoneCoord, LocalCoord, WorldCoord, WorldZone, Settlement, TerrainMap
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoadNetwork {
     pub roads: Vec<Road>,
+    #[serde(with = "super::persistence::map_as_list")]
     pub connections: HashMap<LocalCoord, Vec<LocalCoord>>,
     pub zone_exits: Vec<ZoneExit>,
 }
@@ -21,6 +22,8 @@ pub struct Road {
     pub condition: f32,
     pub width: u32,
     pub traffic_level: TrafficLevel,
+    #[serde(default)]
+    pub bridges: Vec<LocalCoord>, // Path tiles that cross water
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -96,7 +99,7 @@ impl RoadGenerator {
         Self
     }
     
-    pub fn generate(&self, _zone_coord: ZoneCoord, settlements: &[Settlement], _adjacent_zones: &HashMap<ZoneCoord, WorldZone>, _terrain: &TerrainMap, _rng: &mut ChaCha8Rng) -> RoadNetwork {
+    pub fn generate(&self, _zone_coord: ZoneCoord, settlements: &[Settlement], _adjacent_zones: &HashMap<ZoneCoord, WorldZone>, terrain: &TerrainMap, _rng: &mut ChaCha8Rng) -> RoadNetwork {
         let mut roads = Vec::new();
         let connections = HashMap::new();
         let zone_exits = Vec::new();
@@ -122,12 +125,16 @@ impl RoadGenerator {
                     path.push(current);
                 }
                 
+                // Bridge any water the road crosses so the network stays connected
+                let bridges = self.place_bridges(&path, terrain);
+                
                 roads.push(Road {
                     path,
                     road_type: RoadType::Path,
                     condition: 0.8,
                     width: 2,
                     traffic_level: TrafficLevel::Light,
+                    bridges,
                 });
             }
         }
@@ -184,12 +191,14 @@ impl RoadGenerator {
                     let width = self.calculate_road_width(&road_type);
                     let traffic_level = self.determine_traffic_level(&road_type, &settlements[from_idx], &settlements[to_idx]);
                     
+                    let bridges = self.place_bridges(&path, terrain);
                     roads.push(Road {
                         path,
                         road_type,
                         condition,
                         width,
                         traffic_level,
+                        bridges,
                     });
                 }
                 
@@ -254,12 +263,14 @@ impl RoadGenerator {
         let width = self.calculate_road_width(&road_type);
         let traffic_level = self.determine_traffic_level(&road_type, source_settlement, target_settlement);
         
+        let bridges = self.place_bridges(&path, terrain);
         let road = Road {
             path,
             road_type: road_type.clone(),
             condition,
             width,
             traffic_level,
+            bridges,
         };
         
         let exit = ZoneExit {
@@ -341,6 +352,13 @@ impl RoadGenerator {
         None // No path found
     }
     
+    fn place_bridges(&self, path: &[LocalCoord], terrain: &TerrainMap) -> Vec<LocalCoord> {
+        path.iter()
+            .filter(|pos| terrain.is_valid_coord(**pos) && terrain.get_tile(**pos).terrain_type.is_water())
+            .copied()
+            .collect()
+    }
+    
     fn heuristic(&self, from: LocalCoord, to: LocalCoord) -> f32 {
         let dx = (from.x - to.x).abs() as f32;
         let dy = (from.y - to.y).abs() as f32;
@@ -462,12 +480,14 @@ impl RoadGenerator {
                         if !already_connected {
                             if let Some(path) = self.find_path(settlement.position, other_settlement.position, terrain) {
                                 let road_type = self.determine_road_type(settlement, other_settlement, rng);
+                                let bridges = self.place_bridges(&path, terrain);
                                 roads.push(Road {
                                     path,
                                     road_type: road_type.clone(),
                                     condition: rng.gen_range(0.6..0.9),
                                     width: self.calculate_road_width(&road_type),
                                     traffic_level: self.determine_traffic_level(&road_type, settlement, other_settlement),
+                                    bridges,
                                 });
                             }
                         }
@@ -529,6 +549,10 @@ impl RoadNetwork {
         self.roads.iter().find(|road| road.path.contains(&position))
     }
     
+    pub fn is_bridge_at(&self, position: LocalCoord) -> bool {
+        self.roads.iter().any(|road| road.bridges.contains(&position))
+    }
+    
     pub fn find_route(&self, start: LocalCoord, end: LocalCoord) -> Option<Vec<LocalCoord>> {
         // Simple pathfinding using the road network
         let mut queue = std::collections::VecDeque::new();
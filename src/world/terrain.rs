@@ -80,7 +80,7 @@ impl<'a> TerrainGenerator<'a> {
                 let fertility = self.calculate_fertility(&terrain_type, moisture, temperature);
                 let traversal_cost = self.calculate_traversal_cost(&terrain_type);
                 
-                tiles[x as usize][y as usize] = TerrainTile {
+                tiles[y as usize][x as usize] = TerrainTile {
                     terrain_type,
                     elevation,
                     moisture,
@@ -239,7 +239,7 @@ impl<'a> TerrainGenerator<'a> {
                     if x >= 0 && x < ZONE_SIZE && y >= 0 && y < ZONE_SIZE {
                         let distance = ((dx * dx + dy * dy) as f32).sqrt();
                         if distance <= radius as f32 {
-                            let tile = &mut tiles[x as usize][y as usize];
+                            let tile = &mut tiles[y as usize][x as usize];
                             if tile.elevation > 0.2 {
                                 tile.terrain_type = TerrainType::Lake;
                                 tile.elevation = 0.25;
@@ -258,8 +258,8 @@ impl<'a> TerrainGenerator<'a> {
         for x in 1..(ZONE_SIZE - 1) as usize {
             for y in 1..(ZONE_SIZE - 1) as usize {
                 let neighbors = [
-                    &tiles[x-1][y], &tiles[x+1][y],
-                    &tiles[x][y-1], &tiles[x][y+1],
+                    &tiles[y][x-1], &tiles[y][x+1],
+                    &tiles[y-1][x], &tiles[y+1][x],
                 ];
                 
                 // Count neighbor terrain types
@@ -270,11 +270,11 @@ impl<'a> TerrainGenerator<'a> {
                 
                 // If current tile is isolated, change it to most common neighbor
                 if let Some((most_common, count)) = terrain_counts.iter().max_by_key(|(_, &count)| count) {
-                    if *count >= 3 && tiles[x][y].terrain_type != **most_common {
+                    if *count >= 3 && tiles[y][x].terrain_type != **most_common {
                         // Only change if it makes sense (similar elevation)
                         let avg_elevation: f32 = neighbors.iter().map(|n| n.elevation).sum::<f32>() / neighbors.len() as f32;
-                        if (tiles[x][y].elevation - avg_elevation).abs() < 0.2 {
-                            tiles[x][y].terrain_type = (*most_common).clone();
+                        if (tiles[y][x].elevation - avg_elevation).abs() < 0.2 {
+                            tiles[y][x].terrain_type = (*most_common).clone();
                         }
                     }
                 }
@@ -284,12 +284,25 @@ impl<'a> TerrainGenerator<'a> {
 }
 
 impl TerrainMap {
+    // Tiles are stored row-major: tiles[y][x]
     pub fn get_tile(&self, coord: LocalCoord) -> &TerrainTile {
-        &self.tiles[coord.x as usize][coord.y as usize]
+        &self.tiles[coord.y as usize][coord.x as usize]
     }
     
     pub fn get_tile_mut(&mut self, coord: LocalCoord) -> &mut TerrainTile {
-        &mut self.tiles[coord.x as usize][coord.y as usize]
+        &mut self.tiles[coord.y as usize][coord.x as usize]
+    }
+    
+    // Turn tiles stored column-major (tiles[x][y]), as worlds saved in format 1 were, into rows
+    pub fn transpose(&mut self) {
+        let height = self.tiles.first().map_or(0, Vec::len);
+        let mut rows: Vec<Vec<TerrainTile>> = (0..height).map(|_| Vec::with_capacity(self.tiles.len())).collect();
+        for column in std::mem::take(&mut self.tiles) {
+            for (row, tile) in rows.iter_mut().zip(column) {
+                row.push(tile);
+            }
+        }
+        self.tiles = rows;
     }
     
     pub fn is_valid_coord(&self, coord: LocalCoord) -> bool {
@@ -354,6 +367,39 @@ impl Default for TerrainTile {
 }
 
 impl TerrainType {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            TerrainType::Ocean => "Ocean",
+            TerrainType::Lake => "Lake",
+            TerrainType::River => "River",
+            TerrainType::Plains => "Plains",
+            TerrainType::Grassland => "Grassland",
+            TerrainType::Forest => "Forest",
+            TerrainType::Hill => "Hills",
+            TerrainType::Mountain => "Mountain",
+            TerrainType::Desert => "Desert",
+            TerrainType::Swamp => "Swamp",
+            TerrainType::Snow => "Snowfield",
+            TerrainType::Tundra => "Tundra",
+        }
+    }
+
+    pub fn is_water(&self) -> bool {
+        matches!(self, TerrainType::Ocean | TerrainType::Lake | TerrainType::River)
+    }
+
+    // Odds of something stumbling onto a camp here before the night is through
+    pub fn camp_encounter_chance(&self) -> f32 {
+        match self {
+            TerrainType::Ocean | TerrainType::Lake => 0.0,
+            TerrainType::Plains | TerrainType::Grassland => 0.05,
+            TerrainType::River => 0.08,
+            TerrainType::Hill | TerrainType::Snow | TerrainType::Tundra => 0.1,
+            TerrainType::Forest | TerrainType::Desert => 0.12,
+            TerrainType::Swamp | TerrainType::Mountain => 0.25,
+        }
+    }
+    
     pub fn get_ascii_char(&self) -> char {
         match self {
             TerrainType::Ocean => '~',
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+pub const START_HOUR: u32 = 8;      // A new adventure begins in the morning
+pub const MORNING_HOUR: u32 = 7;    // When a night's rest ends
+pub const STEPS_PER_HOUR: u32 = 12; // Steps of travel that take an hour
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeOfDay {
+    Dawn,
+    Day,
+    Dusk,
+    Night,
+}
+
+impl TimeOfDay {
+    pub fn from_hour(hour: u32) -> Self {
+        match hour % 24 {
+            5..=6 => TimeOfDay::Dawn,
+            7..=17 => TimeOfDay::Day,
+            18..=20 => TimeOfDay::Dusk,
+            _ => TimeOfDay::Night,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            TimeOfDay::Dawn => "Dawn",
+            TimeOfDay::Day => "Day",
+            TimeOfDay::Dusk => "Dusk",
+            TimeOfDay::Night => "Night",
+        }
+    }
+
+    // Light out in the open, on the same 0-10 scale as dungeon floors
+    pub fn ambient_light(&self) -> u8 {
+        match self {
+            TimeOfDay::Day => 10,
+            TimeOfDay::Dawn | TimeOfDay::Dusk => 6,
+            TimeOfDay::Night => 1,
+        }
+    }
+
+    // The dead walk more freely after dark
+    pub fn undead_abroad(&self) -> bool {
+        matches!(self, TimeOfDay::Dusk | TimeOfDay::Night)
+    }
+
+    pub fn arrival_message(&self) -> &'static str {
+        match self {
+            TimeOfDay::Dawn => "🌄 The sky pales as dawn breaks.",
+            TimeOfDay::Day => "☀️ The sun is up. It's full day.",
+            TimeOfDay::Dusk => "🌇 The sun sinks low as dusk settles in.",
+            TimeOfDay::Night => "🌙 Night falls. Without a light you'll see little.",
+        }
+    }
+}
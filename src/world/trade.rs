@@ -0,0 +1,70 @@
+use super::{NPCDisposition, Standing};
+
+pub const SELL_PERCENT: u32 = 40; // Share of an item's price a merchant pays for it
+const MIN_PRICE_PERCENT: i32 = 10; // Modifiers never push a price below this share
+
+// Where the player is on a merchant's trade screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradeCursor {
+    pub selling: bool, // Browsing the player's pack instead of the merchant's
+    pub selected: u16,
+}
+
+// What an item is worth to a merchant before any haggling
+pub fn base_price(item: &str) -> u32 {
+    let (name, _) = split_stack(item);
+    match name {
+        "Health Potion" => 25,
+        "Spell Point Potion" => 40,
+        "Magic Amulet" => 120,
+        "Iron Sword" => 15,
+        "Rusty Sword" => 3,
+        "Steel Hammer" => 25,
+        "Leather Armor" => 10,
+        "Chain Mail" => 75,
+        "Iron Ingot" => 4,
+        "Hunting Bow" => 25,
+        "Arrows" | "Bolts" => 1,
+        "Blessing Scroll" => 40,
+        "Holy Water" => 20,
+        "Herbal Remedy" => 8,
+        "Tracking Guide" | "Map" => 10,
+        "Lockpicks" => 20,
+        "Stolen Goods" => 15,
+        "Rations" | "Bedroll" => 5,
+        "Rare Minerals" => 12,
+        "Iron Ore" | "Medicinal Herbs" | "Desert Herbs" => 4,
+        "Game Meat" | "Fresh Fish" | "Berries" | "Wild Grain" | "Edible Roots" => 2,
+        "Wood" | "Stone" | "Sand" | "Murky Water" => 1,
+        name if name.contains("Potion") => 25,
+        name if name.contains("Scroll") => 30,
+        name if name.contains("Mail") || name.contains("Armor") => 30,
+        name if name.contains("Sword") || name.contains("Axe") || name.contains("Bow") => 15,
+        _ => 5,
+    }
+}
+
+// Percentage added to what the merchant charges, from how they feel about the
+// player and the player's standing with the local faction
+pub fn trade_modifier(standing: Standing, disposition: &NPCDisposition) -> i32 {
+    standing.price_modifier() + disposition.price_modifier()
+}
+
+pub fn buy_price(item: &str, modifier: i32) -> u32 {
+    let percent = (100 + modifier).max(MIN_PRICE_PERCENT) as u32;
+    (base_price(item) * percent).div_ceil(100).max(1)
+}
+
+// A merchant who charges more also pays less
+pub fn sell_price(item: &str, modifier: i32) -> u32 {
+    let percent = (100 - modifier).max(MIN_PRICE_PERCENT) as u32;
+    base_price(item) * SELL_PERCENT * percent / 10_000
+}
+
+// "Fresh Fish (3)" is three of "Fresh Fish". Anything else is a single item.
+pub fn split_stack(item: &str) -> (&str, u32) {
+    item.strip_suffix(')')
+        .and_then(|rest| rest.rsplit_once(" ("))
+        .and_then(|(name, count)| count.parse().ok().map(|count| (name, count)))
+        .unwrap_or((item, 1))
+}
@@ -0,0 +1,92 @@
+use super::TrapType;
+
+const BASE_TRAP_DIFFICULTY: u32 = 14;
+const TRAP_DIFFICULTY_PER_FLOOR: u32 = 2;
+const KNOWN_TRAP_BONUS: u32 = 6; // Edging past a trap you know about is much easier
+pub const TRAP_POISON_TURNS: u32 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrapEffect {
+    Damage(u32),
+    Poison(u8), // Hit points per tick of the poison
+    Alarm,      // Wakes every creature on the floor
+}
+
+impl TrapType {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            TrapType::Dart => "dart trap",
+            TrapType::Pit => "pit trap",
+            TrapType::Fire => "fire trap",
+            TrapType::Poison => "poison needle",
+            TrapType::Magic => "warding glyph",
+        }
+    }
+
+    // What springing the trap does, rolled once it goes off
+    pub fn roll_effect(&self, floor_number: i32) -> TrapEffect {
+        use rand::Rng;
+        let mut rng = crate::rng::session_rng();
+        let depth = floor_number.unsigned_abs();
+        match self {
+            TrapType::Dart => TrapEffect::Damage(rng.gen_range(1..=4) + depth),
+            TrapType::Pit => TrapEffect::Damage(rng.gen_range(1..=6) + depth),
+            TrapType::Fire => TrapEffect::Damage(rng.gen_range(2..=8) + depth),
+            TrapType::Poison => TrapEffect::Poison(1 + (depth / 2) as u8),
+            TrapType::Magic => TrapEffect::Alarm,
+        }
+    }
+
+    pub fn sprung_message(&self) -> &'static str {
+        match self {
+            TrapType::Dart => "Darts hiss out of the walls!",
+            TrapType::Pit => "The floor gives way beneath you!",
+            TrapType::Fire => "A gout of flame bursts from the floor!",
+            TrapType::Poison => "A needle jabs into your foot!",
+            TrapType::Magic => "A glyph flares and a shrill wail echoes through the halls!",
+        }
+    }
+}
+
+// Traps are better hidden and nastier deeper down
+pub fn trap_difficulty(floor_number: i32) -> u32 {
+    BASE_TRAP_DIFFICULTY + floor_number.unsigned_abs() * TRAP_DIFFICULTY_PER_FLOOR
+}
+
+// d20 plus Awareness against the trap to notice it before stepping on it
+pub fn spots_trap(awareness: f32, roll: u32, difficulty: u32) -> bool {
+    roll + awareness as u32 >= difficulty
+}
+
+// d20 plus Dexterity against the trap to get clear once it springs
+pub fn avoids_trap(dexterity: f32, roll: u32, difficulty: u32, known: bool) -> bool {
+    let bonus = if known { KNOWN_TRAP_BONUS } else { 0 };
+    roll + dexterity as u32 + bonus >= difficulty
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traps_get_harder_deeper_down() {
+        assert_eq!(trap_difficulty(0), BASE_TRAP_DIFFICULTY);
+        assert!(trap_difficulty(-4) > trap_difficulty(-1));
+    }
+
+    #[test]
+    fn a_trap_is_spotted_on_a_roll_that_meets_its_difficulty() {
+        let difficulty = trap_difficulty(-1);
+        assert!(spots_trap(10.0, difficulty - 10, difficulty));
+        assert!(!spots_trap(10.0, difficulty - 11, difficulty));
+    }
+
+    #[test]
+    fn knowing_about_a_trap_turns_a_near_miss_into_an_escape() {
+        let difficulty = trap_difficulty(-2);
+        let borderline = difficulty - 8 - KNOWN_TRAP_BONUS;
+        assert!(!avoids_trap(8.0, borderline, difficulty, false));
+        assert!(avoids_trap(8.0, borderline, difficulty, true));
+        assert!(avoids_trap(8.0, difficulty - 8, difficulty, false));
+    }
+}